@@ -2,13 +2,17 @@ use std::time::Instant;
 
 use crate::input::Action;
 use crate::types::{
-    ChatMessage, ClickTarget, InputMode, MessageRole, Overlay, Panel, Selection, ViewState,
+    ChatMessage, ClickTarget, InputMode, MessageRole, Overlay, Panel, ScrollTarget, Selection,
+    ViewState,
 };
 use crate::views::file_browser;
 use crate::views::fix::FixViewState;
 
 use super::{App, AppCommand};
 
+/// Max entries kept in [`App::yank_registers`].
+const YANK_REGISTER_CAP: usize = 9;
+
 impl App {
     pub fn apply_action(&mut self, action: Action) -> Option<AppCommand> {
         // Reset idle suggestion timer on any non-None action
@@ -48,20 +52,34 @@ impl App {
                 None
             }
             Action::CloseFile => {
-                self.code_content = None;
+                self.code_buffer = None;
                 self.open_file_path = None;
                 self.code_scroll = 0;
                 self.selection = None;
                 self.active_panel = Panel::FileBrowser;
+                self.push_nav_point();
+                None
+            }
+            Action::InsertChar(c) if self.file_browser_filtering => {
+                self.file_browser_filter.push(c);
+                self.file_browser_index = 0;
+                None
+            }
+            Action::DeleteChar if self.file_browser_filtering => {
+                self.file_browser_filter.pop();
+                self.file_browser_index = 0;
                 None
             }
             Action::InsertChar(c) => {
+                self.snapshot_input_undo();
                 self.input.insert(self.input_cursor, c);
                 self.input_cursor += c.len_utf8();
+                self.mention_index = 0;
                 None
             }
             Action::DeleteChar => {
                 if self.input_cursor > 0 {
+                    self.snapshot_input_undo();
                     let mut boundary = self.input_cursor - 1;
                     while !self.input.is_char_boundary(boundary) {
                         boundary -= 1;
@@ -69,6 +87,30 @@ impl App {
                     self.input.remove(boundary);
                     self.input_cursor = boundary;
                 }
+                self.mention_index = 0;
+                None
+            }
+            Action::ToggleFileBrowserFilter => {
+                if self.file_browser_filtering {
+                    self.file_browser_filtering = false;
+                } else if self.active_panel == Panel::FileBrowser {
+                    self.file_browser_filtering = true;
+                    self.file_browser_filter.clear();
+                    self.file_browser_index = 0;
+                }
+                None
+            }
+            Action::ToggleFileBrowserFlatten => {
+                self.file_browser_flatten = !self.file_browser_flatten;
+                self.file_browser_index = 0;
+                None
+            }
+            Action::InputUndo => {
+                self.input_undo();
+                None
+            }
+            Action::InputRedo => {
+                self.input_redo();
                 None
             }
             Action::MoveCursorLeft => {
@@ -103,12 +145,32 @@ impl App {
                 self.try_tab_complete();
                 None
             }
+            Action::MentionUp => {
+                self.mention_index = self.mention_index.saturating_sub(1);
+                None
+            }
+            Action::MentionDown => {
+                let count = self.mention_matches().len();
+                if count > 0 {
+                    self.mention_index = (self.mention_index + 1).min(count - 1);
+                }
+                None
+            }
+            Action::MentionAccept => {
+                self.accept_mention();
+                None
+            }
             Action::ScrollUp => {
                 match self.view_state {
                     ViewState::Scan => {
                         let count = self.filtered_findings_count();
                         self.scan_view.navigate_up();
                         let _ = count; // used for bounds checking inside navigate_up
+                        if self.scan_view.code_view_open
+                            && let Some(cmd) = self.open_selected_finding_file()
+                        {
+                            return Some(cmd);
+                        }
                     }
                     ViewState::Fix => {
                         if self.fix_view.is_single_fix() {
@@ -122,8 +184,13 @@ impl App {
                             self.timeline_view.scroll_offset.saturating_sub(1);
                     }
                     ViewState::Report => {
-                        self.report_view.scroll_offset =
-                            self.report_view.scroll_offset.saturating_sub(1);
+                        if self.report_view.composer_open {
+                            self.report_view.composer_cursor =
+                                self.report_view.composer_cursor.saturating_sub(1);
+                        } else {
+                            self.report_view.scroll_offset =
+                                self.report_view.scroll_offset.saturating_sub(1);
+                        }
                     }
                     ViewState::Passport => {
                         use crate::views::passport::{PassportDetailMode, PassportViewMode};
@@ -177,6 +244,11 @@ impl App {
                     ViewState::Scan => {
                         let count = self.filtered_findings_count();
                         self.scan_view.navigate_down(count);
+                        if self.scan_view.code_view_open
+                            && let Some(cmd) = self.open_selected_finding_file()
+                        {
+                            return Some(cmd);
+                        }
                     }
                     ViewState::Fix => {
                         if self.fix_view.is_single_fix() {
@@ -189,7 +261,14 @@ impl App {
                         self.timeline_view.scroll_offset += 1;
                     }
                     ViewState::Report => {
-                        self.report_view.scroll_offset += 1;
+                        if self.report_view.composer_open {
+                            let max = self.report_sections.len().saturating_sub(1);
+                            if self.report_view.composer_cursor < max {
+                                self.report_view.composer_cursor += 1;
+                            }
+                        } else {
+                            self.report_view.scroll_offset += 1;
+                        }
                     }
                     ViewState::Passport => {
                         use crate::views::passport::{PassportDetailMode, PassportViewMode};
@@ -328,6 +407,8 @@ impl App {
             Action::SubmitInput => {
                 let text = std::mem::take(&mut self.input);
                 self.input_cursor = 0;
+                self.input_undo_stack.clear();
+                self.input_redo_stack.clear();
 
                 if text.trim().is_empty() {
                     return None;
@@ -369,9 +450,9 @@ impl App {
                     // Code search: if in CodeViewer and text doesn't start with /
                     if self.active_panel == Panel::CodeViewer && !text.starts_with('/') {
                         // Treat as code search query
-                        if let Some(content) = &self.code_content {
+                        if let Some(buffer) = &self.code_buffer {
                             let matches =
-                                crate::views::code_viewer::find_search_matches(content, &text);
+                                crate::views::code_viewer::find_search_matches(buffer, &text);
                             self.code_search_current = 0;
                             if !matches.is_empty() {
                                 self.code_scroll = matches[0];
@@ -401,11 +482,14 @@ impl App {
                 None
             }
             Action::SendSelectionToAi => {
-                if let (Some(content), Some(sel)) = (&self.code_content, &self.selection) {
-                    let lines: Vec<&str> = content.lines().collect();
-                    let start = sel.start_line.min(lines.len().saturating_sub(1));
-                    let end = sel.end_line.min(lines.len().saturating_sub(1));
-                    let selected: String = lines[start..=end].join("\n");
+                if let (Some(buffer), Some(sel)) = (&self.code_buffer, &self.selection) {
+                    let last = buffer.line_count().saturating_sub(1);
+                    let start = sel.start_line.min(last);
+                    let end = sel.end_line.min(last);
+                    let selected: String = buffer
+                        .lines_in(start, end + 1)
+                        .collect::<Vec<_>>()
+                        .join("\n");
 
                     let file = self.open_file_path.as_deref().unwrap_or("unknown");
                     let context = format!(
@@ -422,6 +506,47 @@ impl App {
                 }
                 None
             }
+            Action::Yank => {
+                if let (Some(buffer), Some(sel)) = (&self.code_buffer, &self.selection) {
+                    let last = buffer.line_count().saturating_sub(1);
+                    let start = sel.start_line.min(last);
+                    let end = sel.end_line.min(last);
+                    let text: String = buffer
+                        .lines_in(start, end + 1)
+                        .collect::<Vec<_>>()
+                        .join("\n");
+                    let count = end - start + 1;
+
+                    self.yank_registers.insert(0, text);
+                    self.yank_registers.truncate(YANK_REGISTER_CAP);
+                    self.toasts.push(
+                        crate::components::toast::ToastKind::Info,
+                        format!("Yanked {count} lines"),
+                    );
+                }
+                self.input_mode = InputMode::Normal;
+                None
+            }
+            Action::PasteYank => {
+                if let Some(text) = self.yank_registers.first().cloned() {
+                    self.snapshot_input_undo();
+                    let fenced = format!("```\n{text}\n```\n");
+                    self.input.insert_str(self.input_cursor, &fenced);
+                    self.input_cursor += fenced.len();
+                    self.input_mode = InputMode::Insert;
+                    self.active_panel = Panel::Chat;
+                }
+                None
+            }
+            Action::OpenInEditor => {
+                if let Some(path) = self.open_file_path.clone() {
+                    Some(AppCommand::OpenInEditor(path, self.code_scroll + 1))
+                } else {
+                    self.toasts
+                        .push(crate::components::toast::ToastKind::Warning, "No file open");
+                    None
+                }
+            }
             Action::AcceptDiff => {
                 self.active_panel = Panel::Chat;
                 self.messages.push(ChatMessage::new(
@@ -473,7 +598,7 @@ impl App {
                 None
             }
             Action::SwitchView(view) => {
-                self.view_state = view;
+                self.switch_view(view);
                 // Populate Fix view from latest scan when switching to it
                 if view == ViewState::Fix
                     && let Some(scan) = &self.last_scan
@@ -580,6 +705,50 @@ impl App {
                 self.overlay = Overlay::UndoHistory;
                 Some(AppCommand::FetchUndoHistory)
             }
+            Action::ShowChangesFeed => {
+                self.overlay = Overlay::ChangesFeed;
+                None
+            }
+            Action::ShowFloatingChat => {
+                self.overlay = Overlay::FloatingChat;
+                self.input_mode = InputMode::Insert;
+                None
+            }
+            Action::ShowRecentFiles => {
+                self.overlay = Overlay::RecentFiles;
+                self.recent_files_view.entries = self.recent_files.clone();
+                self.recent_files_view.selected = 0;
+                None
+            }
+            Action::JumpBack => self.nav_back(),
+            Action::JumpForward => self.nav_forward(),
+            Action::ToggleBookmark => {
+                self.toggle_bookmark();
+                None
+            }
+            Action::ShowBookmarks => {
+                self.overlay = Overlay::Bookmarks;
+                None
+            }
+            Action::ShowNotifications => {
+                self.show_notifications();
+                None
+            }
+            // Only meaningful while the Notifications overlay is open, and
+            // handled by handle_overlay_action above; outside it, a no-op.
+            Action::CycleNotificationFilter => None,
+            Action::ShowActivityHistory => {
+                self.show_activity_history();
+                None
+            }
+            // Only meaningful while the Activity History overlay is open, and
+            // handled by handle_overlay_action above; outside it, a no-op.
+            Action::CycleActivityFilter => None,
+            // These only apply while the Changes Feed overlay is open, and are
+            // handled by handle_overlay_action above; outside it, they're no-ops.
+            Action::ChangesFeedOpen | Action::ChangesFeedRescan | Action::ChangesFeedIgnoreDir => {
+                None
+            }
             Action::EnterColonMode => {
                 self.input_mode = InputMode::Command;
                 self.colon_mode = true;
@@ -590,7 +759,7 @@ impl App {
             Action::ClickAt(target) => {
                 match target {
                     ClickTarget::ViewTab(view) => {
-                        self.view_state = view;
+                        self.switch_view(view);
                         if view == ViewState::Fix
                             && let Some(scan) = &self.last_scan
                         {
@@ -606,6 +775,60 @@ impl App {
                     ClickTarget::SidebarToggle => {
                         self.sidebar_visible = !self.sidebar_visible;
                     }
+                    ClickTarget::ChatBody => {}
+                    ClickTarget::ScrollbarTrack(_) => {}
+                }
+                None
+            }
+            Action::TextSelectStart(line) => {
+                self.chat_selection = Some(Selection {
+                    start_line: line,
+                    end_line: line,
+                });
+                None
+            }
+            Action::TextSelectExtend(line) => {
+                if let Some(sel) = &mut self.chat_selection {
+                    sel.end_line = line;
+                }
+                None
+            }
+            Action::TextSelectEnd => {
+                if let Some(sel) = self.chat_selection.take() {
+                    let lo = sel.start_line.min(sel.end_line);
+                    let hi = sel.start_line.max(sel.end_line);
+                    let lines = crate::views::chat::plain_lines(self);
+                    let text = lines
+                        .get(lo..=hi.min(lines.len().saturating_sub(1)))
+                        .map(|slice| slice.join("\n"))
+                        .unwrap_or_default();
+                    if !text.is_empty() {
+                        crate::clipboard::copy(&text);
+                        self.toasts.push(
+                            crate::components::toast::ToastKind::Info,
+                            "Copied to clipboard".to_string(),
+                        );
+                    }
+                }
+                None
+            }
+            Action::HoverIndicator(indicator) => {
+                self.hovered_indicator = Some(indicator);
+                None
+            }
+            Action::ClearHover => {
+                self.hovered_indicator = None;
+                None
+            }
+            Action::JumpScroll(target, value) => {
+                match target {
+                    ScrollTarget::Chat => {
+                        self.chat_auto_scroll = false;
+                        self.chat_scroll = value;
+                    }
+                    ScrollTarget::Findings => {
+                        self.scan_view.selected_finding = Some(value);
+                    }
                 }
                 None
             }
@@ -628,6 +851,39 @@ impl App {
                 }
                 None
             }
+            Action::ArrangeDashboardCursorUp => {
+                self.arrange_dashboard_cursor = self.arrange_dashboard_cursor.saturating_sub(1);
+                None
+            }
+            Action::ArrangeDashboardCursorDown => {
+                let last = crate::types::DashboardWidget::ALL.len().saturating_sub(1);
+                self.arrange_dashboard_cursor = (self.arrange_dashboard_cursor + 1).min(last);
+                None
+            }
+            Action::ArrangeDashboardToggle => {
+                let order = self.arrange_dashboard_display_order();
+                if let Some(widget) = order.get(self.arrange_dashboard_cursor).copied() {
+                    if let Some(pos) = self
+                        .config
+                        .dashboard_layout
+                        .iter()
+                        .position(|w| *w == widget)
+                    {
+                        self.config.dashboard_layout.remove(pos);
+                    } else {
+                        self.config.dashboard_layout.push(widget);
+                    }
+                }
+                None
+            }
+            Action::ArrangeDashboardMoveEarlier => {
+                self.move_arrange_dashboard_widget(-1);
+                None
+            }
+            Action::ArrangeDashboardMoveLater => {
+                self.move_arrange_dashboard_widget(1);
+                None
+            }
             Action::None => None,
         }
     }
@@ -1,11 +1,721 @@
 use std::time::Instant;
 
-use crate::types::{ChatMessage, MessageRole, Overlay, ViewState};
+use crate::config;
+use crate::types::{ChatMessage, InputMode, MessageRole, Overlay, ViewState};
 use crate::views::fix::FixViewState;
 
 use super::{App, AppCommand};
 
+/// One line of `.complior/dismissals.jsonl`, written by
+/// [`App::record_dismissal`] and read back by [`App::read_dismissals`].
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DismissalEntry {
+    pub(crate) check_id: String,
+    pub(crate) file: Option<String>,
+    pub(crate) reason: String,
+    pub(crate) dismissed_at: u64,
+}
+
+/// Max entries kept in [`App::recent_commands`].
+const RECENT_COMMANDS_CAP: usize = 8;
+
+/// Pastes longer than this many lines require confirmation before being
+/// inserted, instead of dumping straight into the input.
+const PASTE_CONFIRM_LINE_THRESHOLD: usize = 20;
+
+/// Max snapshots kept in [`App::input_undo_stack`].
+const INPUT_UNDO_CAP: usize = 100;
+
+/// Marker line written into every hook script `/hooks install` writes, so
+/// `/hooks uninstall`/`status` can tell a complior-managed hook apart from
+/// one the user wrote by hand.
+const HOOK_MARKER: &str = "# complior-managed-hook: do not edit, run `/hooks uninstall`";
+
+const KNOWN_HOOK_STAGES: [&str; 2] = ["pre-commit", "pre-push"];
+
+/// A bracketed paste too large to insert without confirmation (see
+/// [`App::handle_paste`]).
+#[derive(Debug, Clone)]
+pub struct PendingPaste {
+    pub text: String,
+    pub line_count: usize,
+}
+
 impl App {
+    /// Context-sensitive command-palette entries for the current view, on
+    /// top of the always-available static command list.
+    pub(crate) fn palette_contextual_commands(&self) -> Vec<(&'static str, &'static str)> {
+        let mut extra = Vec::new();
+        if self.view_state == ViewState::Fix && !self.fix_view.fixable_findings.is_empty() {
+            extra.push(("/apply", "Apply selected fixes"));
+        }
+        extra
+    }
+
+    /// Record a palette-executed command as most recently used, deduping
+    /// against any earlier occurrence and capping the list.
+    pub(crate) fn remember_recent_command(&mut self, cmd: &str) {
+        self.recent_commands.retain(|c| c != cmd);
+        self.recent_commands.insert(0, cmd.to_string());
+        self.recent_commands.truncate(RECENT_COMMANDS_CAP);
+    }
+
+    /// The `@`-mention being typed at the cursor, if any: the byte offset
+    /// of the triggering `@` plus everything typed after it so far. `None`
+    /// once whitespace breaks the mention (the user moved on to plain
+    /// text) or there's no `@` on the current line at all.
+    pub(crate) fn mention_query(&self) -> Option<(usize, &str)> {
+        let before_cursor = &self.input[..self.input_cursor];
+        let start = before_cursor.rfind('@')?;
+        let prefix = &before_cursor[start + 1..];
+        if prefix.contains(char::is_whitespace) {
+            return None;
+        }
+        Some((start, prefix))
+    }
+
+    /// Fuzzy file/obligation matches for the mention popup, or an empty
+    /// list when there's no active mention.
+    pub(crate) fn mention_matches(&self) -> Vec<crate::components::mention_popup::MentionMatch> {
+        self.mention_query().map_or_else(Vec::new, |(_, prefix)| {
+            crate::components::mention_popup::mention_matches(&self.file_tree, prefix)
+        })
+    }
+
+    /// Splice the mention popup's currently selected match into the input
+    /// in place of the `@prefix` that triggered it.
+    pub(crate) fn accept_mention(&mut self) {
+        let Some((start, _)) = self.mention_query() else {
+            return;
+        };
+        let matches = self.mention_matches();
+        let Some(m) = matches.get(self.mention_index) else {
+            return;
+        };
+        let insert = m.insert.clone();
+        self.snapshot_input_undo();
+        self.input.replace_range(start..self.input_cursor, &insert);
+        self.input_cursor = start + insert.len();
+        self.mention_index = 0;
+    }
+
+    /// Snapshot the input line before an edit, for `Ctrl+Z`/`Ctrl+Y`
+    /// recovery. Starting a new edit clears any pending redo.
+    pub(crate) fn snapshot_input_undo(&mut self) {
+        self.input_redo_stack.clear();
+        self.input_undo_stack
+            .push((self.input.clone(), self.input_cursor));
+        if self.input_undo_stack.len() > INPUT_UNDO_CAP {
+            self.input_undo_stack.remove(0);
+        }
+    }
+
+    /// Restore the previous input snapshot, pushing the current one onto
+    /// the redo stack.
+    pub(crate) fn input_undo(&mut self) {
+        let Some(prev) = self.input_undo_stack.pop() else {
+            return;
+        };
+        self.input_redo_stack
+            .push((self.input.clone(), self.input_cursor));
+        (self.input, self.input_cursor) = prev;
+        self.mention_index = 0;
+    }
+
+    /// Reapply a snapshot undone via `input_undo`.
+    pub(crate) fn input_redo(&mut self) {
+        let Some(next) = self.input_redo_stack.pop() else {
+            return;
+        };
+        self.input_undo_stack
+            .push((self.input.clone(), self.input_cursor));
+        (self.input, self.input_cursor) = next;
+        self.mention_index = 0;
+    }
+
+    /// Handle a bracketed-paste event: small pastes are inserted as a
+    /// single edit (instead of one `InsertChar` per character), large
+    /// pastes are fenced as a code block and held for confirmation via
+    /// [`Overlay::PasteConfirm`].
+    pub(crate) fn handle_paste(&mut self, text: String) {
+        if self.input_mode != InputMode::Insert || text.is_empty() {
+            return;
+        }
+        let line_count = text.lines().count().max(1);
+        if line_count > PASTE_CONFIRM_LINE_THRESHOLD {
+            self.pending_paste = Some(PendingPaste { text, line_count });
+            self.overlay = Overlay::PasteConfirm;
+            return;
+        }
+        self.snapshot_input_undo();
+        self.insert_text(&text);
+    }
+
+    /// Insert `text` at the cursor as a single edit and advance the cursor
+    /// past it.
+    fn insert_text(&mut self, text: &str) {
+        self.input.insert_str(self.input_cursor, text);
+        self.input_cursor += text.len();
+        self.mention_index = 0;
+    }
+
+    /// `/projects [add|remove]`: multi-project workspace. With no argument,
+    /// opens the Projects switcher overlay (Enter re-points the active
+    /// project). `add`/`remove` (un)register the current project path.
+    pub(crate) fn handle_projects_command(&mut self, arg: Option<&str>) -> Option<AppCommand> {
+        let path = self.project_path.to_string_lossy().to_string();
+        match arg.map(str::trim) {
+            Some("add") => Some(AppCommand::RegisterProject(path)),
+            Some("remove") => Some(AppCommand::UnregisterProject(path)),
+            _ => {
+                self.overlay = Overlay::ProjectSwitcher;
+                Some(AppCommand::FetchProjectList)
+            }
+        }
+    }
+
+    /// `/config sources`: show which layer (env var / `.complior/project.toml`
+    /// / `~/.config/complior/settings.toml` / default) each overridable
+    /// setting's effective value came from.
+    pub(crate) fn handle_config_command(&self, arg: &str) -> String {
+        match arg {
+            "" | "sources" => {
+                let mut lines = vec!["Config sources:".to_string()];
+                for src in config::config_sources() {
+                    if src.value.is_empty() {
+                        lines.push(format!("  {} = ({})", src.key, src.source));
+                    } else {
+                        lines.push(format!("  {} = {} [{}]", src.key, src.value, src.source));
+                    }
+                }
+                lines.join("\n")
+            }
+            other => format!("Unknown /config subcommand: {other}. Use: /config sources"),
+        }
+    }
+
+    /// `/env set KEY=VALUE` / `/env unset KEY` / `/env list`: session-scoped
+    /// environment overrides passed through to `/run` and `!cmd` (see
+    /// `AppCommand::RunCommand`'s executor) — never written to disk or the
+    /// saved session, so tokens typed here don't outlive the process.
+    pub(crate) fn handle_env_command(&mut self, arg: &str) -> String {
+        let mut words = arg.split_whitespace();
+        match words.next() {
+            Some("set") => {
+                let Some(assignment) = words.next() else {
+                    return "Usage: /env set KEY=VALUE".to_string();
+                };
+                let Some((key, value)) = assignment.split_once('=') else {
+                    return "Usage: /env set KEY=VALUE".to_string();
+                };
+                if key.is_empty() {
+                    return "Usage: /env set KEY=VALUE".to_string();
+                }
+                self.env_overrides.retain(|(k, _)| k != key);
+                self.env_overrides.push((key.to_string(), value.to_string()));
+                format!("Set {key} for this session.")
+            }
+            Some("unset") => {
+                let Some(key) = words.next() else {
+                    return "Usage: /env unset KEY".to_string();
+                };
+                let before = self.env_overrides.len();
+                self.env_overrides.retain(|(k, _)| k != key);
+                if self.env_overrides.len() == before {
+                    format!("{key} is not set.")
+                } else {
+                    format!("Unset {key}.")
+                }
+            }
+            Some("list") | None => {
+                if self.env_overrides.is_empty() {
+                    "No session env overrides set. Use: /env set KEY=VALUE".to_string()
+                } else {
+                    let mut lines = vec!["Session env overrides:".to_string()];
+                    for (key, value) in &self.env_overrides {
+                        lines.push(format!("  {key}={value}"));
+                    }
+                    lines.join("\n")
+                }
+            }
+            Some(other) => {
+                format!("Unknown /env subcommand: {other}. Use: set/unset/list.")
+            }
+        }
+    }
+
+    /// `/stats`: opens the per-day usage stats overlay (scans, fixes
+    /// applied, average score, LLM analysis cost), loaded from the same
+    /// on-disk store `record_scan`/`record_fixes` write to.
+    pub(crate) fn handle_stats_command(&mut self) {
+        self.stats.entries = crate::stats::load_history(&self.project_path);
+        self.stats.selected = 0;
+        self.overlay = Overlay::Stats;
+    }
+
+    /// `/risk-classify`: opens the EU AI Act risk classification
+    /// questionnaire (Annex III + GPAI systemic risk), whose result is
+    /// saved to `project.toml` on completion.
+    pub(crate) fn handle_risk_classify_command(&mut self) {
+        self.risk_wizard = Some(crate::views::risk_classification::RiskWizard::new());
+        self.overlay = Overlay::RiskClassification;
+    }
+
+    /// `/keys export [md|html] [path]`: write the effective keymap to a
+    /// cheat-sheet file. Returns the chat message to show the user.
+    pub(crate) fn handle_keys_export(&self, arg: &str) -> String {
+        let mut words = arg.split_whitespace();
+        let format = match words.next() {
+            Some("html") => "html",
+            _ => "md",
+        };
+        let default_path = if format == "html" { "keybindings.html" } else { "keybindings.md" };
+        let path = words.next().unwrap_or(default_path);
+        let content = if format == "html" {
+            crate::components::keybindings::render_html()
+        } else {
+            crate::components::keybindings::render_markdown()
+        };
+        match std::fs::write(path, content) {
+            Ok(()) => format!("Wrote keybinding cheat sheet to {path}"),
+            Err(e) => format!("Could not write {path}: {e}"),
+        }
+    }
+
+    /// `/hooks install|uninstall|status`: manage git pre-commit/pre-push
+    /// compliance gate hooks. Returns the chat message to show the user.
+    pub(crate) fn handle_hooks_command(&self, arg: &str) -> String {
+        let mut words = arg.split_whitespace();
+        match words.next() {
+            Some("install") => {
+                let mut stage = "pre-commit".to_string();
+                let mut threshold: u32 = 60;
+                for word in words {
+                    if let Ok(n) = word.parse::<u32>() {
+                        threshold = n;
+                    } else {
+                        stage = word.to_string();
+                    }
+                }
+                self.install_hook(&stage, threshold)
+            }
+            Some("uninstall") => self.uninstall_hooks(words.next()),
+            Some("status") | None => self.hooks_status(),
+            Some(other) => {
+                format!("Unknown /hooks subcommand: {other}. Use install/uninstall/status.")
+            }
+        }
+    }
+
+    /// `<project>/.git/hooks`, resolved via `git rev-parse --git-dir` so
+    /// worktrees (where `.git` is a file, not a directory) still work.
+    fn hooks_dir(&self) -> std::path::PathBuf {
+        let git_dir = std::process::Command::new("git")
+            .args(["rev-parse", "--git-dir"])
+            .current_dir(&self.project_path)
+            .output()
+            .ok()
+            .filter(|o| o.status.success())
+            .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string());
+
+        match git_dir {
+            Some(dir) => {
+                let dir = std::path::PathBuf::from(dir);
+                if dir.is_absolute() {
+                    dir.join("hooks")
+                } else {
+                    self.project_path.join(dir).join("hooks")
+                }
+            }
+            None => self.project_path.join(".git/hooks"),
+        }
+    }
+
+    fn install_hook(&self, stage: &str, threshold: u32) -> String {
+        if !KNOWN_HOOK_STAGES.contains(&stage) {
+            return format!(
+                "Unknown hook stage '{stage}' (expected one of: {})",
+                KNOWN_HOOK_STAGES.join(", ")
+            );
+        }
+        let dir = self.hooks_dir();
+        if let Err(e) = std::fs::create_dir_all(&dir) {
+            return format!("Could not create {}: {e}", dir.display());
+        }
+        let hook_path = dir.join(stage);
+        if hook_path.exists() {
+            let existing = std::fs::read_to_string(&hook_path).unwrap_or_default();
+            if !existing.contains(HOOK_MARKER) {
+                return format!(
+                    "{} already exists and was not installed by complior. Remove it first.",
+                    hook_path.display()
+                );
+            }
+        }
+        let script =
+            format!("#!/bin/sh\n{HOOK_MARKER}\ncomplior scan --ci --threshold {threshold}\n");
+        if let Err(e) = std::fs::write(&hook_path, script) {
+            return format!("Could not write {}: {e}", hook_path.display());
+        }
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            if let Ok(mut perms) = std::fs::metadata(&hook_path).map(|m| m.permissions()) {
+                perms.set_mode(perms.mode() | 0o111);
+                let _ = std::fs::set_permissions(&hook_path, perms);
+            }
+        }
+        format!(
+            "Installed {stage} hook at {} (threshold: {threshold})",
+            hook_path.display()
+        )
+    }
+
+    fn uninstall_hooks(&self, stage: Option<&str>) -> String {
+        let dir = self.hooks_dir();
+        let stages: Vec<&str> = stage.map_or_else(|| KNOWN_HOOK_STAGES.to_vec(), |s| vec![s]);
+        let mut removed = Vec::new();
+        for stage in stages {
+            let hook_path = dir.join(stage);
+            let Ok(content) = std::fs::read_to_string(&hook_path) else {
+                continue;
+            };
+            if !content.contains(HOOK_MARKER) {
+                continue;
+            }
+            if std::fs::remove_file(&hook_path).is_ok() {
+                removed.push(stage.to_string());
+            }
+        }
+        if removed.is_empty() {
+            "No complior-managed hooks found.".to_string()
+        } else {
+            format!("Removed hook(s): {}", removed.join(", "))
+        }
+    }
+
+    fn hooks_status(&self) -> String {
+        let dir = self.hooks_dir();
+        let lines: Vec<String> = KNOWN_HOOK_STAGES
+            .iter()
+            .map(|stage| {
+                let status = match std::fs::read_to_string(dir.join(stage)) {
+                    Ok(content) if content.contains(HOOK_MARKER) => "installed",
+                    Ok(_) => "present (not managed by complior)",
+                    Err(_) => "not installed",
+                };
+                format!("  {stage}: {status}")
+            })
+            .collect();
+        format!("Git hooks:\n{}", lines.join("\n"))
+    }
+
+    /// Append a dismissed finding to `.complior/dismissals.jsonl` — one JSON
+    /// object per line so concurrent dismissals from different branches
+    /// merge cleanly (each line is an independent git hunk, unlike a single
+    /// JSON array).
+    pub(crate) fn record_dismissal(
+        &self,
+        check_id: &str,
+        file: Option<&str>,
+        reason: &crate::components::quick_actions::DismissReason,
+    ) -> std::io::Result<()> {
+        let dir = self.project_path.join(".complior");
+        std::fs::create_dir_all(&dir)?;
+        let dismissed_at = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        let entry = serde_json::json!({
+            "checkId": check_id,
+            "file": file,
+            "reason": reason.label(),
+            "dismissedAt": dismissed_at,
+        });
+        let mut line = serde_json::to_string(&entry)?;
+        line.push('\n');
+        use std::io::Write as _;
+        std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(dir.join("dismissals.jsonl"))?
+            .write_all(line.as_bytes())
+    }
+
+    /// Read every entry ever appended by [`Self::record_dismissal`], for the
+    /// Report view's "Dismissals" section — malformed lines are skipped
+    /// rather than failing the whole read.
+    pub(crate) fn read_dismissals(&self) -> Vec<DismissalEntry> {
+        let path = self.project_path.join(".complior").join("dismissals.jsonl");
+        let Ok(content) = std::fs::read_to_string(path) else {
+            return Vec::new();
+        };
+        content
+            .lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect()
+    }
+
+    /// `/assign <name>`: assign the currently selected Scan view finding to
+    /// `name` (free-text or from the configured team list); `/assign clear`
+    /// unassigns it. Returns the chat message to show the user.
+    pub(crate) fn handle_assign_command(&mut self, arg: &str) -> String {
+        let Some(idx) = self.scan_view.selected_finding else {
+            return "No finding selected. Open the Scan view and pick a finding first.".to_string();
+        };
+        let Some(scan) = &self.last_scan else {
+            return "No scan results yet — run /scan first.".to_string();
+        };
+        let Some(finding) = crate::views::scan::resolve_selected_finding(
+            &scan.findings,
+            self.scan_view.findings_filter,
+            idx,
+            &self.passport_view.loaded_passports,
+            &self.assignments,
+            self.scan_view.assignee_filter.as_deref(),
+            &self.finding_states,
+            self.scan_view.show_snoozed,
+        ) else {
+            return "No finding selected. Open the Scan view and pick a finding first.".to_string();
+        };
+        let check_id = finding.check_id.clone();
+        let file = finding.file.clone();
+        let assignee = if arg.is_empty() || arg.eq_ignore_ascii_case("clear") {
+            None
+        } else {
+            Some(arg.to_string())
+        };
+        let label = assignee.clone().unwrap_or_else(|| "Unassigned".to_string());
+        match self.assign_finding(&check_id, file.as_deref(), assignee) {
+            Ok(()) => format!("{check_id} assigned to {label}"),
+            Err(e) => format!("Failed to save assignment: {e}"),
+        }
+    }
+
+    /// `/assignee <name|clear>`: filter the Scan view's findings list by
+    /// assignee (`unassigned` matches findings with no assignee).
+    pub(crate) fn handle_assignee_filter_command(&mut self, arg: &str) -> String {
+        if arg.is_empty() || arg.eq_ignore_ascii_case("clear") {
+            self.scan_view.assignee_filter = None;
+            self.scan_view.selected_finding = Some(0);
+            "Assignee filter cleared".to_string()
+        } else {
+            self.scan_view.assignee_filter = Some(arg.to_string());
+            self.scan_view.selected_finding = Some(0);
+            format!("Filtering Scan view by assignee: {arg}")
+        }
+    }
+
+    /// Assign (or, if `assignee` is `None`, unassign) a finding, persisting
+    /// the change to `.complior/tracked-issues.json` and refreshing `self.assignments`.
+    pub(crate) fn assign_finding(
+        &mut self,
+        check_id: &str,
+        file: Option<&str>,
+        assignee: Option<String>,
+    ) -> std::io::Result<()> {
+        self.assignments =
+            crate::assignments::set_assignee(&self.project_path, check_id, file, assignee)?;
+        Ok(())
+    }
+
+    /// `/triage <name>`: set the currently selected Scan view finding's
+    /// workflow status (`open`/`in-progress`/`remediated`/`accepted-risk`).
+    /// With no argument, reports the current status instead of changing it.
+    pub(crate) fn handle_status_command(&mut self, arg: &str) -> String {
+        let Some((check_id, file)) = self.selected_scan_finding_key() else {
+            return "No finding selected. Open the Scan view and pick a finding first.".to_string();
+        };
+        if arg.is_empty() {
+            let status =
+                crate::findings_state::status_for(&self.finding_states, &check_id, file.as_deref());
+            return format!("{check_id}: {}", status.label());
+        }
+        let Some(status) = crate::findings_state::FindingStatus::from_command(arg) else {
+            return format!(
+                "Unknown status: {arg}. Use: open, in-progress, remediated, accepted-risk"
+            );
+        };
+        match crate::findings_state::set_status(
+            &self.project_path,
+            &check_id,
+            file.as_deref(),
+            status,
+        ) {
+            Ok(states) => {
+                self.finding_states = states;
+                format!("{check_id} status set to {}", status.label())
+            }
+            Err(e) => format!("Failed to save status: {e}"),
+        }
+    }
+
+    /// `/due <date|clear>`: set (or clear) the due date (`YYYY-MM-DD`) of the
+    /// currently selected Scan view finding.
+    pub(crate) fn handle_due_command(&mut self, arg: &str) -> String {
+        let Some((check_id, file)) = self.selected_scan_finding_key() else {
+            return "No finding selected. Open the Scan view and pick a finding first.".to_string();
+        };
+        let due_date = if arg.is_empty() || arg.eq_ignore_ascii_case("clear") {
+            None
+        } else {
+            Some(arg.to_string())
+        };
+        match crate::findings_state::set_due_date(
+            &self.project_path,
+            &check_id,
+            file.as_deref(),
+            due_date.clone(),
+        ) {
+            Ok(states) => {
+                self.finding_states = states;
+                match due_date {
+                    Some(d) => format!("{check_id} due date set to {d}"),
+                    None => format!("{check_id} due date cleared"),
+                }
+            }
+            Err(e) => format!("Failed to save due date: {e}"),
+        }
+    }
+
+    /// `/snooze-until <date|clear>`: hide the currently selected Scan view
+    /// finding from the default list until `date` (`YYYY-MM-DD`) — e.g.
+    /// until the Art. 6 deadline it depends on applies. Auto-resurfaces once
+    /// today reaches that date; `z` still reveals snoozed findings early
+    /// (`ScanViewState::show_snoozed`).
+    pub(crate) fn handle_snooze_until_command(&mut self, arg: &str) -> String {
+        let Some((check_id, file)) = self.selected_scan_finding_key() else {
+            return "No finding selected. Open the Scan view and pick a finding first.".to_string();
+        };
+        let snoozed_until = if arg.is_empty() || arg.eq_ignore_ascii_case("clear") {
+            None
+        } else {
+            Some(arg.to_string())
+        };
+        match crate::findings_state::set_snooze(
+            &self.project_path,
+            &check_id,
+            file.as_deref(),
+            snoozed_until.clone(),
+        ) {
+            Ok(states) => {
+                self.finding_states = states;
+                match snoozed_until {
+                    Some(d) => format!("{check_id} snoozed until {d}"),
+                    None => format!("{check_id} snooze cleared"),
+                }
+            }
+            Err(e) => format!("Failed to save snooze: {e}"),
+        }
+    }
+
+    /// `/snooze [kind] [days]`: hide idle suggestions of `kind` (default: the
+    /// kind currently shown, if any) for `days` (default: 7). Persisted to
+    /// `settings.toml` so the preference survives restarts.
+    pub(crate) fn handle_snooze_command(&mut self, arg: &str) -> Option<AppCommand> {
+        let mut tokens = arg.split_whitespace();
+        let first = tokens.next();
+        let (kind, days_token) =
+            match first.and_then(crate::components::suggestions::SuggestionKind::from_key) {
+                Some(k) => (Some(k), tokens.next()),
+                None => (
+                    self.idle_suggestions.current.as_ref().map(|s| s.kind),
+                    first,
+                ),
+            };
+        let Some(kind) = kind else {
+            self.toasts.push(
+                crate::components::toast::ToastKind::Warning,
+                "No active suggestion to snooze. Usage: /snooze [tip|fix|deadline|score|new] [days]",
+            );
+            return None;
+        };
+        let days: u64 = days_token.and_then(|s| s.parse().ok()).unwrap_or(7);
+        let now_secs = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        let until_secs = now_secs + days * 86_400;
+        self.idle_suggestions.dismiss();
+        self.toasts.push(
+            crate::components::toast::ToastKind::Info,
+            format!("Snoozing \"{}\" suggestions for {days} day(s)", kind.key()),
+        );
+        Some(AppCommand::SnoozeSuggestion(kind, until_secs))
+    }
+
+    /// Resolve the currently selected Scan view finding to its
+    /// `(check_id, file)` key, used by `/triage`, `/due`, and the `s` quick action.
+    pub(crate) fn selected_scan_finding_key(&self) -> Option<(String, Option<String>)> {
+        let idx = self.scan_view.selected_finding?;
+        let scan = self.last_scan.as_ref()?;
+        let finding = crate::views::scan::resolve_selected_finding(
+            &scan.findings,
+            self.scan_view.findings_filter,
+            idx,
+            &self.passport_view.loaded_passports,
+            &self.assignments,
+            self.scan_view.assignee_filter.as_deref(),
+            &self.finding_states,
+            self.scan_view.show_snoozed,
+        )?;
+        Some((finding.check_id.clone(), finding.file.clone()))
+    }
+
+    /// `/team status`: show which team-shared `.complior/` files exist and
+    /// whether they're committed, locally modified, or not yet shared.
+    pub(crate) fn handle_team_status(&self) -> String {
+        const SHARED_FILES: [&str; 5] = [
+            "dismissals.jsonl",
+            "project.toml",
+            "tracked-issues.json",
+            "findings-state.json",
+            "report-sections.json",
+        ];
+
+        let dir = self.project_path.join(".complior");
+        let mut lines = vec!["Team-shared compliance files (.complior/):".to_string()];
+        for name in SHARED_FILES {
+            let path = dir.join(name);
+            if !path.exists() {
+                continue;
+            }
+            let status = self.git_file_status(&path);
+            lines.push(format!("  {name:<20} {status}"));
+        }
+        if lines.len() == 1 {
+            lines.push(
+                "  (none yet — dismiss a finding or run `complior init` to create some)"
+                    .to_string(),
+            );
+        }
+        lines.join("\n")
+    }
+
+    /// Git status of a single file relative to `self.project_path`:
+    /// "committed", "locally modified", "untracked", or "unknown" outside a
+    /// git repo.
+    fn git_file_status(&self, file: &std::path::Path) -> &'static str {
+        let rel = file.strip_prefix(&self.project_path).unwrap_or(file);
+        let output = std::process::Command::new("git")
+            .args(["status", "--porcelain", "--"])
+            .arg(rel)
+            .current_dir(&self.project_path)
+            .output();
+        match output {
+            Ok(o) if o.status.success() => {
+                let text = String::from_utf8_lossy(&o.stdout);
+                if text.trim().is_empty() {
+                    "committed (in sync with team)"
+                } else if text.starts_with("??") {
+                    "untracked (not shared with team yet)"
+                } else {
+                    "locally modified (not yet committed)"
+                }
+            }
+            _ => "unknown (not a git repository?)",
+        }
+    }
+
     pub(crate) fn try_tab_complete(&mut self) {
         // Colon mode tab completion
         if self.colon_mode {
@@ -20,9 +730,8 @@ impl App {
         }
 
         if self.input.starts_with('/') {
-            let partial = &self.input[1..];
-            if let Some(completed) = crate::components::command_palette::complete_command(partial) {
-                self.input = completed.to_string();
+            if let Some(completed) = self.completion_preview() {
+                self.input = completed;
                 self.input_cursor = self.input.len();
             }
             return;
@@ -72,16 +781,70 @@ impl App {
         }
     }
 
+    /// The full input `try_tab_complete`/the ghost-text hint would produce
+    /// from the current input, or `None` when there's nothing to suggest.
+    /// Covers command-name completion (`/sc` -> `/scan`) and argument-aware
+    /// completion for commands with a well-known argument kind.
+    pub(crate) fn completion_preview(&self) -> Option<String> {
+        let rest = self.input.strip_prefix('/')?;
+        if let Some(space_idx) = rest.find(' ') {
+            let command = &rest[..space_idx];
+            let arg = &rest[space_idx + 1..];
+            let completed_arg = self.complete_command_arg(command, arg)?;
+            return (completed_arg != arg).then(|| format!("/{command} {completed_arg}"));
+        }
+        let completed = crate::components::command_palette::complete_command(rest)?;
+        (completed != rest).then(|| format!("/{completed}"))
+    }
+
+    /// Argument-aware completion for slash commands with a well-known
+    /// argument kind: file paths for `/edit`, theme names for `/theme`,
+    /// view names for `/view`, saved session names for `/load`.
+    fn complete_command_arg(&self, command: &str, arg: &str) -> Option<String> {
+        match command {
+            "edit" => crate::components::file_picker::fuzzy_match_files(&self.file_tree, arg)
+                .first()
+                .map(|f| f.path.to_string_lossy().to_string()),
+            "theme" => crate::theme::list_themes()
+                .into_iter()
+                .map(|t| t.name.to_string())
+                .find(|name| name.to_lowercase().starts_with(&arg.to_lowercase())),
+            "view" => ViewState::ALL
+                .iter()
+                .map(|v| v.short_name().to_string())
+                .find(|name| name.to_lowercase().starts_with(&arg.to_lowercase())),
+            "load" => crate::session::list_sessions_sync(&self.project_path)
+                .into_iter()
+                .find(|name| name.to_lowercase().starts_with(&arg.to_lowercase())),
+            _ => None,
+        }
+    }
+
     pub fn handle_command(&mut self, cmd: &str) -> Option<AppCommand> {
         let parts: Vec<&str> = cmd.splitn(2, ' ').collect();
+        if let Some(name) = parts.first() {
+            crate::telemetry::record_feature(name);
+        }
         match parts.first().copied() {
             Some("scan") => {
-                self.messages.push(ChatMessage::new(
-                    MessageRole::System,
-                    "Scanning project...".to_string(),
-                ));
-                self.operation_start = Some(Instant::now());
-                Some(AppCommand::Scan)
+                let arg = parts.get(1).copied().unwrap_or("").trim();
+                let mut words = arg.split_whitespace();
+                if words.next() == Some("diff") {
+                    let base = words.next().unwrap_or("origin/main").to_string();
+                    self.messages.push(ChatMessage::new(
+                        MessageRole::System,
+                        format!("Scanning diff against {base}..."),
+                    ));
+                    self.operation_start = Some(Instant::now());
+                    Some(AppCommand::ScanDiff(base))
+                } else {
+                    self.messages.push(ChatMessage::new(
+                        MessageRole::System,
+                        "Scanning project...".to_string(),
+                    ));
+                    self.operation_start = Some(Instant::now());
+                    Some(AppCommand::Scan)
+                }
             }
             Some("edit") => {
                 let path = parts.get(1).unwrap_or(&"").to_string();
@@ -144,6 +907,7 @@ impl App {
                     concat!(
                         "Commands:\n",
                         "  /scan          — Scan project for compliance\n",
+                        "  /scan diff [base] — Scan only files changed vs base (default origin/main)\n",
                         "  /status        — Show compliance status summary\n",
                         "  /fix           — Open Fix view\n",
                         "  /fix --dry-run — Preview fixes without applying\n",
@@ -151,16 +915,26 @@ impl App {
                         "  /report        — Open Report view\n",
                         "  /edit <path>   — Open file in viewer\n",
                         "  /run <cmd>     — Run shell command\n",
+                        "  /env set KEY=VALUE — Set a session-scoped env var for /run and !cmd\n",
+                        "  /env unset KEY — Remove a session env override\n",
+                        "  /env list      — Show active session env overrides\n",
                         "  /clear         — Clear terminal output\n",
                         "  /reconnect     — Reconnect to engine\n",
                         "  /theme <name>  — Switch theme (dark/light/high-contrast)\n",
                         "  /watch         — Toggle file watch mode\n",
+                        "  /doctor        — Run system health checks\n",
+                        "  /keys [export [md|html] [path]] — Browse or export keybindings\n",
+                        "  /hooks install [stage] [threshold] — Install git compliance hook\n",
+                        "  /hooks uninstall [stage] — Remove complior-managed git hook(s)\n",
+                        "  /hooks status  — Show installed git compliance hooks\n",
+                        "  /team status   — Show which .complior/ files are shared vs. locally modified\n",
                         "  /view <1-9>    — Switch to view (Dashboard/Scan/Fix/Passport/Oblig/Timeline/Report/Log/Chat)\n",
                         "  /save [name]   — Save session\n",
                         "  /load [name]   — Load session\n",
                         "  /sessions      — List saved sessions\n",
                         "  /whatif <text> — What-if scenario analysis\n",
                         "  /welcome       — Show getting started\n",
+                        "  /tour          — Guided tour of the dashboard\n",
                         "  /help          — Show this help\n",
                         "\n",
                         "Shortcuts:\n",
@@ -193,7 +967,7 @@ impl App {
                 if let Ok(num) = num_str.parse::<u8>()
                     && let Some(view) = ViewState::from_key(num)
                 {
-                    self.view_state = view;
+                    self.switch_view(view);
                     return None;
                 }
                 self.messages.push(ChatMessage::new(
@@ -204,10 +978,119 @@ impl App {
                 None
             }
             Some("watch") => Some(AppCommand::ToggleWatch),
+            Some("doctor") => Some(AppCommand::Doctor),
+            Some("keys") => {
+                let arg = parts.get(1).copied().unwrap_or("").trim();
+                if let Some(export_arg) = arg.strip_prefix("export") {
+                    let msg = self.handle_keys_export(export_arg.trim());
+                    self.messages
+                        .push(ChatMessage::new(MessageRole::System, msg));
+                } else {
+                    self.overlay = Overlay::Keybindings;
+                }
+                None
+            }
+            Some("hooks") => {
+                let arg = parts.get(1).copied().unwrap_or("").trim();
+                let msg = self.handle_hooks_command(arg);
+                self.messages
+                    .push(ChatMessage::new(MessageRole::System, msg));
+                None
+            }
+            Some("team") => {
+                let arg = parts.get(1).copied().unwrap_or("").trim();
+                let msg = match arg {
+                    "" | "status" => self.handle_team_status(),
+                    other => format!("Unknown /team subcommand: {other}. Use: /team status"),
+                };
+                self.messages
+                    .push(ChatMessage::new(MessageRole::System, msg));
+                None
+            }
             Some("welcome") => {
                 self.overlay = Overlay::GettingStarted;
                 None
             }
+            // Guided tour: resumes on whatever step it was left on.
+            Some("tour") => {
+                self.switch_view(self.tour.current().view);
+                self.overlay = Overlay::Tour;
+                None
+            }
+            // Re-run project setup: same step framework as the first-run
+            // onboarding wizard (scan profile, jurisdiction, role, industry).
+            Some("init") => {
+                self.onboarding = Some(crate::views::onboarding::OnboardingWizard::new());
+                self.overlay = Overlay::Onboarding;
+                None
+            }
+            // Multi-project workspace: register project paths, switch between them.
+            Some("projects") => self.handle_projects_command(parts.get(1).copied()),
+            Some("config") => {
+                let msg = self.handle_config_command(parts.get(1).copied().unwrap_or("").trim());
+                self.messages
+                    .push(ChatMessage::new(MessageRole::System, msg));
+                None
+            }
+            Some("env") => {
+                let msg = self.handle_env_command(parts.get(1).copied().unwrap_or("").trim());
+                self.messages
+                    .push(ChatMessage::new(MessageRole::System, msg));
+                None
+            }
+            Some("stats") => {
+                self.handle_stats_command();
+                None
+            }
+            Some("settings") => {
+                self.settings_overlay =
+                    Some(crate::settings_overlay::SettingsState::new(&self.config));
+                self.overlay = Overlay::Settings;
+                None
+            }
+            Some("risk-classify") => {
+                self.handle_risk_classify_command();
+                None
+            }
+            Some("assign") => {
+                let arg = parts.get(1).copied().unwrap_or("").trim();
+                let msg = self.handle_assign_command(arg);
+                self.messages
+                    .push(ChatMessage::new(MessageRole::System, msg));
+                None
+            }
+            Some("assignee") => {
+                let arg = parts.get(1).copied().unwrap_or("").trim();
+                let msg = self.handle_assignee_filter_command(arg);
+                self.messages
+                    .push(ChatMessage::new(MessageRole::System, msg));
+                None
+            }
+            Some("triage") => {
+                let arg = parts.get(1).copied().unwrap_or("").trim();
+                let msg = self.handle_status_command(arg);
+                self.messages
+                    .push(ChatMessage::new(MessageRole::System, msg));
+                None
+            }
+            Some("due") => {
+                let arg = parts.get(1).copied().unwrap_or("").trim();
+                let msg = self.handle_due_command(arg);
+                self.messages
+                    .push(ChatMessage::new(MessageRole::System, msg));
+                None
+            }
+            Some("snooze-until") => {
+                let arg = parts.get(1).copied().unwrap_or("").trim();
+                let msg = self.handle_snooze_until_command(arg);
+                self.messages
+                    .push(ChatMessage::new(MessageRole::System, msg));
+                None
+            }
+            Some("snooze") => {
+                let arg = parts[1..].join(" ");
+                self.handle_snooze_command(arg.trim())
+            }
             // T905: What-If scenario command
             Some("whatif") => {
                 let scenario = parts.get(1).unwrap_or(&"").to_string();
@@ -224,7 +1107,18 @@ impl App {
             // T906: Dry-run fix (also /fix --dry-run)
             Some("fix") => {
                 let args = parts.get(1).unwrap_or(&"").to_string();
-                if args.contains("--dry-run") {
+                if args.contains("--sandbox") {
+                    if self.fix_view.fixable_findings.iter().any(|f| f.selected) {
+                        Some(AppCommand::FixSandbox)
+                    } else {
+                        self.messages.push(ChatMessage::new(
+                            MessageRole::System,
+                            "No fixes selected. Go to Fix view (3) and select fixes first."
+                                .to_string(),
+                        ));
+                        None
+                    }
+                } else if args.contains("--dry-run") {
                     let selected: Vec<String> = self
                         .fix_view
                         .fixable_findings
@@ -244,7 +1138,7 @@ impl App {
                     }
                 } else {
                     // Regular /fix: switch to Fix view
-                    self.view_state = ViewState::Fix;
+                    self.switch_view(ViewState::Fix);
                     if let Some(scan) = &self.last_scan {
                         self.fix_view = FixViewState::from_scan(&scan.findings);
                     }
@@ -255,6 +1149,22 @@ impl App {
                     None
                 }
             }
+            // Contextual command-palette entry for the Fix view: select every
+            // fixable finding and apply them, same as pressing Enter after
+            // manually selecting all of them.
+            Some("apply") => {
+                if self.fix_view.fixable_findings.is_empty() {
+                    self.messages.push(ChatMessage::new(
+                        MessageRole::System,
+                        "No fixable findings. Run /scan first.".to_string(),
+                    ));
+                    None
+                } else {
+                    self.fix_view.select_all();
+                    self.fix_view.applying = true;
+                    Some(AppCommand::ApplyFixes)
+                }
+            }
             Some("status") => {
                 if let Some(scan) = &self.last_scan {
                     let total = scan.score.total_score;
@@ -315,7 +1225,7 @@ impl App {
                 None
             }
             Some("report") => {
-                self.view_state = ViewState::Report;
+                self.switch_view(ViewState::Report);
                 self.messages.push(ChatMessage::new(
                     MessageRole::System,
                     "Switched to Report view. Use /export to generate a file.".to_string(),
@@ -359,7 +1269,7 @@ impl App {
             Some("fix") => {
                 let target = parts.get(1).unwrap_or(&"").to_string();
                 if target.is_empty() {
-                    self.view_state = ViewState::Fix;
+                    self.switch_view(ViewState::Fix);
                     if let Some(scan) = &self.last_scan {
                         self.fix_view = FixViewState::from_scan(&scan.findings);
                     }
@@ -449,7 +1359,7 @@ impl App {
                 None
             }
             Some("report" | "r") => {
-                self.view_state = ViewState::Report;
+                self.switch_view(ViewState::Report);
                 self.toasts.push(
                     crate::components::toast::ToastKind::Info,
                     "Report view opened",
@@ -457,6 +1367,83 @@ impl App {
                 None
             }
             Some("watch" | "w") => Some(AppCommand::ToggleWatch),
+            Some("doctor") => Some(AppCommand::Doctor),
+            Some("keys") => {
+                let arg = parts.get(1).copied().unwrap_or("").trim();
+                if let Some(export_arg) = arg.strip_prefix("export") {
+                    let msg = self.handle_keys_export(export_arg.trim());
+                    self.messages
+                        .push(ChatMessage::new(MessageRole::System, msg));
+                } else {
+                    self.overlay = Overlay::Keybindings;
+                }
+                None
+            }
+            Some("tour") => {
+                self.switch_view(self.tour.current().view);
+                self.overlay = Overlay::Tour;
+                None
+            }
+            Some("projects" | "proj") => self.handle_projects_command(parts.get(1).copied()),
+            Some("config") => {
+                let msg = self.handle_config_command(parts.get(1).copied().unwrap_or("").trim());
+                self.messages
+                    .push(ChatMessage::new(MessageRole::System, msg));
+                None
+            }
+            Some("env") => {
+                let msg = self.handle_env_command(parts.get(1).copied().unwrap_or("").trim());
+                self.messages
+                    .push(ChatMessage::new(MessageRole::System, msg));
+                None
+            }
+            Some("stats") => {
+                self.handle_stats_command();
+                None
+            }
+            Some("risk-classify" | "risk") => {
+                self.handle_risk_classify_command();
+                None
+            }
+            Some("assign") => {
+                let arg = parts.get(1).copied().unwrap_or("").trim();
+                let msg = self.handle_assign_command(arg);
+                self.messages
+                    .push(ChatMessage::new(MessageRole::System, msg));
+                None
+            }
+            Some("assignee") => {
+                let arg = parts.get(1).copied().unwrap_or("").trim();
+                let msg = self.handle_assignee_filter_command(arg);
+                self.messages
+                    .push(ChatMessage::new(MessageRole::System, msg));
+                None
+            }
+            Some("triage") => {
+                let arg = parts.get(1).copied().unwrap_or("").trim();
+                let msg = self.handle_status_command(arg);
+                self.messages
+                    .push(ChatMessage::new(MessageRole::System, msg));
+                None
+            }
+            Some("due") => {
+                let arg = parts.get(1).copied().unwrap_or("").trim();
+                let msg = self.handle_due_command(arg);
+                self.messages
+                    .push(ChatMessage::new(MessageRole::System, msg));
+                None
+            }
+            Some("snooze-until") => {
+                let arg = parts.get(1).copied().unwrap_or("").trim();
+                let msg = self.handle_snooze_until_command(arg);
+                self.messages
+                    .push(ChatMessage::new(MessageRole::System, msg));
+                None
+            }
+            Some("snooze") => {
+                let arg = parts[1..].join(" ");
+                self.handle_snooze_command(arg.trim())
+            }
             Some("quit" | "q") => {
                 self.running = false;
                 None
@@ -472,7 +1459,7 @@ impl App {
                 if let Ok(num) = num_str.parse::<u8>()
                     && let Some(view) = ViewState::from_key(num)
                 {
-                    self.view_state = view;
+                    self.switch_view(view);
                     return None;
                 }
                 self.toasts.push(
@@ -490,6 +1477,73 @@ impl App {
                 );
                 None
             }
+            // Opt-in anonymous usage telemetry: `/telemetry on|off|show`.
+            Some("telemetry") => {
+                let arg = parts.get(1).copied().unwrap_or("").trim();
+                match arg {
+                    "on" => {
+                        crate::telemetry::set_enabled(true);
+                        self.toasts
+                            .push(crate::components::toast::ToastKind::Info, "Telemetry: on");
+                    }
+                    "off" => {
+                        crate::telemetry::set_enabled(false);
+                        self.toasts
+                            .push(crate::components::toast::ToastKind::Info, "Telemetry: off");
+                    }
+                    "" | "show" => {
+                        let msg = crate::telemetry::render_show();
+                        self.messages
+                            .push(ChatMessage::new(MessageRole::System, msg));
+                    }
+                    other => {
+                        self.toasts.push(
+                            crate::components::toast::ToastKind::Warning,
+                            format!("Unknown /telemetry subcommand: {other}. Use: /telemetry on|off|show"),
+                        );
+                    }
+                }
+                None
+            }
+            // Resolve a fix batch left interrupted by a crash/kill/power
+            // loss: `/fix-recovery forward|back|discard`. See `App::new`'s
+            // startup check and `crate::fix_journal`.
+            Some("fix-recovery") => {
+                let arg = parts.get(1).copied().unwrap_or("").trim();
+                let Some(journal) = crate::fix_journal::load_journal(&self.project_path) else {
+                    self.toasts.push(
+                        crate::components::toast::ToastKind::Info,
+                        "No interrupted fix batch found",
+                    );
+                    return None;
+                };
+                let msg = match arg {
+                    "forward" => {
+                        let (applied, skipped, failed) =
+                            crate::fix_journal::roll_forward(&self.project_path, &journal);
+                        format!(
+                            "Rolled forward: {applied} file(s) applied, {skipped} skipped (changed on disk since the fix ran), {failed} failed"
+                        )
+                    }
+                    "back" => {
+                        let (restored, skipped, failed) =
+                            crate::fix_journal::roll_back(&self.project_path, &journal);
+                        format!(
+                            "Rolled back: {restored} file(s) restored, {skipped} skipped (changed on disk since the fix ran), {failed} failed"
+                        )
+                    }
+                    "discard" => {
+                        crate::fix_journal::clear_journal(&self.project_path);
+                        "Discarded the fix journal without touching any files".to_string()
+                    }
+                    other => format!(
+                        "Unknown /fix-recovery subcommand: {other}. Use: /fix-recovery forward|back|discard"
+                    ),
+                };
+                self.messages
+                    .push(ChatMessage::new(MessageRole::System, msg));
+                None
+            }
             // T905: What-If scenario (colon mode)
             Some("whatif" | "wi") => {
                 let scenario = parts[1..].join(" ");
@@ -522,12 +1576,70 @@ impl App {
                     Some(AppCommand::FixDryRun(selected))
                 }
             }
-            Some("llm" | "settings") => {
+            // Real (not predicted) dry run: apply selected fixes to a throwaway
+            // copy of the project and rescan that copy (colon mode).
+            Some("sandbox" | "sb") => {
+                if self.fix_view.fixable_findings.iter().any(|f| f.selected) {
+                    Some(AppCommand::FixSandbox)
+                } else {
+                    self.toasts.push(
+                        crate::components::toast::ToastKind::Warning,
+                        "No fixes selected. Select fixes in Fix view first.",
+                    );
+                    None
+                }
+            }
+            // Saveable layout presets: panel visibility, split ratios, sidebar
+            // state, and active view, so users can flip between e.g. a
+            // "review" layout and a "coding" layout instantly.
+            Some("layout" | "lay") => {
+                let args: Vec<&str> = parts.get(1).unwrap_or(&"").splitn(2, ' ').collect();
+                match (args.first().copied(), args.get(1).copied()) {
+                    (Some("save"), Some(name)) if !name.is_empty() => {
+                        Some(AppCommand::SaveLayout(name.to_string()))
+                    }
+                    (Some("load"), Some(name)) if !name.is_empty() => {
+                        Some(AppCommand::LoadLayout(name.to_string()))
+                    }
+                    _ => {
+                        self.toasts.push(
+                            crate::components::toast::ToastKind::Warning,
+                            "Usage: :layout save|load <name>",
+                        );
+                        None
+                    }
+                }
+            }
+            Some("llm") => {
                 self.llm_settings =
                     Some(crate::llm_settings::LlmSettingsState::new(&self.llm_config));
                 self.overlay = Overlay::LlmSettings;
                 None
             }
+            // Editor integration: focus the code viewer at a location, e.g.
+            // for a VS Code/Neovim plugin driving this instance via the
+            // control socket (`{"command": "open src/main.rs:42"}`).
+            Some("open") => {
+                let arg = parts.get(1).unwrap_or(&"").trim();
+                if arg.is_empty() {
+                    self.toasts.push(
+                        crate::components::toast::ToastKind::Warning,
+                        "Usage: :open <file>[:<line>]",
+                    );
+                    None
+                } else {
+                    let (path, line) = arg.rsplit_once(':').map_or((arg, 1), |(p, l)| {
+                        l.parse::<usize>().map_or((arg, 1), |n| (p, n))
+                    });
+                    Some(AppCommand::OpenFileAtLine(path.to_string(), line))
+                }
+            }
+            Some("settings") => {
+                self.settings_overlay =
+                    Some(crate::settings_overlay::SettingsState::new(&self.config));
+                self.overlay = Overlay::Settings;
+                None
+            }
             _ => {
                 self.toasts.push(
                     crate::components::toast::ToastKind::Warning,
@@ -543,13 +1655,177 @@ impl App {
 mod tests {
     use crate::app::App;
     use crate::config::TuiConfig;
-    use crate::types::ViewState;
+    use crate::types::{InputMode, Overlay, ViewState};
+
+    use super::HOOK_MARKER;
 
     fn make_app() -> App {
         crate::theme::init_theme("dark");
         App::new(TuiConfig::default())
     }
 
+    /// `completion_preview` completes a bare command name, matching
+    /// `complete_command`.
+    #[test]
+    fn test_completion_preview_command_name() {
+        let mut app = make_app();
+        app.input = "/sc".to_string();
+        assert_eq!(app.completion_preview().as_deref(), Some("/scan"));
+    }
+
+    /// `completion_preview` completes a `/theme` argument against the
+    /// built-in theme names.
+    #[test]
+    fn test_completion_preview_theme_arg() {
+        let mut app = make_app();
+        app.input = "/theme com".to_string();
+        assert_eq!(
+            app.completion_preview().as_deref(),
+            Some("/theme Complior Dark")
+        );
+    }
+
+    /// `completion_preview` completes a `/view` argument against
+    /// `ViewState` short names.
+    #[test]
+    fn test_completion_preview_view_arg() {
+        let mut app = make_app();
+        app.input = "/view Da".to_string();
+        assert_eq!(app.completion_preview().as_deref(), Some("/view Dashboard"));
+    }
+
+    /// `completion_preview` returns `None` when nothing matches, so the
+    /// input is left untouched.
+    #[test]
+    fn test_completion_preview_no_match() {
+        let mut app = make_app();
+        app.input = "/theme zzz".to_string();
+        assert!(app.completion_preview().is_none());
+    }
+
+    /// `mention_query` reports the byte offset of the triggering `@` and
+    /// everything typed after it so far.
+    #[test]
+    fn test_mention_query_active() {
+        let mut app = make_app();
+        app.input = "check @OBL-0".to_string();
+        app.input_cursor = app.input.len();
+        assert_eq!(app.mention_query(), Some((6, "OBL-0")));
+    }
+
+    /// Whitespace after the `@` ends the mention — the user moved on to
+    /// plain text.
+    #[test]
+    fn test_mention_query_broken_by_whitespace() {
+        let mut app = make_app();
+        app.input = "@foo bar".to_string();
+        app.input_cursor = app.input.len();
+        assert!(app.mention_query().is_none());
+    }
+
+    /// With no `@` on the line, there is no active mention.
+    #[test]
+    fn test_mention_query_no_at() {
+        let mut app = make_app();
+        app.input = "hello world".to_string();
+        app.input_cursor = app.input.len();
+        assert!(app.mention_query().is_none());
+    }
+
+    /// `mention_matches` surfaces obligation matches for an `@OBL` prefix.
+    #[test]
+    fn test_mention_matches_obligation_prefix() {
+        let mut app = make_app();
+        app.input = "@OBL-0".to_string();
+        app.input_cursor = app.input.len();
+        let matches = app.mention_matches();
+        assert!(!matches.is_empty());
+        assert!(matches[0].insert.starts_with("@OBL-"));
+    }
+
+    /// `accept_mention` splices the selected match in place of the
+    /// `@prefix` and resets the popup selection.
+    #[test]
+    fn test_accept_mention_splices_selection() {
+        let mut app = make_app();
+        app.input = "@OBL-0".to_string();
+        app.input_cursor = app.input.len();
+        app.mention_index = 0;
+        app.accept_mention();
+        assert_eq!(app.input, "@OBL-001 ");
+        assert_eq!(app.input_cursor, app.input.len());
+        assert_eq!(app.mention_index, 0);
+    }
+
+    /// A short paste is inserted directly, with no confirmation prompt.
+    #[test]
+    fn test_handle_paste_short_inserts_directly() {
+        let mut app = make_app();
+        app.input_mode = InputMode::Insert;
+        app.handle_paste("hello world".to_string());
+        assert_eq!(app.input, "hello world");
+        assert_eq!(app.input_cursor, app.input.len());
+        assert!(app.pending_paste.is_none());
+        assert_eq!(app.overlay, Overlay::None);
+    }
+
+    /// A paste over the line threshold is held for confirmation instead of
+    /// being inserted immediately.
+    #[test]
+    fn test_handle_paste_long_requires_confirmation() {
+        let mut app = make_app();
+        app.input_mode = InputMode::Insert;
+        let long_paste = "line\n".repeat(25);
+        app.handle_paste(long_paste.clone());
+        assert!(app.input.is_empty());
+        assert_eq!(app.overlay, Overlay::PasteConfirm);
+        let pending = app.pending_paste.as_ref().expect("paste held for confirm");
+        assert_eq!(pending.text, long_paste);
+        assert_eq!(pending.line_count, 25);
+    }
+
+    /// `input_undo` restores the input line to its state before the last
+    /// edit, and can be replayed with `input_redo`.
+    #[test]
+    fn test_input_undo_and_redo_roundtrip() {
+        let mut app = make_app();
+        app.snapshot_input_undo();
+        app.input = "a".to_string();
+        app.input_cursor = 1;
+        app.snapshot_input_undo();
+        app.input = "ab".to_string();
+        app.input_cursor = 2;
+
+        app.input_undo();
+        assert_eq!(app.input, "a");
+        assert_eq!(app.input_cursor, 1);
+
+        app.input_undo();
+        assert_eq!(app.input, "");
+        assert_eq!(app.input_cursor, 0);
+
+        app.input_redo();
+        assert_eq!(app.input, "a");
+        app.input_redo();
+        assert_eq!(app.input, "ab");
+    }
+
+    /// Starting a fresh edit after an undo discards the redo stack.
+    #[test]
+    fn test_input_undo_new_edit_clears_redo() {
+        let mut app = make_app();
+        app.snapshot_input_undo();
+        app.input = "a".to_string();
+        app.input_undo();
+        assert!(app.input.is_empty());
+
+        app.snapshot_input_undo();
+        app.input = "b".to_string();
+        assert!(app.input_redo_stack.is_empty());
+        app.input_redo();
+        assert_eq!(app.input, "b", "redo is a no-op with an empty stack");
+    }
+
     // US-S0204: named tests — slash commands
 
     /// `/status` with no scan shows a prompt to run /scan.
@@ -578,6 +1854,30 @@ mod tests {
         );
     }
 
+    /// `/scan diff` with no base defaults to `origin/main`.
+    #[test]
+    fn test_slash_scan_diff_defaults_to_origin_main() {
+        let mut app = make_app();
+        let cmd = app.handle_command("scan diff");
+        assert!(matches!(cmd, Some(AppCommand::ScanDiff(base)) if base == "origin/main"));
+    }
+
+    /// `/scan diff <base>` uses the given ref instead of the default.
+    #[test]
+    fn test_slash_scan_diff_with_base() {
+        let mut app = make_app();
+        let cmd = app.handle_command("scan diff release/v1");
+        assert!(matches!(cmd, Some(AppCommand::ScanDiff(base)) if base == "release/v1"));
+    }
+
+    /// Plain `/scan` (no `diff` argument) still runs a full scan.
+    #[test]
+    fn test_slash_scan_without_diff_runs_full_scan() {
+        let mut app = make_app();
+        let cmd = app.handle_command("scan");
+        assert!(matches!(cmd, Some(AppCommand::Scan)));
+    }
+
     /// `/report` switches view to Report.
     #[test]
     fn test_slash_report_switches_view() {
@@ -590,4 +1890,69 @@ mod tests {
             "/report should switch to Report view"
         );
     }
+
+    /// Set up a scratch git repo and point `App::project_path` at it.
+    fn make_app_in_git_repo(name: &str) -> App {
+        let dir = std::env::temp_dir().join(name);
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::process::Command::new("git")
+            .args(["init", "-q"])
+            .current_dir(&dir)
+            .status()
+            .unwrap();
+        let mut app = make_app();
+        app.project_path = dir;
+        app
+    }
+
+    /// `/hooks install` writes an executable pre-commit hook with the
+    /// default threshold.
+    #[test]
+    fn test_hooks_install_writes_pre_commit_hook() {
+        let app = make_app_in_git_repo("complior_test_hooks_install");
+        let msg = app.handle_hooks_command("install");
+        assert!(msg.contains("Installed pre-commit hook"));
+
+        let hook_path = app.hooks_dir().join("pre-commit");
+        let content = std::fs::read_to_string(&hook_path).unwrap();
+        assert!(content.contains(HOOK_MARKER));
+        assert!(content.contains("--threshold 60"));
+    }
+
+    /// `/hooks install pre-push 80` uses the given stage and threshold.
+    #[test]
+    fn test_hooks_install_custom_stage_and_threshold() {
+        let app = make_app_in_git_repo("complior_test_hooks_install_custom");
+        let msg = app.handle_hooks_command("install pre-push 80");
+        assert!(msg.contains("Installed pre-push hook"));
+
+        let content = std::fs::read_to_string(app.hooks_dir().join("pre-push")).unwrap();
+        assert!(content.contains("--threshold 80"));
+    }
+
+    /// `/hooks uninstall` removes a complior-managed hook but leaves an
+    /// unrelated one untouched.
+    #[test]
+    fn test_hooks_uninstall_only_removes_managed_hooks() {
+        let app = make_app_in_git_repo("complior_test_hooks_uninstall");
+        app.handle_hooks_command("install");
+        let dir = app.hooks_dir();
+        std::fs::write(dir.join("pre-push"), "#!/bin/sh\necho manual\n").unwrap();
+
+        let msg = app.handle_hooks_command("uninstall");
+        assert!(msg.contains("pre-commit"));
+        assert!(!dir.join("pre-commit").exists());
+        assert!(dir.join("pre-push").exists(), "manual hook must survive");
+    }
+
+    /// `/hooks status` reports installed vs. absent hooks.
+    #[test]
+    fn test_hooks_status_reports_installed_and_missing() {
+        let app = make_app_in_git_repo("complior_test_hooks_status");
+        app.handle_hooks_command("install");
+        let msg = app.handle_hooks_command("status");
+        assert!(msg.contains("pre-commit: installed"));
+        assert!(msg.contains("pre-push: not installed"));
+    }
 }
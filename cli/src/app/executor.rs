@@ -3,13 +3,133 @@ use tokio::sync::mpsc;
 use super::{App, AppCommand};
 use crate::components;
 use crate::config;
+use crate::error::TuiError;
 use crate::headless::common::url_encode;
 use crate::session;
 use crate::types;
 use crate::views;
 use crate::watcher;
 
+/// Files changed versus `base` (e.g. `origin/main`), via `git diff
+/// --name-only`. Tries the triple-dot merge-base form first, falling back
+/// to a plain diff for detached-HEAD or shallow-clone setups.
+fn git_diff_files(base: &str, project_path: &str) -> Vec<String> {
+    let run = |args: &[&str]| {
+        std::process::Command::new("git")
+            .args(args)
+            .current_dir(project_path)
+            .output()
+            .ok()
+            .filter(|o| o.status.success())
+            .map(|o| {
+                String::from_utf8_lossy(&o.stdout)
+                    .lines()
+                    .filter(|l| !l.is_empty())
+                    .map(String::from)
+                    .collect::<Vec<_>>()
+            })
+    };
+    run(&["diff", "--name-only", &format!("{base}...HEAD")])
+        .or_else(|| run(&["diff", "--name-only", base]))
+        .unwrap_or_default()
+}
+
+/// Resolve the Fix view's selected findings into computed [`views::fix::FixPlan`]s,
+/// paired with their index into `fix_view.fixable_findings` so the caller
+/// can update item status. Findings that can't be planned (missing from the
+/// last scan, or `plan_fix` itself failing) are reported as `(index, detail)`
+/// in the second vec instead.
+fn plan_selected_fixes(app: &App) -> (Vec<(usize, views::fix::FixPlan)>, Vec<(usize, String)>) {
+    let mut plans = Vec::new();
+    let mut errors = Vec::new();
+    for (idx, item) in app.fix_view.fixable_findings.iter().enumerate() {
+        if !item.selected {
+            continue;
+        }
+        let finding = app
+            .last_scan
+            .as_ref()
+            .and_then(|s| s.findings.get(item.finding_index))
+            .cloned();
+        match finding {
+            Some(f) => {
+                let override_content = app.fix_view.template_override(&f.check_id);
+                match views::fix::plan_fix(&app.project_path, &f, override_content) {
+                    Ok(plan) => plans.push((idx, plan)),
+                    Err(detail) => errors.push((idx, detail)),
+                }
+            }
+            None => errors.push((idx, "Finding not found in scan".to_string())),
+        }
+    }
+    (plans, errors)
+}
+
+/// Human-readable summary of a `/scan/diff` engine response for the chat
+/// panel: score delta plus new/resolved finding counts.
+fn format_scan_diff_summary(base: &str, result: &serde_json::Value) -> String {
+    if let Some(err) = result.get("error").and_then(|v| v.as_str()) {
+        let msg = result
+            .get("message")
+            .and_then(|v| v.as_str())
+            .unwrap_or(err);
+        return format!("Scan diff failed: {msg}");
+    }
+    let before = result
+        .get("scoreBefore")
+        .and_then(serde_json::Value::as_u64)
+        .unwrap_or(0);
+    let after = result
+        .get("scoreAfter")
+        .and_then(serde_json::Value::as_u64)
+        .unwrap_or(0);
+    let delta = result
+        .get("scoreDelta")
+        .and_then(serde_json::Value::as_i64)
+        .unwrap_or(0);
+    let new_count = result
+        .get("newFindings")
+        .and_then(|v| v.as_array())
+        .map_or(0, Vec::len);
+    let resolved_count = result
+        .get("resolvedFindings")
+        .and_then(|v| v.as_array())
+        .map_or(0, Vec::len);
+    format!(
+        "Diff vs {base}: score {before} -> {after} ({delta:+}). {new_count} new finding(s) introduced by this change, {resolved_count} resolved.",
+    )
+}
+
 impl App {
+    /// Point `chat_tool_focus` at the last tool call/result block across
+    /// all messages, so `Enter` in Chat view opens the inspector for it.
+    fn refresh_chat_tool_focus(&mut self) {
+        for (mi, msg) in self.messages.iter().enumerate().rev() {
+            if let Some(bi) = msg.blocks.iter().rposition(|b| {
+                matches!(
+                    b,
+                    types::ChatBlock::ToolCall { .. } | types::ChatBlock::ToolResult { .. }
+                )
+            }) {
+                self.chat_tool_focus = Some((mi, bi));
+                return;
+            }
+        }
+    }
+
+    /// Record that an engine request failed: sets the footer's degraded-mode
+    /// badge from the error's category and pushes a toast carrying its
+    /// remediation hint. Does not touch `app.messages` — callers still push
+    /// their own chat-visible failure message.
+    fn report_engine_error(&mut self, e: &crate::error::TuiError) {
+        let category = e.category();
+        self.degraded_mode = Some(category);
+        self.toasts.push(
+            components::toast::ToastKind::Error,
+            format!("{e} — {}", category.remediation_hint()),
+        );
+    }
+
     /// Extract the project path and name from the first loaded passport.
     /// Returns `None` if no passport is loaded.
     fn passport_path_name(&self) -> Option<(String, String)> {
@@ -31,8 +151,9 @@ impl App {
 pub async fn execute_command(
     app: &mut App,
     cmd: AppCommand,
-    watch_tx: &mpsc::UnboundedSender<std::path::PathBuf>,
+    watch_tx: &mpsc::UnboundedSender<Vec<(std::path::PathBuf, watcher::ChangeKind)>>,
     watch_handle: &mut Option<tokio::task::JoinHandle<()>>,
+    persist_tx: &mpsc::UnboundedSender<crate::session::SaveJob>,
 ) {
     match cmd {
         AppCommand::ToggleWatch => {
@@ -50,8 +171,12 @@ pub async fn execute_command(
             } else {
                 // Start watcher
                 *watch_handle = Some(watcher::spawn_watcher(
-                    app.project_path.clone(),
+                    app.watch_roots(),
                     watch_tx.clone(),
+                    app.config.watch_debounce_ms,
+                    watcher::PatternSet::new(&app.config.watch_include, &app.config.watch_exclude),
+                    app.watch_suppressor.clone(),
+                    app.watch_options(),
                 ));
                 app.watch_active = true;
                 app.mode = types::Mode::Watch;
@@ -61,6 +186,84 @@ pub async fn execute_command(
                 ));
             }
         }
+        AppCommand::RestartWatcher => {
+            if app.watch_active
+                && let Some(handle) = watch_handle.take()
+            {
+                handle.abort();
+                *watch_handle = Some(watcher::spawn_watcher(
+                    app.watch_roots(),
+                    watch_tx.clone(),
+                    app.config.watch_debounce_ms,
+                    watcher::PatternSet::new(&app.config.watch_include, &app.config.watch_exclude),
+                    app.watch_suppressor.clone(),
+                    app.watch_options(),
+                ));
+            }
+        }
+        AppCommand::SwitchProject(path) => {
+            let name = path.file_name().map_or_else(
+                || path.display().to_string(),
+                |n| n.to_string_lossy().to_string(),
+            );
+            app.switch_project(path).await;
+            if app.watch_active
+                && let Some(handle) = watch_handle.take()
+            {
+                handle.abort();
+                *watch_handle = Some(watcher::spawn_watcher(
+                    app.watch_roots(),
+                    watch_tx.clone(),
+                    app.config.watch_debounce_ms,
+                    watcher::PatternSet::new(&app.config.watch_include, &app.config.watch_exclude),
+                    app.watch_suppressor.clone(),
+                    app.watch_options(),
+                ));
+            }
+            app.messages.push(types::ChatMessage::new(
+                types::MessageRole::System,
+                format!("Switched to project: {name}"),
+            ));
+            let path = app.project_path.to_string_lossy().to_string();
+            match app.engine_client.scan(&path).await {
+                Ok(result) => app.set_scan_result(result),
+                Err(e) => {
+                    app.messages.push(types::ChatMessage::new(
+                        types::MessageRole::System,
+                        format!("Scan failed: {e}"),
+                    ));
+                    app.report_engine_error(&e);
+                }
+            }
+        }
+        AppCommand::FetchProjectList => {
+            let active = app.project_path.to_string_lossy().to_string();
+            let mut paths = app.config.registered_projects.clone();
+            if !paths.iter().any(|p| p == &active) {
+                paths.push(active);
+            }
+            app.project_switcher.entries = paths
+                .into_iter()
+                .map(|path| {
+                    let name = std::path::Path::new(&path)
+                        .file_name()
+                        .map_or_else(|| path.clone(), |n| n.to_string_lossy().to_string());
+                    let last_scan = std::fs::read_to_string(
+                        std::path::Path::new(&path).join(".complior/last-scan.json"),
+                    )
+                    .ok()
+                    .and_then(|content| serde_json::from_str::<types::ScanResult>(&content).ok());
+                    components::project_switcher::ProjectEntry {
+                        path,
+                        name,
+                        score: last_scan.as_ref().map(|s| s.score.total_score),
+                        zone: last_scan.as_ref().map(|s| s.score.zone),
+                        findings_count: last_scan.as_ref().map(|s| s.findings.len()),
+                    }
+                })
+                .collect();
+            app.project_switcher.selected = 0;
+        }
         AppCommand::AutoScan => {
             // Save previous score for regression detection
             let prev_score = app.last_scan.as_ref().map(|s| s.score.total_score);
@@ -124,6 +327,7 @@ pub async fn execute_command(
                         types::MessageRole::System,
                         format!("Auto-scan failed: {e}"),
                     ));
+                    app.report_engine_error(&e);
                 }
             }
         }
@@ -140,26 +344,57 @@ pub async fn execute_command(
                     app.scan_view.scanning = false;
                     app.scan_view.scan_error = Some(msg);
                     app.operation_start = None;
+                    app.report_engine_error(&e);
                 }
             }
         }
-        AppCommand::OpenFile(path) => match app.engine_client.read_file(&path).await {
-            Ok(content) => app.open_file(&path, content),
-            Err(_) => {
-                // Fallback: try reading locally
-                match tokio::fs::read_to_string(&path).await {
-                    Ok(content) => app.open_file(&path, content),
+        AppCommand::ScanDiff(base) => {
+            let path = app.project_path.to_string_lossy().to_string();
+            let changed_files = git_diff_files(&base, &path);
+            if changed_files.is_empty() {
+                app.messages.push(types::ChatMessage::new(
+                    types::MessageRole::System,
+                    format!("No changed files found between HEAD and {base}."),
+                ));
+                app.operation_start = None;
+            } else {
+                let body = serde_json::json!({
+                    "path": path,
+                    "changedFiles": changed_files,
+                });
+                match app.engine_client.post_json("/scan/diff", &body).await {
+                    Ok(result) => {
+                        app.messages.push(types::ChatMessage::new(
+                            types::MessageRole::System,
+                            format_scan_diff_summary(&base, &result),
+                        ));
+                    }
                     Err(e) => {
                         app.messages.push(types::ChatMessage::new(
                             types::MessageRole::System,
-                            format!("Cannot open file: {e}"),
+                            format!("Scan diff failed: {e}"),
                         ));
                     }
                 }
+                app.operation_start = None;
             }
-        },
+        }
+        AppCommand::OpenFile(path) => open_file_or_report(app, &path).await,
+        AppCommand::OpenFileAtLine(path, line) => {
+            open_file_or_report(app, &path).await;
+            if app.code_buffer.is_some() {
+                app.code_scroll = line.saturating_sub(1);
+            }
+        }
+        AppCommand::OpenInEditor(path, line) => {
+            open_in_editor(app, &path, line).await;
+        }
+        AppCommand::CheckOpenFileChanged(path) => {
+            check_open_file_changed(app, path).await;
+        }
         AppCommand::RunCommand(command) => {
             app.add_terminal_line(format!("$ {command}"));
+            let command = with_env_overrides(&command, &app.env_overrides);
             match app.engine_client.run_command(&command).await {
                 Ok(output) => {
                     for line in output.lines() {
@@ -179,6 +414,7 @@ pub async fn execute_command(
             match app.engine_client.status().await {
                 Ok(status) if status.ready => {
                     app.engine_status = types::EngineConnectionStatus::Connected;
+                    app.engine_info = Some(status);
                     app.messages.push(types::ChatMessage::new(
                         types::MessageRole::System,
                         "Reconnected successfully.".to_string(),
@@ -186,6 +422,7 @@ pub async fn execute_command(
                 }
                 _ => {
                     app.engine_status = types::EngineConnectionStatus::Disconnected;
+                    app.engine_info = None;
                     app.messages.push(types::ChatMessage::new(
                         types::MessageRole::System,
                         "Reconnect failed. Is engine running?".to_string(),
@@ -193,6 +430,11 @@ pub async fn execute_command(
                 }
             }
         }
+        AppCommand::Doctor => {
+            let (report, _code) = crate::headless::doctor_report(&app.config).await;
+            app.messages
+                .push(types::ChatMessage::new(types::MessageRole::System, report));
+        }
         AppCommand::SwitchTheme(name) => {
             crate::theme::init_theme(&name);
             app.messages.push(types::ChatMessage::new(
@@ -201,43 +443,84 @@ pub async fn execute_command(
             ));
         }
         AppCommand::SaveSession(name) => {
-            let data = app.to_session_data();
-            match session::save_session(&data, &name).await {
-                Ok(()) => {
+            let job = session::SaveJob {
+                data: app.to_session_data(),
+                name: name.clone(),
+                project_path: app.project_path.clone(),
+                encrypt: app.config.session_encryption,
+            };
+            // Handed off to the background writer (see `session::spawn_writer`)
+            // instead of awaited here, so a large history never stalls the
+            // event loop. The send only fails if the writer task has died,
+            // which is unexpected enough to just drop the request.
+            let _ = persist_tx.send(job);
+            app.messages.push(types::ChatMessage::new(
+                types::MessageRole::System,
+                format!("Session saved: {name}"),
+            ));
+        }
+        AppCommand::LoadSession(name) => {
+            match session::load_session(&name, &app.project_path).await {
+                Ok(data) => {
+                    app.load_session_data(data);
                     app.messages.push(types::ChatMessage::new(
                         types::MessageRole::System,
-                        format!("Session saved: {name}"),
+                        format!("Session loaded: {name}"),
                     ));
                 }
                 Err(e) => {
                     app.messages.push(types::ChatMessage::new(
                         types::MessageRole::System,
-                        format!("Save failed: {e}"),
+                        format!("Load failed: {e}"),
                     ));
                 }
             }
         }
-        AppCommand::LoadSession(name) => match session::load_session(&name).await {
-            Ok(data) => {
-                app.load_session_data(data);
-                app.messages.push(types::ChatMessage::new(
-                    types::MessageRole::System,
-                    format!("Session loaded: {name}"),
-                ));
+        AppCommand::SaveLayout(name) => {
+            match session::save_layout(&app.to_layout_preset(), &name).await {
+                Ok(()) => {
+                    app.toasts.push(
+                        components::toast::ToastKind::Success,
+                        format!("Layout saved: {name}"),
+                    );
+                }
+                Err(e) => {
+                    app.toasts.push(
+                        components::toast::ToastKind::Warning,
+                        format!("Save failed: {e}"),
+                    );
+                }
+            }
+        }
+        AppCommand::LoadLayout(name) => match session::load_layout(&name).await {
+            Ok(preset) => {
+                app.load_layout_preset(preset);
+                app.toasts.push(
+                    components::toast::ToastKind::Success,
+                    format!("Layout loaded: {name}"),
+                );
             }
             Err(e) => {
-                app.messages.push(types::ChatMessage::new(
-                    types::MessageRole::System,
+                app.toasts.push(
+                    components::toast::ToastKind::Warning,
                     format!("Load failed: {e}"),
-                ));
+                );
             }
         },
+        AppCommand::Undo(_id)
+            if app
+                .engine_info
+                .as_ref()
+                .is_some_and(|s| !s.supports("/undo")) =>
+        {
+            app.toasts.push(
+                components::toast::ToastKind::Warning,
+                "Undo not supported by this engine version".to_string(),
+            );
+        }
         AppCommand::Undo(id) => match app.engine_client.undo(id).await {
             Ok(result) => {
-                let msg = result
-                    .get("message")
-                    .and_then(|v| v.as_str())
-                    .unwrap_or("Undo applied");
+                let msg = result.message.as_deref().unwrap_or("Undo applied");
                 app.toasts
                     .push(components::toast::ToastKind::Success, msg.to_string());
                 app.push_activity(types::ActivityKind::Fix, "Undo");
@@ -248,34 +531,32 @@ pub async fn execute_command(
                     .push(components::toast::ToastKind::Warning, "Nothing to undo");
             }
         },
+        AppCommand::FetchUndoHistory
+            if app
+                .engine_info
+                .as_ref()
+                .is_some_and(|s| !s.supports("/undo")) =>
+        {
+            app.undo_history.entries.clear();
+            app.toasts.push(
+                components::toast::ToastKind::Warning,
+                "Undo history not supported by this engine version".to_string(),
+            );
+        }
         AppCommand::FetchUndoHistory => match app.engine_client.undo_history().await {
             Ok(entries) => {
                 app.undo_history.entries = entries
-                    .iter()
-                    .filter_map(|v| {
-                        Some(components::undo_history::UndoEntry {
-                            id: v.get("id")?.as_u64()? as u32,
-                            timestamp: v
-                                .get("timestamp")
-                                .and_then(|t| t.as_str())
-                                .unwrap_or("")
-                                .to_string(),
-                            action: v
-                                .get("action")
-                                .and_then(|a| a.as_str())
-                                .unwrap_or("")
-                                .to_string(),
-                            status: match v
-                                .get("status")
-                                .and_then(|s| s.as_str())
-                                .unwrap_or("applied")
-                            {
-                                "undone" => components::undo_history::UndoStatus::Undone,
-                                "baseline" => components::undo_history::UndoStatus::Baseline,
-                                _ => components::undo_history::UndoStatus::Applied,
-                            },
-                            score_delta: v.get("scoreDelta").and_then(serde_json::Value::as_f64),
-                        })
+                    .into_iter()
+                    .map(|e| components::undo_history::UndoEntry {
+                        id: e.id,
+                        timestamp: e.timestamp,
+                        action: e.action,
+                        status: match e.status.as_str() {
+                            "undone" => components::undo_history::UndoStatus::Undone,
+                            "baseline" => components::undo_history::UndoStatus::Baseline,
+                            _ => components::undo_history::UndoStatus::Applied,
+                        },
+                        score_delta: e.score_delta,
                     })
                     .collect();
                 app.undo_history.selected = 0;
@@ -286,37 +567,54 @@ pub async fn execute_command(
         },
         AppCommand::FetchSuggestions => {
             app.idle_suggestions.fetch_pending = false;
-            match app.engine_client.suggestions().await {
-                Ok(items) if !items.is_empty() => {
-                    if let Some(first) = items.first() {
-                        let kind_str = first.get("kind").and_then(|k| k.as_str()).unwrap_or("tip");
-                        let kind = match kind_str {
-                            "fix" => components::suggestions::SuggestionKind::Fix,
-                            "deadline" => components::suggestions::SuggestionKind::DeadlineWarning,
-                            "score" => components::suggestions::SuggestionKind::ScoreImprovement,
-                            "new" => components::suggestions::SuggestionKind::NewFeature,
-                            _ => components::suggestions::SuggestionKind::Tip,
-                        };
-                        app.idle_suggestions.current = Some(components::suggestions::Suggestion {
-                            kind,
-                            text: first
-                                .get("text")
-                                .and_then(|t| t.as_str())
-                                .unwrap_or("")
-                                .to_string(),
-                            detail: first
-                                .get("detail")
-                                .and_then(|d| d.as_str())
-                                .map(String::from),
-                        });
-                    }
-                }
-                _ => {
-                    // Engine doesn't have /suggestions or returned empty — use local context
-                    let suggestion = build_local_suggestion(app);
-                    app.idle_suggestions.current = Some(suggestion);
+            let scan_key = app.scan_cache_key();
+            let supports_suggestions = app
+                .engine_info
+                .as_ref()
+                .is_none_or(|s| s.supports("/suggestions"));
+            let cached: Option<Vec<types::SuggestionItem>> = app
+                .response_cache
+                .get("suggestions", &scan_key)
+                .and_then(|v| serde_json::from_value(v.clone()).ok());
+            let items = if let Some(cached) = cached {
+                Ok(cached)
+            } else if supports_suggestions {
+                let fetched = app.engine_client.suggestions().await;
+                if let Ok(ref items) = fetched
+                    && let Ok(value) = serde_json::to_value(items)
+                {
+                    app.response_cache.put("suggestions", scan_key, value);
                 }
-            }
+                fetched
+            } else {
+                // Engine explicitly doesn't support /suggestions — skip the
+                // round trip and go straight to the local fallback below.
+                Ok(Vec::new())
+            };
+            let suggestion = match items {
+                Ok(items) if !items.is_empty() => items.into_iter().next().map(|first| {
+                    let kind_str = first.kind.as_deref().unwrap_or("tip");
+                    let kind = components::suggestions::SuggestionKind::from_key(kind_str)
+                        .unwrap_or(components::suggestions::SuggestionKind::Tip);
+                    components::suggestions::Suggestion {
+                        kind,
+                        text: first.text.unwrap_or_default(),
+                        detail: first.detail,
+                    }
+                }),
+                // Engine doesn't have /suggestions or returned empty — use local context
+                _ => Some(build_local_suggestion(app)),
+            };
+            let now_secs = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs());
+            app.idle_suggestions.current = suggestion.filter(|s| {
+                !components::suggestions::is_snoozed(
+                    &app.config.snoozed_suggestions,
+                    s.kind,
+                    now_secs,
+                )
+            });
         }
         // T905: What-If scenario analysis
         AppCommand::WhatIf(scenario) => {
@@ -430,50 +728,99 @@ pub async fn execute_command(
             }
         }
         AppCommand::ApplyFixes => {
-            use views::fix::{FixItemStatus, apply_fix_to_file};
+            use views::fix::FixItemStatus;
 
             let old_score = app.last_scan.as_ref().map_or(0.0, |s| s.score.total_score);
             app.pre_fix_score = Some(old_score);
 
-            let selected_indices: Vec<usize> = app
-                .fix_view
-                .fixable_findings
-                .iter()
-                .enumerate()
-                .filter(|(_, item)| item.selected)
-                .map(|(i, _)| i)
-                .collect();
+            let mut pre_failed: u32 = 0;
+            let mut pre_details: Vec<String> = Vec::new();
+
+            // Fixes write to disk directly, which the watcher would otherwise
+            // see as external changes and re-trigger AutoScan for — on top of
+            // the explicit rescan below. Suppress until that rescan is done.
+            app.watch_suppressor.suppress();
+
+            // Plan every selected fix (read + compute, no writes yet), then
+            // journal the whole batch before touching disk — a crash mid-batch
+            // leaves `.complior/fix-journal.json` behind for the next startup
+            // to offer roll-forward/roll-back on (see `crate::fix_journal`).
+            let (plans, plan_errors) = plan_selected_fixes(app);
+            for (idx, detail) in plan_errors {
+                app.fix_view.fixable_findings[idx].status = FixItemStatus::Failed;
+                pre_failed += 1;
+                pre_details.push(detail);
+            }
+
+            let plan_indices: Vec<usize> = plans.iter().map(|(idx, _)| *idx).collect();
+            let plan_values: Vec<views::fix::FixPlan> =
+                plans.into_iter().map(|(_, p)| p).collect();
+            if !plan_values.is_empty()
+                && let Err(e) = crate::fix_journal::write_journal(&app.project_path, &plan_values)
+            {
+                pre_details.push(format!("Warning: could not write fix journal: {e}"));
+            }
+
+            // Write the batch on a background task so the footer's progress
+            // bar (see `views::dashboard::footer`) can advance one fix at a
+            // time instead of jumping from empty to done — same `bg_tx`
+            // pattern as `LoadRegistry`/streaming chat.
+            let total = plan_values.len() as u32;
+            app.fix_view.applying_current = 0;
+            app.fix_view.applying_total = total;
 
+            let project_path = app.project_path.clone();
+            let tx = app.bg_tx.clone();
+            tokio::spawn(async move {
+                use views::fix::write_plan;
+
+                let mut statuses = Vec::with_capacity(plan_values.len());
+                for (i, plan) in plan_values.iter().enumerate() {
+                    let result = write_plan(&project_path, plan);
+                    statuses.push((plan_indices[i], result.success, result.detail));
+                    let _ = tx.send(AppCommand::FixProgress {
+                        current: (i + 1) as u32,
+                        total,
+                    });
+                }
+                crate::fix_journal::clear_journal(&project_path);
+                let _ = tx.send(AppCommand::FixesApplied {
+                    statuses,
+                    pre_failed,
+                    pre_details,
+                });
+            });
+        }
+        AppCommand::FixProgress { current, total } => {
+            app.fix_view.applying_current = current;
+            app.fix_view.applying_total = total;
+        }
+        AppCommand::FixesApplied {
+            statuses,
+            pre_failed,
+            pre_details,
+        } => {
+            use views::fix::FixItemStatus;
+
+            let old_score = app.pre_fix_score.unwrap_or(0.0);
             let mut applied: u32 = 0;
-            let mut failed: u32 = 0;
-            let mut details: Vec<String> = Vec::new();
-
-            for idx in &selected_indices {
-                let finding_index = app.fix_view.fixable_findings[*idx].finding_index;
-                let finding = app
-                    .last_scan
-                    .as_ref()
-                    .and_then(|s| s.findings.get(finding_index))
-                    .cloned();
-
-                if let Some(f) = finding {
-                    let result = apply_fix_to_file(&app.project_path, &f);
-                    if result.success {
-                        app.fix_view.fixable_findings[*idx].status = FixItemStatus::Applied;
-                        applied += 1;
-                    } else {
-                        app.fix_view.fixable_findings[*idx].status = FixItemStatus::Failed;
-                        failed += 1;
-                    }
-                    details.push(result.detail);
+            let mut failed: u32 = pre_failed;
+            let mut details = pre_details;
+
+            for (idx, success, detail) in statuses {
+                if success {
+                    app.fix_view.fixable_findings[idx].status = FixItemStatus::Applied;
+                    applied += 1;
                 } else {
-                    app.fix_view.fixable_findings[*idx].status = FixItemStatus::Failed;
+                    app.fix_view.fixable_findings[idx].status = FixItemStatus::Failed;
                     failed += 1;
-                    details.push("Finding not found in scan".to_string());
                 }
+                details.push(detail);
             }
 
             app.fix_view.applying = false;
+            app.fix_view.applying_current = 0;
+            app.fix_view.applying_total = 0;
 
             // Log details
             for d in &details {
@@ -493,6 +840,7 @@ pub async fn execute_command(
             });
 
             if applied > 0 {
+                crate::stats::record_fixes(&app.project_path, applied);
                 app.toasts.push(
                     components::toast::ToastKind::Success,
                     format!("{applied} fix(es) applied to disk. Re-scanning..."),
@@ -502,7 +850,7 @@ pub async fn execute_command(
                     .unwrap_or_default()
                     .as_secs();
                 app.activity_log.push(types::ActivityEntry {
-                    timestamp: format!("{:02}:{:02}", (now % 86400) / 3600, (now % 3600) / 60),
+                    timestamp: crate::timezone::format_hm(now),
                     kind: types::ActivityKind::Fix,
                     detail: format!("{applied} applied, {failed} failed"),
                 });
@@ -541,6 +889,7 @@ pub async fn execute_command(
                             types::MessageRole::System,
                             format!("Re-scan failed: {e}"),
                         ));
+                        app.report_engine_error(&e);
                     }
                 }
             } else {
@@ -549,10 +898,103 @@ pub async fn execute_command(
                     format!("No fixes applied. {failed} failed."),
                 );
             }
+
+            app.watch_suppressor.resume();
+        }
+        AppCommand::FixSandbox => {
+            use views::fix::write_plan;
+
+            let old_score = app.last_scan.as_ref().map_or(0.0, |s| s.score.total_score);
+            let (plans, plan_errors) = plan_selected_fixes(app);
+
+            for (_, detail) in &plan_errors {
+                app.messages.push(types::ChatMessage::new(
+                    types::MessageRole::System,
+                    detail.clone(),
+                ));
+            }
+            if plans.is_empty() {
+                app.toasts.push(
+                    components::toast::ToastKind::Warning,
+                    "No fixes could be planned for the sandbox run.",
+                );
+                return;
+            }
+
+            let sandbox = match crate::fix_sandbox::create_sandbox_copy(&app.project_path) {
+                Ok(dir) => dir,
+                Err(e) => {
+                    app.toasts.push(
+                        components::toast::ToastKind::Error,
+                        format!("Could not create sandbox copy: {e}"),
+                    );
+                    return;
+                }
+            };
+
+            let mut applied: u32 = 0;
+            let mut failed: u32 = 0;
+            for (_, plan) in &plans {
+                if write_plan(&sandbox, plan).success {
+                    applied += 1;
+                } else {
+                    failed += 1;
+                }
+            }
+
+            let sandbox_path = sandbox.to_string_lossy().to_string();
+            match app.engine_client.scan(&sandbox_path).await {
+                Ok(result) => {
+                    let new_score = result.score.total_score;
+                    let delta = new_score - old_score;
+                    let msg = format!(
+                        "Sandbox Fix Analysis (no files modified in your project)\n\
+                         Applied {applied} fix(es) to a throwaway copy ({failed} failed to write).\n\
+                         Measured score: {old_score:.0} -> {new_score:.0} ({delta:+.0})\n\
+                         Run /fix to apply for real."
+                    );
+                    app.messages
+                        .push(types::ChatMessage::new(types::MessageRole::Assistant, msg));
+                    app.toasts.push(
+                        components::toast::ToastKind::Success,
+                        format!("Sandbox measured score: {new_score:.0} ({delta:+.0})"),
+                    );
+                }
+                Err(e) => {
+                    app.toasts.push(
+                        components::toast::ToastKind::Warning,
+                        "Sandbox rescan failed. Run /fix --dry-run for an offline estimate.",
+                    );
+                    app.messages.push(types::ChatMessage::new(
+                        types::MessageRole::System,
+                        format!("Sandbox rescan failed: {e}"),
+                    ));
+                    app.report_engine_error(&e);
+                }
+            }
+
+            crate::fix_sandbox::cleanup_sandbox(&sandbox);
         }
         AppCommand::SaveTheme(name) => {
             config::save_theme(&name).await;
         }
+        AppCommand::RegisterProject(path) => {
+            config::add_registered_project(&path).await;
+            app.config.registered_projects.retain(|p| p != &path);
+            app.config.registered_projects.push(path);
+        }
+        AppCommand::UnregisterProject(path) => {
+            config::remove_registered_project(&path).await;
+            app.config.registered_projects.retain(|p| p != &path);
+        }
+        AppCommand::SnoozeSuggestion(kind, until_secs) => {
+            config::save_snoozed_suggestion(kind, until_secs).await;
+            app.config.snoozed_suggestions = components::suggestions::snooze_until(
+                &app.config.snoozed_suggestions,
+                kind,
+                until_secs,
+            );
+        }
         AppCommand::MarkOnboardingComplete => {
             config::mark_onboarding_complete().await;
         }
@@ -560,7 +1002,7 @@ pub async fn execute_command(
             session::mark_first_run_done().await;
         }
         AppCommand::ListSessions => {
-            let sessions = session::list_sessions().await;
+            let sessions = session::list_sessions(&app.project_path).await;
             if sessions.is_empty() {
                 app.messages.push(types::ChatMessage::new(
                     types::MessageRole::System,
@@ -575,7 +1017,17 @@ pub async fn execute_command(
         }
         AppCommand::ExportReport => {
             if let Some(scan) = &app.last_scan {
-                match views::report::export_report(scan).await {
+                let dismissals = app.read_dismissals();
+                match views::report::export_report(
+                    scan,
+                    &app.assignments,
+                    &app.finding_states,
+                    &app.score_history,
+                    &dismissals,
+                    &app.report_sections,
+                )
+                .await
+                {
                     Ok(path) => {
                         app.report_view.export_status =
                             views::report::ExportStatus::Done(path.clone());
@@ -648,6 +1100,7 @@ pub async fn execute_command(
                                 types::MessageRole::System,
                                 format!("First scan failed: {e}. Use /scan to retry."),
                             ));
+                            app.report_engine_error(&e);
                         }
                     }
                 }
@@ -671,6 +1124,14 @@ pub async fn execute_command(
         AppCommand::SaveOnboardingPartial(last_step) => {
             config::save_onboarding_partial(last_step).await;
         }
+        AppCommand::SaveRiskClassification(level) => {
+            config::save_risk_classification(level).await;
+            app.config = config::load_config();
+            app.toasts.push(
+                components::toast::ToastKind::Info,
+                format!("Risk classification saved: {}", level.label()),
+            );
+        }
         AppCommand::LoadPassports => {
             app.passport_view.passport_loading = true;
             app.passport_view.passport_error = None;
@@ -854,27 +1315,40 @@ pub async fn execute_command(
                 );
             }
         }
-        AppCommand::LoadObligations => match app.engine_client.get_json("/obligations").await {
-            Ok(result) => {
-                if let Some(arr) = result.as_array() {
-                    app.obligations_view.load_from_json(arr);
-                    let count = arr.len();
-                    let covered = app.obligations_view.covered_count();
-                    if count > 0 {
-                        app.messages.push(types::ChatMessage::new(
-                            types::MessageRole::System,
-                            format!("Loaded {count} obligations ({covered} covered)."),
-                        ));
+        AppCommand::LoadObligations => {
+            let scan_key = app.scan_cache_key();
+            let result = if let Some(cached) = app.response_cache.get("obligations", &scan_key) {
+                Ok(cached.clone())
+            } else {
+                let fetched = app.engine_client.get_json("/obligations").await;
+                if let Ok(ref result) = fetched {
+                    app.response_cache
+                        .put("obligations", scan_key, result.clone());
+                }
+                fetched
+            };
+            match result {
+                Ok(result) => {
+                    if let Some(arr) = result.as_array() {
+                        app.obligations_view.load_from_json(arr);
+                        let count = arr.len();
+                        let covered = app.obligations_view.covered_count();
+                        if count > 0 {
+                            app.messages.push(types::ChatMessage::new(
+                                types::MessageRole::System,
+                                format!("Loaded {count} obligations ({covered} covered)."),
+                            ));
+                        }
                     }
                 }
+                Err(e) => {
+                    app.messages.push(types::ChatMessage::new(
+                        types::MessageRole::System,
+                        format!("Failed to load obligations: {e}"),
+                    ));
+                }
             }
-            Err(e) => {
-                app.messages.push(types::ChatMessage::new(
-                    types::MessageRole::System,
-                    format!("Failed to load obligations: {e}"),
-                ));
-            }
-        },
+        }
         AppCommand::LoadRegistry => {
             if app.passport_view.registry_loading {
                 return; // Already loading, skip duplicate request
@@ -1028,22 +1502,56 @@ pub async fn execute_command(
                 }
             }
         }
-        AppCommand::ChatSend(msg) => {
-            // Push user message
-            app.messages.push(types::ChatMessage::new(
-                types::MessageRole::User,
-                msg.clone(),
-            ));
-            app.streaming = types::StreamingState {
-                partial_text: String::new(),
-                blocks: Vec::new(),
-                active: true,
-                stream_start: Some(std::time::Instant::now()),
+        AppCommand::LoadDashboardWidgets => {
+            let client = app.engine_client.clone();
+            let tx = app.bg_tx.clone();
+            tokio::spawn(async move {
+                let result = tokio::time::timeout(
+                    std::time::Duration::from_secs(10),
+                    client.dashboard_widgets(),
+                )
+                .await;
+                let mapped = match result {
+                    Ok(inner) => inner.map_err(|e| e.to_string()),
+                    Err(_) => Err("Dashboard widgets load timed out".to_string()),
+                };
+                let _ = tx.send(AppCommand::DashboardWidgetsLoaded(mapped));
+            });
+        }
+        AppCommand::DashboardWidgetsLoaded(result) => match result {
+            Ok(widgets) => app.dashboard_widgets = widgets,
+            Err(e) => {
+                // Older/offline engines don't expose `/widgets` at all —
+                // stay quiet rather than spamming a system message for a
+                // purely optional data source.
+                tracing::debug!("Dashboard widgets unavailable: {e}");
+            }
+        },
+        AppCommand::GenerateFixTemplate(check_id) => {
+            if app.config.offline_mode {
+                app.toasts.push(
+                    components::toast::ToastKind::Warning,
+                    "Template customization is disabled in offline mode (--offline).",
+                );
+                return;
+            }
+            let Some(item) = app
+                .fix_view
+                .fixable_findings
+                .iter()
+                .find(|f| f.check_id == check_id)
+            else {
+                return;
             };
-            app.chat_auto_scroll = true;
+            app.fix_view.generating_templates.insert(check_id.clone());
 
-            // Build request body
-            let mut body = serde_json::json!({ "message": msg });
+            let mut body = serde_json::json!({
+                "checkId": item.check_id,
+                "message": item.message,
+                "obligationId": item.obligation_id,
+                "articleReference": item.article_reference,
+                "projectPath": app.project_path.to_string_lossy(),
+            });
             if let Some(ref provider) = app.llm_config.provider {
                 body["provider"] = serde_json::Value::String(provider.clone());
             }
@@ -1056,31 +1564,132 @@ pub async fn execute_command(
 
             let client = app.engine_client.clone();
             let tx = app.bg_tx.clone();
-            let cancel = std::sync::Arc::new(tokio::sync::Notify::new());
-            app.chat_cancel = Some(cancel.clone());
-
+            let check_id_for_result = check_id;
             tokio::spawn(async move {
-                match client.post_stream("/chat", &body).await {
-                    Ok(resp) => {
-                        if crate::chat_stream::is_json_response(&resp) {
-                            // Slash command response (JSON, not SSE)
-                            let text = resp.text().await.unwrap_or_default();
-                            if let Ok(val) = serde_json::from_str::<serde_json::Value>(&text) {
-                                let display = format_slash_command_response(&val);
-                                let _ = tx.send(AppCommand::ChatStreamDelta(display));
+                let result = client
+                    .post_json("/fix/template/generate", &body)
+                    .await
+                    .map_err(|e| e.to_string())
+                    .and_then(|resp| {
+                        resp.get("content")
+                            .and_then(|v| v.as_str())
+                            .map(str::to_string)
+                            .ok_or_else(|| "Engine response missing 'content'".to_string())
+                    });
+                let _ = tx.send(AppCommand::FixTemplateGenerated {
+                    check_id: check_id_for_result,
+                    result,
+                });
+            });
+        }
+        AppCommand::FixTemplateGenerated { check_id, result } => {
+            app.fix_view.generating_templates.remove(&check_id);
+            match result {
+                Ok(content) => {
+                    app.fix_view
+                        .template_overrides
+                        .insert(check_id.clone(), content);
+                    app.toasts.push(
+                        components::toast::ToastKind::Success,
+                        format!("{check_id}: template customized with AI"),
+                    );
+                }
+                Err(e) => {
+                    app.toasts.push(
+                        components::toast::ToastKind::Error,
+                        format!("Failed to generate template: {e}"),
+                    );
+                }
+            }
+        }
+        AppCommand::ChatSend(msg) => {
+            if app.config.offline_mode {
+                app.messages.push(types::ChatMessage::new(
+                    types::MessageRole::System,
+                    "Chat is disabled in offline mode (--offline). Only the local engine is used."
+                        .to_string(),
+                ));
+                app.chat_auto_scroll = true;
+                return;
+            }
+
+            // Redact anything secret-shaped (API keys, JWTs, .env-style
+            // assignments) before it ever reaches the LLM — this covers
+            // plain typed messages and pasted code selections
+            // (`SendSelectionToAi`). `@file` attachment content is redacted
+            // separately below, once it's chunked, since it never becomes
+            // part of this string.
+            let (msg, redacted_count) = crate::secrets_redact::redact(&msg);
+            if redacted_count > 0 {
+                app.toasts.push(
+                    crate::components::toast::ToastKind::Warning,
+                    format!(
+                        "Redacted {redacted_count} secret-looking value(s) before sending to the LLM"
+                    ),
+                );
+            }
+
+            // Resolve `@file` mentions to actual file content, chunked so a
+            // large file can't blow the request past the LLM's context
+            // window in one go.
+            let attachments = crate::attachments::extract_attachments(&msg, &app.project_path);
+
+            // Push user message, with one block per attachment so the chat
+            // bubble shows what was actually sent alongside the text.
+            let mut user_message = types::ChatMessage::new(types::MessageRole::User, msg.clone());
+            for attachment in &attachments {
+                user_message.blocks.push(types::ChatBlock::Attachment {
+                    path: attachment.path.clone(),
+                    size_bytes: attachment.size_bytes,
+                    chunk_count: attachment.chunk_count(),
+                });
+            }
+            app.messages.push(user_message);
+
+            // Build request body
+            let mut body = serde_json::json!({ "message": msg });
+            if !attachments.is_empty() {
+                let mut attachment_redacted_count = 0;
+                let payload: Vec<serde_json::Value> = attachments
+                    .iter()
+                    .flat_map(|a| {
+                        let multi = a.chunk_count() > 1;
+                        a.chunks.iter().enumerate().map(move |(i, chunk)| {
+                            let path = if multi {
+                                format!("{} (part {}/{})", a.path, i + 1, a.chunk_count())
                             } else {
-                                let _ = tx.send(AppCommand::ChatStreamDelta(text));
-                            }
-                            let _ = tx.send(AppCommand::ChatStreamDone);
-                        } else {
-                            crate::chat_stream::spawn_stream_reader(resp, tx, cancel);
-                        }
-                    }
-                    Err(e) => {
-                        let _ = tx.send(AppCommand::ChatStreamError(e.to_string()));
-                    }
+                                a.path.clone()
+                            };
+                            (path, chunk)
+                        })
+                    })
+                    .map(|(path, chunk)| {
+                        let (chunk, count) = crate::secrets_redact::redact(chunk);
+                        attachment_redacted_count += count;
+                        serde_json::json!({ "path": path, "content": chunk })
+                    })
+                    .collect();
+                body["attachments"] = serde_json::Value::Array(payload);
+                if attachment_redacted_count > 0 {
+                    app.toasts.push(
+                        crate::components::toast::ToastKind::Warning,
+                        format!(
+                            "Redacted {attachment_redacted_count} secret-looking value(s) in attached file(s) before sending to the LLM"
+                        ),
+                    );
                 }
-            });
+            }
+            if let Some(ref provider) = app.llm_config.provider {
+                body["provider"] = serde_json::Value::String(provider.clone());
+            }
+            if let Some(ref model) = app.llm_config.model {
+                body["model"] = serde_json::Value::String(model.clone());
+            }
+            if let Some(ref api_key) = app.llm_config.api_key {
+                body["apiKey"] = serde_json::Value::String(api_key.clone());
+            }
+
+            spawn_chat_stream(app, body);
         }
         AppCommand::ChatStreamDelta(text) => {
             app.streaming.partial_text.push_str(&text);
@@ -1090,6 +1699,22 @@ pub async fn execute_command(
             app.streaming.blocks.push(block);
             app.chat_auto_scroll = true;
         }
+        AppCommand::ChatToolApprovalRequested {
+            tool_name,
+            args,
+            respond,
+        } => {
+            if app.tool_always_allow.contains(&tool_name) {
+                let _ = respond.0.send(types::ToolApprovalDecision::AlwaysAllow);
+            } else {
+                app.pending_tool_approval = Some(components::tool_approval::PendingToolApproval {
+                    tool_name,
+                    args,
+                    respond,
+                });
+                app.overlay = types::Overlay::ToolCallApproval;
+            }
+        }
         AppCommand::ChatStreamDone => {
             if app.streaming.active {
                 let content = if app.streaming.partial_text.is_empty() {
@@ -1103,6 +1728,7 @@ pub async fn execute_command(
                 app.streaming.active = false;
                 app.chat_cancel = None;
                 app.chat_auto_scroll = true;
+                app.refresh_chat_tool_focus();
             }
         }
         AppCommand::ChatStreamError(err) => {
@@ -1145,10 +1771,34 @@ pub async fn execute_command(
             ));
             app.chat_auto_scroll = true;
         }
+        AppCommand::ChatRateLimited { retry_secs, body } => {
+            app.streaming.active = false;
+            app.chat_cancel = None;
+            app.toasts.push(
+                components::toast::ToastKind::Warning,
+                format!("Rate limited. Retrying in {retry_secs}s..."),
+            );
+            app.chat_retry = Some(types::ChatRateLimitState {
+                resume_at: std::time::Instant::now() + std::time::Duration::from_secs(retry_secs),
+                total_secs: retry_secs,
+                body,
+            });
+        }
+        AppCommand::ChatRetryNow => {
+            if let Some(retry) = app.chat_retry.take() {
+                spawn_chat_stream(app, retry.body);
+            }
+        }
         AppCommand::ChatCancel => {
             if let Some(cancel) = app.chat_cancel.take() {
                 cancel.notify_one();
             }
+            if app.chat_retry.take().is_some() {
+                app.messages.push(types::ChatMessage::new(
+                    types::MessageRole::System,
+                    "Queued retry cancelled.".to_string(),
+                ));
+            }
             if app.streaming.active {
                 app.streaming.active = false;
                 app.messages.push(types::ChatMessage::new(
@@ -1189,9 +1839,190 @@ pub async fn execute_command(
             )
             .await;
         }
+        AppCommand::SaveConfig => {
+            config::save_settings(&app.config).await;
+        }
     }
 }
 
+/// Read a local file for the code viewer, stopping once
+/// [`views::code_viewer::MAX_BUFFER_BYTES`] (plus a little slack for
+/// `CodeBuffer` to round down to a line boundary) has been read, so an
+/// oversized file doesn't get pulled fully into memory just to be truncated
+/// afterwards.
+async fn read_capped(path: &str) -> std::io::Result<String> {
+    use tokio::io::AsyncReadExt;
+
+    let cap = views::code_viewer::MAX_BUFFER_BYTES + 4096;
+    let file = tokio::fs::File::open(path).await?;
+    let mut buf = Vec::new();
+    file.take(cap as u64).read_to_end(&mut buf).await?;
+    Ok(String::from_utf8_lossy(&buf).into_owned())
+}
+
+/// Open `path` in the code viewer via the engine (falling back to a local
+/// read), reporting a chat message on failure. Shared by `OpenFile` and
+/// `OpenFileAtLine`.
+async fn open_file_or_report(app: &mut App, path: &str) {
+    match app.engine_client.read_file(path).await {
+        Ok(content) => app.open_file(path, content),
+        Err(_) => {
+            // Fallback: try reading locally, capped so a multi-GB generated
+            // file doesn't get pulled fully into memory before `CodeBuffer`
+            // gets a chance to truncate it.
+            match read_capped(path).await {
+                Ok(content) => app.open_file(path, content),
+                Err(e) => {
+                    app.messages.push(types::ChatMessage::new(
+                        types::MessageRole::System,
+                        format!("Cannot open file: {e}"),
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// Re-read `path` and, if it's still the open file and its disk content
+/// diverged from the loaded `CodeBuffer`, surface `Overlay::FileReloadPrompt`
+/// instead of leaving the code viewer silently stale. A no-op if the user
+/// already navigated away or another prompt/overlay is already up.
+async fn check_open_file_changed(app: &mut App, path: String) {
+    if app.overlay != types::Overlay::None || app.open_file_path.as_deref() != Some(path.as_str())
+    {
+        return;
+    }
+    let Some(buffer) = &app.code_buffer else {
+        return;
+    };
+    let disk_content = match app.engine_client.read_file(&path).await {
+        Ok(content) => content,
+        Err(_) => match read_capped(&path).await {
+            Ok(content) => content,
+            Err(_) => return,
+        },
+    };
+    if disk_content == buffer.as_str() {
+        return;
+    }
+    app.file_reload_prompt = Some(components::file_reload_prompt::FileReloadPrompt::new(
+        path,
+        disk_content,
+        buffer.as_str().to_string(),
+    ));
+    app.overlay = types::Overlay::FileReloadPrompt;
+}
+
+/// Prefix `overrides` onto `command` as shell-quoted `KEY='VALUE'` env
+/// assignments, so `/env set` variables reach the engine's `run_command`
+/// without changing the `/shell` wire protocol. A no-op when there are no
+/// overrides.
+fn with_env_overrides(command: &str, overrides: &[(String, String)]) -> String {
+    if overrides.is_empty() {
+        return command.to_string();
+    }
+    let assignments: Vec<String> = overrides
+        .iter()
+        .map(|(key, value)| format!("{key}={}", shell_quote(value)))
+        .collect();
+    format!("{} {command}", assignments.join(" "))
+}
+
+/// Wrap `s` in single quotes for safe use as a shell word, escaping any
+/// embedded single quotes as `'\''`.
+fn shell_quote(s: &str) -> String {
+    format!("'{}'", s.replace('\'', r"'\''"))
+}
+
+/// Suspend the TUI and shell out to `$EDITOR` (falling back to `vi`) at
+/// `path:line`, restoring the alternate screen and raw mode once it exits.
+/// Runs the blocking `wait()` on a blocking-pool thread so the async runtime
+/// keeps servicing the file watcher and control socket while the editor has
+/// the terminal.
+async fn open_in_editor(app: &mut App, path: &str, line: usize) {
+    use crossterm::event::{DisableMouseCapture, EnableMouseCapture};
+    use crossterm::execute;
+    use crossterm::terminal::{
+        EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode,
+    };
+
+    let editor = std::env::var("EDITOR").unwrap_or_else(|_| "vi".to_string());
+    let full_path = app.project_path.join(path);
+
+    let _ = disable_raw_mode();
+    let _ = execute!(std::io::stdout(), LeaveAlternateScreen, DisableMouseCapture);
+
+    let status = tokio::task::spawn_blocking(move || {
+        std::process::Command::new(&editor)
+            .arg(format!("+{line}"))
+            .arg(&full_path)
+            .status()
+    })
+    .await;
+
+    let _ = execute!(std::io::stdout(), EnterAlternateScreen, EnableMouseCapture);
+    let _ = enable_raw_mode();
+    app.needs_terminal_reset = true;
+
+    match status {
+        Ok(Ok(exit)) if exit.success() => {}
+        Ok(Ok(exit)) => app.toasts.push(
+            components::toast::ToastKind::Warning,
+            format!("Editor exited with {exit}"),
+        ),
+        _ => app.toasts.push(
+            components::toast::ToastKind::Warning,
+            "Failed to launch editor",
+        ),
+    }
+}
+
+/// POST `body` to `/chat` and route the response to the app: a JSON body is
+/// a slash-command reply, an SSE body is handed to the stream reader, a 429
+/// queues `app.chat_retry` for auto-retry instead of surfacing a bare error.
+/// Shared by the initial send (`ChatSend`) and the auto-retry (`ChatRetryNow`)
+/// so a retry is a byte-for-byte resend of the original request.
+fn spawn_chat_stream(app: &mut App, body: serde_json::Value) {
+    app.streaming = types::StreamingState {
+        partial_text: String::new(),
+        blocks: Vec::new(),
+        active: true,
+        stream_start: Some(std::time::Instant::now()),
+    };
+    app.chat_auto_scroll = true;
+
+    let client = app.engine_client.clone();
+    let tx = app.bg_tx.clone();
+    let cancel = std::sync::Arc::new(tokio::sync::Notify::new());
+    app.chat_cancel = Some(cancel.clone());
+
+    tokio::spawn(async move {
+        match client.post_stream("/chat", &body).await {
+            Ok(resp) => {
+                if crate::chat_stream::is_json_response(&resp) {
+                    // Slash command response (JSON, not SSE)
+                    let text = resp.text().await.unwrap_or_default();
+                    if let Ok(val) = serde_json::from_str::<serde_json::Value>(&text) {
+                        let display = format_slash_command_response(&val);
+                        let _ = tx.send(AppCommand::ChatStreamDelta(display));
+                    } else {
+                        let _ = tx.send(AppCommand::ChatStreamDelta(text));
+                    }
+                    let _ = tx.send(AppCommand::ChatStreamDone);
+                } else {
+                    crate::chat_stream::spawn_stream_reader(resp, tx, cancel, client);
+                }
+            }
+            Err(TuiError::RateLimited(retry_secs)) => {
+                let _ = tx.send(AppCommand::ChatRateLimited { retry_secs, body });
+            }
+            Err(e) => {
+                let _ = tx.send(AppCommand::ChatStreamError(e.to_string()));
+            }
+        }
+    });
+}
+
 /// Format a JSON slash command response for display.
 fn format_slash_command_response(val: &serde_json::Value) -> String {
     if let Some(cmd) = val.get("command").and_then(|v| v.as_str()) {
@@ -1,5 +1,5 @@
 mod actions;
-mod commands;
+pub(crate) mod commands;
 pub mod executor;
 mod overlays;
 mod scan;
@@ -12,7 +12,16 @@ use std::time::Instant;
 use ratatui::layout::Rect;
 
 use crate::animation::AnimationState;
+use crate::components::bookmarks::BookmarksState;
+use crate::components::keybindings::KeybindingsState;
+use crate::components::tour::TourState;
+use crate::components::notifications::NotificationCenterState;
+use crate::components::changes_feed::ChangesFeedState;
+use crate::components::file_reload_prompt::FileReloadPrompt;
+use crate::components::project_switcher::ProjectSwitcherState;
+use crate::components::recent_files::RecentFilesState;
 use crate::components::spinner::Spinner;
+use crate::components::stats::StatsState;
 use crate::components::suggestions::IdleSuggestionState;
 use crate::components::undo_history::UndoHistoryState;
 use crate::config::TuiConfig;
@@ -20,11 +29,12 @@ use crate::engine_client::EngineClient;
 use crate::layout::Breakpoint;
 use crate::saas_client::SyncStats;
 use crate::types::{
-    ActivityEntry, ActivityKind, ChatBlock, ChatMessage, ClickTarget, CostEstimateResult,
+    ActivityEntry, ActivityKind, Bookmark, ChatBlock, ChatMessage, ClickTarget, CostEstimateResult,
     DebtResult, EngineConnectionStatus, FileEntry, InputMode, LlmSessionConfig, MessageRole, Mode,
-    MultiFrameworkScoreResult, Overlay, Panel, ReadinessResult, ScanResult, Selection,
-    StreamingState, ViewState,
+    MultiFrameworkScoreResult, Overlay, Panel, ReadinessResult, RemoteWidget, ScanResult,
+    Selection, StreamingState, ViewState,
 };
+use crate::views::code_viewer::CodeBuffer;
 use crate::views::file_browser;
 use crate::views::fix::FixViewState;
 use crate::views::obligations::ObligationsViewState;
@@ -46,15 +56,26 @@ pub struct SyncState {
 pub struct App {
     // Core state
     pub running: bool,
+    /// Set whenever visible state changes; the event loop only redraws when
+    /// this is `true`, then clears it. Starts `true` for the first frame.
+    pub dirty: bool,
     pub active_panel: Panel,
     pub input_mode: InputMode,
     pub config: TuiConfig,
     pub view_state: ViewState,
     pub mode: Mode,
+    /// `Some` when `--perf-overlay` is set; accumulates frame timing shown
+    /// in the corner overlay.
+    pub perf: Option<crate::components::perf_overlay::PerfStats>,
 
     // Engine
     pub engine_status: EngineConnectionStatus,
     pub engine_client: EngineClient,
+    /// Capabilities handshake from the last successful `/status` call, used
+    /// to gate optional features (`/suggestions`, `/undo`, `/explain`) on
+    /// engines old enough not to support them. `None` before the first
+    /// successful connection.
+    pub engine_info: Option<crate::types::EngineStatus>,
 
     // Status Log (system messages)
     pub messages: Vec<ChatMessage>,
@@ -71,22 +92,57 @@ pub struct App {
     // Score
     pub last_scan: Option<ScanResult>,
     pub score_history: Vec<f64>,
+    /// Commit hash, branch, and dirty flag captured with `last_scan`.
+    pub last_scan_git: Option<crate::headless::scan::GitContext>,
 
     // File browser
     pub file_tree: Vec<FileEntry>,
     pub file_browser_index: usize,
+    /// Inline filter pattern for the browser panel (`f` while it's focused),
+    /// narrowing the visible entries live — separate from the `@`-mention
+    /// `Overlay::FilePicker` popup, which has its own `overlay_filter`.
+    pub file_browser_filter: String,
+    /// `true` while typing into `file_browser_filter`.
+    pub file_browser_filtering: bool,
+    /// "Flatten matches" mode: show only entries matching the filter as a
+    /// flat depth-0 list instead of the nested tree, for jumping straight
+    /// to a file buried deep in a monorepo.
+    pub file_browser_flatten: bool,
 
     // Code viewer
-    pub code_content: Option<String>,
+    pub code_buffer: Option<CodeBuffer>,
     pub open_file_path: Option<String>,
     pub code_scroll: usize,
     pub selection: Option<Selection>,
+    /// Most-recently-opened files, newest first, capped at
+    /// [`RECENT_FILES_CAP`] — persisted in the session and browsable via the
+    /// `Ctrl+E` quick switcher (`Overlay::RecentFiles`).
+    pub recent_files: Vec<String>,
+    /// Snapshot + cursor for the open `Overlay::RecentFiles` popup.
+    pub recent_files_view: RecentFilesState,
+    /// Set when the watcher reports that `open_file_path` changed on disk
+    /// while loaded — drives `Overlay::FileReloadPrompt`.
+    pub file_reload_prompt: Option<FileReloadPrompt>,
+    /// Set after shelling out to `$EDITOR`: the alternate screen was left
+    /// and re-entered outside of ratatui's control, so the next render must
+    /// do a full repaint instead of diffing against its stale buffer.
+    pub needs_terminal_reset: bool,
+
+    /// Mouse click-drag selection in progress over the chat/log body, in
+    /// rendered-line indices. `start_line` is the drag anchor; `end_line`
+    /// tracks the live cursor row and may be smaller than `start_line` when
+    /// dragging upward.
+    pub chat_selection: Option<Selection>,
 
     // Terminal
     pub terminal_output: Vec<String>,
     pub terminal_visible: bool,
     pub terminal_scroll: usize,
     pub terminal_auto_scroll: bool,
+    /// Session-scoped `KEY=VALUE` overrides (`/env set`), passed through as
+    /// environment for every `/run` and `!cmd` shell command — not persisted
+    /// to disk or the saved session, cleared when the process exits.
+    pub env_overrides: Vec<(String, String)>,
 
     // Panels visibility
     pub sidebar_visible: bool,
@@ -96,6 +152,25 @@ pub struct App {
     pub overlay: Overlay,
     pub overlay_filter: String,
     pub palette_index: usize,
+    /// Commands executed via the command palette, most recent first,
+    /// capped at [`RECENT_COMMANDS_CAP`] entries. Surfaced as a "recently
+    /// used" section when the palette filter is empty.
+    pub recent_commands: Vec<String>,
+    /// Selected index into the inline `@`-mention popup (see
+    /// [`App::mention_query`]), reset to 0 whenever the input changes.
+    pub mention_index: usize,
+    /// A large bracketed paste awaiting confirmation (see
+    /// [`App::handle_paste`]) before it's fenced and inserted.
+    pub pending_paste: Option<crate::app::commands::PendingPaste>,
+    /// Edit-history stack for the chat input line (Ctrl+Z), separate from
+    /// `input_history`'s submitted-message recall. Each entry is a
+    /// `(text, cursor)` snapshot taken just before an edit.
+    pub input_undo_stack: Vec<(String, usize)>,
+    /// Snapshots popped off `input_undo_stack`, replayed by Ctrl+Y.
+    pub input_redo_stack: Vec<(String, usize)>,
+    /// Vim-style yank registers for the code viewer's visual selection,
+    /// most recent first. `p` pastes register 0 into the chat input.
+    pub yank_registers: Vec<String>,
 
     // View-specific state
     pub scan_view: ScanViewState,
@@ -108,9 +183,19 @@ pub struct App {
     // Activity log (Dashboard widget)
     pub activity_log: Vec<ActivityEntry>,
 
+    /// Full activity history, backing the Activity History overlay (`a`
+    /// while the Activity Log widget is zoomed) — `activity_log` above is
+    /// just the last `MAX_ACTIVITY_LOG` entries for the Dashboard widget.
+    pub activity_history: Vec<ActivityEntry>,
+    pub activity_history_view: crate::components::activity_history::ActivityHistoryState,
+
     // Watch mode
     pub watch_active: bool,
     pub watch_last_score: Option<f64>,
+    /// Shared with the running watcher task; suppressed while the fix
+    /// pipeline is writing to disk so its own writes don't queue a
+    /// redundant `AutoScan`.
+    pub watch_suppressor: crate::watcher::WatchSuppressor,
 
     // T904: Pre-fix score for auto-validate delta
     pub pre_fix_score: Option<f64>,
@@ -138,6 +223,9 @@ pub struct App {
     // T07: Widget zoom
     pub zoom: crate::components::zoom::ZoomState,
 
+    /// Cursor row in the Dashboard "arrange" overlay.
+    pub arrange_dashboard_cursor: usize,
+
     // T07: Fix split ratio (percentage for left panel, 25-75)
     pub fix_split_pct: u16,
 
@@ -149,13 +237,102 @@ pub struct App {
     // T07: Dismiss modal
     pub dismiss_modal: Option<crate::components::quick_actions::DismissModal>,
 
+    /// Check Docs overlay (`?` on a finding in Scan view).
+    pub check_docs: Option<crate::components::check_docs::CheckDocsState>,
+
+    /// A chat tool call awaiting approval, if any (see `ToolCallApproval` overlay).
+    pub pending_tool_approval: Option<crate::components::tool_approval::PendingToolApproval>,
+
+    /// Tool names the user chose "always allow" for this session.
+    pub tool_always_allow: std::collections::HashSet<String>,
+
+    /// (message index, block index) of the most recent tool call/result
+    /// block in `messages`, for `Enter` to open `ToolResultInspector`.
+    pub chat_tool_focus: Option<(usize, usize)>,
+
+    /// Scroll offset within the `ToolResultInspector` overlay.
+    pub tool_inspector_scroll: usize,
+
+    /// Set when the last engine request failed, categorizing why. Cleared
+    /// by a subsequent successful scan. Drives the footer's persistent
+    /// degraded-mode badge.
+    pub degraded_mode: Option<crate::error::ErrorCategory>,
+
+    /// Cache for `/suggestions` and `/obligations` engine responses, keyed
+    /// by the current scan so navigating back and forth through findings
+    /// doesn't refetch (and, for LLM-backed endpoints, re-bill) identical
+    /// data. Invalidated implicitly: entries are keyed by `scan_cache_key`,
+    /// which changes on every new scan.
+    pub response_cache: crate::response_cache::ResponseCache,
+
+    /// Jump list for Ctrl+O / Ctrl+I: view/file/finding snapshots recorded
+    /// by `push_nav_point`, in visit order.
+    pub nav_history: Vec<crate::types::NavPoint>,
+
+    /// Index into `nav_history` for "where we are now". `None` before the
+    /// first point is recorded.
+    pub nav_cursor: Option<usize>,
+
+    /// Finding → assignee map (`/assign`, `/assignee`), loaded from
+    /// `.complior/tracked-issues.json` at startup.
+    pub assignments: Vec<crate::assignments::TrackedIssue>,
+
+    /// Finding → status/due-date map (`s` quick action, `/status`, `/due`),
+    /// loaded from `.complior/findings-state.json` at startup.
+    pub finding_states: Vec<crate::findings_state::FindingState>,
+
+    /// Report composer's section list (enabled + order), loaded from
+    /// `.complior/report-sections.json` at startup and applied to every
+    /// export format.
+    pub report_sections: Vec<crate::report_sections::SectionConfig>,
+
     // T08: Mouse click areas (populated each render frame)
     pub click_areas: Vec<(Rect, ClickTarget)>,
     pub scroll_events: Vec<Instant>,
 
+    /// Row of the footer status-bar line (line 1 of 2), recomputed
+    /// alongside `click_areas`. Used to hit-test mouse-move events against
+    /// footer indicators for hover tooltips.
+    pub footer_row: u16,
+    pub hovered_indicator: Option<crate::types::FooterIndicator>,
+
     // T08: Undo history
     pub undo_history: UndoHistoryState,
 
+    /// Multi-project switcher (`/projects`).
+    pub project_switcher: ProjectSwitcherState,
+
+    /// Findings/files flagged for a triage session (`M` to mark, `'` to
+    /// open the overlay), persisted in the session.
+    pub bookmarks: BookmarksState,
+
+    /// Notification center overlay (`N`) — snapshot of toast history plus
+    /// system chat messages, taken when the overlay opens.
+    pub notification_center: NotificationCenterState,
+
+    /// Guided tour overlay (`/tour`). The step index lives here rather than
+    /// in `Overlay::Tour` itself so dismissing with `Esc` and reopening
+    /// resumes on the same step for the rest of the session.
+    pub tour: TourState,
+
+    /// Searchable keybinding browser (`/keys`); search text reuses
+    /// `overlay_filter`.
+    pub keybindings: KeybindingsState,
+
+    /// Critical-cap drill-down (`c` in Dashboard when `critical_cap_applied`)
+    /// — snapshot of the critical findings and an uncapped score estimate,
+    /// taken when the overlay opens.
+    pub critical_cap_detail: crate::components::critical_cap_detail::CriticalCapDetailState,
+
+    /// Per-day usage stats overlay (`/stats`).
+    pub stats: StatsState,
+
+    /// Risk classification questionnaire (`/risk-classify`), `None` until opened.
+    pub risk_wizard: Option<crate::views::risk_classification::RiskWizard>,
+
+    // Watch-mode change feed panel
+    pub changes: ChangesFeedState,
+
     // T08: Colon-command mode
     pub colon_mode: bool,
 
@@ -183,6 +360,10 @@ pub struct App {
     pub debt_score: Option<DebtResult>,
     pub readiness_score: Option<ReadinessResult>,
 
+    /// Server-driven dashboard widgets from the engine's `/widgets`
+    /// endpoint (empty on engines that don't expose it).
+    pub dashboard_widgets: Vec<RemoteWidget>,
+
     // SaaS sync state
     pub sync_state: SyncState,
 
@@ -190,7 +371,10 @@ pub struct App {
     pub streaming: StreamingState,
     pub llm_config: LlmSessionConfig,
     pub llm_settings: Option<crate::llm_settings::LlmSettingsState>,
+    pub settings_overlay: Option<crate::settings_overlay::SettingsState>,
     pub chat_cancel: Option<std::sync::Arc<tokio::sync::Notify>>,
+    /// A rate-limited chat request awaiting auto-retry (footer countdown).
+    pub chat_retry: Option<crate::types::ChatRateLimitState>,
 
     // Background command channel (for async results → event loop)
     pub bg_tx: tokio::sync::mpsc::UnboundedSender<AppCommand>,
@@ -200,6 +384,11 @@ pub struct App {
 const MAX_HISTORY: usize = 50;
 const MAX_TERMINAL_LINES: usize = 1000;
 const MAX_ACTIVITY_LOG: usize = 10;
+/// Cap on `App::activity_history` — the persistent store backing the
+/// Activity History overlay, well beyond the 10-entry Dashboard widget.
+const MAX_ACTIVITY_HISTORY: usize = 500;
+/// Cap on `App::recent_files` — the `Ctrl+E` quick switcher's history.
+const RECENT_FILES_CAP: usize = 10;
 
 impl App {
     pub fn new(config: TuiConfig) -> Self {
@@ -207,6 +396,8 @@ impl App {
         let (bg_tx, bg_rx) = tokio::sync::mpsc::unbounded_channel();
         let sidebar_visible = config.sidebar_visible;
         let animations_enabled = config.animations_enabled;
+        let auto_scroll_enabled = config.auto_scroll_enabled;
+        let toast_duration_secs = config.toast_duration_secs;
         let llm_config = LlmSessionConfig {
             api_key: config
                 .llm_provider
@@ -219,16 +410,25 @@ impl App {
             || std::env::current_dir().unwrap_or_else(|_| PathBuf::from(".")),
             PathBuf::from,
         );
+        let perf = config
+            .perf_overlay
+            .then(crate::components::perf_overlay::PerfStats::new);
+        let assignments = crate::assignments::load_all(&project_path);
+        let finding_states = crate::findings_state::load_all(&project_path);
+        let report_sections = crate::report_sections::load(&project_path);
 
         let mut app = Self {
             running: true,
+            dirty: true,
             active_panel: Panel::Chat,
             input_mode: InputMode::Normal,
             config,
             view_state: ViewState::Dashboard,
+            perf,
             mode: Mode::Scan,
             engine_status: EngineConnectionStatus::Disconnected,
             engine_client,
+            engine_info: None,
             messages: vec![ChatMessage::new(
                 MessageRole::System,
                 "Welcome to Complior. Use /scan to start, /help for commands.".to_string(),
@@ -236,27 +436,43 @@ impl App {
             input: String::new(),
             input_cursor: 0,
             chat_scroll: 0,
-            chat_auto_scroll: true,
+            chat_auto_scroll: auto_scroll_enabled,
             input_history: Vec::new(),
             history_index: None,
             history_saved_input: String::new(),
             last_scan: None,
             score_history: Vec::new(),
+            last_scan_git: None,
             file_tree: Vec::new(),
             file_browser_index: 0,
-            code_content: None,
+            file_browser_filter: String::new(),
+            file_browser_filtering: false,
+            file_browser_flatten: false,
+            code_buffer: None,
+            chat_selection: None,
             open_file_path: None,
             code_scroll: 0,
             selection: None,
+            recent_files: Vec::new(),
+            recent_files_view: RecentFilesState::new(),
+            file_reload_prompt: None,
+            needs_terminal_reset: false,
             terminal_output: Vec::new(),
             terminal_visible: false,
             terminal_scroll: 0,
-            terminal_auto_scroll: true,
+            terminal_auto_scroll: auto_scroll_enabled,
+            env_overrides: Vec::new(),
             sidebar_visible,
             files_panel_visible: true,
             overlay: Overlay::None,
             overlay_filter: String::new(),
             palette_index: 0,
+            recent_commands: Vec::new(),
+            mention_index: 0,
+            pending_paste: None,
+            input_undo_stack: Vec::new(),
+            input_redo_stack: Vec::new(),
+            yank_registers: Vec::new(),
             scan_view: ScanViewState::default(),
             fix_view: FixViewState::default(),
             timeline_view: TimelineViewState::default(),
@@ -264,8 +480,11 @@ impl App {
             passport_view: PassportViewState::default(),
             obligations_view: ObligationsViewState::default(),
             activity_log: Vec::new(),
+            activity_history: Vec::new(),
+            activity_history_view: crate::components::activity_history::ActivityHistoryState::new(),
             watch_active: false,
             watch_last_score: None,
+            watch_suppressor: crate::watcher::WatchSuppressor::default(),
             pre_fix_score: None,
             help_scroll: 0,
             theme_picker: None,
@@ -273,17 +492,41 @@ impl App {
             code_search_query: None,
             code_search_matches: Vec::new(),
             code_search_current: 0,
-            toasts: crate::components::toast::ToastStack::new(),
+            toasts: crate::components::toast::ToastStack::with_duration(toast_duration_secs),
             confirm_dialog: None,
             zoom: crate::components::zoom::ZoomState::new(),
+            arrange_dashboard_cursor: 0,
             fix_split_pct: 40,
             zen_messages_used: 0,
             zen_messages_limit: 1000,
             zen_active: false,
             dismiss_modal: None,
+            check_docs: None,
+            pending_tool_approval: None,
+            tool_always_allow: std::collections::HashSet::new(),
+            chat_tool_focus: None,
+            tool_inspector_scroll: 0,
+            degraded_mode: None,
+            response_cache: crate::response_cache::ResponseCache::default(),
+            nav_history: Vec::new(),
+            nav_cursor: None,
+            assignments,
+            finding_states,
+            report_sections,
             click_areas: Vec::new(),
+            footer_row: 0,
+            hovered_indicator: None,
             scroll_events: Vec::new(),
             undo_history: UndoHistoryState::new(),
+            project_switcher: ProjectSwitcherState::new(),
+            bookmarks: BookmarksState::new(),
+            notification_center: NotificationCenterState::new(),
+            critical_cap_detail: crate::components::critical_cap_detail::CriticalCapDetailState::new(),
+            tour: TourState::new(),
+            keybindings: KeybindingsState::new(),
+            stats: StatsState::new(),
+            risk_wizard: None,
+            changes: ChangesFeedState::new(),
             colon_mode: false,
             idle_suggestions: IdleSuggestionState::new(),
             animation: AnimationState::new(animations_enabled),
@@ -292,6 +535,7 @@ impl App {
             cost_estimate: None,
             debt_score: None,
             readiness_score: None,
+            dashboard_widgets: Vec::new(),
             whatif: crate::components::whatif::WhatIfState::new(),
             spinner: Spinner::new(),
             project_path,
@@ -300,7 +544,9 @@ impl App {
             streaming: StreamingState::default(),
             llm_config,
             llm_settings: None,
+            settings_overlay: None,
             chat_cancel: None,
+            chat_retry: None,
             bg_tx,
             bg_rx: Some(bg_rx),
         };
@@ -312,6 +558,24 @@ impl App {
             app.sync_state.org_name = tokens.org_name;
         }
 
+        // A leftover fix journal means a previous batch fix was interrupted
+        // (crash, kill, power loss) mid-write — surface it instead of
+        // silently leaving the project half-patched.
+        if let Some(journal) = crate::fix_journal::load_journal(&app.project_path) {
+            app.messages.push(ChatMessage::new(
+                MessageRole::System,
+                format!(
+                    "An interrupted fix batch was found ({} file(s)). Run `/fix-recovery forward` \
+                     to finish applying it, or `/fix-recovery back` to restore the original files.",
+                    journal.entries.len()
+                ),
+            ));
+            app.toasts.push(
+                crate::components::toast::ToastKind::Warning,
+                "Interrupted fix batch found — see /fix-recovery",
+            );
+        }
+
         app
     }
 
@@ -320,9 +584,27 @@ impl App {
         self.bg_rx.take().expect("bg_rx already taken")
     }
 
-    pub fn tick(&mut self) -> Option<AppCommand> {
+    /// Advance per-tick state (spinner, toast expiry, idle suggestions).
+    ///
+    /// Returns whether anything visibly changed — the event loop only calls
+    /// `terminal.draw` when this is `true`, so an idle TUI with no active
+    /// operation, expiring toast, or animation drops to near-zero CPU between
+    /// keystrokes instead of redrawing on every 250ms tick.
+    pub fn tick(&mut self) -> (bool, Option<AppCommand>) {
         self.spinner.advance();
-        self.toasts.gc();
+        let toasts_expired = self.toasts.gc() > 0;
+        let dirty = toasts_expired || self.operation_start.is_some();
+
+        // A queued rate-limited chat request whose countdown has elapsed —
+        // fire the auto-retry.
+        if let Some(retry) = &self.chat_retry
+            && retry.remaining_secs() == 0
+        {
+            return (true, Some(AppCommand::ChatRetryNow));
+        }
+        if self.chat_retry.is_some() {
+            return (true, None);
+        }
 
         // Idle suggestion: check if idle > 10s and no blockers
         if self.idle_suggestions.current.is_none()
@@ -335,9 +617,9 @@ impl App {
         {
             // Mark fetch as pending so we don't re-trigger every tick
             self.idle_suggestions.fetch_pending = true;
-            return Some(AppCommand::FetchSuggestions);
+            return (true, Some(AppCommand::FetchSuggestions));
         }
-        None
+        (dirty, None)
     }
 
     /// Elapsed seconds since operation started.
@@ -345,10 +627,236 @@ impl App {
         self.operation_start.map(|s| s.elapsed().as_secs())
     }
 
+    /// Identifies the current scan for `response_cache` lookups: cached
+    /// `/suggestions`/`/obligations` responses are only reused while this
+    /// stays the same, so a fresh scan naturally invalidates them.
+    pub fn scan_cache_key(&self) -> String {
+        self.last_scan
+            .as_ref()
+            .map_or_else(|| "no-scan".to_string(), |s| s.scanned_at.clone())
+    }
+
+    /// Switch to `view`, recording the current view/file/finding on the jump
+    /// list first so Ctrl+O can return to it. Use this instead of assigning
+    /// `view_state` directly whenever the switch is a user-visible navigation
+    /// (not e.g. `load_layout_preset`, which restores rather than navigates).
+    pub fn switch_view(&mut self, view: ViewState) {
+        self.view_state = view;
+        self.push_nav_point();
+    }
+
+    /// Record the current view/file/finding as a jump-list entry. Discards
+    /// any forward history past the current position, matching how browser
+    /// back/forward history is truncated by navigating somewhere new.
+    pub fn push_nav_point(&mut self) {
+        let point = crate::types::NavPoint {
+            view_state: self.view_state,
+            open_file_path: self.open_file_path.clone(),
+            selected_finding: self.scan_view.selected_finding,
+        };
+        if self
+            .nav_cursor
+            .is_some_and(|c| self.nav_history[c] == point)
+        {
+            return;
+        }
+        let insert_at = self.nav_cursor.map_or(0, |c| c + 1);
+        self.nav_history.truncate(insert_at);
+        self.nav_history.push(point);
+        self.nav_cursor = Some(self.nav_history.len() - 1);
+    }
+
+    /// Jump backward one entry in the jump list (Ctrl+O). Returns a command
+    /// to re-open the entry's file if it isn't already open.
+    pub fn nav_back(&mut self) -> Option<AppCommand> {
+        let cursor = self.nav_cursor?;
+        if cursor == 0 {
+            return None;
+        }
+        self.nav_cursor = Some(cursor - 1);
+        self.apply_nav_point(cursor - 1)
+    }
+
+    /// Jump forward one entry in the jump list (Ctrl+I), undoing `nav_back`.
+    /// Returns a command to re-open the entry's file if it isn't already open.
+    pub fn nav_forward(&mut self) -> Option<AppCommand> {
+        let cursor = self.nav_cursor?;
+        let target = cursor + 1;
+        if target >= self.nav_history.len() {
+            return None;
+        }
+        self.nav_cursor = Some(target);
+        self.apply_nav_point(target)
+    }
+
+    /// Restore the view/finding recorded at `nav_history[idx]`. The file
+    /// buffer is only reloaded (via the returned `AppCommand`) when the
+    /// target path differs from what's currently open — its content is read
+    /// from disk again by the executor, same as any other `OpenFile`.
+    fn apply_nav_point(&mut self, idx: usize) -> Option<AppCommand> {
+        let point = self.nav_history[idx].clone();
+        self.view_state = point.view_state;
+        self.scan_view.selected_finding = point.selected_finding;
+        if point.open_file_path != self.open_file_path {
+            return if let Some(path) = point.open_file_path {
+                Some(AppCommand::OpenFile(path))
+            } else {
+                self.code_buffer = None;
+                self.open_file_path = None;
+                None
+            };
+        }
+        None
+    }
+
+    /// Toggle a bookmark for whatever's currently focused: the open file in
+    /// the code viewer, or the selected finding in Scan view (`M`). No-op if
+    /// neither applies.
+    pub fn toggle_bookmark(&mut self) {
+        let bookmark = if self.active_panel == Panel::CodeViewer {
+            self.open_file_path
+                .clone()
+                .map(|path| Bookmark::File { path })
+        } else if self.view_state == ViewState::Scan {
+            self.scan_view.selected_finding.and_then(|idx| {
+                let scan = self.last_scan.as_ref()?;
+                crate::views::scan::resolve_selected_finding(
+                    &scan.findings,
+                    self.scan_view.findings_filter,
+                    idx,
+                    &self.passport_view.loaded_passports,
+                    &self.assignments,
+                    self.scan_view.assignee_filter.as_deref(),
+                    &self.finding_states,
+                    self.scan_view.show_snoozed,
+                )
+                .map(|finding| Bookmark::Finding {
+                    check_id: finding.check_id.clone(),
+                    file: finding.file.clone(),
+                })
+            })
+        } else {
+            None
+        };
+
+        let Some(bookmark) = bookmark else {
+            return;
+        };
+
+        if let Some(pos) = self.bookmarks.entries.iter().position(|b| *b == bookmark) {
+            self.bookmarks.entries.remove(pos);
+            self.toasts.push(
+                crate::components::toast::ToastKind::Info,
+                format!("Removed bookmark: {}", bookmark.label()),
+            );
+        } else {
+            self.toasts.push(
+                crate::components::toast::ToastKind::Info,
+                format!("Bookmarked: {}", bookmark.label()),
+            );
+            self.bookmarks.entries.push(bookmark);
+        }
+    }
+
+    /// Open the notification center (`N`): snapshot toast history plus
+    /// system chat messages so both survive past a toast's auto-dismiss.
+    pub fn show_notifications(&mut self) {
+        let mut entries: Vec<crate::components::notifications::NotificationEntry> = self
+            .toasts
+            .history
+            .iter()
+            .map(|toast| crate::components::notifications::NotificationEntry {
+                timestamp: toast.timestamp.clone(),
+                kind: Some(toast.kind),
+                message: toast.message.clone(),
+            })
+            .collect();
+        entries.extend(self.messages.iter().filter(|m| m.role == MessageRole::System).map(
+            |m| crate::components::notifications::NotificationEntry {
+                timestamp: m.timestamp.clone(),
+                kind: None,
+                message: m.content.clone(),
+            },
+        ));
+
+        self.notification_center.entries = entries;
+        self.notification_center.selected = 0;
+        self.overlay = Overlay::Notifications;
+    }
+
+    /// Open the critical-cap drill-down: the critical findings from the
+    /// last scan, and a category-weighted score estimate ignoring the cap.
+    pub fn show_critical_cap_detail(&mut self) {
+        let Some(scan) = &self.last_scan else {
+            return;
+        };
+        self.critical_cap_detail.findings = scan
+            .findings
+            .iter()
+            .filter(|f| f.severity == crate::types::Severity::Critical)
+            .cloned()
+            .collect();
+        self.critical_cap_detail.uncapped_estimate = if scan.score.category_scores.is_empty() {
+            scan.score.total_score
+        } else {
+            let total_weight: f64 = scan.score.category_scores.iter().map(|c| c.weight).sum();
+            if total_weight > 0.0 {
+                scan.score
+                    .category_scores
+                    .iter()
+                    .map(|c| c.weight * c.score)
+                    .sum::<f64>()
+                    / total_weight
+            } else {
+                scan.score.total_score
+            }
+        };
+        self.critical_cap_detail.selected = 0;
+        self.overlay = Overlay::CriticalCapDetail;
+    }
+
+    /// Display order for the Arrange overlay: visible widgets first (in
+    /// `dashboard_layout` order), followed by hidden widgets.
+    pub fn arrange_dashboard_display_order(&self) -> Vec<crate::types::DashboardWidget> {
+        let mut order = self.config.dashboard_layout.clone();
+        for widget in crate::types::DashboardWidget::ALL {
+            if !order.contains(&widget) {
+                order.push(widget);
+            }
+        }
+        order
+    }
+
+    /// Swap the widget under the arrange-overlay cursor with its neighbour
+    /// `delta` slots away in `dashboard_layout`. No-op for hidden widgets
+    /// (they have no position in the visible order to move).
+    fn move_arrange_dashboard_widget(&mut self, delta: i32) {
+        let order = self.arrange_dashboard_display_order();
+        let Some(widget) = order.get(self.arrange_dashboard_cursor).copied() else {
+            return;
+        };
+        let Some(pos) = self
+            .config
+            .dashboard_layout
+            .iter()
+            .position(|w| *w == widget)
+        else {
+            return;
+        };
+        let new_pos = pos as i32 + delta;
+        if new_pos < 0 || new_pos as usize >= self.config.dashboard_layout.len() {
+            return;
+        }
+        self.config.dashboard_layout.swap(pos, new_pos as usize);
+        self.arrange_dashboard_cursor =
+            (self.arrange_dashboard_cursor as i32 + delta).max(0) as usize;
+    }
+
     /// Rebuild mouse click targets based on current terminal size and view state.
     pub fn rebuild_click_areas(&mut self, width: u16, height: u16) {
         use crate::types::ClickTarget;
         self.click_areas.clear();
+        self.footer_row = height.saturating_sub(2);
 
         // Footer view tabs — letter-key tabs across the bottom line
         let footer_y = height.saturating_sub(1);
@@ -378,12 +886,20 @@ impl App {
         if self.view_state == ViewState::Scan {
             let count = self.last_scan.as_ref().map_or(0, |s| s.findings.len());
             let start_y: u16 = 5; // approximate start of findings list
+            let list_width = (width / 2).saturating_sub(1); // leave a column for the scrollbar
             for i in 0..count.min(20) {
                 self.click_areas.push((
-                    Rect::new(0, start_y + i as u16, width / 2, 1),
+                    Rect::new(0, start_y + i as u16, list_width, 1),
                     ClickTarget::FindingRow(i),
                 ));
             }
+            // Scrollbar track on the right edge of the findings list column.
+            if count > 0 {
+                self.click_areas.push((
+                    Rect::new(list_width, start_y, 1, height.saturating_sub(start_y + 1)),
+                    ClickTarget::ScrollbarTrack(crate::types::ScrollTarget::Findings),
+                ));
+            }
         }
 
         // Fix view: checkboxes
@@ -396,13 +912,47 @@ impl App {
                 ));
             }
         }
+
+        // Chat view: message body (approximate — excludes the 1-line top
+        // border and the 5-line input area at the bottom).
+        if self.view_state == ViewState::Chat {
+            let top: u16 = 1;
+            let reserved = top + 5;
+            let body_height = height.saturating_sub(reserved);
+            // Leave the rightmost content column for the scrollbar track.
+            self.click_areas.push((
+                Rect::new(1, top, width.saturating_sub(3), body_height),
+                ClickTarget::ChatBody,
+            ));
+            self.click_areas.push((
+                Rect::new(width.saturating_sub(2), top, 1, body_height),
+                ClickTarget::ScrollbarTrack(crate::types::ScrollTarget::Chat),
+            ));
+        }
+    }
+
+    /// Handle a terminal resize: the new `Breakpoint` is recomputed on the
+    /// next render (it's always derived fresh from frame width, never
+    /// cached), so this rebuilds click targets against the new dimensions
+    /// immediately and clamps scroll offsets that would otherwise point
+    /// past the end of their content in the new, possibly-smaller viewport.
+    pub fn handle_resize(&mut self, width: u16, height: u16) {
+        self.rebuild_click_areas(width, height);
+
+        let chat_lines = crate::views::chat::plain_lines(self).len();
+        self.chat_scroll = self.chat_scroll.min(chat_lines.saturating_sub(1));
+        self.terminal_scroll = self
+            .terminal_scroll
+            .min(self.terminal_output.len().saturating_sub(1));
+
+        self.dirty = true;
     }
 
     pub const fn next_panel(&mut self) {
         self.active_panel = match self.active_panel {
             Panel::Chat => Panel::Score,
             Panel::Score => {
-                if self.code_content.is_some() {
+                if self.code_buffer.is_some() {
                     Panel::CodeViewer
                 } else {
                     Panel::FileBrowser
@@ -479,45 +1029,127 @@ impl App {
         }
     }
 
+    /// Terminal panel title, annotated with the active `/env` override count
+    /// so it's obvious injected vars are in play before running a command.
+    pub fn terminal_panel_title(&self) -> String {
+        if self.env_overrides.is_empty() {
+            " Terminal ".to_string()
+        } else {
+            format!(" Terminal ({} env) ", self.env_overrides.len())
+        }
+    }
+
     pub fn push_activity(&mut self, kind: ActivityKind, detail: impl Into<String>) {
         let now = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .unwrap_or_default()
             .as_secs();
-        let hours = (now % 86400) / 3600;
-        let mins = (now % 3600) / 60;
-        let timestamp = format!("{hours:02}:{mins:02}");
+        let timestamp = crate::timezone::format_hm(now);
+        let detail = detail.into();
 
-        self.activity_log.push(ActivityEntry {
+        crate::crash_report::record_activity(format!("{timestamp} {kind:?} {detail}"));
+        let entry = ActivityEntry {
             timestamp,
             kind,
-            detail: detail.into(),
-        });
+            detail,
+        };
+
+        self.activity_history.push(entry.clone());
+        if self.activity_history.len() > MAX_ACTIVITY_HISTORY {
+            self.activity_history.remove(0);
+        }
+
+        self.activity_log.push(entry);
         if self.activity_log.len() > MAX_ACTIVITY_LOG {
             self.activity_log.remove(0);
         }
     }
 
+    /// Open the Activity History overlay (`a` while the Activity Log widget
+    /// is zoomed): snapshot the persistent history and reset search/filter.
+    pub fn show_activity_history(&mut self) {
+        self.activity_history_view.entries = self.activity_history.clone();
+        self.activity_history_view.selected = 0;
+        self.activity_history_view.filter = None;
+        self.overlay_filter.clear();
+        self.overlay = Overlay::ActivityHistory;
+    }
+
+    /// `project_path` plus any configured `watch_roots`, resolved to
+    /// absolute paths (relative entries are joined against `project_path`).
+    /// Used to watch and browse a project split across sibling directories
+    /// as one logical whole.
+    pub fn watch_roots(&self) -> Vec<PathBuf> {
+        let mut roots = vec![self.project_path.clone()];
+        roots.extend(self.config.watch_roots.iter().map(|r| {
+            let p = PathBuf::from(r);
+            if p.is_relative() {
+                self.project_path.join(p)
+            } else {
+                p
+            }
+        }));
+        roots
+    }
+
+    /// Symlink and polling knobs for [`crate::watcher::spawn_watcher`], from
+    /// `watch_symlinks`/`watch_symlink_depth`/`watch_poll_interval_ms`.
+    pub fn watch_options(&self) -> crate::watcher::WatchOptions {
+        crate::watcher::WatchOptions {
+            symlinks: crate::watcher::SymlinkPolicy::from_config(
+                &self.config.watch_symlinks,
+                self.config.watch_symlink_depth,
+            ),
+            poll_interval_ms: self.config.watch_poll_interval_ms,
+        }
+    }
+
     pub async fn load_file_tree(&mut self) {
-        let path = self.project_path.clone();
+        let roots = self.watch_roots();
         if let Ok(tree) =
-            tokio::task::spawn_blocking(move || file_browser::build_file_tree(&path)).await
+            tokio::task::spawn_blocking(move || file_browser::build_file_tree_multi(&roots)).await
         {
             self.file_tree = tree;
         }
     }
 
+    /// Re-point the app at a different project: reset scan state and the
+    /// file tree, then reload the tree for `path`. Caller is responsible for
+    /// restarting the watcher (if active) and triggering a fresh scan.
+    pub async fn switch_project(&mut self, path: std::path::PathBuf) {
+        self.project_path = path;
+        self.last_scan = None;
+        self.last_scan_git = None;
+        self.score_history.clear();
+        self.scan_view = ScanViewState::default();
+        self.file_tree.clear();
+        self.load_file_tree().await;
+    }
+
     pub fn open_file(&mut self, path: &str, content: String) {
         self.push_activity(ActivityKind::Scan, path.to_string());
-        self.code_content = Some(content);
+        let buffer = CodeBuffer::new(content);
+        if buffer.truncated {
+            self.toasts.push(
+                crate::components::toast::ToastKind::Info,
+                format!("{path} is large — showing the first part only"),
+            );
+        }
+        self.code_buffer = Some(buffer);
         self.open_file_path = Some(path.to_string());
         self.code_scroll = 0;
         self.selection = None;
         self.active_panel = Panel::CodeViewer;
+        self.push_nav_point();
+
+        self.recent_files.retain(|p| p != path);
+        self.recent_files.insert(0, path.to_string());
+        self.recent_files.truncate(RECENT_FILES_CAP);
     }
 
     pub fn to_session_data(&self) -> crate::session::SessionData {
         crate::session::SessionData {
+            version: crate::session::SESSION_VERSION,
             messages: self.messages.clone(),
             score_history: self.score_history.clone(),
             open_file_path: self.open_file_path.clone(),
@@ -530,6 +1162,9 @@ impl App {
                 .cloned()
                 .collect(),
             last_scan: self.last_scan.clone(),
+            bookmarks: self.bookmarks.entries.clone(),
+            activity_history: self.activity_history.clone(),
+            recent_files: self.recent_files.clone(),
         }
     }
 
@@ -539,6 +1174,30 @@ impl App {
         self.open_file_path = data.open_file_path;
         self.terminal_output = data.terminal_output;
         self.last_scan = data.last_scan;
+        self.bookmarks.entries = data.bookmarks;
+        self.bookmarks.selected = 0;
+        self.activity_history = data.activity_history;
+        self.recent_files = data.recent_files;
+    }
+
+    pub fn to_layout_preset(&self) -> crate::session::LayoutPreset {
+        crate::session::LayoutPreset {
+            view_state: self.view_state,
+            sidebar_visible: self.sidebar_visible,
+            files_panel_visible: self.files_panel_visible,
+            terminal_visible: self.terminal_visible,
+            fix_split_pct: self.fix_split_pct,
+            scan_split_pct: self.scan_view.scan_split_pct,
+        }
+    }
+
+    pub fn load_layout_preset(&mut self, preset: crate::session::LayoutPreset) {
+        self.view_state = preset.view_state;
+        self.sidebar_visible = preset.sidebar_visible;
+        self.files_panel_visible = preset.files_panel_visible;
+        self.terminal_visible = preset.terminal_visible;
+        self.fix_split_pct = preset.fix_split_pct;
+        self.scan_view.scan_split_pct = preset.scan_split_pct;
     }
 
     /// Returns true when the app is performing a blocking operation and idle
@@ -552,16 +1211,47 @@ impl App {
 #[derive(Debug)]
 pub enum AppCommand {
     Scan,
+    /// Scan only files changed versus a base git ref (`/scan diff [base]`).
+    ScanDiff(String),
     AutoScan,
     OpenFile(String),
+    /// Open a file and scroll straight to `line` (1-based) — `--open
+    /// <file>:<line>`, `:open <file>:<line>`, and the `open` IPC command.
+    OpenFileAtLine(String, usize),
+    /// Suspend the TUI and shell out to `$EDITOR` for `(path, line)` — the
+    /// `o` key in the code viewer.
+    OpenInEditor(String, usize),
+    /// A file-watch event touched `path`, the file currently open in the
+    /// code viewer — re-read it and, if the content actually diverged from
+    /// the loaded buffer, show `Overlay::FileReloadPrompt`.
+    CheckOpenFileChanged(String),
     RunCommand(String),
     Reconnect,
+    /// `/doctor` — run the same health checks as `complior doctor` and post
+    /// the report as a system chat message.
+    Doctor,
     SwitchTheme(String),
     SaveSession(String),
     LoadSession(String),
+    SaveLayout(String),
+    LoadLayout(String),
     ToggleWatch,
+    /// Restart the watcher task with the current `watch_include`/`watch_exclude`
+    /// patterns, e.g. after the changes feed adds an "ignore this directory" entry.
+    RestartWatcher,
+    /// Switch the active project (`/projects` switcher): re-point
+    /// `project_path`, reset scan state, reload the file tree, restart the
+    /// watcher if it was running, and trigger a fresh scan.
+    SwitchProject(std::path::PathBuf),
     Undo(Option<u32>),
     FetchUndoHistory,
+    /// Rebuild `project_switcher.entries` from `config.registered_projects`,
+    /// reading each project's `.complior/last-scan.json` for score/zone/findings.
+    FetchProjectList,
+    /// Async: persist a project path to the registered-projects list.
+    RegisterProject(String),
+    /// Async: remove a project path from the registered-projects list.
+    UnregisterProject(String),
     FetchSuggestions,
     WhatIf(String),
     FixDryRun(Vec<String>),
@@ -575,12 +1265,32 @@ pub enum AppCommand {
     ListSessions,
     /// Apply selected fixes to files on disk, then auto-rescan.
     ApplyFixes,
+    /// Background: one more fix in the `ApplyFixes` batch was written to
+    /// disk. Drives `FixViewState::applying_current` for the footer's
+    /// progress bar.
+    FixProgress { current: u32, total: u32 },
+    /// Background result: the `ApplyFixes` batch finished writing to disk.
+    /// `pre_failed`/`pre_details` carry planning failures gathered before
+    /// the batch was spawned (see `AppCommand::ApplyFixes`).
+    FixesApplied {
+        statuses: Vec<(usize, bool, String)>,
+        pre_failed: u32,
+        pre_details: Vec<String>,
+    },
+    /// Apply selected fixes to a throwaway copy of the project and rescan
+    /// that copy, to report a real (measured, not predicted) score delta
+    /// before touching the working tree. See `crate::fix_sandbox`.
+    FixSandbox,
     /// Async: export compliance report to markdown file.
     ExportReport,
     /// Complete onboarding: save config + credentials, trigger post-completion action.
     CompleteOnboarding,
     /// Save partial onboarding progress for resume.
     SaveOnboardingPartial(usize),
+    /// Async: persist the `/risk-classify` questionnaire result to project config.
+    SaveRiskClassification(crate::views::risk_classification::RiskLevel),
+    /// Async: persist a per-kind idle-suggestion snooze (`/snooze`) to global config.
+    SnoozeSuggestion(crate::components::suggestions::SuggestionKind, u64),
     /// Load Agent Passports from engine (spawns background task).
     LoadPassports,
     /// Background result: passports loaded from engine.
@@ -621,10 +1331,25 @@ pub enum AppCommand {
     ChatStreamDelta(String),
     /// Structured block (`thinking/tool_call/tool_result`) from stream.
     ChatStreamBlock(ChatBlock),
+    /// A write/execute tool call arrived on the chat stream and the reader
+    /// task is paused on `respond` until the user approves, denies, or
+    /// always-allows it.
+    ChatToolApprovalRequested {
+        tool_name: String,
+        args: String,
+        respond: crate::types::ToolApprovalResponder,
+    },
     /// LLM stream completed.
     ChatStreamDone,
     /// Error from LLM stream.
     ChatStreamError(String),
+    /// Chat request hit a 429; queue it for auto-retry after `retry_secs`.
+    ChatRateLimited {
+        retry_secs: u64,
+        body: serde_json::Value,
+    },
+    /// Fired by `tick()` once a queued rate-limit retry's countdown elapses.
+    ChatRetryNow,
     /// User cancelled streaming.
     ChatCancel,
     /// Test LLM API key validity.
@@ -633,4 +1358,20 @@ pub enum AppCommand {
     LlmConnectionTestResult(Result<String, String>),
     /// Persist LLM settings from overlay.
     SaveLlmSettings,
+    /// Persist runtime preferences from the Settings overlay (`/settings`).
+    SaveConfig,
+    /// Load server-driven dashboard widgets from the engine's `/widgets`
+    /// endpoint.
+    LoadDashboardWidgets,
+    /// Background result: dashboard widgets loaded from engine.
+    DashboardWidgetsLoaded(Result<Vec<RemoteWidget>, String>),
+    /// Async: request an LLM-customized version of the selected Type B
+    /// (missing document) finding's template from the engine, keyed by
+    /// `check_id` (`g` in the Fix view checklist).
+    GenerateFixTemplate(String),
+    /// Background result: customized template content for `check_id`.
+    FixTemplateGenerated {
+        check_id: String,
+        result: Result<String, String>,
+    },
 }
@@ -1,5 +1,8 @@
+use std::path::PathBuf;
+
+use crate::components::keybindings::KeybindingsState;
 use crate::input::Action;
-use crate::types::{ChatMessage, MessageRole, Overlay};
+use crate::types::{ChatMessage, MessageRole, Overlay, ViewState};
 
 use super::{App, AppCommand};
 
@@ -426,12 +429,147 @@ impl App {
         }
     }
 
+    fn handle_settings_action(&mut self, action: Action) -> Option<AppCommand> {
+        use crate::settings_overlay::SettingsField;
+
+        match action {
+            Action::ScrollDown => {
+                if let Some(s) = &mut self.settings_overlay
+                    && !s.editing
+                {
+                    s.focused_field = match s.focused_field {
+                        SettingsField::Animations => SettingsField::WatchOnStart,
+                        SettingsField::WatchOnStart => SettingsField::AutoScroll,
+                        SettingsField::AutoScroll => SettingsField::SidebarDefault,
+                        SettingsField::SidebarDefault => SettingsField::TickRate,
+                        SettingsField::TickRate => SettingsField::ToastDuration,
+                        SettingsField::ToastDuration => SettingsField::ToastDuration,
+                    };
+                }
+                None
+            }
+            Action::ScrollUp => {
+                if let Some(s) = &mut self.settings_overlay
+                    && !s.editing
+                {
+                    s.focused_field = match s.focused_field {
+                        SettingsField::Animations => SettingsField::Animations,
+                        SettingsField::WatchOnStart => SettingsField::Animations,
+                        SettingsField::AutoScroll => SettingsField::WatchOnStart,
+                        SettingsField::SidebarDefault => SettingsField::AutoScroll,
+                        SettingsField::TickRate => SettingsField::SidebarDefault,
+                        SettingsField::ToastDuration => SettingsField::TickRate,
+                    };
+                }
+                None
+            }
+            Action::SubmitInput | Action::InsertChar(' ') => {
+                if let Some(s) = &mut self.settings_overlay {
+                    if s.editing {
+                        s.editing = false;
+                    } else {
+                        match s.focused_field {
+                            SettingsField::Animations => {
+                                s.animations_enabled = !s.animations_enabled
+                            }
+                            SettingsField::WatchOnStart => s.watch_on_start = !s.watch_on_start,
+                            SettingsField::AutoScroll => {
+                                s.auto_scroll_enabled = !s.auto_scroll_enabled
+                            }
+                            SettingsField::SidebarDefault => s.sidebar_visible = !s.sidebar_visible,
+                            SettingsField::TickRate | SettingsField::ToastDuration => {
+                                s.editing = true;
+                            }
+                        }
+                    }
+                }
+                None
+            }
+            Action::InsertChar(c) if c.is_ascii_digit() => {
+                if let Some(s) = &mut self.settings_overlay
+                    && s.editing
+                {
+                    match s.focused_field {
+                        SettingsField::TickRate => s.tick_rate_input.push(c),
+                        SettingsField::ToastDuration => s.toast_duration_input.push(c),
+                        _ => {}
+                    }
+                }
+                None
+            }
+            Action::DeleteChar => {
+                if let Some(s) = &mut self.settings_overlay
+                    && s.editing
+                {
+                    match s.focused_field {
+                        SettingsField::TickRate => {
+                            s.tick_rate_input.pop();
+                        }
+                        SettingsField::ToastDuration => {
+                            s.toast_duration_input.pop();
+                        }
+                        _ => {}
+                    }
+                }
+                None
+            }
+            Action::EnterNormalMode | Action::Quit => {
+                if let Some(s) = self.settings_overlay.take() {
+                    self.config.animations_enabled = s.animations_enabled;
+                    self.config.watch_on_start = s.watch_on_start;
+                    self.config.auto_scroll_enabled = s.auto_scroll_enabled;
+                    self.config.sidebar_visible = s.sidebar_visible;
+                    if let Ok(v) = s.tick_rate_input.parse() {
+                        self.config.tick_rate_ms = v;
+                    }
+                    if let Ok(v) = s.toast_duration_input.parse() {
+                        self.config.toast_duration_secs = v;
+                    }
+
+                    self.animation.enabled = s.animations_enabled;
+                    self.sidebar_visible = s.sidebar_visible;
+                    self.chat_auto_scroll = s.auto_scroll_enabled;
+                    self.terminal_auto_scroll = s.auto_scroll_enabled;
+                    self.toasts.set_duration(self.config.toast_duration_secs);
+
+                    self.toasts
+                        .push(crate::components::toast::ToastKind::Info, "Settings saved");
+                    self.overlay = Overlay::None;
+                    return Some(AppCommand::SaveConfig);
+                }
+                self.overlay = Overlay::None;
+                None
+            }
+            _ => None,
+        }
+    }
+
+    /// Resolve the pending tool-call approval: record "always allow" if
+    /// chosen, wake the paused stream reader with the decision, and close
+    /// the overlay.
+    fn resolve_tool_approval(&mut self, decision: crate::types::ToolApprovalDecision) {
+        let Some(pending) = self.pending_tool_approval.take() else {
+            self.overlay = Overlay::None;
+            return;
+        };
+        if decision == crate::types::ToolApprovalDecision::AlwaysAllow {
+            self.tool_always_allow.insert(pending.tool_name.clone());
+        }
+        let _ = pending.respond.0.send(decision);
+        self.overlay = Overlay::None;
+    }
+
     pub(super) fn handle_overlay_action(&mut self, action: Action) -> Option<AppCommand> {
         // --- LLM Settings overlay ---
         if self.overlay == Overlay::LlmSettings {
             return self.handle_llm_settings_action(action);
         }
 
+        // --- Settings overlay ---
+        if self.overlay == Overlay::Settings {
+            return self.handle_settings_action(action);
+        }
+
         // --- Theme Picker overlay ---
         if self.overlay == Overlay::ThemePicker {
             return self.handle_theme_picker_action(action);
@@ -460,6 +598,27 @@ impl App {
             return None;
         }
 
+        // --- Paste Confirm overlay ---
+        if self.overlay == Overlay::PasteConfirm {
+            match action {
+                Action::InsertChar('y' | 'Y') => {
+                    if let Some(paste) = self.pending_paste.take() {
+                        let fenced = format!("```\n{}\n```\n", paste.text);
+                        self.snapshot_input_undo();
+                        self.input.insert_str(self.input_cursor, &fenced);
+                        self.input_cursor += fenced.len();
+                    }
+                    self.overlay = Overlay::None;
+                }
+                Action::EnterNormalMode | Action::Quit | Action::InsertChar('n' | 'N') => {
+                    self.pending_paste = None;
+                    self.overlay = Overlay::None;
+                }
+                _ => {}
+            }
+            return None;
+        }
+
         // --- Undo History overlay ---
         if self.overlay == Overlay::UndoHistory {
             match action {
@@ -481,6 +640,284 @@ impl App {
             return None;
         }
 
+        // --- Bookmarks overlay ---
+        if self.overlay == Overlay::Bookmarks {
+            match action {
+                Action::ScrollDown => self.bookmarks.navigate_down(),
+                Action::ScrollUp => self.bookmarks.navigate_up(),
+                Action::DeleteChar => self.bookmarks.remove_selected(),
+                Action::SubmitInput => {
+                    let bookmark = self.bookmarks.selected_bookmark().cloned();
+                    self.overlay = Overlay::None;
+                    if let Some(bookmark) = bookmark {
+                        return match bookmark {
+                            crate::types::Bookmark::File { path } => {
+                                self.switch_view(ViewState::Dashboard);
+                                if Some(&path) == self.open_file_path.as_ref() {
+                                    None
+                                } else {
+                                    Some(AppCommand::OpenFile(path))
+                                }
+                            }
+                            crate::types::Bookmark::Finding { check_id, .. } => {
+                                self.scan_view.selected_finding =
+                                    self.last_scan.as_ref().and_then(|scan| {
+                                        scan.findings.iter().position(|f| f.check_id == check_id)
+                                    });
+                                self.switch_view(ViewState::Scan);
+                                None
+                            }
+                        };
+                    }
+                }
+                Action::EnterNormalMode | Action::Quit => {
+                    self.overlay = Overlay::None;
+                }
+                _ => {}
+            }
+            return None;
+        }
+
+        // --- Guided tour overlay ---
+        if self.overlay == Overlay::Tour {
+            match action {
+                Action::ScrollDown | Action::SubmitInput => {
+                    if self.tour.advance() {
+                        self.switch_view(self.tour.current().view);
+                    } else {
+                        self.overlay = Overlay::None;
+                    }
+                }
+                Action::ScrollUp => {
+                    self.tour.back();
+                    self.switch_view(self.tour.current().view);
+                }
+                Action::EnterNormalMode | Action::Quit => {
+                    self.overlay = Overlay::None;
+                }
+                _ => {}
+            }
+            return None;
+        }
+
+        // --- Keybindings browser overlay ---
+        if self.overlay == Overlay::Keybindings {
+            match action {
+                Action::ScrollDown => {
+                    let len = KeybindingsState::matching(&self.overlay_filter).len();
+                    self.keybindings.navigate_down(len);
+                }
+                Action::ScrollUp => self.keybindings.navigate_up(),
+                Action::EnterNormalMode | Action::Quit => {
+                    self.overlay = Overlay::None;
+                    self.overlay_filter.clear();
+                }
+                _ => {}
+            }
+            return None;
+        }
+
+        // --- Notification center overlay ---
+        if self.overlay == Overlay::Notifications {
+            match action {
+                Action::ScrollDown => self.notification_center.navigate_down(),
+                Action::ScrollUp => self.notification_center.navigate_up(),
+                Action::CycleNotificationFilter => self.notification_center.cycle_filter(),
+                Action::EnterNormalMode | Action::Quit => {
+                    self.overlay = Overlay::None;
+                }
+                _ => {}
+            }
+            return None;
+        }
+
+        // --- Activity history overlay ---
+        if self.overlay == Overlay::ActivityHistory {
+            match action {
+                Action::ScrollDown => {
+                    let len = self.activity_history_view.matching(&self.overlay_filter).len();
+                    self.activity_history_view.navigate_down(len);
+                }
+                Action::ScrollUp => self.activity_history_view.navigate_up(),
+                Action::CycleActivityFilter => self.activity_history_view.cycle_filter(),
+                Action::EnterNormalMode | Action::Quit => {
+                    self.overlay = Overlay::None;
+                }
+                _ => {}
+            }
+            return None;
+        }
+
+        // --- Critical cap drill-down overlay ---
+        if self.overlay == Overlay::CriticalCapDetail {
+            match action {
+                Action::ScrollDown => self.critical_cap_detail.navigate_down(),
+                Action::ScrollUp => self.critical_cap_detail.navigate_up(),
+                Action::EnterNormalMode | Action::Quit => {
+                    self.overlay = Overlay::None;
+                }
+                _ => {}
+            }
+            return None;
+        }
+
+        // --- Project switcher overlay ---
+        if self.overlay == Overlay::ProjectSwitcher {
+            match action {
+                Action::ScrollDown => self.project_switcher.navigate_down(),
+                Action::ScrollUp => self.project_switcher.navigate_up(),
+                Action::SubmitInput => {
+                    let path = self.project_switcher.selected_path().map(PathBuf::from);
+                    self.overlay = Overlay::None;
+                    if let Some(path) = path {
+                        return Some(AppCommand::SwitchProject(path));
+                    }
+                }
+                Action::EnterNormalMode | Action::Quit => {
+                    self.overlay = Overlay::None;
+                }
+                _ => {}
+            }
+            return None;
+        }
+
+        // --- Recent files quick switcher overlay ---
+        if self.overlay == Overlay::RecentFiles {
+            match action {
+                Action::ScrollDown => self.recent_files_view.navigate_down(),
+                Action::ScrollUp => self.recent_files_view.navigate_up(),
+                Action::SubmitInput => {
+                    let path = self.recent_files_view.selected_path().map(str::to_string);
+                    self.overlay = Overlay::None;
+                    if let Some(path) = path {
+                        return Some(AppCommand::OpenFile(path));
+                    }
+                }
+                Action::EnterNormalMode | Action::Quit => {
+                    self.overlay = Overlay::None;
+                }
+                _ => {}
+            }
+            return None;
+        }
+
+        // --- File changed-on-disk reload prompt ---
+        if self.overlay == Overlay::FileReloadPrompt {
+            let Some(prompt) = &mut self.file_reload_prompt else {
+                self.overlay = Overlay::None;
+                return None;
+            };
+            match action {
+                Action::ScrollDown => prompt.move_down(),
+                Action::ScrollUp => prompt.move_up(),
+                Action::SubmitInput if prompt.showing_diff => {}
+                Action::SubmitInput => match prompt.cursor {
+                    0 => {
+                        let path = prompt.path.clone();
+                        let content = prompt.disk_content.clone();
+                        self.file_reload_prompt = None;
+                        self.overlay = Overlay::None;
+                        self.open_file(&path, content);
+                    }
+                    2 => prompt.showing_diff = true,
+                    _ => {
+                        self.file_reload_prompt = None;
+                        self.overlay = Overlay::None;
+                    }
+                },
+                Action::EnterNormalMode | Action::Quit => {
+                    if prompt.showing_diff {
+                        prompt.showing_diff = false;
+                    } else {
+                        self.file_reload_prompt = None;
+                        self.overlay = Overlay::None;
+                    }
+                }
+                _ => {}
+            }
+            return None;
+        }
+
+        // --- Risk classification overlay ---
+        if self.overlay == Overlay::RiskClassification {
+            let Some(wiz) = &mut self.risk_wizard else {
+                self.overlay = Overlay::None;
+                return None;
+            };
+            match action {
+                Action::InsertChar('y' | 'Y') if !wiz.completed => wiz.answer(true),
+                Action::InsertChar('n' | 'N') if !wiz.completed => wiz.answer(false),
+                Action::SubmitInput if wiz.completed => {
+                    let level = wiz.result;
+                    self.risk_wizard = None;
+                    self.overlay = Overlay::None;
+                    if let Some(level) = level {
+                        return Some(AppCommand::SaveRiskClassification(level));
+                    }
+                }
+                Action::EnterNormalMode | Action::Quit => {
+                    if wiz.completed {
+                        wiz.prev();
+                    } else if wiz.current == 0 {
+                        self.risk_wizard = None;
+                        self.overlay = Overlay::None;
+                    } else {
+                        wiz.prev();
+                    }
+                }
+                _ => {}
+            }
+            return None;
+        }
+
+        // --- Stats overlay ---
+        if self.overlay == Overlay::Stats {
+            match action {
+                Action::ScrollDown => self.stats.navigate_down(),
+                Action::ScrollUp => self.stats.navigate_up(),
+                Action::EnterNormalMode | Action::Quit => {
+                    self.overlay = Overlay::None;
+                }
+                _ => {}
+            }
+            return None;
+        }
+
+        // --- Changes Feed overlay ---
+        if self.overlay == Overlay::ChangesFeed {
+            match action {
+                Action::ScrollDown => self.changes.navigate_down(),
+                Action::ScrollUp => self.changes.navigate_up(),
+                Action::ChangesFeedOpen => {
+                    if let Some(path) = self.changes.selected_path() {
+                        let path = path.to_string_lossy().to_string();
+                        self.overlay = Overlay::None;
+                        return Some(AppCommand::OpenFile(path));
+                    }
+                }
+                Action::ChangesFeedRescan => {
+                    self.overlay = Overlay::None;
+                    return Some(AppCommand::AutoScan);
+                }
+                Action::ChangesFeedIgnoreDir => {
+                    if let Some(dir) = self.changes.selected_dir() {
+                        let pattern = format!("{}/**", dir.display());
+                        self.config.watch_exclude.push(pattern.clone());
+                        self.toasts.push(
+                            crate::components::toast::ToastKind::Info,
+                            format!("Ignoring {pattern}"),
+                        );
+                        return Some(AppCommand::RestartWatcher);
+                    }
+                }
+                Action::EnterNormalMode | Action::Quit => {
+                    self.overlay = Overlay::None;
+                }
+                _ => {}
+            }
+            return None;
+        }
+
         // --- Dismiss Modal overlay ---
         if self.overlay == Overlay::DismissModal {
             match action {
@@ -496,11 +933,22 @@ impl App {
                 }
                 Action::SubmitInput => {
                     if let Some(modal) = &self.dismiss_modal {
-                        let reason = modal.selected_reason();
-                        self.toasts.push(
-                            crate::components::toast::ToastKind::Info,
-                            format!("Dismissed: {reason:?}"),
-                        );
+                        let reason = modal.selected_reason().clone();
+                        match self.record_dismissal(&modal.check_id, modal.file.as_deref(), &reason)
+                        {
+                            Ok(()) => {
+                                self.toasts.push(
+                                    crate::components::toast::ToastKind::Info,
+                                    format!("Dismissed: {}", reason.label()),
+                                );
+                            }
+                            Err(e) => {
+                                self.toasts.push(
+                                    crate::components::toast::ToastKind::Error,
+                                    format!("Failed to record dismissal: {e}"),
+                                );
+                            }
+                        }
                     }
                     self.dismiss_modal = None;
                     self.overlay = Overlay::None;
@@ -514,6 +962,73 @@ impl App {
             return None;
         }
 
+        // --- Check Docs overlay ---
+        if self.overlay == Overlay::CheckDocs {
+            match action {
+                Action::ScrollDown => {
+                    if let Some(docs) = &mut self.check_docs {
+                        docs.scroll_down();
+                    }
+                }
+                Action::ScrollUp => {
+                    if let Some(docs) = &mut self.check_docs {
+                        docs.scroll_up();
+                    }
+                }
+                Action::EnterNormalMode | Action::Quit | Action::SubmitInput => {
+                    self.check_docs = None;
+                    self.overlay = Overlay::None;
+                }
+                _ => {}
+            }
+            return None;
+        }
+
+        // --- Tool Call Approval overlay ---
+        if self.overlay == Overlay::ToolCallApproval {
+            match action {
+                Action::InsertChar('y' | 'Y') | Action::SubmitInput => {
+                    self.resolve_tool_approval(crate::types::ToolApprovalDecision::Approve);
+                }
+                Action::InsertChar('a' | 'A') => {
+                    self.resolve_tool_approval(crate::types::ToolApprovalDecision::AlwaysAllow);
+                }
+                Action::InsertChar('n' | 'N') | Action::EnterNormalMode | Action::Quit => {
+                    self.resolve_tool_approval(crate::types::ToolApprovalDecision::Deny);
+                }
+                _ => {}
+            }
+            return None;
+        }
+
+        // --- Floating Chat overlay ---
+        if self.overlay == Overlay::FloatingChat {
+            match action {
+                Action::InsertChar(c) => self.input.push(c),
+                Action::DeleteChar => {
+                    self.input.pop();
+                }
+                Action::SubmitInput => {
+                    let text = std::mem::take(&mut self.input);
+                    self.input_cursor = 0;
+                    if text.trim().is_empty() {
+                        return None;
+                    }
+                    self.push_to_history(&text);
+                    if !self.streaming.active {
+                        self.chat_auto_scroll = true;
+                        return Some(AppCommand::ChatSend(text));
+                    }
+                }
+                Action::EnterNormalMode | Action::Quit => {
+                    self.overlay = Overlay::None;
+                    self.input_mode = crate::types::InputMode::Normal;
+                }
+                _ => {}
+            }
+            return None;
+        }
+
         match action {
             Action::EnterNormalMode | Action::Quit => {
                 let was_getting_started = self.overlay == Overlay::GettingStarted;
@@ -525,8 +1040,11 @@ impl App {
                 None
             }
             Action::ScrollDown if self.overlay == Overlay::CommandPalette => {
-                let count =
-                    crate::components::command_palette::filtered_count(&self.overlay_filter);
+                let count = crate::components::command_palette::filtered_count(
+                    &self.overlay_filter,
+                    &self.palette_contextual_commands(),
+                    &self.recent_commands,
+                );
                 if count > 0 {
                     self.palette_index = (self.palette_index + 1).min(count - 1);
                 }
@@ -553,8 +1071,11 @@ impl App {
                         self.overlay = Overlay::None;
                         if let Some(cmd) = crate::components::command_palette::filtered_command(
                             &filter,
+                            &self.palette_contextual_commands(),
+                            &self.recent_commands,
                             self.palette_index,
                         ) {
+                            self.remember_recent_command(cmd);
                             let cmd = cmd.trim_start_matches('/');
                             return self.handle_command(cmd);
                         }
@@ -591,7 +1112,26 @@ impl App {
                     | Overlay::ThemePicker
                     | Overlay::Onboarding
                     | Overlay::UndoHistory
-                    | Overlay::LlmSettings => {}
+                    | Overlay::LlmSettings
+                    | Overlay::ChangesFeed
+                    | Overlay::ArrangeDashboard
+                    | Overlay::FloatingChat
+                    | Overlay::PasteConfirm
+                    | Overlay::ProjectSwitcher
+                    | Overlay::Stats
+                    | Overlay::RiskClassification
+                    | Overlay::Settings
+                    | Overlay::CheckDocs
+                    | Overlay::ToolCallApproval
+                    | Overlay::ToolResultInspector
+                    | Overlay::Bookmarks
+                    | Overlay::Notifications
+                    | Overlay::ActivityHistory
+                    | Overlay::CriticalCapDetail
+                    | Overlay::Tour
+                    | Overlay::Keybindings
+                    | Overlay::RecentFiles
+                    | Overlay::FileReloadPrompt => {}
                 }
                 None
             }
@@ -604,6 +1144,15 @@ impl App {
                 self.help_scroll += 1;
                 None
             }
+            // Tool Result Inspector overlay scroll with j/k
+            Action::ScrollUp if self.overlay == Overlay::ToolResultInspector => {
+                self.tool_inspector_scroll = self.tool_inspector_scroll.saturating_sub(1);
+                None
+            }
+            Action::ScrollDown if self.overlay == Overlay::ToolResultInspector => {
+                self.tool_inspector_scroll += 1;
+                None
+            }
             // Ignore no-op keys
             Action::None
             | Action::ScrollUp
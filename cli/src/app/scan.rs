@@ -4,9 +4,14 @@ use super::App;
 
 impl App {
     pub fn set_scan_result(&mut self, result: ScanResult) {
+        // A successful scan proves the engine is reachable again.
+        self.degraded_mode = None;
         let score = result.score.total_score;
         let old_score = self.score_history.last().copied().unwrap_or(0.0);
-        self.push_activity(ActivityKind::Scan, format!("{score:.0}/100"));
+        self.push_activity(
+            ActivityKind::Scan,
+            format!("{}/100", crate::locale::format_decimal(score, 0)),
+        );
         self.score_history.push(score);
 
         // T08: Push counter animation on score change
@@ -46,10 +51,29 @@ impl App {
         self.scan_view.selected_finding = None;
         self.scan_view.detail_open = false;
 
+        crate::stats::record_scan(&self.project_path, &result);
         self.last_scan = Some(result);
         self.operation_start = None;
         self.chat_auto_scroll = true;
 
+        // Record commit hash, branch, and dirty flag with this scan, and warn
+        // when the branch has changed since the last scan.
+        let project_path = self.project_path.to_string_lossy().to_string();
+        if let Some(git) = crate::headless::scan::capture_git_context(&project_path) {
+            if let Some(prev) = &self.last_scan_git {
+                if prev.branch != git.branch {
+                    self.messages.push(ChatMessage::new(
+                        MessageRole::System,
+                        format!(
+                            "Warning: comparing against a scan from a different branch ({})",
+                            prev.branch
+                        ),
+                    ));
+                }
+            }
+            self.last_scan_git = Some(git);
+        }
+
         // T07: Toast notification for scan completion
         let toast_msg = format!("Scan complete: {score:.0}/100 ({zone})");
         let kind = if score >= 80.0 {
@@ -68,10 +92,41 @@ impl App {
             s.findings
                 .iter()
                 .filter(|f| self.scan_view.findings_filter.matches(f.severity))
+                .filter(|f| {
+                    crate::assignments::matches(
+                        &self.assignments,
+                        self.scan_view.assignee_filter.as_deref(),
+                        &f.check_id,
+                        f.file.as_deref(),
+                    )
+                })
                 .count()
         })
     }
 
+    /// Open the currently-selected finding's file, for the Scan view's
+    /// live code pane (`code_view_open`). Returns `None` (no-op) when there
+    /// is no selection, no scan, or the finding has no associated file.
+    pub(super) fn open_selected_finding_file(&mut self) -> Option<super::AppCommand> {
+        let idx = self.scan_view.selected_finding?;
+        let scan = self.last_scan.as_ref()?;
+        let finding = crate::views::scan::resolve_selected_finding(
+            &scan.findings,
+            self.scan_view.findings_filter,
+            idx,
+            &self.passport_view.loaded_passports,
+            &self.assignments,
+            self.scan_view.assignee_filter.as_deref(),
+            &self.finding_states,
+            self.scan_view.show_snoozed,
+        )?;
+        let file_path = finding.file.clone()?;
+        if self.open_file_path.as_deref() == Some(file_path.as_str()) {
+            return None;
+        }
+        Some(super::AppCommand::OpenFile(file_path))
+    }
+
     /// Cycle `focus_check_id` to prev/next fixable finding in single-fix mode.
     pub(super) fn cycle_single_fix(&mut self, direction: i32) {
         let len = self.fix_view.fixable_findings.len();
@@ -243,7 +243,9 @@ mod tests {
     #[test]
     fn test_code_search_submission() {
         let mut app = App::new(TuiConfig::default());
-        app.code_content = Some("hello world\nfoo bar\nhello again".to_string());
+        app.code_buffer = Some(crate::views::code_viewer::CodeBuffer::new(
+            "hello world\nfoo bar\nhello again".to_string(),
+        ));
         app.active_panel = Panel::CodeViewer;
 
         // Simulate code search: enter command mode, type query, submit
@@ -315,6 +317,33 @@ mod tests {
         assert!(!has_sidebar, "Small terminal should not have SidebarToggle");
     }
 
+    #[test]
+    fn test_handle_resize_rebuilds_click_areas() {
+        let mut app = App::new(TuiConfig::default());
+        app.view_state = ViewState::Dashboard;
+        app.handle_resize(120, 40);
+        let tab_count = app
+            .click_areas
+            .iter()
+            .filter(|(_, t)| matches!(t, crate::types::ClickTarget::ViewTab(_)))
+            .count();
+        assert_eq!(tab_count, 9);
+        assert!(app.dirty);
+    }
+
+    #[test]
+    fn test_handle_resize_clamps_terminal_scroll() {
+        let mut app = App::new(TuiConfig::default());
+        app.terminal_output = vec!["line".to_string(); 5];
+        app.terminal_scroll = 4;
+        app.handle_resize(80, 24);
+        assert_eq!(app.terminal_scroll, 4);
+
+        app.terminal_output.truncate(2);
+        app.handle_resize(80, 24);
+        assert_eq!(app.terminal_scroll, 1);
+    }
+
     #[test]
     fn test_idle_suggestion_triggers_fetch() {
         let mut app = App::new(TuiConfig::default());
@@ -322,18 +351,41 @@ mod tests {
         // Simulate 15s idle
         app.idle_suggestions.last_input =
             std::time::Instant::now() - std::time::Duration::from_secs(15);
-        let cmd = app.tick();
+        let (_, cmd) = app.tick();
         assert!(matches!(cmd, Some(AppCommand::FetchSuggestions)));
         assert!(app.idle_suggestions.fetch_pending);
     }
 
+    #[test]
+    fn test_tick_not_dirty_when_idle() {
+        let mut app = App::new(TuiConfig::default());
+        app.input_mode = InputMode::Insert; // block idle-suggestion fetch
+        let (dirty, _) = app.tick();
+        assert!(
+            !dirty,
+            "idle tick with no active operation should not redraw"
+        );
+    }
+
+    #[test]
+    fn test_tick_dirty_when_operation_in_progress() {
+        let mut app = App::new(TuiConfig::default());
+        app.input_mode = InputMode::Insert;
+        app.operation_start = Some(std::time::Instant::now());
+        let (dirty, _) = app.tick();
+        assert!(
+            dirty,
+            "elapsed-time counter changes every tick while an operation runs"
+        );
+    }
+
     #[test]
     fn test_idle_no_fetch_when_insert_mode() {
         let mut app = App::new(TuiConfig::default());
         app.input_mode = InputMode::Insert;
         app.idle_suggestions.last_input =
             std::time::Instant::now() - std::time::Duration::from_secs(15);
-        let cmd = app.tick();
+        let (_, cmd) = app.tick();
         assert!(cmd.is_none(), "Should not trigger fetch in insert mode");
     }
 
@@ -346,4 +398,36 @@ mod tests {
         assert!(app.colon_mode);
         assert_eq!(app.input_mode, InputMode::Command);
     }
+
+    #[test]
+    fn test_env_command_set_unset_list() {
+        let mut app = App::new(TuiConfig::default());
+        assert!(app.handle_env_command("").contains("No session env"));
+
+        let msg = app.handle_env_command("set TOKEN=abc123");
+        assert!(msg.contains("Set TOKEN"));
+        assert_eq!(app.env_overrides, vec![("TOKEN".to_string(), "abc123".to_string())]);
+
+        // Setting the same key again replaces it rather than duplicating.
+        app.handle_env_command("set TOKEN=xyz");
+        assert_eq!(app.env_overrides, vec![("TOKEN".to_string(), "xyz".to_string())]);
+
+        let listed = app.handle_env_command("list");
+        assert!(listed.contains("TOKEN=xyz"));
+
+        let msg = app.handle_env_command("unset TOKEN");
+        assert!(msg.contains("Unset TOKEN"));
+        assert!(app.env_overrides.is_empty());
+
+        assert!(app.handle_env_command("unset TOKEN").contains("not set"));
+        assert!(app.handle_env_command("bogus").contains("Unknown"));
+    }
+
+    #[test]
+    fn test_terminal_panel_title_reflects_env_overrides() {
+        let mut app = App::new(TuiConfig::default());
+        assert_eq!(app.terminal_panel_title(), " Terminal ");
+        app.handle_env_command("set TOKEN=abc");
+        assert_eq!(app.terminal_panel_title(), " Terminal (1 env) ");
+    }
 }
@@ -21,14 +21,19 @@ impl App {
                             self.scan_view.findings_filter,
                             idx,
                             &self.passport_view.loaded_passports,
+                            &self.assignments,
+                            self.scan_view.assignee_filter.as_deref(),
+                            &self.finding_states,
+                            self.scan_view.show_snoozed,
                         )
                     {
                         if finding.fix.is_some() {
                             let target_check_id = finding.check_id.clone();
+                            let fix_view = FixViewState::from_scan(&scan.findings);
                             // Navigate to fix view in single-fix mode
                             self.scan_view.detail_open = false;
-                            self.view_state = ViewState::Fix;
-                            self.fix_view = FixViewState::from_scan(&scan.findings);
+                            self.switch_view(ViewState::Fix);
+                            self.fix_view = fix_view;
                             self.fix_view.focus_check_id = Some(target_check_id.clone());
 
                             // Pre-select this finding in fix list
@@ -66,6 +71,10 @@ impl App {
                             self.scan_view.findings_filter,
                             idx,
                             &self.passport_view.loaded_passports,
+                            &self.assignments,
+                            self.scan_view.assignee_filter.as_deref(),
+                            &self.finding_states,
+                            self.scan_view.show_snoozed,
                         )
                     {
                         let explanation = crate::views::scan::explain_finding(finding);
@@ -85,6 +94,10 @@ impl App {
                             self.scan_view.findings_filter,
                             idx,
                             &self.passport_view.loaded_passports,
+                            &self.assignments,
+                            self.scan_view.assignee_filter.as_deref(),
+                            &self.finding_states,
+                            self.scan_view.show_snoozed,
                         )
                     {
                         if let Some(ref file_path) = finding.file {
@@ -97,14 +110,140 @@ impl App {
                     }
                 } else if c == 'd' {
                     // Quick action: Dismiss finding (open dismiss modal)
-                    if let Some(idx) = self.scan_view.selected_finding {
+                    if let Some(idx) = self.scan_view.selected_finding
+                        && let Some(scan) = &self.last_scan
+                        && let Some(finding) = crate::views::scan::resolve_selected_finding(
+                            &scan.findings,
+                            self.scan_view.findings_filter,
+                            idx,
+                            &self.passport_view.loaded_passports,
+                            &self.assignments,
+                            self.scan_view.assignee_filter.as_deref(),
+                            &self.finding_states,
+                            self.scan_view.show_snoozed,
+                        )
+                    {
                         self.dismiss_modal =
-                            Some(crate::components::quick_actions::DismissModal::new(idx));
+                            Some(crate::components::quick_actions::DismissModal::new(
+                                finding.check_id.clone(),
+                                finding.file.clone(),
+                            ));
                         self.overlay = Overlay::DismissModal;
                     }
+                } else if c == 'A' {
+                    // Quick action: cycle selected finding's assignee through
+                    // the configured team roster (None -> team[0] -> ... -> None).
+                    if let Some(idx) = self.scan_view.selected_finding
+                        && let Some(scan) = &self.last_scan
+                        && let Some(finding) = crate::views::scan::resolve_selected_finding(
+                            &scan.findings,
+                            self.scan_view.findings_filter,
+                            idx,
+                            &self.passport_view.loaded_passports,
+                            &self.assignments,
+                            self.scan_view.assignee_filter.as_deref(),
+                            &self.finding_states,
+                            self.scan_view.show_snoozed,
+                        )
+                    {
+                        if self.config.team.is_empty() {
+                            self.toasts.push(
+                                crate::components::toast::ToastKind::Info,
+                                "No team configured — use /assign <name> instead",
+                            );
+                        } else {
+                            let check_id = finding.check_id.clone();
+                            let file = finding.file.clone();
+                            let current = crate::assignments::assignee_for(
+                                &self.assignments,
+                                &check_id,
+                                file.as_deref(),
+                            );
+                            let next = match current
+                                .and_then(|c| self.config.team.iter().position(|t| t == c))
+                            {
+                                Some(i) if i + 1 < self.config.team.len() => {
+                                    Some(self.config.team[i + 1].clone())
+                                }
+                                Some(_) => None,
+                                None => Some(self.config.team[0].clone()),
+                            };
+                            let label = next.clone().unwrap_or_else(|| "Unassigned".to_string());
+                            match self.assign_finding(&check_id, file.as_deref(), next) {
+                                Ok(()) => self.toasts.push(
+                                    crate::components::toast::ToastKind::Info,
+                                    format!("Assigned to {label}"),
+                                ),
+                                Err(e) => self.toasts.push(
+                                    crate::components::toast::ToastKind::Error,
+                                    format!("Failed to save assignment: {e}"),
+                                ),
+                            }
+                        }
+                    }
+                } else if c == '?' {
+                    // Quick action: open the Check Docs overlay for the
+                    // selected finding (what it checks, why it matters,
+                    // article/penalty/deadline, remediation, links).
+                    if let Some(idx) = self.scan_view.selected_finding
+                        && let Some(scan) = &self.last_scan
+                        && let Some(finding) = crate::views::scan::resolve_selected_finding(
+                            &scan.findings,
+                            self.scan_view.findings_filter,
+                            idx,
+                            &self.passport_view.loaded_passports,
+                            &self.assignments,
+                            self.scan_view.assignee_filter.as_deref(),
+                            &self.finding_states,
+                            self.scan_view.show_snoozed,
+                        )
+                    {
+                        self.check_docs = Some(
+                            crate::components::check_docs::CheckDocsState::from_finding(finding),
+                        );
+                        self.overlay = Overlay::CheckDocs;
+                    }
+                } else if c == 's' {
+                    // Quick action: cycle selected finding's workflow status
+                    // (Open -> In Progress -> Remediated -> Accepted Risk -> Open).
+                    if let Some((check_id, file)) = self.selected_scan_finding_key() {
+                        let next = crate::findings_state::status_for(
+                            &self.finding_states,
+                            &check_id,
+                            file.as_deref(),
+                        )
+                        .next();
+                        match crate::findings_state::set_status(
+                            &self.project_path,
+                            &check_id,
+                            file.as_deref(),
+                            next,
+                        ) {
+                            Ok(states) => {
+                                self.finding_states = states;
+                                self.toasts.push(
+                                    crate::components::toast::ToastKind::Info,
+                                    format!("Status: {}", next.label()),
+                                );
+                            }
+                            Err(e) => self.toasts.push(
+                                crate::components::toast::ToastKind::Error,
+                                format!("Failed to save status: {e}"),
+                            ),
+                        }
+                    }
                 } else if c == 'p' {
                     // Toggle show/hide passed checks
                     self.scan_view.show_passed = !self.scan_view.show_passed;
+                } else if c == 'z' {
+                    // Toggle show/hide findings snoozed via /snooze-until
+                    self.scan_view.show_snoozed = !self.scan_view.show_snoozed;
+                } else if c == 'v' {
+                    // Toggle split-view code pane, pinned to the selected finding
+                    self.scan_view.code_view_open = !self.scan_view.code_view_open;
+                    if self.scan_view.code_view_open {
+                        return self.open_selected_finding_file();
+                    }
                 } else if c == '<' {
                     // Resize scan split — shrink left panel
                     self.scan_view.scan_split_pct =
@@ -119,12 +258,23 @@ impl App {
                 'a' => self.fix_view.select_all(),
                 'n' => self.fix_view.deselect_all(),
                 'd' => self.fix_view.diff_visible = !self.fix_view.diff_visible,
+                's' => {
+                    self.fix_view.diff_side_by_side = !self.fix_view.diff_side_by_side;
+                }
                 '<' => {
                     self.fix_split_pct = self.fix_split_pct.saturating_sub(5).max(25);
                 }
                 '>' => {
                     self.fix_split_pct = (self.fix_split_pct + 5).min(75);
                 }
+                'g' => {
+                    if let Some(item) = self.fix_view.fixable_findings.get(self.fix_view.selected_index)
+                        && item.finding_type == crate::types::FindingType::B
+                        && !self.fix_view.generating_templates.contains(&item.check_id)
+                    {
+                        return Some(AppCommand::GenerateFixTemplate(item.check_id.clone()));
+                    }
+                }
                 _ => {}
             },
             ViewState::Dashboard => match c {
@@ -132,6 +282,16 @@ impl App {
                     // Toggle widget zoom
                     self.zoom.toggle();
                 }
+                'a' if self.zoom.zoomed
+                    == Some(crate::components::zoom::ZoomedWidget::ActivityLog) =>
+                {
+                    self.show_activity_history();
+                }
+                'a' => {
+                    // Open the widget-arrangement overlay
+                    self.arrange_dashboard_cursor = 0;
+                    self.overlay = Overlay::ArrangeDashboard;
+                }
                 'f' => {
                     // Cycle focused framework: All → 0 → 1 → ... → N-1 → All
                     // Only active when 2+ frameworks are loaded
@@ -146,12 +306,48 @@ impl App {
                         }
                     }
                 }
+                'c' if self
+                    .last_scan
+                    .as_ref()
+                    .is_some_and(|s| s.score.critical_cap_applied) =>
+                {
+                    self.show_critical_cap_detail();
+                }
+                _ => {}
+            },
+            ViewState::Report if self.report_view.composer_open => match c {
+                ' ' => {
+                    if let Some(cfg) = self
+                        .report_sections
+                        .get_mut(self.report_view.composer_cursor)
+                    {
+                        cfg.enabled = !cfg.enabled;
+                    }
+                }
+                'J' => {
+                    let i = self.report_view.composer_cursor;
+                    if i + 1 < self.report_sections.len() {
+                        self.report_sections.swap(i, i + 1);
+                        self.report_view.composer_cursor += 1;
+                    }
+                }
+                'K' => {
+                    let i = self.report_view.composer_cursor;
+                    if i > 0 {
+                        self.report_sections.swap(i, i - 1);
+                        self.report_view.composer_cursor -= 1;
+                    }
+                }
                 _ => {}
             },
             ViewState::Report => match c {
                 'e' if self.last_scan.is_some() => {
                     return Some(AppCommand::ExportReport);
                 }
+                'c' => {
+                    self.report_view.composer_open = true;
+                    self.report_view.composer_cursor = 0;
+                }
                 c @ ('1'..='9') => {
                     let idx = (c as u8 - b'1') as usize;
                     if idx < crate::views::report::GENERATORS.len() {
@@ -307,6 +503,12 @@ impl App {
                     return Some(AppCommand::ExportReport);
                 }
             }
+            ViewState::Chat => {
+                if self.chat_tool_focus.is_some() {
+                    self.tool_inspector_scroll = 0;
+                    self.overlay = Overlay::ToolResultInspector;
+                }
+            }
             _ => {}
         }
         None
@@ -334,7 +536,7 @@ impl App {
                     self.fix_view.results = None;
                 } else if self.fix_view.is_single_fix() {
                     self.fix_view.focus_check_id = None;
-                    self.view_state = ViewState::Scan;
+                    self.switch_view(ViewState::Scan);
                 }
             }
             ViewState::Passport => {
@@ -364,7 +566,17 @@ impl App {
                 }
             }
             ViewState::Report => {
-                if self.report_view.viewing_report {
+                if self.report_view.composer_open {
+                    self.report_view.composer_open = false;
+                    if let Err(e) =
+                        crate::report_sections::save(&self.project_path, &self.report_sections)
+                    {
+                        self.toasts.push(
+                            crate::components::toast::ToastKind::Error,
+                            format!("Failed to save report sections: {e}"),
+                        );
+                    }
+                } else if self.report_view.viewing_report {
                     self.report_view.viewing_report = false;
                 }
             }
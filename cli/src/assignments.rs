@@ -0,0 +1,143 @@
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+/// Assignment of a finding to a person, persisted to `.complior/tracked-issues.json`
+/// so small teams can triage findings in the TUI without a separate tracker.
+/// Shared via git alongside `dismissals.jsonl` and `project.toml` (see
+/// `App::handle_team_status`'s `SHARED_FILES`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrackedIssue {
+    pub check_id: String,
+    pub file: Option<String>,
+    pub assignee: String,
+    pub updated_at: u64,
+}
+
+fn issues_path(project_path: &Path) -> PathBuf {
+    project_path.join(".complior").join("tracked-issues.json")
+}
+
+fn load(project_path: &Path) -> Vec<TrackedIssue> {
+    std::fs::read_to_string(issues_path(project_path))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save(project_path: &Path, issues: &[TrackedIssue]) -> std::io::Result<()> {
+    let dir = project_path.join(".complior");
+    std::fs::create_dir_all(&dir)?;
+    let json = serde_json::to_string_pretty(issues)?;
+    std::fs::write(issues_path(project_path), json)
+}
+
+/// Assign (or unassign, if `assignee` is `None`) the finding identified by
+/// `check_id` + `file`, persist it, and return the refreshed list so the
+/// caller can update its in-memory copy without a second disk read.
+pub fn set_assignee(
+    project_path: &Path,
+    check_id: &str,
+    file: Option<&str>,
+    assignee: Option<String>,
+) -> std::io::Result<Vec<TrackedIssue>> {
+    let mut issues = load(project_path);
+    issues.retain(|i| !(i.check_id == check_id && i.file.as_deref() == file));
+    if let Some(assignee) = assignee {
+        let updated_at = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        issues.push(TrackedIssue {
+            check_id: check_id.to_string(),
+            file: file.map(String::from),
+            assignee,
+            updated_at,
+        });
+    }
+    save(project_path, &issues)?;
+    Ok(issues)
+}
+
+/// Load all tracked assignments for a project.
+pub fn load_all(project_path: &Path) -> Vec<TrackedIssue> {
+    load(project_path)
+}
+
+/// Current assignee for a finding, if any.
+pub fn assignee_for<'a>(
+    issues: &'a [TrackedIssue],
+    check_id: &str,
+    file: Option<&str>,
+) -> Option<&'a str> {
+    issues
+        .iter()
+        .find(|i| i.check_id == check_id && i.file.as_deref() == file)
+        .map(|i| i.assignee.as_str())
+}
+
+/// Whether a finding passes the Scan view's assignee filter. `None` matches
+/// everything; `Some("unassigned")` matches findings with no assignee;
+/// anything else matches case-insensitively against the assignee name.
+pub fn matches(
+    issues: &[TrackedIssue],
+    filter: Option<&str>,
+    check_id: &str,
+    file: Option<&str>,
+) -> bool {
+    let Some(filter) = filter else {
+        return true;
+    };
+    let current = assignee_for(issues, check_id, file);
+    if filter.eq_ignore_ascii_case("unassigned") {
+        current.is_none()
+    } else {
+        current.is_some_and(|a| a.eq_ignore_ascii_case(filter))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_then_clear_assignee_roundtrips() {
+        let dir =
+            std::env::temp_dir().join(format!("complior-assignments-test-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let issues = set_assignee(
+            &dir,
+            "l4-hardcoded-key",
+            Some("src/main.rs"),
+            Some("alice".into()),
+        )
+        .expect("set assignee");
+        assert_eq!(
+            assignee_for(&issues, "l4-hardcoded-key", Some("src/main.rs")),
+            Some("alice")
+        );
+
+        let issues = set_assignee(&dir, "l4-hardcoded-key", Some("src/main.rs"), None)
+            .expect("clear assignee");
+        assert_eq!(
+            assignee_for(&issues, "l4-hardcoded-key", Some("src/main.rs")),
+            None
+        );
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn matches_handles_unassigned_and_named_filters() {
+        let issues = vec![TrackedIssue {
+            check_id: "l4-x".into(),
+            file: None,
+            assignee: "Bob".into(),
+            updated_at: 0,
+        }];
+        assert!(matches(&issues, None, "l4-x", None));
+        assert!(matches(&issues, Some("bob"), "l4-x", None));
+        assert!(!matches(&issues, Some("unassigned"), "l4-x", None));
+        assert!(matches(&issues, Some("unassigned"), "l4-other", None));
+    }
+}
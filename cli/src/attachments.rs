@@ -0,0 +1,143 @@
+//! Resolves `@file` mentions in an outbound chat message into file-content
+//! attachments: reads the referenced file relative to the project root and
+//! splits it into size-bounded chunks so one large file can't blow the
+//! LLM's context window in a single request. Mirrors [`crate::secrets_redact`]
+//! in shape -- a pure, deterministic text-processing step that runs on the
+//! message before it's sent, with metadata surfaced back to the caller so
+//! the chat bubble can show what was actually attached.
+
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// Rough chars-per-token ratio used to size chunks. Not a real tokenizer --
+/// just enough to keep attachment chunks well under typical context limits.
+const CHARS_PER_TOKEN: usize = 4;
+
+/// Max estimated tokens per attachment chunk.
+const MAX_CHUNK_TOKENS: usize = 2000;
+
+/// One `@file` mention resolved to file content, split into chunks if the
+/// file is larger than [`MAX_CHUNK_TOKENS`] would comfortably allow.
+#[derive(Debug, Clone)]
+pub struct Attachment {
+    pub path: String,
+    pub size_bytes: usize,
+    pub chunks: Vec<String>,
+}
+
+impl Attachment {
+    pub fn chunk_count(&self) -> usize {
+        self.chunks.len()
+    }
+}
+
+fn mention_pattern() -> &'static regex::Regex {
+    static PATTERN: OnceLock<regex::Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| regex::Regex::new(r"@([^\s@]+)").unwrap())
+}
+
+/// Scan `text` for `@path` mentions and resolve any that point at real,
+/// readable files under `project_root`. Mentions of obligations
+/// (`@OBL-...`), anything that doesn't resolve to a file, or a path that
+/// escapes `project_root` (`@../../etc/passwd`) are silently skipped --
+/// this only attaches things that actually exist on disk and stay inside
+/// the project, it doesn't validate mention syntax.
+pub fn extract_attachments(text: &str, project_root: &Path) -> Vec<Attachment> {
+    let Ok(project_root) = project_root.canonicalize() else {
+        return Vec::new();
+    };
+    mention_pattern()
+        .captures_iter(text)
+        .filter_map(|caps| {
+            let rel = caps.get(1)?.as_str();
+            let resolved = project_root.join(rel).canonicalize().ok()?;
+            if !resolved.starts_with(&project_root) {
+                return None;
+            }
+            let content = std::fs::read_to_string(&resolved).ok()?;
+            Some(Attachment {
+                path: rel.to_string(),
+                size_bytes: content.len(),
+                chunks: chunk_content(&content),
+            })
+        })
+        .collect()
+}
+
+/// Split `content` into chunks of at most `MAX_CHUNK_TOKENS` estimated
+/// tokens, on char boundaries so multi-byte UTF-8 is never split mid-char.
+fn chunk_content(content: &str) -> Vec<String> {
+    let max_chars = MAX_CHUNK_TOKENS * CHARS_PER_TOKEN;
+    let chars: Vec<char> = content.chars().collect();
+    if chars.len() <= max_chars {
+        return vec![content.to_string()];
+    }
+    chars
+        .chunks(max_chars)
+        .map(|c| c.iter().collect())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_mention_to_existing_file() {
+        let dir = std::env::temp_dir().join("complior_attachments_test_resolve");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("notes.txt"), "hello world").unwrap();
+
+        let attachments = extract_attachments("check @notes.txt please", &dir);
+
+        assert_eq!(attachments.len(), 1);
+        assert_eq!(attachments[0].path, "notes.txt");
+        assert_eq!(attachments[0].size_bytes, "hello world".len());
+        assert_eq!(attachments[0].chunks, vec!["hello world".to_string()]);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn skips_mentions_that_do_not_resolve_to_a_file() {
+        let dir = std::env::temp_dir().join("complior_attachments_test_skip");
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let attachments = extract_attachments("see @OBL-12 and @missing.rs", &dir);
+
+        assert!(attachments.is_empty());
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn rejects_mentions_that_escape_project_root() {
+        let dir = std::env::temp_dir().join("complior_attachments_test_traversal");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(std::env::temp_dir().join("outside_secret.txt"), "top secret").unwrap();
+
+        let attachments = extract_attachments("leak @../outside_secret.txt", &dir);
+
+        assert!(attachments.is_empty());
+        std::fs::remove_dir_all(&dir).ok();
+        std::fs::remove_file(std::env::temp_dir().join("outside_secret.txt")).ok();
+    }
+
+    #[test]
+    fn chunks_large_files() {
+        let dir = std::env::temp_dir().join("complior_attachments_test_chunk");
+        std::fs::create_dir_all(&dir).unwrap();
+        let big = "x".repeat(MAX_CHUNK_TOKENS * CHARS_PER_TOKEN + 10);
+        std::fs::write(dir.join("big.txt"), &big).unwrap();
+
+        let attachments = extract_attachments("@big.txt", &dir);
+
+        assert_eq!(attachments.len(), 1);
+        assert_eq!(attachments[0].chunk_count(), 2);
+        assert_eq!(
+            attachments[0].chunks.concat().len(),
+            MAX_CHUNK_TOKENS * CHARS_PER_TOKEN + 10
+        );
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -6,7 +6,37 @@ use futures_util::StreamExt;
 use tokio::sync::Notify;
 
 use crate::app::AppCommand;
-use crate::types::ChatBlock;
+use crate::engine_client::EngineClient;
+use crate::types::{ChatBlock, ToolApprovalDecision, ToolApprovalResponder};
+
+/// Substrings of a tool's name that mark it as write/execute -- these
+/// require explicit user approval (see [`spawn_stream_reader`]) rather
+/// than running silently in the background. Read-only tools (search,
+/// lookup, explain, ...) are unaffected.
+const APPROVAL_REQUIRED_SUBSTRINGS: [&str; 5] = ["write", "exec", "delete", "apply", "run_"];
+
+fn requires_approval(tool_name: &str) -> bool {
+    let lower = tool_name.to_lowercase();
+    APPROVAL_REQUIRED_SUBSTRINGS
+        .iter()
+        .any(|needle| lower.contains(needle))
+}
+
+/// Send an approval request to the app and wait for the user's decision.
+/// Denies if the app drops the responder without answering (e.g. app exit).
+async fn request_tool_approval(
+    tx: &tokio::sync::mpsc::UnboundedSender<AppCommand>,
+    tool_name: String,
+    args: String,
+) -> ToolApprovalDecision {
+    let (respond, receiver) = tokio::sync::oneshot::channel();
+    let _ = tx.send(AppCommand::ChatToolApprovalRequested {
+        tool_name,
+        args,
+        respond: ToolApprovalResponder(respond),
+    });
+    receiver.await.unwrap_or(ToolApprovalDecision::Deny)
+}
 
 /// Check whether response content-type is JSON (slash commands return JSON, not SSE).
 pub fn is_json_response(resp: &reqwest::Response) -> bool {
@@ -18,10 +48,17 @@ pub fn is_json_response(resp: &reqwest::Response) -> bool {
 
 /// Spawn a background task that reads an SSE stream and dispatches events
 /// via the `tx` channel. Cancel via the `cancel` Notify.
+///
+/// When a write/execute tool call arrives ([`requires_approval`]), the
+/// stream pauses -- no further chunks are read -- until the app resolves
+/// the approval overlay. The decision is then POSTed to the engine at
+/// `/chat/tool-approval` before the paused read resumes, so the engine
+/// only proceeds once it has the user's explicit ack.
 pub fn spawn_stream_reader(
     resp: reqwest::Response,
     tx: tokio::sync::mpsc::UnboundedSender<AppCommand>,
     cancel: Arc<Notify>,
+    client: EngineClient,
 ) {
     tokio::spawn(async move {
         let mut stream = resp.bytes_stream();
@@ -62,10 +99,55 @@ pub fn spawn_stream_reader(
                             let data = data.trim();
                             let cmd = parse_sse_event(&current_event, data);
                             if let Some(cmd) = cmd {
-                                let is_done = matches!(cmd, AppCommand::ChatStreamDone);
-                                let _ = tx.send(cmd);
-                                if is_done {
-                                    return;
+                                match cmd {
+                                    AppCommand::ChatStreamBlock(ChatBlock::ToolCall {
+                                        tool_name,
+                                        args,
+                                    }) if requires_approval(&tool_name) => {
+                                        let decision = request_tool_approval(
+                                            &tx,
+                                            tool_name.clone(),
+                                            args.clone(),
+                                        )
+                                        .await;
+                                        let approved =
+                                            !matches!(decision, ToolApprovalDecision::Deny);
+                                        let _ = client
+                                            .post_json(
+                                                "/chat/tool-approval",
+                                                &serde_json::json!({
+                                                    "toolName": tool_name,
+                                                    "approved": approved,
+                                                    "alwaysAllow": matches!(
+                                                        decision,
+                                                        ToolApprovalDecision::AlwaysAllow
+                                                    ),
+                                                }),
+                                            )
+                                            .await;
+                                        if approved {
+                                            let _ = tx.send(AppCommand::ChatStreamBlock(
+                                                ChatBlock::ToolCall { tool_name, args },
+                                            ));
+                                        } else {
+                                            let _ = tx.send(AppCommand::ChatStreamBlock(
+                                                ChatBlock::ToolResult {
+                                                    tool_name,
+                                                    result: "Denied by user".to_string(),
+                                                    is_error: true,
+                                                },
+                                            ));
+                                            return;
+                                        }
+                                    }
+                                    other => {
+                                        let is_done =
+                                            matches!(other, AppCommand::ChatStreamDone);
+                                        let _ = tx.send(other);
+                                        if is_done {
+                                            return;
+                                        }
+                                    }
                                 }
                             }
                         }
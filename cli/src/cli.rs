@@ -31,6 +31,44 @@ pub struct Cli {
     /// Disable colored output (same as `NO_COLOR=1`)
     #[arg(long, global = true)]
     pub no_color: bool,
+
+    /// Hard-disable all LLM providers and remote calls (chat, `fix --ai`,
+    /// issue tracker export) — only the local engine is used. Same as
+    /// `OFFLINE_MODE=1` or setting `offline_mode = true` in
+    /// `settings.toml`. Required by some corporate network policies.
+    #[arg(long, global = true)]
+    pub offline: bool,
+
+    /// Show a frame time / events-per-second / per-view render duration
+    /// overlay in a corner of the dashboard. Undocumented — for diagnosing
+    /// TUI perf regressions, not a supported user-facing flag.
+    #[arg(long, global = true, hide = true)]
+    pub perf_overlay: bool,
+
+    /// Run colon-commands after launch, e.g. `--exec "scan; view 2"`. Runs
+    /// after `startup_commands` from `.complior/project.toml`.
+    #[arg(long, global = true)]
+    pub exec: Option<String>,
+
+    /// Open a file at launch, focusing the code viewer at that location,
+    /// e.g. `--open src/main.rs:42`. Runs before `startup_commands`/`--exec`.
+    #[arg(long, global = true)]
+    pub open: Option<String>,
+
+    /// Launch with a synthetic demo project — fake scan results, findings,
+    /// score history, and chat transcript. No engine or LLM provider is
+    /// started; every command that would call out to one is unavailable.
+    /// For screenshots, conference demos, and first-look evaluation.
+    #[arg(long, global = true)]
+    pub demo: bool,
+
+    /// Launch against a built-in mock engine instead of the real one — an
+    /// in-process HTTP server (see `mock_engine`) serving canned scan/chat/
+    /// undo responses. Unlike `--demo`, real requests go out over HTTP and
+    /// exercise the full client code path; useful for end-to-end TUI
+    /// testing and development on machines without the Node engine.
+    #[arg(long, global = true)]
+    pub mock_engine: bool,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
@@ -93,11 +131,36 @@ impl SeverityLevel {
     }
 }
 
+/// PR annotation format for the `--annotate` flag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum AnnotationFormat {
+    Github,
+    Gitlab,
+}
+
+/// Issue tracker for the `track create` command.
+#[cfg(feature = "extras")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum TicketProvider {
+    Github,
+    Jira,
+}
+
+#[cfg(feature = "extras")]
+impl TicketProvider {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Github => "github",
+            Self::Jira => "jira",
+        }
+    }
+}
+
 #[derive(Subcommand)]
 pub enum Command {
     /// Scan project for AI Act compliance
     #[command(
-        after_long_help = "\x1b[1mExamples:\x1b[0m\n  complior scan                         Basic scan (L1-L4)\n  complior scan --deep                  Include external tools\n  complior scan --llm                   Add LLM analysis (L5)\n  complior scan --ci --threshold 80     CI mode with threshold\n  complior scan --json                  JSON output\n  complior scan --diff main             Compare against branch"
+        after_long_help = "\x1b[1mExamples:\x1b[0m\n  complior scan                         Basic scan (L1-L4)\n  complior scan --deep                  Include external tools\n  complior scan --llm                   Add LLM analysis (L5)\n  complior scan --ci --threshold 80     CI mode with threshold\n  complior scan --json                  JSON output\n  complior scan --diff main             Compare against branch\n  complior scan --annotate github       GitHub Checks annotations"
     )]
     Scan {
         /// CI mode: exit 0 if score >= threshold, exit 1 otherwise
@@ -112,6 +175,11 @@ pub enum Command {
         #[arg(long)]
         sarif: bool,
 
+        /// Output findings as PR annotations (github: Checks annotation JSON,
+        /// gitlab: Code Quality report JSON)
+        #[arg(long, value_enum)]
+        annotate: Option<AnnotationFormat>,
+
         /// [deprecated] Scans are always headless; this flag is a no-op
         #[arg(long, hide = true)]
         no_tui: bool,
@@ -406,6 +474,42 @@ pub enum Command {
         action: ToolsAction,
     },
 
+    /// Manage third-party WASM plugin manifests (discovery/inspection only —
+    /// sandboxed WASM loading and the `/plugins` TUI overlay are not
+    /// implemented yet, see `complior-cli::plugins` module docs)
+    #[cfg(feature = "extras")]
+    Plugins {
+        #[command(subcommand)]
+        action: PluginsAction,
+    },
+
+    /// Inspect the SBOM and model inventory (.complior/inventory/)
+    #[cfg(feature = "extras")]
+    Inventory {
+        #[command(subcommand)]
+        action: InventoryAction,
+    },
+
+    /// Manage git pre-commit/pre-push compliance gate hooks
+    #[cfg(feature = "extras")]
+    #[command(
+        after_long_help = "\x1b[1mExamples:\x1b[0m\n  complior hooks install                 Install pre-commit gate (threshold 60)\n  complior hooks install --stage pre-push --threshold 80\n  complior hooks status                  Show installed hooks\n  complior hooks uninstall               Remove complior-managed hooks"
+    )]
+    Hooks {
+        #[command(subcommand)]
+        action: HooksAction,
+    },
+
+    /// File findings as Jira/GitHub issues (dedups against already-tracked findings)
+    #[cfg(feature = "extras")]
+    #[command(
+        after_long_help = "\x1b[1mExamples:\x1b[0m\n  complior track create l2-001 --provider github --repo acme/app\n  complior track create l2-001 --provider jira --jira-project COMP\n  complior track list                    Show findings already filed"
+    )]
+    Track {
+        #[command(subcommand)]
+        action: TrackAction,
+    },
+
     /// Run dynamic AI system evaluation (probes + LLM judge + security)
     #[command(
         after_long_help = "\x1b[1mExamples:\x1b[0m\n  complior eval http://localhost:4000    Deterministic tests\n  complior eval http://localhost:4000 --llm    Add LLM judge\n  complior eval http://localhost:4000 --full   All test suites\n  complior eval --last --failures       Review last failures"
@@ -926,6 +1030,36 @@ pub enum ImportAction {
         #[arg(long)]
         file: Option<String>,
 
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Import Semgrep scan results (JSON), mapped into Complior's findings
+    Semgrep {
+        /// Path to Semgrep `--json` output file (or read from stdin)
+        #[arg(long)]
+        file: Option<String>,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Import Bandit scan results (JSON), mapped into Complior's findings
+    Bandit {
+        /// Path to Bandit `-f json` output file (or read from stdin)
+        #[arg(long)]
+        file: Option<String>,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Import Trivy scan results (JSON), mapped into Complior's findings
+    Trivy {
+        /// Path to Trivy `--format json` output file (or read from stdin)
+        #[arg(long)]
+        file: Option<String>,
+
         /// Output as JSON
         #[arg(long)]
         json: bool,
@@ -989,6 +1123,107 @@ pub enum ToolsAction {
     Update,
 }
 
+#[cfg(feature = "extras")]
+#[derive(Subcommand, Debug, Clone)]
+pub enum HooksAction {
+    /// Write a git hook that runs `complior scan --ci` before the given stage
+    Install {
+        /// Git hook to install into (pre-commit or pre-push)
+        #[arg(long, default_value = "pre-commit")]
+        stage: String,
+        /// Score threshold the hook enforces (default: 60)
+        #[arg(long, default_value = "60")]
+        threshold: u32,
+        /// Project path (default: current directory)
+        path: Option<String>,
+    },
+    /// Remove complior-managed git hooks
+    Uninstall {
+        /// Git hook to remove (pre-commit or pre-push); removes both if omitted
+        #[arg(long)]
+        stage: Option<String>,
+        /// Project path (default: current directory)
+        path: Option<String>,
+    },
+    /// Show which git hooks complior currently manages
+    Status {
+        /// Project path (default: current directory)
+        path: Option<String>,
+    },
+}
+
+#[cfg(feature = "extras")]
+#[derive(Subcommand, Debug, Clone)]
+pub enum PluginsAction {
+    /// List plugins discovered under `.complior/plugins/`
+    List {
+        /// Project path (default: current directory)
+        path: Option<String>,
+    },
+    /// Show manifest details for one plugin
+    Info {
+        /// Plugin name (matches its manifest's `name` field)
+        name: String,
+        /// Project path (default: current directory)
+        path: Option<String>,
+    },
+}
+
+#[cfg(feature = "extras")]
+#[derive(Subcommand, Debug, Clone)]
+pub enum InventoryAction {
+    /// Summarize the SBOM and model inventory
+    Show {
+        /// Project path (default: current directory)
+        path: Option<String>,
+    },
+    /// List third-party models flagged as GPAI providers
+    Gpai {
+        /// Project path (default: current directory)
+        path: Option<String>,
+    },
+}
+
+#[cfg(feature = "extras")]
+#[derive(Subcommand, Debug, Clone)]
+pub enum TrackAction {
+    /// Create a Jira/GitHub issue from a finding in the last scan.
+    /// If the finding is already tracked, prints the existing issue instead
+    /// of filing a duplicate.
+    Create {
+        /// Check ID of the finding to file (see `complior scan --json`)
+        check_id: String,
+
+        /// Issue tracker to file into
+        #[arg(long, value_enum)]
+        provider: TicketProvider,
+
+        /// GitHub "owner/repo" (required for `--provider github`)
+        #[arg(long)]
+        repo: Option<String>,
+
+        /// Jira project key, e.g. "COMP" (required for `--provider jira`)
+        #[arg(long)]
+        jira_project: Option<String>,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+
+        /// Project path (default: current directory)
+        path: Option<String>,
+    },
+    /// List findings already filed as tracker issues
+    List {
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+
+        /// Project path (default: current directory)
+        path: Option<String>,
+    },
+}
+
 #[cfg(feature = "extras")]
 #[derive(Subcommand, Debug, Clone)]
 pub enum JurisdictionAction {
@@ -2339,6 +2574,74 @@ mod tests {
         assert!(is_headless(&cli));
     }
 
+    #[cfg(feature = "extras")]
+    #[test]
+    fn cli_parse_hooks_install_defaults() {
+        let cli = Cli::parse_from(["complior", "hooks", "install"]);
+        assert!(matches!(
+            cli.command,
+            Some(Command::Hooks {
+                action: HooksAction::Install {
+                    ref stage,
+                    threshold: 60,
+                    path: None,
+                }
+            }) if stage == "pre-commit"
+        ));
+        assert!(is_headless(&cli));
+    }
+
+    #[cfg(feature = "extras")]
+    #[test]
+    fn cli_parse_hooks_install_custom_stage_and_threshold() {
+        let cli = Cli::parse_from([
+            "complior",
+            "hooks",
+            "install",
+            "--stage",
+            "pre-push",
+            "--threshold",
+            "80",
+        ]);
+        assert!(matches!(
+            cli.command,
+            Some(Command::Hooks {
+                action: HooksAction::Install {
+                    ref stage,
+                    threshold: 80,
+                    path: None,
+                }
+            }) if stage == "pre-push"
+        ));
+    }
+
+    #[cfg(feature = "extras")]
+    #[test]
+    fn cli_parse_hooks_uninstall() {
+        let cli = Cli::parse_from(["complior", "hooks", "uninstall"]);
+        assert!(matches!(
+            cli.command,
+            Some(Command::Hooks {
+                action: HooksAction::Uninstall {
+                    stage: None,
+                    path: None,
+                }
+            })
+        ));
+    }
+
+    #[cfg(feature = "extras")]
+    #[test]
+    fn cli_parse_hooks_status() {
+        let cli = Cli::parse_from(["complior", "hooks", "status"]);
+        assert!(matches!(
+            cli.command,
+            Some(Command::Hooks {
+                action: HooksAction::Status { path: None }
+            })
+        ));
+    }
+
     #[test]
     fn cli_parse_eval_default() {
         let cli = Cli::parse_from(["complior", "eval", "http://localhost:4000/api/chat"]);
@@ -2768,6 +3071,37 @@ mod tests {
         assert!(is_headless(&eval_cli));
     }
 
+    #[test]
+    fn cli_parse_scan_annotate_github() {
+        let cli = Cli::parse_from(["complior", "scan", "--annotate", "github"]);
+        match &cli.command {
+            Some(Command::Scan { annotate, .. }) => {
+                assert_eq!(*annotate, Some(AnnotationFormat::Github));
+            }
+            _ => panic!("Expected Scan command"),
+        }
+    }
+
+    #[test]
+    fn cli_parse_scan_annotate_gitlab() {
+        let cli = Cli::parse_from(["complior", "scan", "--annotate", "gitlab"]);
+        match &cli.command {
+            Some(Command::Scan { annotate, .. }) => {
+                assert_eq!(*annotate, Some(AnnotationFormat::Gitlab));
+            }
+            _ => panic!("Expected Scan command"),
+        }
+    }
+
+    #[test]
+    fn cli_parse_scan_annotate_defaults_none() {
+        let cli = Cli::parse_from(["complior", "scan"]);
+        match &cli.command {
+            Some(Command::Scan { annotate, .. }) => assert_eq!(*annotate, None),
+            _ => panic!("Expected Scan command"),
+        }
+    }
+
     #[cfg(feature = "extras")]
     #[test]
     fn cli_headless_detection_extras() {
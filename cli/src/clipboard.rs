@@ -0,0 +1,19 @@
+use std::io::Write;
+
+use base64::Engine;
+
+/// Copy `text` to the system clipboard via an OSC 52 escape sequence.
+///
+/// Written straight to stdout so it reaches the terminal even though the
+/// TUI runs in the alternate screen with mouse capture enabled, which
+/// breaks the terminal's own native text selection. OSC 52 is honored by
+/// most modern terminal emulators (iTerm2, kitty, WezTerm, Windows
+/// Terminal, tmux with `set -g set-clipboard on`) including over SSH,
+/// which is why it's used unconditionally here rather than as a fallback
+/// behind an OS clipboard crate.
+pub fn copy(text: &str) {
+    let encoded = base64::engine::general_purpose::STANDARD.encode(text);
+    let mut stdout = std::io::stdout();
+    let _ = write!(stdout, "\x1b]52;c;{encoded}\x07");
+    let _ = stdout.flush();
+}
@@ -0,0 +1,200 @@
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem};
+
+use crate::theme;
+use crate::types::{ActivityEntry, ActivityKind};
+
+/// Snapshot of `App::activity_history`, taken when the overlay opens (`a`
+/// while the Activity Log widget is zoomed) — same snapshot-on-open shape
+/// as `UndoHistoryState`/`ProjectSwitcherState`. Text search reuses
+/// `App::overlay_filter` (the same field `CommandPalette`/`FilePicker` use)
+/// rather than a dedicated field.
+pub struct ActivityHistoryState {
+    pub entries: Vec<ActivityEntry>,
+    pub selected: usize,
+    pub filter: Option<ActivityKind>,
+}
+
+impl ActivityHistoryState {
+    pub const fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            selected: 0,
+            filter: None,
+        }
+    }
+
+    /// Cycle the kind filter: All -> Scan -> Fix -> Watch -> All.
+    pub const fn cycle_filter(&mut self) {
+        self.filter = match self.filter {
+            None => Some(ActivityKind::Scan),
+            Some(ActivityKind::Scan) => Some(ActivityKind::Fix),
+            Some(ActivityKind::Fix) => Some(ActivityKind::Watch),
+            Some(ActivityKind::Watch) => None,
+        };
+        self.selected = 0;
+    }
+
+    /// Entries matching the current kind filter and `search` substring
+    /// (case-insensitive, matched against `detail`), most recent first.
+    pub fn matching(&self, search: &str) -> Vec<&ActivityEntry> {
+        let search = search.to_lowercase();
+        self.entries
+            .iter()
+            .rev()
+            .filter(|e| self.filter.is_none_or(|f| e.kind == f))
+            .filter(|e| search.is_empty() || e.detail.to_lowercase().contains(&search))
+            .collect()
+    }
+
+    pub fn navigate_up(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn navigate_down(&mut self, matching_len: usize) {
+        if matching_len > 0 {
+            self.selected = (self.selected + 1).min(matching_len - 1);
+        }
+    }
+}
+
+fn filter_label(filter: Option<ActivityKind>) -> &'static str {
+    match filter {
+        None => "All",
+        Some(ActivityKind::Scan) => "Scan",
+        Some(ActivityKind::Fix) => "Fix",
+        Some(ActivityKind::Watch) => "Watch",
+    }
+}
+
+pub fn render_activity_history(frame: &mut Frame, state: &ActivityHistoryState, search: &str) {
+    let t = theme::theme();
+    let area = centered_rect(70, 60, frame.area());
+
+    frame.render_widget(Clear, area);
+
+    let block = Block::default()
+        .title(format!(
+            " Activity History — filter: {} (Tab)  search: {search}_ ",
+            filter_label(state.filter)
+        ))
+        .title_style(theme::title_style())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(t.accent));
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let entries = state.matching(search);
+    if entries.is_empty() {
+        frame.render_widget(
+            ratatui::widgets::Paragraph::new(Line::from(Span::styled(
+                " No matching activity.",
+                Style::default().fg(t.muted),
+            ))),
+            inner,
+        );
+        return;
+    }
+
+    let items: Vec<ListItem<'_>> = entries
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let selected = i == state.selected;
+            let color = match entry.kind {
+                ActivityKind::Scan => t.zone_green,
+                ActivityKind::Fix | ActivityKind::Watch => t.zone_yellow,
+            };
+            let style = if selected {
+                Style::default().fg(color).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(t.fg)
+            };
+            let prefix = if selected { "> " } else { "  " };
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{prefix}[{}] ", entry.timestamp),
+                    Style::default().fg(t.muted),
+                ),
+                Span::styled(format!("{} ", entry.kind.icon()), Style::default().fg(color)),
+                Span::styled(entry.detail.clone(), style),
+            ]))
+        })
+        .collect();
+
+    frame.render_widget(List::new(items), inner);
+}
+
+fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let v = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(r);
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(v[1])[1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(kind: ActivityKind, detail: &str) -> ActivityEntry {
+        ActivityEntry {
+            timestamp: "12:00".to_string(),
+            kind,
+            detail: detail.to_string(),
+        }
+    }
+
+    #[test]
+    fn cycle_filter_covers_all_kinds_and_wraps() {
+        let mut state = ActivityHistoryState::new();
+        assert_eq!(state.filter, None);
+        state.cycle_filter();
+        assert_eq!(state.filter, Some(ActivityKind::Scan));
+        state.cycle_filter();
+        assert_eq!(state.filter, Some(ActivityKind::Fix));
+        state.cycle_filter();
+        assert_eq!(state.filter, Some(ActivityKind::Watch));
+        state.cycle_filter();
+        assert_eq!(state.filter, None);
+    }
+
+    #[test]
+    fn matching_filters_by_kind_and_search() {
+        let mut state = ActivityHistoryState::new();
+        state.entries.push(entry(ActivityKind::Scan, "scanned project"));
+        state.entries.push(entry(ActivityKind::Fix, "applied fix"));
+
+        assert_eq!(state.matching("").len(), 2);
+        assert_eq!(state.matching("fix").len(), 1);
+
+        state.filter = Some(ActivityKind::Scan);
+        assert_eq!(state.matching("").len(), 1);
+        assert_eq!(state.matching("fix").len(), 0);
+    }
+
+    #[test]
+    fn navigate_down_clamps_to_matching_length() {
+        let mut state = ActivityHistoryState::new();
+        state.entries.push(entry(ActivityKind::Scan, "a"));
+        let len = state.matching("").len();
+
+        state.navigate_down(len);
+        assert_eq!(state.selected, 0);
+    }
+}
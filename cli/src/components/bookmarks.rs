@@ -0,0 +1,152 @@
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem};
+
+use crate::theme;
+use crate::types::Bookmark;
+
+pub struct BookmarksState {
+    pub entries: Vec<Bookmark>,
+    pub selected: usize,
+}
+
+impl BookmarksState {
+    pub const fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            selected: 0,
+        }
+    }
+
+    pub const fn navigate_up(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn navigate_down(&mut self) {
+        if !self.entries.is_empty() {
+            self.selected = (self.selected + 1).min(self.entries.len() - 1);
+        }
+    }
+
+    pub fn selected_bookmark(&self) -> Option<&Bookmark> {
+        self.entries.get(self.selected)
+    }
+
+    /// Remove the selected entry, clamping the cursor to the new length.
+    pub fn remove_selected(&mut self) {
+        if self.selected < self.entries.len() {
+            self.entries.remove(self.selected);
+            self.selected = self.selected.min(self.entries.len().saturating_sub(1));
+        }
+    }
+}
+
+pub fn render_bookmarks(frame: &mut Frame, state: &BookmarksState) {
+    let t = theme::theme();
+    let area = centered_rect(60, 50, frame.area());
+
+    frame.render_widget(Clear, area);
+
+    let block = Block::default()
+        .title(" Bookmarks ")
+        .title_style(theme::title_style())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(t.accent));
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    if state.entries.is_empty() {
+        frame.render_widget(
+            ratatui::widgets::Paragraph::new(Line::from(Span::styled(
+                " No bookmarks yet. Press M on a finding or open file to mark it.",
+                Style::default().fg(t.muted),
+            ))),
+            inner,
+        );
+        return;
+    }
+
+    let items: Vec<ListItem<'_>> = state
+        .entries
+        .iter()
+        .enumerate()
+        .map(|(i, bookmark)| {
+            let selected = i == state.selected;
+            let style = if selected {
+                Style::default().fg(t.accent).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(t.fg)
+            };
+            let marker = if selected { "> " } else { "  " };
+            ListItem::new(Line::from(Span::styled(
+                format!("{marker}{}", bookmark.label()),
+                style,
+            )))
+        })
+        .collect();
+
+    frame.render_widget(List::new(items), inner);
+}
+
+fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let v = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(r);
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(v[1])[1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bookmarks_nav() {
+        let mut state = BookmarksState::new();
+        state.entries.push(Bookmark::File {
+            path: "a.rs".to_string(),
+        });
+        state.entries.push(Bookmark::File {
+            path: "b.rs".to_string(),
+        });
+
+        state.navigate_down();
+        assert_eq!(state.selected, 1);
+        state.navigate_down();
+        assert_eq!(state.selected, 1);
+        state.navigate_up();
+        assert_eq!(state.selected, 0);
+        state.navigate_up();
+        assert_eq!(state.selected, 0);
+    }
+
+    #[test]
+    fn remove_selected_clamps_cursor() {
+        let mut state = BookmarksState::new();
+        state.entries.push(Bookmark::File {
+            path: "a.rs".to_string(),
+        });
+        state.entries.push(Bookmark::File {
+            path: "b.rs".to_string(),
+        });
+        state.selected = 1;
+
+        state.remove_selected();
+        assert_eq!(state.entries.len(), 1);
+        assert_eq!(state.selected, 0);
+    }
+}
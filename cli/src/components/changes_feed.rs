@@ -0,0 +1,219 @@
+use std::path::PathBuf;
+
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Row, Table};
+
+use crate::theme;
+use crate::watcher::ChangeKind;
+
+const MAX_ENTRIES: usize = 50;
+
+#[derive(Debug, Clone)]
+pub struct ChangeEntry {
+    pub path: PathBuf,
+    pub kind: ChangeKind,
+    pub timestamp: String,
+}
+
+pub struct ChangesFeedState {
+    pub entries: Vec<ChangeEntry>,
+    pub selected: usize,
+}
+
+impl ChangesFeedState {
+    pub const fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            selected: 0,
+        }
+    }
+
+    /// Record a batch of watcher events, newest first, capped at `MAX_ENTRIES`.
+    pub fn push_batch(&mut self, batch: &[(PathBuf, ChangeKind)], timestamp: &str) {
+        for (path, kind) in batch {
+            self.entries.insert(
+                0,
+                ChangeEntry {
+                    path: path.clone(),
+                    kind: *kind,
+                    timestamp: timestamp.to_string(),
+                },
+            );
+        }
+        self.entries.truncate(MAX_ENTRIES);
+    }
+
+    pub const fn navigate_up(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn navigate_down(&mut self) {
+        if !self.entries.is_empty() {
+            self.selected = (self.selected + 1).min(self.entries.len() - 1);
+        }
+    }
+
+    pub fn selected_path(&self) -> Option<&PathBuf> {
+        self.entries.get(self.selected).map(|e| &e.path)
+    }
+
+    /// Parent directory of the selected entry, for the "ignore directory" action.
+    pub fn selected_dir(&self) -> Option<PathBuf> {
+        self.selected_path()
+            .and_then(|p| p.parent())
+            .map(std::path::Path::to_path_buf)
+    }
+}
+
+impl Default for ChangesFeedState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub fn render_changes_feed(frame: &mut Frame, state: &ChangesFeedState) {
+    let t = theme::theme();
+    let area = centered_rect(70, 60, frame.area());
+
+    frame.render_widget(Clear, area);
+
+    let block = Block::default()
+        .title(" Changes (Enter: rescan · o: open · i: ignore dir · Esc: close) ")
+        .title_style(theme::title_style())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(t.accent));
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    if state.entries.is_empty() {
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                " No file changes observed yet.",
+                Style::default().fg(t.muted),
+            ))),
+            inner,
+        );
+        return;
+    }
+
+    let rows: Vec<Row<'_>> = state
+        .entries
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let selected = i == state.selected;
+            let style = if selected {
+                Style::default().fg(t.accent).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(t.fg)
+            };
+            let marker = if selected { "> " } else { "  " };
+
+            Row::new(vec![
+                format!("{marker}{}", entry.timestamp),
+                entry.kind.label().to_string(),
+                entry.path.display().to_string(),
+            ])
+            .style(style)
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Length(14),
+        Constraint::Length(10),
+        Constraint::Min(20),
+    ];
+
+    let header = Row::new(vec!["  Time", "Kind", "Path"])
+        .style(Style::default().fg(t.muted).add_modifier(Modifier::BOLD));
+
+    let table = Table::new(rows, widths).header(header);
+    frame.render_widget(table, inner);
+}
+
+fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let v = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(r);
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(v[1])[1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn batch(paths: &[&str]) -> Vec<(PathBuf, ChangeKind)> {
+        paths
+            .iter()
+            .map(|p| (PathBuf::from(p), ChangeKind::Modified))
+            .collect()
+    }
+
+    #[test]
+    fn changes_feed_push_batch_prepends_newest_first() {
+        let mut state = ChangesFeedState::new();
+        state.push_batch(&batch(&["a.rs"]), "12:00");
+        state.push_batch(&batch(&["b.rs"]), "12:01");
+        assert_eq!(state.entries[0].path, PathBuf::from("b.rs"));
+        assert_eq!(state.entries[1].path, PathBuf::from("a.rs"));
+    }
+
+    #[test]
+    fn changes_feed_caps_at_max_entries() {
+        let mut state = ChangesFeedState::new();
+        for i in 0..(MAX_ENTRIES + 10) {
+            state.push_batch(&batch(&[&format!("f{i}.rs")]), "12:00");
+        }
+        assert_eq!(state.entries.len(), MAX_ENTRIES);
+    }
+
+    #[test]
+    fn changes_feed_nav_clamps() {
+        let mut state = ChangesFeedState::new();
+        state.push_batch(&batch(&["a.rs", "b.rs"]), "12:00");
+        state.selected = 0;
+        state.navigate_down();
+        assert_eq!(state.selected, 1);
+        state.navigate_down(); // clamp
+        assert_eq!(state.selected, 1);
+        state.navigate_up();
+        assert_eq!(state.selected, 0);
+        state.navigate_up(); // clamp
+        assert_eq!(state.selected, 0);
+    }
+
+    #[test]
+    fn changes_feed_selected_dir() {
+        let mut state = ChangesFeedState::new();
+        state.push_batch(&batch(&["src/watcher.rs"]), "12:00");
+        assert_eq!(state.selected_dir(), Some(PathBuf::from("src")));
+    }
+
+    #[test]
+    fn e2e_changes_feed_render() {
+        crate::theme::init_theme("dark");
+        let backend = ratatui::backend::TestBackend::new(120, 40);
+        let mut terminal = ratatui::Terminal::new(backend).expect("terminal");
+
+        let state = ChangesFeedState::new();
+        terminal
+            .draw(|frame| render_changes_feed(frame, &state))
+            .expect("render empty changes feed");
+    }
+}
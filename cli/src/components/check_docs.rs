@@ -0,0 +1,143 @@
+//! State for the Check Docs overlay (`?` on a finding in the Scan view):
+//! what the check verifies, why it matters, the relevant article,
+//! remediation guidance, and links -- sourced from the bundled docs
+//! bundle (`views::scan::explain`) and, when the engine sent one, its
+//! per-finding `explanation` metadata.
+
+use crate::types::Finding;
+
+/// Content shown in the Check Docs overlay for one finding.
+#[derive(Debug, Clone)]
+pub struct CheckDocsState {
+    pub check_id: String,
+    pub what_it_checks: String,
+    pub why_it_matters: String,
+    pub article: String,
+    pub penalty: String,
+    pub deadline: String,
+    pub remediation: String,
+    pub links: Vec<String>,
+    pub scroll: usize,
+}
+
+impl CheckDocsState {
+    pub fn from_finding(finding: &Finding) -> Self {
+        let (what_it_checks, action, file_hint) =
+            crate::views::scan::explain_check(&finding.check_id);
+
+        let article = finding
+            .article_reference
+            .clone()
+            .or_else(|| finding.explanation.as_ref().map(|e| e.article.clone()))
+            .filter(|a| !a.is_empty())
+            .unwrap_or_else(|| "See EU AI Act".to_string());
+
+        // Prefer engine-provided metadata when it sent a real explanation;
+        // fall back to the bundled penalty/deadline lookup + a generic
+        // business-impact line otherwise.
+        let (penalty, deadline, why_it_matters) = match &finding.explanation {
+            Some(exp) if !exp.business_impact.is_empty() => (
+                exp.penalty.clone(),
+                exp.deadline.clone(),
+                exp.business_impact.clone(),
+            ),
+            _ => (
+                crate::views::scan::penalty_for_article(&article).to_string(),
+                crate::views::scan::deadline_for_article(&article),
+                format!(
+                    "Non-compliance with {article} exposes your organization to regulatory \
+                     penalties and blocks EU AI Act certification."
+                ),
+            ),
+        };
+
+        let mut links = vec![format!("File to create/edit: {file_hint}")];
+        if let Some(obligation_id) = &finding.obligation_id {
+            links.push(format!("Obligation: {obligation_id}"));
+        }
+
+        Self {
+            check_id: finding.check_id.clone(),
+            what_it_checks: what_it_checks.to_string(),
+            why_it_matters,
+            article,
+            penalty,
+            deadline,
+            remediation: finding.fix.clone().unwrap_or_else(|| action.to_string()),
+            links,
+            scroll: 0,
+        }
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.scroll = self.scroll.saturating_sub(1);
+    }
+
+    pub fn scroll_down(&mut self) {
+        self.scroll = self.scroll.saturating_add(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{CheckResultType, FindingExplanation, Severity};
+
+    fn base_finding() -> Finding {
+        Finding {
+            check_id: "declaration-conformity".to_string(),
+            r#type: CheckResultType::Fail,
+            message: "Missing declaration".to_string(),
+            severity: Severity::High,
+            obligation_id: Some("OBL-047".to_string()),
+            article_reference: Some("Art. 47".to_string()),
+            fix: None,
+            file: None,
+            line: None,
+            code_context: None,
+            fix_diff: None,
+            priority: None,
+            confidence: None,
+            confidence_level: None,
+            evidence: None,
+            explanation: None,
+            agent_id: None,
+            doc_quality: None,
+        }
+    }
+
+    #[test]
+    fn falls_back_to_bundled_docs_when_engine_sends_no_explanation() {
+        let finding = base_finding();
+        let docs = CheckDocsState::from_finding(&finding);
+        assert_eq!(docs.check_id, "declaration-conformity");
+        assert_eq!(docs.article, "Art. 47");
+        assert!(docs.what_it_checks.contains("Declaration of Conformity"));
+        assert!(docs.links.iter().any(|l| l.contains("OBL-047")));
+    }
+
+    #[test]
+    fn prefers_engine_provided_explanation_when_present() {
+        let mut finding = base_finding();
+        finding.explanation = Some(FindingExplanation {
+            article: "Art. 47".to_string(),
+            penalty: "Custom penalty".to_string(),
+            deadline: "Custom deadline".to_string(),
+            business_impact: "Custom business impact".to_string(),
+        });
+        let docs = CheckDocsState::from_finding(&finding);
+        assert_eq!(docs.penalty, "Custom penalty");
+        assert_eq!(docs.deadline, "Custom deadline");
+        assert_eq!(docs.why_it_matters, "Custom business impact");
+    }
+
+    #[test]
+    fn scroll_up_saturates_at_zero() {
+        let mut docs = CheckDocsState::from_finding(&base_finding());
+        docs.scroll_up();
+        assert_eq!(docs.scroll, 0);
+        docs.scroll_down();
+        docs.scroll_up();
+        assert_eq!(docs.scroll, 0);
+    }
+}
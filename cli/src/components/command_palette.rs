@@ -24,8 +24,56 @@ const COMMANDS: &[(&str, &str)] = &[
     ("/load", "Load saved session"),
     ("/sessions", "List saved sessions"),
     ("/watch", "Toggle file watch mode"),
+    (
+        "/doctor",
+        "Run system health checks (engine, Node.js, terminal, config, providers)",
+    ),
+    ("/tour", "Guided tour of the dashboard, spotlighting one widget per step"),
+    (
+        "/keys",
+        "Browse keybindings (search as you type), or `export [md|html] [path]` a cheat sheet",
+    ),
     ("/undo", "Undo last fix"),
     ("/animations", "Toggle animations on/off"),
+    (
+        "/telemetry",
+        "Opt-in anonymous usage telemetry: on/off/show",
+    ),
+    (
+        "/fix-recovery",
+        "Resolve an interrupted fix batch: forward/back/discard",
+    ),
+    (
+        "/settings",
+        "Open runtime preferences (animations, auto-scroll, sidebar, tick rate, toast duration)",
+    ),
+    ("/projects", "Switch between registered projects"),
+    ("/config", "Show effective config and its sources"),
+    (
+        "/env",
+        "Session-scoped env vars for /run and !cmd: set KEY=VALUE/unset/list",
+    ),
+    ("/init", "Re-run project setup wizard"),
+    ("/stats", "Show per-day scans, fixes, score, and cost"),
+    (
+        "/risk-classify",
+        "Run the Annex III / GPAI risk classification questionnaire",
+    ),
+    ("/assign", "Assign the selected finding to a person"),
+    ("/assignee", "Filter the Scan view by assignee"),
+    (
+        "/triage",
+        "Set the selected finding's status (open/in-progress/remediated/accepted-risk)",
+    ),
+    ("/due", "Set the selected finding's due date (YYYY-MM-DD)"),
+    (
+        "/snooze-until",
+        "Hide the selected finding until a date (YYYY-MM-DD), then auto-resurface it",
+    ),
+    (
+        "/snooze",
+        "Snooze idle suggestions of a kind (or the current one) for N days",
+    ),
 ];
 
 /// Colon commands — used for tab completion in colon mode.
@@ -39,11 +87,28 @@ pub const COLON_COMMANDS: &[&str] = &[
     "theme",
     "export",
     "watch",
+    "doctor",
+    "tour",
+    "keys",
     "quit",
     "help",
     "undo",
     "view",
     "animations",
+    "telemetry",
+    "fix-recovery",
+    "settings",
+    "projects",
+    "config",
+    "env",
+    "stats",
+    "risk-classify",
+    "assign",
+    "assignee",
+    "triage",
+    "due",
+    "snooze-until",
+    "snooze",
 ];
 
 /// Complete a partial colon-mode command against known commands.
@@ -55,31 +120,111 @@ pub fn complete_colon_command(partial: &str) -> Option<&'static str> {
         .copied()
 }
 
-/// Return filtered commands matching the filter string.
-fn filtered_commands(filter: &str) -> Vec<(&'static str, &'static str)> {
-    let filter_lower = filter.to_lowercase();
-    COMMANDS
-        .iter()
-        .filter(|(cmd, desc)| {
-            filter_lower.is_empty()
-                || cmd.to_lowercase().contains(&filter_lower)
-                || desc.to_lowercase().contains(&filter_lower)
+/// How many entries `recent` contributes to a "recently used" section
+/// (subject to still matching the filter, since a stale recent command can
+/// scroll off once the underlying list shrinks).
+const RECENT_SECTION_CAP: usize = 5;
+
+/// Score a fuzzy subsequence match of `pattern` against `candidate`
+/// (case-insensitive): every char of `pattern` must appear in `candidate`
+/// in order, though not necessarily contiguously. Returns `None` on no
+/// match. Higher is better -- matches anchored at the start and
+/// contiguous runs score higher, so "sc" ranks "/scan" above "/reconnect".
+fn fuzzy_score(candidate: &str, pattern: &str) -> Option<i32> {
+    if pattern.is_empty() {
+        return Some(0);
+    }
+    let pattern_lower = pattern.to_lowercase();
+    let mut pattern_chars = pattern_lower.chars().peekable();
+    let mut score = 0i32;
+    let mut last_match: Option<usize> = None;
+    for (i, c) in candidate.to_lowercase().chars().enumerate() {
+        if pattern_chars.peek() == Some(&c) {
+            score += if i == 0 { 10 } else { 1 };
+            if last_match == Some(i.wrapping_sub(1)) {
+                score += 5;
+            }
+            last_match = Some(i);
+            pattern_chars.next();
+        }
+    }
+    if pattern_chars.peek().is_none() {
+        Some(score)
+    } else {
+        None
+    }
+}
+
+/// Rank commands for the palette: `extra` (context-sensitive entries for
+/// the current view, e.g. "Apply selected fixes" while in Fix view) plus
+/// the static [`COMMANDS`] list, fuzzy-matched and scored against `filter`.
+/// With an empty filter, `recent` (most-recently-executed first) is
+/// surfaced ahead of the rest instead of scoring.
+pub fn ranked_commands(
+    filter: &str,
+    extra: &[(&'static str, &'static str)],
+    recent: &[String],
+) -> Vec<(&'static str, &'static str)> {
+    let mut all: Vec<(&'static str, &'static str)> =
+        extra.iter().chain(COMMANDS.iter()).copied().collect();
+    all.dedup_by_key(|(cmd, _)| *cmd);
+
+    if filter.is_empty() {
+        let mut ranked: Vec<(&'static str, &'static str)> = recent
+            .iter()
+            .take(RECENT_SECTION_CAP)
+            .filter_map(|name| all.iter().find(|(cmd, _)| cmd == name).copied())
+            .collect();
+        for entry in &all {
+            if !ranked.contains(entry) {
+                ranked.push(*entry);
+            }
+        }
+        return ranked;
+    }
+
+    let mut scored: Vec<(i32, (&'static str, &'static str))> = all
+        .into_iter()
+        .filter_map(|(cmd, desc)| {
+            let score = fuzzy_score(&cmd[1..], filter)
+                .into_iter()
+                .chain(fuzzy_score(desc, filter).map(|s| s / 2))
+                .max()?;
+            Some((score, (cmd, desc)))
         })
-        .copied()
-        .collect()
+        .collect();
+    scored.sort_by(|a, b| b.0.cmp(&a.0));
+    scored.into_iter().map(|(_, entry)| entry).collect()
 }
 
 /// Count of commands matching the current filter.
-pub fn filtered_count(filter: &str) -> usize {
-    filtered_commands(filter).len()
+pub fn filtered_count(
+    filter: &str,
+    extra: &[(&'static str, &'static str)],
+    recent: &[String],
+) -> usize {
+    ranked_commands(filter, extra, recent).len()
 }
 
-/// Get command at index from filtered list.
-pub fn filtered_command(filter: &str, index: usize) -> Option<&'static str> {
-    filtered_commands(filter).get(index).map(|(cmd, _)| *cmd)
+/// Get command at index from the ranked list.
+pub fn filtered_command(
+    filter: &str,
+    extra: &[(&'static str, &'static str)],
+    recent: &[String],
+    index: usize,
+) -> Option<&'static str> {
+    ranked_commands(filter, extra, recent)
+        .get(index)
+        .map(|(cmd, _)| *cmd)
 }
 
-pub fn render_command_palette(frame: &mut Frame, filter: &str, selected: usize) {
+pub fn render_command_palette(
+    frame: &mut Frame,
+    filter: &str,
+    selected: usize,
+    extra: &[(&'static str, &'static str)],
+    recent: &[String],
+) {
     let area = frame.area();
     let popup = centered_rect(50, 40, area);
 
@@ -113,32 +258,52 @@ pub fn render_command_palette(frame: &mut Frame, filter: &str, selected: usize)
     );
     frame.render_widget(input, chunks[0]);
 
-    // Filtered command list with cursor highlight
-    let matches = filtered_commands(filter);
-    let items: Vec<ListItem<'_>> = matches
-        .iter()
-        .enumerate()
-        .map(|(i, (cmd, desc))| {
-            let (cmd_style, desc_style) = if i == selected {
-                (
-                    Style::default()
-                        .fg(t.bg)
-                        .bg(t.accent)
-                        .add_modifier(Modifier::BOLD),
-                    Style::default().fg(t.bg).bg(t.accent),
-                )
-            } else {
-                (
-                    Style::default().fg(t.accent).add_modifier(Modifier::BOLD),
+    // Ranked command list with cursor highlight. Section headers (shown
+    // only on an empty filter, when a "recently used" group exists) are
+    // extra, non-selectable list rows -- `real_idx` (not the row index)
+    // tracks position against `selected` so highlighting stays aligned
+    // with `filtered_command`'s indexing.
+    let matches = ranked_commands(filter, extra, recent);
+    let show_sections = filter.is_empty() && !recent.is_empty();
+    let mut items: Vec<ListItem<'_>> = Vec::new();
+    let mut real_idx = 0usize;
+    let mut past_recent_section = false;
+    for (cmd, desc) in &matches {
+        let is_recent = recent.iter().take(RECENT_SECTION_CAP).any(|r| r == cmd);
+        if show_sections {
+            if is_recent && real_idx == 0 {
+                items.push(ListItem::new(Line::from(Span::styled(
+                    "-- Recent --",
                     Style::default().fg(t.muted),
-                )
-            };
-            ListItem::new(Line::from(vec![
-                Span::styled(format!("{cmd:<14}"), cmd_style),
-                Span::styled(*desc, desc_style),
-            ]))
-        })
-        .collect();
+                ))));
+            } else if !is_recent && !past_recent_section {
+                items.push(ListItem::new(Line::from(Span::styled(
+                    "-- All commands --",
+                    Style::default().fg(t.muted),
+                ))));
+                past_recent_section = true;
+            }
+        }
+        let (cmd_style, desc_style) = if real_idx == selected {
+            (
+                Style::default()
+                    .fg(t.bg)
+                    .bg(t.accent)
+                    .add_modifier(Modifier::BOLD),
+                Style::default().fg(t.bg).bg(t.accent),
+            )
+        } else {
+            (
+                Style::default().fg(t.accent).add_modifier(Modifier::BOLD),
+                Style::default().fg(t.muted),
+            )
+        };
+        items.push(ListItem::new(Line::from(vec![
+            Span::styled(format!("{cmd:<14}"), cmd_style),
+            Span::styled(*desc, desc_style),
+        ])));
+        real_idx += 1;
+    }
 
     let list = List::new(items);
     frame.render_widget(list, chunks[1]);
@@ -183,4 +348,37 @@ mod tests {
         assert_eq!(complete_command("th"), Some("/theme"));
         assert_eq!(complete_command("xyz"), None);
     }
+
+    #[test]
+    fn test_fuzzy_score_matches_non_contiguous_subsequence() {
+        // "scn" is a subsequence of "scan" but not of "status".
+        assert!(fuzzy_score("scan", "scn").is_some());
+        assert!(fuzzy_score("status", "scn").is_none());
+    }
+
+    #[test]
+    fn test_fuzzy_score_ranks_contiguous_prefix_above_scattered_match() {
+        let scan_score = fuzzy_score("scan", "sc").unwrap();
+        let reconnect_score = fuzzy_score("reconnect", "sc").unwrap();
+        assert!(scan_score > reconnect_score);
+    }
+
+    #[test]
+    fn test_ranked_commands_empty_filter_surfaces_recent_first() {
+        let recent = vec!["/theme".to_string()];
+        let ranked = ranked_commands("", &[], &recent);
+        assert_eq!(ranked.first().map(|(cmd, _)| *cmd), Some("/theme"));
+    }
+
+    #[test]
+    fn test_ranked_commands_includes_contextual_entries() {
+        let extra = [("/apply", "Apply selected fixes")];
+        let ranked = ranked_commands("appl", &extra, &[]);
+        assert_eq!(ranked.first().map(|(cmd, _)| *cmd), Some("/apply"));
+    }
+
+    #[test]
+    fn test_filtered_command_no_match_returns_none() {
+        assert_eq!(filtered_command("zzz", &[], &[], 0), None);
+    }
 }
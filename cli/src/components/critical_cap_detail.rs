@@ -0,0 +1,189 @@
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, Paragraph, Wrap};
+
+use crate::theme;
+use crate::types::Finding;
+
+/// Snapshot of the critical findings that triggered `critical_cap_applied`,
+/// taken when the overlay opens (`c` in Dashboard) — same snapshot-on-open
+/// shape as `BookmarksState`/`ActivityHistoryState`.
+pub struct CriticalCapDetailState {
+    pub findings: Vec<Finding>,
+    /// Category-weighted average score, ignoring the critical cap — an
+    /// estimate computed client-side from `category_scores`, since the
+    /// engine doesn't report a separate pre-cap total.
+    pub uncapped_estimate: f64,
+    pub selected: usize,
+}
+
+impl CriticalCapDetailState {
+    pub const fn new() -> Self {
+        Self {
+            findings: Vec::new(),
+            uncapped_estimate: 0.0,
+            selected: 0,
+        }
+    }
+
+    pub fn navigate_up(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn navigate_down(&mut self) {
+        if !self.findings.is_empty() {
+            self.selected = (self.selected + 1).min(self.findings.len() - 1);
+        }
+    }
+}
+
+pub fn render_critical_cap_detail(frame: &mut Frame, state: &CriticalCapDetailState) {
+    let t = theme::theme();
+    let area = centered_rect(72, 65, frame.area());
+
+    frame.render_widget(Clear, area);
+
+    let block = Block::default()
+        .title(" Critical Cap — Drill-Down ")
+        .title_style(theme::title_style())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(t.zone_red));
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(0)])
+        .split(inner);
+
+    let summary = Paragraph::new(vec![
+        Line::from(Span::styled(
+            " Score capped at 40 — one or more unresolved critical findings.",
+            Style::default().fg(t.zone_red),
+        )),
+        Line::from(vec![
+            Span::styled(" Without the cap (est.): ", Style::default().fg(t.muted)),
+            Span::styled(
+                format!("{:.0}/100", state.uncapped_estimate),
+                Style::default()
+                    .fg(t.accent)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+    ])
+    .wrap(Wrap { trim: false });
+    frame.render_widget(summary, chunks[0]);
+
+    if state.findings.is_empty() {
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                " No critical findings in the last scan snapshot.",
+                Style::default().fg(t.muted),
+            ))),
+            chunks[1],
+        );
+        return;
+    }
+
+    let items: Vec<ListItem<'_>> = state
+        .findings
+        .iter()
+        .enumerate()
+        .map(|(i, f)| {
+            let selected = i == state.selected;
+            let style = if selected {
+                Style::default().fg(t.zone_red).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(t.fg)
+            };
+            let prefix = if selected { "> " } else { "  " };
+            let mut spans = vec![
+                Span::styled(prefix, Style::default().fg(t.zone_red)),
+                Span::styled(f.message.clone(), style),
+            ];
+            if let Some(loc) = f.file_line_label() {
+                spans.push(Span::styled(format!(" ({loc})"), Style::default().fg(t.muted)));
+            }
+            let mut lines = vec![Line::from(spans)];
+            if let Some(fix) = &f.fix {
+                lines.push(Line::from(Span::styled(
+                    format!("    Fix: {fix}"),
+                    Style::default().fg(t.zone_green),
+                )));
+            }
+            ListItem::new(lines)
+        })
+        .collect();
+
+    frame.render_widget(List::new(items), chunks[1]);
+}
+
+fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let v = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(r);
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(v[1])[1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{CheckResultType, Severity};
+
+    fn finding(message: &str, severity: Severity) -> Finding {
+        Finding {
+            check_id: "chk".to_string(),
+            r#type: CheckResultType::Fail,
+            message: message.to_string(),
+            severity,
+            obligation_id: None,
+            article_reference: None,
+            fix: None,
+            file: None,
+            line: None,
+            code_context: None,
+            fix_diff: None,
+            priority: None,
+            confidence: None,
+            confidence_level: None,
+            evidence: None,
+            explanation: None,
+            agent_id: None,
+            doc_quality: None,
+            l5_analyzed: None,
+        }
+    }
+
+    #[test]
+    fn navigate_down_clamps_to_findings_length() {
+        let mut state = CriticalCapDetailState::new();
+        state.findings.push(finding("a", Severity::Critical));
+
+        state.navigate_down();
+        assert_eq!(state.selected, 0);
+        state.navigate_down();
+        assert_eq!(state.selected, 0);
+    }
+
+    #[test]
+    fn navigate_up_saturates_at_zero() {
+        let mut state = CriticalCapDetailState::new();
+        state.navigate_up();
+        assert_eq!(state.selected, 0);
+    }
+}
@@ -0,0 +1,174 @@
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+
+use crate::theme;
+use crate::types::FixDiff;
+use crate::views::scan::render_fix_diff;
+
+/// The three choices offered when the watcher reports that the file open in
+/// the code viewer changed on disk while it was loaded.
+const OPTIONS: [&str; 3] = ["Reload from disk", "Keep open version", "Show diff"];
+
+/// Shown when a file-watch event touches `App::open_file_path` and the disk
+/// content no longer matches the loaded `CodeBuffer` — offers reload, keep,
+/// or an inline diff instead of silently leaving the viewer stale.
+#[derive(Debug, Clone)]
+pub struct FileReloadPrompt {
+    pub path: String,
+    pub disk_content: String,
+    pub buffer_content: String,
+    pub cursor: usize,
+    /// `true` once "Show diff" has been picked — renders the diff instead of
+    /// the option list until the prompt is dismissed.
+    pub showing_diff: bool,
+}
+
+impl FileReloadPrompt {
+    pub const fn new(path: String, disk_content: String, buffer_content: String) -> Self {
+        Self {
+            path,
+            disk_content,
+            buffer_content,
+            cursor: 0,
+            showing_diff: false,
+        }
+    }
+
+    pub const fn move_up(&mut self) {
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+
+    pub fn move_down(&mut self) {
+        if self.cursor + 1 < OPTIONS.len() {
+            self.cursor += 1;
+        }
+    }
+
+    pub fn diff(&self) -> FixDiff {
+        FixDiff {
+            before: self.buffer_content.lines().map(str::to_string).collect(),
+            after: self.disk_content.lines().map(str::to_string).collect(),
+            start_line: 1,
+            file_path: self.path.clone(),
+            import_line: None,
+        }
+    }
+}
+
+pub fn render_file_reload_prompt(frame: &mut Frame, prompt: &FileReloadPrompt) {
+    let t = theme::theme();
+    let area = centered_rect(70, 60, frame.area());
+
+    frame.render_widget(Clear, area);
+
+    let block = Block::default()
+        .title(format!(" Changed on disk \u{2014} {} ", prompt.path))
+        .title_style(
+            Style::default()
+                .fg(t.zone_yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(t.zone_yellow))
+        .style(Style::default().bg(t.bg));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    if prompt.showing_diff {
+        let chunks = Layout::vertical([Constraint::Min(4), Constraint::Length(1)]).split(inner);
+        let mut lines: Vec<Line<'_>> = vec![Line::raw("")];
+        render_fix_diff(&mut lines, &prompt.diff(), &t);
+        frame.render_widget(
+            Paragraph::new(lines).wrap(Wrap { trim: false }),
+            chunks[0],
+        );
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                "  [Esc] Back",
+                Style::default().fg(t.muted),
+            ))),
+            chunks[1],
+        );
+        return;
+    }
+
+    let chunks = Layout::vertical([Constraint::Length(2), Constraint::Min(3)]).split(inner);
+
+    frame.render_widget(
+        Paragraph::new(Line::from(Span::styled(
+            "  This file changed on disk while it was open here.",
+            Style::default().fg(t.fg),
+        )))
+        .wrap(Wrap { trim: false }),
+        chunks[0],
+    );
+
+    let items: Vec<Line<'_>> = OPTIONS
+        .iter()
+        .enumerate()
+        .map(|(i, label)| {
+            let selected = i == prompt.cursor;
+            let style = if selected {
+                Style::default().fg(t.accent).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(t.fg)
+            };
+            let marker = if selected { "> " } else { "  " };
+            Line::from(Span::styled(format!("{marker}{label}"), style))
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(items), chunks[1]);
+}
+
+fn centered_rect(width_pct: u16, height_pct: u16, r: Rect) -> Rect {
+    let v = Layout::vertical([
+        Constraint::Percentage((100 - height_pct) / 2),
+        Constraint::Percentage(height_pct),
+        Constraint::Percentage((100 - height_pct) / 2),
+    ])
+    .split(r);
+    Layout::horizontal([
+        Constraint::Percentage((100 - width_pct) / 2),
+        Constraint::Percentage(width_pct),
+        Constraint::Percentage((100 - width_pct) / 2),
+    ])
+    .split(v[1])[1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_reload_prompt_navigation() {
+        let mut prompt = FileReloadPrompt::new(
+            "src/lib.rs".to_string(),
+            "new".to_string(),
+            "old".to_string(),
+        );
+        assert_eq!(prompt.cursor, 0);
+        prompt.move_down();
+        assert_eq!(prompt.cursor, 1);
+        prompt.move_down();
+        assert_eq!(prompt.cursor, 2);
+        prompt.move_down(); // clamp
+        assert_eq!(prompt.cursor, 2);
+        prompt.move_up();
+        assert_eq!(prompt.cursor, 1);
+    }
+
+    #[test]
+    fn test_file_reload_prompt_diff() {
+        let prompt = FileReloadPrompt::new(
+            "src/lib.rs".to_string(),
+            "line1\nline2".to_string(),
+            "line1".to_string(),
+        );
+        let diff = prompt.diff();
+        assert_eq!(diff.before, vec!["line1".to_string()]);
+        assert_eq!(diff.after, vec!["line1".to_string(), "line2".to_string()]);
+    }
+}
@@ -0,0 +1,297 @@
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem};
+
+use crate::theme;
+
+/// One row of the effective keymap. `keys` and `action` mirror the wording
+/// used in the static Help overlay (`views/dashboard/overlays.rs`) so the
+/// two never drift out of sync in spirit, even though this table now is
+/// the single source of truth for `/keys export` and the browser overlay.
+///
+/// All keys are currently hardcoded — once keymaps are user-configurable
+/// this table is where overrides would be merged in before rendering.
+pub struct KeyBinding {
+    pub context: &'static str,
+    pub keys: &'static str,
+    pub action: &'static str,
+}
+
+pub const KEYBINDINGS: &[KeyBinding] = &[
+    KeyBinding { context: "General", keys: "Ctrl+C", action: "Quit" },
+    KeyBinding { context: "General", keys: "D/S/F/P/T/R/L", action: "Switch view" },
+    KeyBinding { context: "General", keys: "Tab", action: "Toggle mode (Scan/Fix/Watch)" },
+    KeyBinding { context: "General", keys: "w", action: "Toggle watch mode" },
+    KeyBinding { context: "General", keys: "Alt+1..5", action: "Jump to panel" },
+    KeyBinding { context: "General", keys: "i", action: "Insert mode" },
+    KeyBinding { context: "General", keys: "Esc", action: "Normal mode" },
+    KeyBinding { context: "General", keys: "/", action: "Command mode" },
+    KeyBinding { context: "Navigation", keys: "j/k", action: "Scroll up/down" },
+    KeyBinding { context: "Navigation", keys: "Ctrl+D/U", action: "Half-page down/up" },
+    KeyBinding { context: "Navigation", keys: "g/G", action: "Top/bottom" },
+    KeyBinding { context: "Navigation", keys: "Up/Down", action: "History (insert mode)" },
+    KeyBinding { context: "Navigation", keys: "Ctrl+O", action: "Jump back" },
+    KeyBinding { context: "Navigation", keys: "Shift+Tab", action: "Jump forward" },
+    KeyBinding { context: "Features", keys: "Ctrl+P", action: "Command palette" },
+    KeyBinding { context: "Features", keys: "Ctrl+B", action: "Toggle sidebar" },
+    KeyBinding { context: "Features", keys: "Ctrl+T", action: "Toggle terminal" },
+    KeyBinding { context: "Features", keys: "Ctrl+S", action: "Start scan" },
+    KeyBinding { context: "Features", keys: "@", action: "File picker" },
+    KeyBinding { context: "Features", keys: "@OBL-", action: "Obligation reference" },
+    KeyBinding { context: "Features", keys: "!cmd", action: "Run shell command" },
+    KeyBinding { context: "Features", keys: "V", action: "Visual select" },
+    KeyBinding { context: "Features", keys: "Ctrl+K", action: "Send selection to AI" },
+    KeyBinding { context: "Features", keys: "Ctrl+A", action: "Floating chat overlay" },
+    KeyBinding { context: "Features", keys: "M", action: "Bookmark finding/file" },
+    KeyBinding { context: "Features", keys: "'", action: "Bookmarks overlay" },
+    KeyBinding { context: "Features", keys: "N", action: "Notification center" },
+    KeyBinding { context: "Dashboard", keys: "e", action: "Zoom/expand widget" },
+    KeyBinding { context: "Dashboard", keys: "a", action: "Full activity history" },
+    KeyBinding { context: "Dashboard", keys: "c", action: "Critical cap drill-down (when capped)" },
+    KeyBinding { context: "Scan", keys: "a", action: "Show all findings" },
+    KeyBinding { context: "Scan", keys: "c/h/m/l", action: "Filter by severity" },
+    KeyBinding { context: "Scan", keys: "p", action: "Toggle show passed" },
+    KeyBinding { context: "Scan", keys: "z", action: "Toggle show snoozed" },
+    KeyBinding { context: "Scan", keys: "Enter", action: "Open/close detail" },
+    KeyBinding { context: "Scan", keys: "f", action: "Apply fix (inline)" },
+    KeyBinding { context: "Scan", keys: "x", action: "Explain finding" },
+    KeyBinding { context: "Scan", keys: "?", action: "Check docs" },
+    KeyBinding { context: "Scan", keys: "d", action: "Dismiss finding" },
+    KeyBinding { context: "Scan", keys: "o", action: "Open related file" },
+    KeyBinding { context: "Scan", keys: "v", action: "Toggle live code pane" },
+    KeyBinding { context: "Scan", keys: "n/N", action: "Next/prev finding (detail)" },
+    KeyBinding { context: "Scan", keys: "</>", action: "Resize split panel" },
+    KeyBinding { context: "Fix", keys: "Space", action: "Toggle current fix" },
+    KeyBinding { context: "Fix", keys: "a", action: "Select all fixes" },
+    KeyBinding { context: "Fix", keys: "n", action: "Deselect all" },
+    KeyBinding { context: "Fix", keys: "d", action: "Toggle diff preview" },
+    KeyBinding { context: "Fix", keys: "s", action: "Side-by-side diff" },
+    KeyBinding { context: "Fix", keys: "g", action: "Generate AI-customized template" },
+    KeyBinding { context: "Fix", keys: "Enter", action: "Apply selected fixes" },
+    KeyBinding { context: "Chat", keys: "Tab", action: "Autocomplete (@OBL-, /cmd)" },
+    KeyBinding { context: "Chat", keys: "@OBL-xxx", action: "Reference obligation" },
+    KeyBinding { context: "Chat", keys: "!cmd", action: "Run shell command" },
+    KeyBinding { context: "Chat", keys: "Enter", action: "Send message" },
+    KeyBinding { context: "Chat", keys: "Enter (normal)", action: "Inspect last tool result" },
+    KeyBinding { context: "Passport", keys: "e", action: "Edit selected field" },
+    KeyBinding { context: "Passport", keys: "o", action: "Toggle obligations" },
+    KeyBinding { context: "Passport", keys: "c", action: "Validate passport" },
+    KeyBinding { context: "Passport", keys: "f", action: "Generate FRIA" },
+    KeyBinding { context: "Passport", keys: "x", action: "Export passport" },
+    KeyBinding { context: "Obligations", keys: "f", action: "Cycle filter" },
+    KeyBinding { context: "Obligations", keys: "l", action: "Reload obligations" },
+    KeyBinding { context: "Report", keys: "e", action: "Export report" },
+    KeyBinding { context: "Report", keys: "c", action: "Compose report sections" },
+    KeyBinding { context: "Report", keys: "Space", action: "Toggle section (composer)" },
+    KeyBinding { context: "Report", keys: "J/K", action: "Reorder section (composer)" },
+];
+
+/// Searchable browser for `KEYBINDINGS` (`/keys`), replacing the static
+/// scroll-only Help overlay text with something filterable. Search text
+/// reuses `App::overlay_filter` (the same field `CommandPalette`/
+/// `ActivityHistory` use) rather than a dedicated field.
+pub struct KeybindingsState {
+    pub selected: usize,
+}
+
+impl KeybindingsState {
+    pub const fn new() -> Self {
+        Self { selected: 0 }
+    }
+
+    /// Bindings matching `search` (case-insensitive, matched against
+    /// context, keys, and action).
+    pub fn matching(search: &str) -> Vec<&'static KeyBinding> {
+        let search = search.to_lowercase();
+        KEYBINDINGS
+            .iter()
+            .filter(|b| {
+                search.is_empty()
+                    || b.context.to_lowercase().contains(&search)
+                    || b.keys.to_lowercase().contains(&search)
+                    || b.action.to_lowercase().contains(&search)
+            })
+            .collect()
+    }
+
+    pub fn navigate_up(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn navigate_down(&mut self, matching_len: usize) {
+        if matching_len > 0 {
+            self.selected = (self.selected + 1).min(matching_len - 1);
+        }
+    }
+}
+
+impl Default for KeybindingsState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Render `KEYBINDINGS` grouped by context as a Markdown cheat sheet.
+pub fn render_markdown() -> String {
+    use std::fmt::Write as _;
+
+    let mut out = String::from("# Complior Keybindings\n");
+    let mut last_context = "";
+    for binding in KEYBINDINGS {
+        if binding.context != last_context {
+            let _ = write!(out, "\n## {}\n\n| Keys | Action |\n|---|---|\n", binding.context);
+            last_context = binding.context;
+        }
+        let _ = writeln!(out, "| `{}` | {} |", binding.keys, binding.action);
+    }
+    out
+}
+
+/// Render `KEYBINDINGS` grouped by context as a self-contained HTML page.
+pub fn render_html() -> String {
+    use std::fmt::Write as _;
+
+    let mut out = String::from(
+        "<!doctype html>\n<html><head><meta charset=\"utf-8\">\
+         <title>Complior Keybindings</title></head><body>\n<h1>Complior Keybindings</h1>\n",
+    );
+    let mut last_context = "";
+    for binding in KEYBINDINGS {
+        if binding.context != last_context {
+            if !last_context.is_empty() {
+                let _ = write!(out, "</table>\n");
+            }
+            let _ = write!(
+                out,
+                "<h2>{}</h2>\n<table border=\"1\" cellpadding=\"4\">\n\
+                 <tr><th>Keys</th><th>Action</th></tr>\n",
+                binding.context
+            );
+            last_context = binding.context;
+        }
+        let _ = writeln!(
+            out,
+            "<tr><td><code>{}</code></td><td>{}</td></tr>",
+            binding.keys, binding.action
+        );
+    }
+    if !last_context.is_empty() {
+        out.push_str("</table>\n");
+    }
+    out.push_str("</body></html>\n");
+    out
+}
+
+pub fn render_keybindings(frame: &mut Frame, state: &KeybindingsState, search: &str) {
+    let t = theme::theme();
+    let area = centered_rect(70, 70, frame.area());
+
+    frame.render_widget(Clear, area);
+
+    let block = Block::default()
+        .title(format!(" Keybindings — search: {search}_ "))
+        .title_style(theme::title_style())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(t.accent));
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let bindings = KeybindingsState::matching(search);
+    if bindings.is_empty() {
+        frame.render_widget(
+            ratatui::widgets::Paragraph::new(Line::from(Span::styled(
+                " No matching keybindings.",
+                Style::default().fg(t.muted),
+            ))),
+            inner,
+        );
+        return;
+    }
+
+    let items: Vec<ListItem<'_>> = bindings
+        .iter()
+        .enumerate()
+        .map(|(i, binding)| {
+            let selected = i == state.selected;
+            let style = if selected {
+                Style::default().fg(t.accent).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(t.fg)
+            };
+            let prefix = if selected { "> " } else { "  " };
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("{prefix}[{}] ", binding.context), Style::default().fg(t.muted)),
+                Span::styled(format!("{:<16}", binding.keys), Style::default().fg(t.accent)),
+                Span::styled(binding.action, style),
+            ]))
+        })
+        .collect();
+
+    frame.render_widget(List::new(items), inner);
+}
+
+fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let v = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(r);
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(v[1])[1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matching_filters_case_insensitively_across_fields() {
+        assert_eq!(KeybindingsState::matching("").len(), KEYBINDINGS.len());
+        assert!(KeybindingsState::matching("QUIT").iter().any(|b| b.action == "Quit"));
+        assert!(KeybindingsState::matching("ctrl+c").iter().any(|b| b.action == "Quit"));
+        assert!(KeybindingsState::matching("scan").iter().any(|b| b.context == "Scan"));
+    }
+
+    #[test]
+    fn navigate_down_clamps_to_matching_length() {
+        let mut state = KeybindingsState::new();
+        let len = KeybindingsState::matching("quit").len();
+        state.navigate_down(len);
+        assert_eq!(state.selected, len.saturating_sub(1));
+    }
+
+    #[test]
+    fn navigate_up_saturates_at_zero() {
+        let mut state = KeybindingsState::new();
+        state.navigate_up();
+        assert_eq!(state.selected, 0);
+    }
+
+    #[test]
+    fn markdown_export_groups_by_context() {
+        let md = render_markdown();
+        assert!(md.starts_with("# Complior Keybindings"));
+        assert!(md.contains("## General"));
+        assert!(md.contains("| `Ctrl+C` | Quit |"));
+    }
+
+    #[test]
+    fn html_export_is_well_formed() {
+        let html = render_html();
+        assert!(html.contains("<h1>Complior Keybindings</h1>"));
+        assert!(html.contains("<h2>General</h2>"));
+        assert!(html.contains("</html>"));
+    }
+}
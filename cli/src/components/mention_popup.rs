@@ -0,0 +1,99 @@
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem};
+
+use crate::obligations;
+use crate::theme;
+use crate::types::FileEntry;
+
+use super::file_picker::fuzzy_match_files;
+
+/// Max files / obligations shown at once in the `@`-mention popup.
+const MAX_FILE_MATCHES: usize = 5;
+const MAX_OBLIGATION_MATCHES: usize = 3;
+
+/// One entry in the inline `@`-mention popup: a project file or an
+/// obligation reference, plus the text to splice into the input when
+/// selected.
+pub struct MentionMatch {
+    pub label: String,
+    pub insert: String,
+}
+
+/// Fuzzy-match `prefix` (the text typed after `@`) against project files
+/// and EU AI Act obligations, for the inline popup shown while typing in
+/// the chat input -- the live alternative to opening the separate
+/// [`crate::components::file_picker`] overlay.
+pub fn mention_matches(files: &[FileEntry], prefix: &str) -> Vec<MentionMatch> {
+    let file_matches = fuzzy_match_files(files, prefix)
+        .into_iter()
+        .take(MAX_FILE_MATCHES)
+        .map(|f| {
+            let path = f.path.to_string_lossy().to_string();
+            MentionMatch {
+                label: format!("file  {path}"),
+                insert: format!("@{path} "),
+            }
+        });
+
+    let obligation_matches = obligations::autocomplete_obl(prefix)
+        .into_iter()
+        .take(MAX_OBLIGATION_MATCHES)
+        .map(|o| MentionMatch {
+            label: format!("OBL   OBL-{} {} ({})", o.id, o.title, o.article),
+            insert: format!("@OBL-{} ", o.id),
+        });
+
+    file_matches.chain(obligation_matches).collect()
+}
+
+/// Render the mention popup as a small dropdown anchored just above the
+/// chat input box, so it doesn't cover the line being typed.
+pub fn render_mention_popup(
+    frame: &mut Frame,
+    input_area: Rect,
+    matches: &[MentionMatch],
+    selected: usize,
+) {
+    if matches.is_empty() {
+        return;
+    }
+    let height = (matches.len() as u16 + 2).min(8);
+    let popup = Rect {
+        x: input_area.x,
+        y: input_area.y.saturating_sub(height),
+        width: input_area.width,
+        height,
+    };
+
+    frame.render_widget(Clear, popup);
+
+    let t = theme::theme();
+    let block = Block::default()
+        .title(" @mention ")
+        .title_style(theme::title_style())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(t.accent));
+    let inner = block.inner(popup);
+    frame.render_widget(block, popup);
+
+    let items: Vec<ListItem<'_>> = matches
+        .iter()
+        .enumerate()
+        .map(|(i, m)| {
+            let style = if i == selected {
+                Style::default()
+                    .fg(t.bg)
+                    .bg(t.accent)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(t.fg)
+            };
+            ListItem::new(Line::from(Span::styled(m.label.clone(), style)))
+        })
+        .collect();
+
+    frame.render_widget(List::new(items), inner);
+}
@@ -1,10 +1,26 @@
+pub mod activity_history;
+pub mod bookmarks;
+pub mod changes_feed;
+pub mod check_docs;
 pub mod command_palette;
 pub mod confirm_dialog;
+pub mod critical_cap_detail;
 pub mod file_picker;
+pub mod file_reload_prompt;
+pub mod keybindings;
+pub mod mention_popup;
+pub mod notifications;
+pub mod perf_overlay;
+pub mod project_switcher;
 pub mod quick_actions;
+pub mod recent_files;
 pub mod spinner;
+pub mod stats;
 pub mod suggestions;
 pub mod toast;
+pub mod tool_approval;
+pub mod tooltip;
+pub mod tour;
 pub mod undo_history;
 pub mod whatif;
 pub mod zoom;
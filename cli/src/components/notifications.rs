@@ -0,0 +1,204 @@
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem};
+
+use crate::components::toast::ToastKind;
+use crate::theme;
+
+/// A toast preserved past its auto-dismiss, or a system chat message —
+/// unified for the notification center's single scrollback.
+#[derive(Debug, Clone)]
+pub struct NotificationEntry {
+    pub timestamp: String,
+    /// `None` for a system chat message, which has no severity of its own.
+    pub kind: Option<ToastKind>,
+    pub message: String,
+}
+
+/// Snapshot of `ToastStack::history` plus system chat messages, taken when
+/// the overlay opens (`N`) — same snapshot-on-open shape as
+/// `UndoHistoryState`/`ProjectSwitcherState`.
+pub struct NotificationCenterState {
+    pub entries: Vec<NotificationEntry>,
+    pub selected: usize,
+    pub filter: Option<ToastKind>,
+}
+
+impl NotificationCenterState {
+    pub const fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            selected: 0,
+            filter: None,
+        }
+    }
+
+    /// Cycle the severity filter: All -> Success -> Info -> Warning -> Error -> All.
+    pub const fn cycle_filter(&mut self) {
+        self.filter = match self.filter {
+            None => Some(ToastKind::Success),
+            Some(ToastKind::Success) => Some(ToastKind::Info),
+            Some(ToastKind::Info) => Some(ToastKind::Warning),
+            Some(ToastKind::Warning) => Some(ToastKind::Error),
+            Some(ToastKind::Error) => None,
+        };
+        self.selected = 0;
+    }
+
+    pub const fn navigate_up(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn navigate_down(&mut self) {
+        if !self.filtered().is_empty() {
+            self.selected = (self.selected + 1).min(self.filtered().len() - 1);
+        }
+    }
+
+    /// Entries matching the current filter, oldest first (system messages
+    /// have no severity, so they always pass a severity filter).
+    pub fn filtered(&self) -> Vec<&NotificationEntry> {
+        self.entries
+            .iter()
+            .filter(|e| self.filter.is_none_or(|f| e.kind.is_none_or(|k| k == f)))
+            .collect()
+    }
+}
+
+fn filter_label(filter: Option<ToastKind>) -> &'static str {
+    match filter {
+        None => "All",
+        Some(ToastKind::Success) => "Success",
+        Some(ToastKind::Info) => "Info",
+        Some(ToastKind::Warning) => "Warning",
+        Some(ToastKind::Error) => "Error",
+    }
+}
+
+pub fn render_notification_center(frame: &mut Frame, state: &NotificationCenterState) {
+    let t = theme::theme();
+    let area = centered_rect(70, 60, frame.area());
+
+    frame.render_widget(Clear, area);
+
+    let block = Block::default()
+        .title(format!(" Notifications — filter: {} (f) ", filter_label(state.filter)))
+        .title_style(theme::title_style())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(t.accent));
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let entries = state.filtered();
+    if entries.is_empty() {
+        frame.render_widget(
+            ratatui::widgets::Paragraph::new(Line::from(Span::styled(
+                " Nothing to show for this filter.",
+                Style::default().fg(t.muted),
+            ))),
+            inner,
+        );
+        return;
+    }
+
+    let items: Vec<ListItem<'_>> = entries
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let selected = i == state.selected;
+            let color = match entry.kind {
+                Some(ToastKind::Success) => t.zone_green,
+                Some(ToastKind::Info) | None => t.accent,
+                Some(ToastKind::Warning) => t.zone_yellow,
+                Some(ToastKind::Error) => t.zone_red,
+            };
+            let marker = entry.kind.map_or("[sys]", ToastKind::marker);
+            let style = if selected {
+                Style::default().fg(color).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(t.fg)
+            };
+            let prefix = if selected { "> " } else { "  " };
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("{prefix}{} ", entry.timestamp), Style::default().fg(t.muted)),
+                Span::styled(format!("{marker} "), Style::default().fg(color)),
+                Span::styled(entry.message.clone(), style),
+            ]))
+        })
+        .collect();
+
+    frame.render_widget(List::new(items), inner);
+}
+
+fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let v = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(r);
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(v[1])[1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(kind: Option<ToastKind>) -> NotificationEntry {
+        NotificationEntry {
+            timestamp: "12:00".to_string(),
+            kind,
+            message: "msg".to_string(),
+        }
+    }
+
+    #[test]
+    fn cycle_filter_covers_all_kinds_and_wraps() {
+        let mut state = NotificationCenterState::new();
+        assert_eq!(state.filter, None);
+        state.cycle_filter();
+        assert_eq!(state.filter, Some(ToastKind::Success));
+        state.cycle_filter();
+        assert_eq!(state.filter, Some(ToastKind::Info));
+        state.cycle_filter();
+        assert_eq!(state.filter, Some(ToastKind::Warning));
+        state.cycle_filter();
+        assert_eq!(state.filter, Some(ToastKind::Error));
+        state.cycle_filter();
+        assert_eq!(state.filter, None);
+    }
+
+    #[test]
+    fn filtered_keeps_system_messages_under_any_severity_filter() {
+        let mut state = NotificationCenterState::new();
+        state.entries.push(entry(Some(ToastKind::Error)));
+        state.entries.push(entry(None));
+        state.filter = Some(ToastKind::Success);
+
+        assert_eq!(state.filtered().len(), 1);
+        assert!(state.filtered()[0].kind.is_none());
+    }
+
+    #[test]
+    fn navigate_down_clamps_to_filtered_length() {
+        let mut state = NotificationCenterState::new();
+        state.entries.push(entry(Some(ToastKind::Info)));
+        state.entries.push(entry(Some(ToastKind::Error)));
+        state.filter = Some(ToastKind::Info);
+
+        state.navigate_down();
+        assert_eq!(state.selected, 0);
+    }
+}
@@ -0,0 +1,129 @@
+use std::time::{Duration, Instant};
+
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+use crate::theme;
+
+/// How often the events/sec counter's window resets.
+const EVENTS_WINDOW: Duration = Duration::from_secs(1);
+
+/// Frame time, event rate, and per-view render duration, shown by the
+/// hidden `--perf-overlay` flag. This is a by-eye regression signal for TUI
+/// perf work, not production telemetry — no persistence, no percentiles.
+#[derive(Debug)]
+pub struct PerfStats {
+    frame_ms: f64,
+    view_render_ms: f64,
+    events_in_window: u32,
+    window_start: Instant,
+    events_per_sec: f64,
+}
+
+impl PerfStats {
+    pub fn new() -> Self {
+        Self {
+            frame_ms: 0.0,
+            view_render_ms: 0.0,
+            events_in_window: 0,
+            window_start: Instant::now(),
+            events_per_sec: 0.0,
+        }
+    }
+
+    /// Wall time for the whole `terminal.draw` call (widget render + diff + flush).
+    pub fn record_frame(&mut self, duration: Duration) {
+        self.frame_ms = duration.as_secs_f64() * 1000.0;
+    }
+
+    /// Wall time for just the active view's render function, excluding
+    /// ratatui's buffer diff/flush — separates app-side cost from backend cost.
+    pub fn record_view_render(&mut self, duration: Duration) {
+        self.view_render_ms = duration.as_secs_f64() * 1000.0;
+    }
+
+    /// Count one input/watch/background event toward the events/sec rate.
+    pub fn record_event(&mut self) {
+        self.events_in_window += 1;
+        let elapsed = self.window_start.elapsed();
+        if elapsed >= EVENTS_WINDOW {
+            self.events_per_sec = f64::from(self.events_in_window) / elapsed.as_secs_f64();
+            self.events_in_window = 0;
+            self.window_start = Instant::now();
+        }
+    }
+}
+
+impl Default for PerfStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Render the perf overlay as a small box in the top-right corner.
+pub fn render_perf_overlay(frame: &mut Frame, area: Rect, stats: &PerfStats) {
+    let t = theme::theme();
+
+    let width: u16 = 28;
+    let height: u16 = 5;
+    let x = area.x + area.width.saturating_sub(width + 1);
+    let rect = Rect::new(x, area.y, width.min(area.width), height.min(area.height));
+
+    frame.render_widget(Clear, rect);
+
+    let lines = vec![
+        Line::from(Span::styled(
+            format!("frame  {:>6.2} ms", stats.frame_ms),
+            Style::default().fg(t.fg),
+        )),
+        Line::from(Span::styled(
+            format!("view   {:>6.2} ms", stats.view_render_ms),
+            Style::default().fg(t.fg),
+        )),
+        Line::from(Span::styled(
+            format!("events {:>6.1} /s", stats.events_per_sec),
+            Style::default().fg(t.fg),
+        )),
+    ];
+
+    let block = Block::default()
+        .title(" perf ")
+        .title_style(Style::default().fg(t.muted))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(t.border))
+        .style(Style::default().bg(t.bg));
+    frame.render_widget(Paragraph::new(lines).block(block), rect);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_frame_and_view_render() {
+        let mut stats = PerfStats::new();
+        stats.record_frame(Duration::from_millis(16));
+        stats.record_view_render(Duration::from_millis(4));
+        assert!((stats.frame_ms - 16.0).abs() < 0.01);
+        assert!((stats.view_render_ms - 4.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_events_per_sec_starts_at_zero() {
+        let stats = PerfStats::new();
+        assert_eq!(stats.events_per_sec, 0.0);
+    }
+
+    #[test]
+    fn test_record_event_accumulates_within_window() {
+        let mut stats = PerfStats::new();
+        stats.record_event();
+        stats.record_event();
+        // Window hasn't elapsed yet, so the rate hasn't been computed.
+        assert_eq!(stats.events_per_sec, 0.0);
+        assert_eq!(stats.events_in_window, 2);
+    }
+}
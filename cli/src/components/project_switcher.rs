@@ -0,0 +1,195 @@
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Row, Table};
+
+use crate::theme;
+use crate::types::Zone;
+
+/// One registered project's last-known status, read from its
+/// `.complior/last-scan.json` (absent if the project has never been scanned).
+#[derive(Debug, Clone)]
+pub struct ProjectEntry {
+    pub path: String,
+    pub name: String,
+    pub score: Option<f64>,
+    pub zone: Option<Zone>,
+    pub findings_count: Option<usize>,
+}
+
+pub struct ProjectSwitcherState {
+    pub entries: Vec<ProjectEntry>,
+    pub selected: usize,
+}
+
+impl ProjectSwitcherState {
+    pub const fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            selected: 0,
+        }
+    }
+
+    pub const fn navigate_up(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn navigate_down(&mut self) {
+        if !self.entries.is_empty() {
+            self.selected = (self.selected + 1).min(self.entries.len() - 1);
+        }
+    }
+
+    pub fn selected_path(&self) -> Option<&str> {
+        self.entries.get(self.selected).map(|e| e.path.as_str())
+    }
+}
+
+pub fn render_project_switcher(frame: &mut Frame, state: &ProjectSwitcherState, active_path: &str) {
+    let t = theme::theme();
+    let area = centered_rect(60, 50, frame.area());
+
+    frame.render_widget(Clear, area);
+
+    let block = Block::default()
+        .title(" Projects ")
+        .title_style(theme::title_style())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(t.accent));
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    if state.entries.is_empty() {
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                " No registered projects. Use /projects add to register this one.",
+                Style::default().fg(t.muted),
+            ))),
+            inner,
+        );
+        return;
+    }
+
+    let rows: Vec<Row<'_>> = state
+        .entries
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let selected = i == state.selected;
+            let style = if selected {
+                Style::default().fg(t.accent).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(t.fg)
+            };
+            let marker = if entry.path == active_path {
+                "* "
+            } else if selected {
+                "> "
+            } else {
+                "  "
+            };
+            let score_str = entry
+                .score
+                .map_or_else(|| "—".to_string(), |s| format!("{s:.0}"));
+            let zone_str = entry.zone.map_or("—", |z| z.label());
+            let findings_str = entry
+                .findings_count
+                .map_or_else(|| "—".to_string(), |n| n.to_string());
+
+            Row::new(vec![
+                format!("{marker}{}", entry.name),
+                score_str,
+                zone_str.to_string(),
+                findings_str,
+            ])
+            .style(style)
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Min(20),
+        Constraint::Length(7),
+        Constraint::Length(8),
+        Constraint::Length(10),
+    ];
+
+    let header = Row::new(vec!["  Project", "Score", "Zone", "Findings"])
+        .style(Style::default().fg(t.muted).add_modifier(Modifier::BOLD));
+
+    let table = Table::new(rows, widths).header(header);
+    frame.render_widget(table, inner);
+}
+
+fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let v = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(r);
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(v[1])[1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn project_switcher_nav() {
+        let mut state = ProjectSwitcherState::new();
+        state.entries = vec![
+            ProjectEntry {
+                path: "/a".to_string(),
+                name: "a".to_string(),
+                score: Some(80.0),
+                zone: Some(Zone::Green),
+                findings_count: Some(2),
+            },
+            ProjectEntry {
+                path: "/b".to_string(),
+                name: "b".to_string(),
+                score: None,
+                zone: None,
+                findings_count: None,
+            },
+        ];
+        state.selected = 0;
+
+        state.navigate_down();
+        assert_eq!(state.selected, 1);
+        assert_eq!(state.selected_path(), Some("/b"));
+
+        state.navigate_down(); // should clamp
+        assert_eq!(state.selected, 1);
+
+        state.navigate_up();
+        assert_eq!(state.selected, 0);
+        assert_eq!(state.selected_path(), Some("/a"));
+
+        state.navigate_up(); // should clamp
+        assert_eq!(state.selected, 0);
+    }
+
+    #[test]
+    fn e2e_project_switcher_render() {
+        crate::theme::init_theme("dark");
+        let backend = ratatui::backend::TestBackend::new(120, 40);
+        let mut terminal = ratatui::Terminal::new(backend).expect("terminal");
+
+        let state = ProjectSwitcherState::new();
+        terminal
+            .draw(|frame| render_project_switcher(frame, &state, "/active"))
+            .expect("render empty project switcher");
+    }
+}
@@ -53,13 +53,19 @@ impl DismissReason {
 pub struct DismissModal {
     pub cursor: usize,
     pub reasons: Vec<DismissReason>,
+    /// Check ID of the finding being dismissed, so the choice can be
+    /// recorded in `.complior/dismissals.jsonl` on confirm.
+    pub check_id: String,
+    pub file: Option<String>,
 }
 
 impl DismissModal {
-    pub fn new(_finding_index: usize) -> Self {
+    pub fn new(check_id: String, file: Option<String>) -> Self {
         Self {
             cursor: 0,
             reasons: DismissReason::all().to_vec(),
+            check_id,
+            file,
         }
     }
 
@@ -84,7 +90,7 @@ mod tests {
 
     #[test]
     fn test_dismiss_modal_navigation() {
-        let mut modal = DismissModal::new(0);
+        let mut modal = DismissModal::new("test-check".to_string(), None);
         assert_eq!(modal.cursor, 0);
         modal.move_down();
         assert_eq!(modal.cursor, 1);
@@ -0,0 +1,139 @@
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem};
+
+use crate::theme;
+
+/// Snapshot of `App::recent_files` plus a cursor, live for as long as the
+/// `Overlay::RecentFiles` quick switcher (`Ctrl+E`) is open.
+pub struct RecentFilesState {
+    pub entries: Vec<String>,
+    pub selected: usize,
+}
+
+impl RecentFilesState {
+    pub const fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            selected: 0,
+        }
+    }
+
+    pub const fn navigate_up(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn navigate_down(&mut self) {
+        if !self.entries.is_empty() {
+            self.selected = (self.selected + 1).min(self.entries.len() - 1);
+        }
+    }
+
+    pub fn selected_path(&self) -> Option<&str> {
+        self.entries.get(self.selected).map(String::as_str)
+    }
+}
+
+pub fn render_recent_files(frame: &mut Frame, state: &RecentFilesState) {
+    let t = theme::theme();
+    let area = centered_rect(60, 50, frame.area());
+
+    frame.render_widget(Clear, area);
+
+    let block = Block::default()
+        .title(" Recent Files ")
+        .title_style(theme::title_style())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(t.accent));
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    if state.entries.is_empty() {
+        frame.render_widget(
+            ratatui::widgets::Paragraph::new(Line::from(Span::styled(
+                " No recently opened files.",
+                Style::default().fg(t.muted),
+            ))),
+            inner,
+        );
+        return;
+    }
+
+    let items: Vec<ListItem<'_>> = state
+        .entries
+        .iter()
+        .enumerate()
+        .map(|(i, path)| {
+            let selected = i == state.selected;
+            let style = if selected {
+                Style::default().fg(t.accent).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(t.fg)
+            };
+            let marker = if selected { "> " } else { "  " };
+            ListItem::new(Line::from(Span::styled(format!("{marker}{path}"), style)))
+        })
+        .collect();
+
+    frame.render_widget(List::new(items), inner);
+}
+
+fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let v = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(r);
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(v[1])[1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recent_files_nav() {
+        let mut state = RecentFilesState::new();
+        state.entries = vec!["a.rs".to_string(), "b.rs".to_string()];
+        state.selected = 0;
+
+        state.navigate_down();
+        assert_eq!(state.selected, 1);
+        assert_eq!(state.selected_path(), Some("b.rs"));
+
+        state.navigate_down(); // should clamp
+        assert_eq!(state.selected, 1);
+
+        state.navigate_up();
+        assert_eq!(state.selected, 0);
+        assert_eq!(state.selected_path(), Some("a.rs"));
+
+        state.navigate_up(); // should clamp
+        assert_eq!(state.selected, 0);
+    }
+
+    #[test]
+    fn e2e_recent_files_render() {
+        crate::theme::init_theme("dark");
+        let backend = ratatui::backend::TestBackend::new(120, 40);
+        let mut terminal = ratatui::Terminal::new(backend).expect("terminal");
+
+        let state = RecentFilesState::new();
+        terminal
+            .draw(|frame| render_recent_files(frame, &state))
+            .expect("render empty recent files");
+    }
+}
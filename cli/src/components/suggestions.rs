@@ -5,6 +5,7 @@ use ratatui::layout::Rect;
 use ratatui::style::Style;
 use ratatui::text::{Line, Span};
 use ratatui::widgets::Paragraph;
+use serde::{Deserialize, Serialize};
 
 use crate::theme;
 
@@ -24,6 +25,68 @@ pub enum SuggestionKind {
     NewFeature,
 }
 
+impl SuggestionKind {
+    /// Wire key used by the engine `/suggestions` JSON (`kind` field) and by
+    /// `/snooze`/config persistence, so a kind never needs `Debug`-formatting.
+    pub const fn key(self) -> &'static str {
+        match self {
+            Self::Tip => "tip",
+            Self::Fix => "fix",
+            Self::DeadlineWarning => "deadline",
+            Self::ScoreImprovement => "score",
+            Self::NewFeature => "new",
+        }
+    }
+
+    /// Parse a wire key back into a kind, e.g. `/snooze deadline 7`.
+    pub fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "tip" => Some(Self::Tip),
+            "fix" => Some(Self::Fix),
+            "deadline" => Some(Self::DeadlineWarning),
+            "score" => Some(Self::ScoreImprovement),
+            "new" => Some(Self::NewFeature),
+            _ => None,
+        }
+    }
+}
+
+/// A per-kind suggestion snooze, persisted to `settings.toml`
+/// (`GlobalConfig::snoozed_suggestions`) so "don't show deadline warnings
+/// for a week" survives restarts.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnoozedSuggestion {
+    pub kind: String,
+    pub until_secs: u64,
+}
+
+/// Whether `kind` is currently snoozed, i.e. `now_secs` hasn't reached its
+/// recorded expiry yet.
+pub fn is_snoozed(snoozed: &[SnoozedSuggestion], kind: SuggestionKind, now_secs: u64) -> bool {
+    snoozed
+        .iter()
+        .any(|s| s.kind == kind.key() && s.until_secs > now_secs)
+}
+
+/// Snooze `kind` until `until_secs`, replacing any existing snooze for that
+/// kind. Pure function — the caller persists the result.
+pub fn snooze_until(
+    snoozed: &[SnoozedSuggestion],
+    kind: SuggestionKind,
+    until_secs: u64,
+) -> Vec<SnoozedSuggestion> {
+    let mut next: Vec<SnoozedSuggestion> = snoozed
+        .iter()
+        .filter(|s| s.kind != kind.key())
+        .cloned()
+        .collect();
+    next.push(SnoozedSuggestion {
+        kind: kind.key().to_string(),
+        until_secs,
+    });
+    next
+}
+
 pub struct IdleSuggestionState {
     pub current: Option<Suggestion>,
     pub last_input: Instant,
@@ -69,13 +132,7 @@ impl IdleSuggestionState {
 pub fn render_suggestion(frame: &mut Frame, area: Rect, suggestion: &Suggestion) {
     let t = theme::theme();
 
-    let kind_label = match suggestion.kind {
-        SuggestionKind::Tip => "tip",
-        SuggestionKind::Fix => "fix",
-        SuggestionKind::DeadlineWarning => "deadline",
-        SuggestionKind::ScoreImprovement => "score",
-        SuggestionKind::NewFeature => "new",
-    };
+    let kind_label = suggestion.kind.key();
 
     let mut lines = vec![Line::from(vec![
         Span::styled(format!(" [{kind_label}] "), Style::default().fg(t.accent)),
@@ -135,4 +192,43 @@ mod tests {
         assert!(state.current.is_none());
         assert!(state.recently_dismissed());
     }
+
+    #[test]
+    fn suggestion_kind_key_roundtrips() {
+        for kind in [
+            SuggestionKind::Tip,
+            SuggestionKind::Fix,
+            SuggestionKind::DeadlineWarning,
+            SuggestionKind::ScoreImprovement,
+            SuggestionKind::NewFeature,
+        ] {
+            assert_eq!(SuggestionKind::from_key(kind.key()), Some(kind));
+        }
+        assert_eq!(SuggestionKind::from_key("bogus"), None);
+    }
+
+    #[test]
+    fn is_snoozed_respects_expiry() {
+        let snoozed = vec![SnoozedSuggestion {
+            kind: SuggestionKind::DeadlineWarning.key().to_string(),
+            until_secs: 1_000,
+        }];
+        assert!(is_snoozed(&snoozed, SuggestionKind::DeadlineWarning, 500));
+        assert!(!is_snoozed(
+            &snoozed,
+            SuggestionKind::DeadlineWarning,
+            1_500
+        ));
+        assert!(!is_snoozed(&snoozed, SuggestionKind::Tip, 500));
+    }
+
+    #[test]
+    fn snooze_until_replaces_existing_entry_for_kind() {
+        let snoozed = snooze_until(&[], SuggestionKind::Tip, 1_000);
+        assert_eq!(snoozed.len(), 1);
+
+        let snoozed = snooze_until(&snoozed, SuggestionKind::Tip, 2_000);
+        assert_eq!(snoozed.len(), 1);
+        assert_eq!(snoozed[0].until_secs, 2_000);
+    }
 }
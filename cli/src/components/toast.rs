@@ -10,6 +10,9 @@ use crate::theme;
 
 const AUTO_DISMISS_SECS: u64 = 3;
 const MAX_VISIBLE: usize = 5;
+/// Cap on `ToastStack::history` — enough to cover "what did I miss" for a
+/// long session without growing unbounded.
+const MAX_HISTORY: usize = 100;
 
 /// Type of toast notification.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -37,6 +40,9 @@ pub struct Toast {
     pub kind: ToastKind,
     pub message: String,
     pub created_at: Instant,
+    /// Wall-clock "HH:MM" the toast fired, for the notification center —
+    /// `created_at` is monotonic and can't be displayed to the user.
+    pub timestamp: String,
 }
 
 impl Toast {
@@ -45,18 +51,31 @@ impl Toast {
             kind,
             message: message.into(),
             created_at: Instant::now(),
+            timestamp: crate::types::chrono_now(),
         }
     }
 
-    pub fn is_expired(&self) -> bool {
-        self.created_at.elapsed().as_secs() >= AUTO_DISMISS_SECS
+    /// Whether this toast has been visible for at least `duration_secs`.
+    pub fn is_expired(&self, duration_secs: u64) -> bool {
+        self.created_at.elapsed().as_secs() >= duration_secs
     }
 }
 
 /// Stack of toast notifications (newest on top, max 5 visible).
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone)]
 pub struct ToastStack {
     pub toasts: Vec<Toast>,
+    /// Every toast ever pushed this session, capped at `MAX_HISTORY`, so the
+    /// notification center overlay (`N`) can show what was missed after a
+    /// toast auto-dismisses.
+    pub history: Vec<Toast>,
+    duration_secs: u64,
+}
+
+impl Default for ToastStack {
+    fn default() -> Self {
+        Self::with_duration(AUTO_DISMISS_SECS)
+    }
 }
 
 impl ToastStack {
@@ -64,8 +83,30 @@ impl ToastStack {
         Self::default()
     }
 
+    /// Construct with a configurable auto-dismiss duration (`/settings`'s
+    /// "toast duration" toggle), rather than the hardcoded default.
+    pub fn with_duration(duration_secs: u64) -> Self {
+        Self {
+            toasts: Vec::new(),
+            history: Vec::new(),
+            duration_secs,
+        }
+    }
+
+    /// Update the auto-dismiss duration for future `gc()` calls.
+    pub fn set_duration(&mut self, duration_secs: u64) {
+        self.duration_secs = duration_secs;
+    }
+
     pub fn push(&mut self, kind: ToastKind, message: impl Into<String>) {
+        if kind == ToastKind::Error {
+            crate::telemetry::record_error("toast_error");
+        }
         let toast = Toast::new(kind, message);
+        self.history.push(toast.clone());
+        if self.history.len() > MAX_HISTORY {
+            self.history.remove(0);
+        }
         self.toasts.push(toast);
         if self.toasts.len() > MAX_VISIBLE {
             self.toasts.remove(0);
@@ -75,7 +116,8 @@ impl ToastStack {
     /// Remove expired toasts. Returns number removed.
     pub fn gc(&mut self) -> usize {
         let before = self.toasts.len();
-        self.toasts.retain(|t| !t.is_expired());
+        let duration_secs = self.duration_secs;
+        self.toasts.retain(|t| !t.is_expired(duration_secs));
         before - self.toasts.len()
     }
 
@@ -140,7 +182,7 @@ mod tests {
     #[test]
     fn test_toast_lifecycle() {
         let toast = Toast::new(ToastKind::Success, "Fix applied");
-        assert!(!toast.is_expired());
+        assert!(!toast.is_expired(AUTO_DISMISS_SECS));
         assert_eq!(toast.kind, ToastKind::Success);
         assert_eq!(toast.message, "Fix applied");
     }
@@ -205,6 +247,16 @@ mod tests {
         );
     }
 
+    /// A configurable duration is honored by `gc()` instead of the hardcoded default.
+    #[test]
+    fn test_toast_stack_with_duration_overrides_gc() {
+        let mut stack = ToastStack::with_duration(0);
+        stack.push(ToastKind::Info, "instant");
+        // duration_secs=0 means any elapsed time (even ~0s) expires it.
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        assert_eq!(stack.gc(), 1);
+    }
+
     /// All 4 toast kinds have distinct markers.
     #[test]
     fn test_toast_4_kinds() {
@@ -0,0 +1,13 @@
+//! State for the tool-call approval overlay: when the chat agent wants to
+//! run a write/execute tool, the stream is paused (see
+//! [`crate::chat_stream::spawn_stream_reader`]) until the user approves,
+//! denies, or always-allows that tool for the rest of the session.
+
+/// A tool call awaiting the user's decision. Holding on to `respond` keeps
+/// the stream reader task paused -- dropping this without sending resolves
+/// it to [`crate::types::ToolApprovalDecision::Deny`].
+pub struct PendingToolApproval {
+    pub tool_name: String,
+    pub args: String,
+    pub respond: crate::types::ToolApprovalResponder,
+}
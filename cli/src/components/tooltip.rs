@@ -0,0 +1,33 @@
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+use crate::theme;
+use crate::types::FooterIndicator;
+
+/// Render a one-line hover tooltip for a footer status-bar indicator, just
+/// above the footer so it doesn't cover the indicator itself.
+pub fn render_tooltip(frame: &mut Frame, area: Rect, indicator: FooterIndicator) {
+    let t = theme::theme();
+    let text = indicator.tooltip();
+
+    let width = (text.len() as u16 + 4).min(area.width);
+    let height: u16 = 3;
+    let y = area.y + area.height.saturating_sub(2 + height);
+    let rect = Rect::new(area.x, y, width, height);
+
+    frame.render_widget(Clear, rect);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(t.accent))
+        .style(Style::default().bg(t.bg));
+    let paragraph = Paragraph::new(Line::from(vec![Span::styled(
+        text,
+        Style::default().fg(t.fg),
+    )]))
+    .block(block);
+    frame.render_widget(paragraph, rect);
+}
@@ -0,0 +1,207 @@
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+
+use crate::layout::compute_layout;
+use crate::theme;
+use crate::types::ViewState;
+
+/// Which region of the current view a tour step spotlights. `FullScreen`
+/// leaves the whole frame undimmed — used for the intro/outro steps that
+/// aren't about one widget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Spotlight {
+    FullScreen,
+    Sidebar,
+    Main,
+}
+
+pub struct TourStep {
+    pub view: ViewState,
+    pub spotlight: Spotlight,
+    pub title: &'static str,
+    pub body: &'static str,
+}
+
+/// Scripted sequence the tour steps through. Order matters: each step's
+/// `view` is switched to when the tour advances onto it.
+const TOUR_STEPS: &[TourStep] = &[
+    TourStep {
+        view: ViewState::Dashboard,
+        spotlight: Spotlight::FullScreen,
+        title: "Welcome to Complior",
+        body: "A quick tour of the dashboard. Use \u{2192}/l or Enter to move forward, \
+               \u{2190}/h to go back, Esc to leave any time — reopen with /tour to resume.",
+    },
+    TourStep {
+        view: ViewState::Dashboard,
+        spotlight: Spotlight::Sidebar,
+        title: "Sidebar",
+        body: "Your compliance score, zone, and quick navigation across all nine views live here.",
+    },
+    TourStep {
+        view: ViewState::Scan,
+        spotlight: Spotlight::Main,
+        title: "Scan view",
+        body: "Every finding from the last scan, with severity, obligation, and article \
+               reference. Press `?` on a finding for its full documentation.",
+    },
+    TourStep {
+        view: ViewState::Fix,
+        spotlight: Spotlight::Main,
+        title: "Fix queue",
+        body: "Deterministic fixes Complior can apply automatically. Use --ai for \
+               refactors too complex for a template.",
+    },
+    TourStep {
+        view: ViewState::Chat,
+        spotlight: Spotlight::Main,
+        title: "Chat",
+        body: "Ask about any finding or run slash commands like /scan, /fix, and /doctor.",
+    },
+    TourStep {
+        view: ViewState::Dashboard,
+        spotlight: Spotlight::FullScreen,
+        title: "That's it",
+        body: "Run /doctor any time to check your setup, or /help for the full command list.",
+    },
+];
+
+/// State for the guided tour overlay (`/tour`). Not wrapped in `Option`
+/// because the step index is meant to survive the overlay being dismissed
+/// and reopened — same snapshot-on-open shape as `BookmarksState`, except
+/// the "snapshot" here is just a cursor into a fixed script rather than
+/// data pulled from the current scan.
+pub struct TourState {
+    pub step: usize,
+}
+
+impl TourState {
+    pub const fn new() -> Self {
+        Self { step: 0 }
+    }
+
+    pub fn current(&self) -> &'static TourStep {
+        &TOUR_STEPS[self.step.min(TOUR_STEPS.len() - 1)]
+    }
+
+    pub const fn is_first(&self) -> bool {
+        self.step == 0
+    }
+
+    pub const fn is_last(&self) -> bool {
+        self.step + 1 >= TOUR_STEPS.len()
+    }
+
+    /// Advance to the next step. Returns `false` (and does nothing) if
+    /// already on the last step, so the caller knows to end the tour instead.
+    pub fn advance(&mut self) -> bool {
+        if self.is_last() {
+            return false;
+        }
+        self.step += 1;
+        true
+    }
+
+    pub const fn back(&mut self) {
+        self.step = self.step.saturating_sub(1);
+    }
+}
+
+impl Default for TourState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Split `area` into the up-to-four bands surrounding `spotlight`, so the
+/// caller can dim them while leaving `spotlight` itself untouched.
+fn surrounding_bands(area: Rect, spotlight: Rect) -> Vec<Rect> {
+    let mut bands = Vec::with_capacity(4);
+    let top_h = spotlight.y.saturating_sub(area.y);
+    if top_h > 0 {
+        bands.push(Rect { x: area.x, y: area.y, width: area.width, height: top_h });
+    }
+    let bottom_y = spotlight.y + spotlight.height;
+    let bottom_h = (area.y + area.height).saturating_sub(bottom_y);
+    if bottom_h > 0 {
+        bands.push(Rect { x: area.x, y: bottom_y, width: area.width, height: bottom_h });
+    }
+    let left_w = spotlight.x.saturating_sub(area.x);
+    if left_w > 0 {
+        bands.push(Rect { x: area.x, y: spotlight.y, width: left_w, height: spotlight.height });
+    }
+    let right_x = spotlight.x + spotlight.width;
+    let right_w = (area.x + area.width).saturating_sub(right_x);
+    if right_w > 0 {
+        bands.push(Rect { x: right_x, y: spotlight.y, width: right_w, height: spotlight.height });
+    }
+    bands
+}
+
+/// Render the guided tour: dim everything outside the current step's
+/// spotlight region, outline the spotlight, and show a caption box with the
+/// step's title, body, and progress.
+pub fn render_tour(frame: &mut Frame, state: &TourState) {
+    let t = theme::theme();
+    let step = state.current();
+    let full = frame.area();
+
+    let spotlight = match step.spotlight {
+        Spotlight::FullScreen => None,
+        Spotlight::Sidebar => compute_layout(full, Some(true)).sidebar_area,
+        Spotlight::Main => Some(compute_layout(full, None).main_area),
+    };
+
+    if let Some(rect) = spotlight {
+        let dim = Style::default().bg(t.bg).fg(t.muted);
+        for band in surrounding_bands(full, rect) {
+            frame.render_widget(Clear, band);
+            frame.render_widget(Block::default().style(dim), band);
+        }
+        frame.render_widget(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(t.accent).add_modifier(Modifier::BOLD)),
+            rect,
+        );
+    }
+
+    let caption_h = 6.min(full.height);
+    let caption = Rect {
+        x: full.x,
+        y: (full.y + full.height).saturating_sub(caption_h),
+        width: full.width,
+        height: caption_h,
+    };
+    frame.render_widget(Clear, caption);
+
+    let block = Block::default()
+        .title(format!(" Tour ({}/{}) ", state.step + 1, TOUR_STEPS.len()))
+        .title_style(theme::title_style())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(t.border_focused))
+        .style(Style::default().bg(t.bg));
+    let inner = block.inner(caption);
+    frame.render_widget(block, caption);
+
+    let mut hints = vec![Span::styled("Esc", Style::default().fg(t.accent)), Span::raw(" exit  ")];
+    if !state.is_first() {
+        hints.push(Span::styled("\u{2190}/h", Style::default().fg(t.accent)));
+        hints.push(Span::raw(" back  "));
+    }
+    hints.push(Span::styled("\u{2192}/l/Enter", Style::default().fg(t.accent)));
+    hints.push(Span::raw(if state.is_last() { " finish" } else { " next" }));
+
+    let lines = vec![
+        Line::from(Span::styled(
+            step.title,
+            Style::default().fg(t.fg).add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::styled(step.body, Style::default().fg(t.fg))),
+        Line::from(hints),
+    ];
+    frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: true }), inner);
+}
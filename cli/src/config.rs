@@ -1,5 +1,6 @@
 use std::path::PathBuf;
 
+use ring::rand::SecureRandom;
 use serde::{Deserialize, Serialize};
 
 pub const DEFAULT_ENGINE_PORT: u16 = 3099;
@@ -28,6 +29,7 @@ impl Default for ConfirmationsConfig {
 }
 
 const DEFAULT_TICK_RATE_MS: u64 = 250;
+const DEFAULT_WATCH_DEBOUNCE_MS: u64 = 500;
 /// No hardcoded `SaaS` URL default.  Users set it via:
 ///   1. `PROJECT_API_URL` env var, or
 ///   2. `complior login` (persists to settings.toml), or
@@ -44,16 +46,70 @@ struct GlobalConfig {
     engine_port: u16,
     engine_host: String,
     tick_rate_ms: u64,
+    /// Quiet period after the last file change before a watch-mode
+    /// rescan fires; changes seen during the window are batched into one scan.
+    watch_debounce_ms: u64,
     theme: String,
+    /// Date/number formatting convention: `"auto"` (detect from
+    /// `LC_ALL`/`LC_NUMERIC`/`LANG`), `"us"`, `"eu"`, or an explicit locale
+    /// tag like `"de-DE"`. Default: `"auto"`.
+    locale: String,
+    /// UTC offset used for activity/chat/changes-feed timestamps: `"auto"`
+    /// (detect from the system clock), `"utc"`, or an explicit `+HH:MM`/
+    /// `-HH:MM` offset. Default: `"auto"`.
+    timezone: String,
+    /// Opt-in anonymous usage telemetry (feature counts, error categories —
+    /// never code or chat content). Off by default; `/telemetry show`
+    /// previews the exact payload before anything would be sent.
+    telemetry_enabled: bool,
     navigation: String,
     sidebar_visible: bool,
     animations_enabled: bool,
+    /// Auto-scroll the chat/terminal panels to the bottom on new output,
+    /// until the user scrolls up manually. Default: true.
+    auto_scroll_enabled: bool,
+    /// Seconds a toast notification stays visible before auto-dismissing.
+    /// Default: 3, matching `components::toast::AUTO_DISMISS_SECS`.
+    toast_duration_secs: u64,
     scroll_acceleration: f32,
     llm_provider: Option<String>,
     llm_model: Option<String>,
     project_api_url: String,
     offline_mode: bool,
+    /// Encrypt saved session files (chat transcripts) at rest. Default: false.
+    session_encryption: bool,
+    /// Send a native desktop notification when a headless `scan`/`fix` run
+    /// finishes. Default: false (opt-in, requires a system notifier).
+    notifications_enabled: bool,
+    /// HTTP(S) proxy URL for engine/provider requests, e.g.
+    /// `http://proxy.corp.example:8080`. Overridable via `HTTPS_PROXY`/
+    /// `HTTP_PROXY` env vars, which take precedence when set.
+    http_proxy: Option<String>,
+    /// Path to a PEM-encoded custom CA bundle to trust, for corporate
+    /// TLS-intercepting proxies. Overridable via `COMPLIOR_CA_BUNDLE` env var.
+    ca_bundle_path: Option<String>,
     confirmations: ConfirmationsConfig,
+    /// Widgets shown in the Dashboard grid, and their order. Only consulted
+    /// when `dashboard_grid_mode` is enabled. Default: all widgets, default order.
+    #[cfg(feature = "tui")]
+    dashboard_layout: Vec<crate::types::DashboardWidget>,
+    /// When `true`, the Dashboard renders a configurable widget grid built
+    /// from `dashboard_layout` instead of the fixed default layout.
+    #[cfg(feature = "tui")]
+    dashboard_grid_mode: bool,
+    /// Project paths registered for the multi-project `/projects` switcher,
+    /// most-recently-added last. Machine-wide, not per-project.
+    #[cfg(feature = "tui")]
+    registered_projects: Vec<String>,
+    /// Per-kind idle-suggestion snoozes (`/snooze`), e.g. "don't show
+    /// deadline warnings for a week". Machine-wide, like `animations_enabled`.
+    #[cfg(feature = "tui")]
+    snoozed_suggestions: Vec<crate::components::suggestions::SnoozedSuggestion>,
+    /// Opt-in local Unix-socket JSON-RPC server (`.complior/control.sock`)
+    /// so editor plugins and scripts can drive the running TUI instance.
+    /// Default: false — it's a local trust boundary the operator opts into.
+    #[cfg(feature = "tui")]
+    control_socket_enabled: bool,
 }
 
 impl Default for GlobalConfig {
@@ -62,16 +118,36 @@ impl Default for GlobalConfig {
             engine_port: DEFAULT_ENGINE_PORT,
             engine_host: "127.0.0.1".to_string(),
             tick_rate_ms: DEFAULT_TICK_RATE_MS,
+            watch_debounce_ms: DEFAULT_WATCH_DEBOUNCE_MS,
             theme: "dark".to_string(),
+            locale: "auto".to_string(),
+            timezone: "auto".to_string(),
+            telemetry_enabled: false,
             navigation: "standard".to_string(),
             sidebar_visible: true,
             animations_enabled: true,
+            auto_scroll_enabled: true,
+            toast_duration_secs: 3,
             scroll_acceleration: 1.5,
             llm_provider: None,
             llm_model: None,
             project_api_url: DEFAULT_PROJECT_API_URL.to_string(),
             offline_mode: false,
+            session_encryption: false,
+            notifications_enabled: false,
+            http_proxy: None,
+            ca_bundle_path: None,
             confirmations: ConfirmationsConfig::default(),
+            #[cfg(feature = "tui")]
+            dashboard_layout: crate::types::DashboardWidget::ALL.to_vec(),
+            #[cfg(feature = "tui")]
+            dashboard_grid_mode: false,
+            #[cfg(feature = "tui")]
+            registered_projects: Vec::new(),
+            #[cfg(feature = "tui")]
+            snoozed_suggestions: Vec::new(),
+            #[cfg(feature = "tui")]
+            control_socket_enabled: false,
         }
     }
 }
@@ -91,10 +167,50 @@ struct ProjectConfig {
     industry: String,
     scan_scope: Vec<String>,
     watch_on_start: bool,
+    /// Glob patterns (e.g. `src/**/*.rs`); only matching paths trigger a
+    /// watch-mode rescan. Empty means "everything not excluded".
+    watch_include: Vec<String>,
+    /// Glob patterns; matching paths never trigger a watch-mode rescan,
+    /// on top of the watcher's built-in `node_modules`/`target`/etc. skip list.
+    watch_exclude: Vec<String>,
+    /// Additional filesystem roots (e.g. sibling `app`/`infra` directories)
+    /// watched and scanned alongside `project_path`, so a project split
+    /// across directories that share no common ancestor still behaves as
+    /// one logical project. Relative paths are resolved against
+    /// `project_path`. The file browser shows each configured root as its
+    /// own top-level node.
+    watch_roots: Vec<String>,
+    /// How the watcher treats symlinked directories: `"ignore"` (default —
+    /// `notify`'s native behavior), `"follow"` (resolve and watch them,
+    /// bounded against symlink cycles), or `"limit"` (follow, but only
+    /// `watch_symlink_depth` levels of symlink indirection deep).
+    watch_symlinks: String,
+    /// Symlink indirection depth watched when `watch_symlinks = "limit"`.
+    watch_symlink_depth: u32,
+    /// Force polling instead of OS-native file-change events, at this
+    /// interval. `None` (default) uses inotify/`FSEvents`/etc., which is
+    /// what local filesystems want. NFS mounts and some Docker bind mounts
+    /// don't deliver those events reliably, silently missing auto-scans —
+    /// set this to fall back to polling for such a project.
+    watch_poll_interval_ms: Option<u64>,
+    /// Colon-commands (without the leading `:`) run in order right after
+    /// launch, e.g. `["scan", "view 2"]` to open straight into a scanned
+    /// Scan view. Combined with any `--exec` flag, which runs after these.
+    startup_commands: Vec<String>,
     llm_provider: Option<String>,
     llm_model: Option<String>,
     project_api_url: Option<String>,
     offline_mode: Option<bool>,
+    session_encryption: Option<bool>,
+    notifications_enabled: Option<bool>,
+    /// Outcome of the `/risk-classify` questionnaire (Annex III / GPAI
+    /// systemic risk): `"high-risk"`, `"gpai-systemic"`, or `"minimal-risk"`.
+    /// `None` until the questionnaire has been run once.
+    risk_classification: Option<String>,
+    /// Configurable team roster for finding assignment (`/assign`). Names
+    /// suggested in the assign quick-pick; free-text assignees not on this
+    /// list are still accepted.
+    team: Vec<String>,
 }
 
 impl Default for ProjectConfig {
@@ -109,10 +225,21 @@ impl Default for ProjectConfig {
             industry: "general".to_string(),
             scan_scope: vec!["deps".to_string(), "env".to_string(), "source".to_string()],
             watch_on_start: false,
+            watch_include: Vec::new(),
+            watch_exclude: Vec::new(),
+            watch_roots: Vec::new(),
+            watch_symlinks: "ignore".to_string(),
+            watch_symlink_depth: 3,
+            watch_poll_interval_ms: None,
+            startup_commands: Vec::new(),
             llm_provider: None,
             llm_model: None,
             project_api_url: None,
             offline_mode: None,
+            session_encryption: None,
+            notifications_enabled: None,
+            risk_classification: None,
+            team: Vec::new(),
         }
     }
 }
@@ -133,12 +260,28 @@ pub struct TuiConfig {
     pub engine_port: u16,
     pub engine_host: String,
     pub tick_rate_ms: u64,
+    /// Quiet period after the last file change before a watch-mode
+    /// rescan fires; changes seen during the window are batched into one scan.
+    pub watch_debounce_ms: u64,
     pub project_path: Option<String>,
     pub theme: String,
+    /// Date/number formatting convention: `"auto"`, `"us"`, `"eu"`, or an
+    /// explicit locale tag like `"de-DE"`.
+    pub locale: String,
+    /// UTC offset for activity/chat/changes-feed timestamps: `"auto"`,
+    /// `"utc"`, or an explicit `+HH:MM`/`-HH:MM` offset.
+    pub timezone: String,
+    /// Opt-in anonymous usage telemetry, off by default. See `crate::telemetry`.
+    pub telemetry_enabled: bool,
     pub sidebar_visible: bool,
     pub watch_on_start: bool,
     pub onboarding_completed: bool,
     pub animations_enabled: bool,
+    /// Auto-scroll the chat/terminal panels to the bottom on new output,
+    /// until the user scrolls up manually.
+    pub auto_scroll_enabled: bool,
+    /// Seconds a toast notification stays visible before auto-dismissing.
+    pub toast_duration_secs: u64,
     pub scroll_acceleration: f32,
 
     // Onboarding-derived config fields
@@ -149,8 +292,33 @@ pub struct TuiConfig {
     pub role: String,
     pub industry: String,
     pub scan_scope: Vec<String>,
+    /// Watch-mode include glob patterns. Empty means "everything not excluded".
+    pub watch_include: Vec<String>,
+    /// Watch-mode exclude glob patterns, on top of the watcher's built-in
+    /// `node_modules`/`target`/etc. skip list.
+    pub watch_exclude: Vec<String>,
+    /// Additional filesystem roots watched and scanned alongside
+    /// `project_path` — see `ProjectConfig::watch_roots`.
+    pub watch_roots: Vec<String>,
+    /// Symlink handling for the watcher — see `ProjectConfig::watch_symlinks`.
+    pub watch_symlinks: String,
+    /// Symlink depth watched when `watch_symlinks = "limit"`.
+    pub watch_symlink_depth: u32,
+    /// Polling interval that replaces OS-native file events, for network
+    /// filesystems — see `ProjectConfig::watch_poll_interval_ms`.
+    pub watch_poll_interval_ms: Option<u64>,
+    /// Colon-commands (without the leading `:`) run in order right after
+    /// launch, e.g. `["scan", "view 2"]`. The `--exec` flag runs after these.
+    pub startup_commands: Vec<String>,
     /// Last completed onboarding step (for resume on partial completion).
     pub onboarding_last_step: Option<usize>,
+    /// Outcome of the `/risk-classify` questionnaire (Annex III / GPAI
+    /// systemic risk). `None` until the questionnaire has been run once.
+    #[serde(default)]
+    pub risk_classification: Option<String>,
+    /// Configurable team roster for finding assignment (`/assign`).
+    #[serde(default)]
+    pub team: Vec<String>,
 
     #[serde(skip)]
     pub engine_url_override: Option<String>,
@@ -168,6 +336,28 @@ pub struct TuiConfig {
     /// TUI shows empty state until a local scan is run.
     #[serde(default)]
     pub offline_mode: bool,
+    /// Bearer token sent as `Authorization: Bearer <token>` on every request
+    /// to the engine (local or `--engine-url` remote). Loaded at startup from
+    /// `~/.config/complior/credentials` (key: `COMPLIOR_ENGINE_TOKEN`) or the
+    /// env var of the same name. Not persisted to TOML.
+    #[serde(skip)]
+    pub engine_auth_token: Option<String>,
+    /// When `true`, saved session files (chat transcripts, provider config)
+    /// are encrypted at rest. Project overrides global when set.
+    #[serde(default)]
+    pub session_encryption: bool,
+    /// When `true`, send a native desktop notification when a headless
+    /// `scan`/`fix` run finishes. Project overrides global when set.
+    #[serde(default)]
+    pub notifications_enabled: bool,
+    /// HTTP(S) proxy URL for engine/provider requests. Env
+    /// `HTTPS_PROXY`/`HTTP_PROXY` take precedence when set.
+    #[serde(default)]
+    pub http_proxy: Option<String>,
+    /// Path to a PEM-encoded custom CA bundle to trust. Env
+    /// `COMPLIOR_CA_BUNDLE` takes precedence when set.
+    #[serde(default)]
+    pub ca_bundle_path: Option<String>,
 
     // ── LLM Settings ──────────────────────────────────────────────────────────
     /// Preferred LLM provider (anthropic/openai/openrouter). Not sensitive.
@@ -181,6 +371,38 @@ pub struct TuiConfig {
     /// Controls which destructive operations show a y/N confirmation dialog.
     #[serde(default)]
     pub confirmations: ConfirmationsConfig,
+
+    /// Set from the hidden `--perf-overlay` flag; not persisted to TOML.
+    #[serde(skip)]
+    pub perf_overlay: bool,
+
+    // ── Dashboard grid (arrange mode) ─────────────────────────────────────────
+    /// Widgets shown in the Dashboard grid, and their order. Only consulted
+    /// when `dashboard_grid_mode` is enabled.
+    #[cfg(feature = "tui")]
+    #[serde(default)]
+    pub dashboard_layout: Vec<crate::types::DashboardWidget>,
+    /// When `true`, the Dashboard renders a configurable widget grid built
+    /// from `dashboard_layout` instead of the fixed default layout.
+    #[cfg(feature = "tui")]
+    #[serde(default)]
+    pub dashboard_grid_mode: bool,
+
+    // ── Multi-project workspace (`/projects` switcher) ─────────────────────────
+    /// Project paths registered for quick switching, most-recently-added last.
+    #[cfg(feature = "tui")]
+    #[serde(default)]
+    pub registered_projects: Vec<String>,
+
+    /// Per-kind idle-suggestion snoozes (`/snooze`). Expired entries are
+    /// left in place (cheap to skip) rather than pruned on load.
+    #[cfg(feature = "tui")]
+    #[serde(default)]
+    pub snoozed_suggestions: Vec<crate::components::suggestions::SnoozedSuggestion>,
+
+    /// Opt-in local Unix-socket JSON-RPC server for external automation.
+    #[cfg(feature = "tui")]
+    pub control_socket_enabled: bool,
 }
 
 impl Default for TuiConfig {
@@ -189,12 +411,18 @@ impl Default for TuiConfig {
             engine_port: DEFAULT_ENGINE_PORT,
             engine_host: "127.0.0.1".to_string(),
             tick_rate_ms: DEFAULT_TICK_RATE_MS,
+            watch_debounce_ms: DEFAULT_WATCH_DEBOUNCE_MS,
             project_path: None,
             theme: "dark".to_string(),
+            locale: "auto".to_string(),
+            timezone: "auto".to_string(),
+            telemetry_enabled: false,
             sidebar_visible: true,
             watch_on_start: false,
             onboarding_completed: false,
             animations_enabled: true,
+            auto_scroll_enabled: true,
+            toast_duration_secs: 3,
             scroll_acceleration: 1.5,
             navigation: "standard".to_string(),
             project_type: "existing".to_string(),
@@ -203,14 +431,39 @@ impl Default for TuiConfig {
             role: "deployer".to_string(),
             industry: "general".to_string(),
             scan_scope: vec!["deps".to_string(), "env".to_string(), "source".to_string()],
+            watch_include: Vec::new(),
+            watch_exclude: Vec::new(),
+            watch_roots: Vec::new(),
+            watch_symlinks: "ignore".to_string(),
+            watch_symlink_depth: 3,
+            watch_poll_interval_ms: None,
+            startup_commands: Vec::new(),
             onboarding_last_step: None,
+            risk_classification: None,
+            team: Vec::new(),
             engine_url_override: None,
             llm_provider: None,
             llm_model: None,
             project_api_url: DEFAULT_PROJECT_API_URL.to_string(),
             api_key: None,
             offline_mode: false,
+            engine_auth_token: None,
+            session_encryption: false,
+            notifications_enabled: false,
+            http_proxy: None,
+            ca_bundle_path: None,
             confirmations: ConfirmationsConfig::default(),
+            perf_overlay: false,
+            #[cfg(feature = "tui")]
+            dashboard_layout: crate::types::DashboardWidget::ALL.to_vec(),
+            #[cfg(feature = "tui")]
+            dashboard_grid_mode: false,
+            #[cfg(feature = "tui")]
+            registered_projects: Vec::new(),
+            #[cfg(feature = "tui")]
+            snoozed_suggestions: Vec::new(),
+            #[cfg(feature = "tui")]
+            control_socket_enabled: false,
         }
     }
 }
@@ -329,19 +582,35 @@ fn merge_config(global: GlobalConfig, project: ProjectConfig) -> TuiConfig {
         engine_port: global.engine_port,
         engine_host: global.engine_host,
         tick_rate_ms: global.tick_rate_ms,
+        watch_debounce_ms: global.watch_debounce_ms,
         project_path: None,
         theme: global.theme,
+        locale: global.locale,
+        timezone: global.timezone,
+        telemetry_enabled: global.telemetry_enabled,
         sidebar_visible: global.sidebar_visible,
         animations_enabled: global.animations_enabled,
+        auto_scroll_enabled: global.auto_scroll_enabled,
+        toast_duration_secs: global.toast_duration_secs,
         scroll_acceleration: global.scroll_acceleration,
         navigation: global.navigation,
         project_api_url: project.project_api_url.unwrap_or(global.project_api_url),
         offline_mode: project.offline_mode.unwrap_or(global.offline_mode),
+        session_encryption: project
+            .session_encryption
+            .unwrap_or(global.session_encryption),
+        notifications_enabled: project
+            .notifications_enabled
+            .unwrap_or(global.notifications_enabled),
+        http_proxy: global.http_proxy,
+        ca_bundle_path: global.ca_bundle_path,
         confirmations: global.confirmations,
 
         // Project fields
         onboarding_completed: project.onboarding_completed,
         onboarding_last_step: project.onboarding_last_step,
+        risk_classification: project.risk_classification,
+        team: project.team,
         project_type: project.project_type,
         jurisdiction: project.jurisdiction,
         requirements: project.requirements,
@@ -349,6 +618,13 @@ fn merge_config(global: GlobalConfig, project: ProjectConfig) -> TuiConfig {
         industry: project.industry,
         scan_scope: project.scan_scope,
         watch_on_start: project.watch_on_start,
+        watch_include: project.watch_include,
+        watch_exclude: project.watch_exclude,
+        watch_roots: project.watch_roots,
+        watch_symlinks: project.watch_symlinks,
+        watch_symlink_depth: project.watch_symlink_depth,
+        watch_poll_interval_ms: project.watch_poll_interval_ms,
+        startup_commands: project.startup_commands,
 
         // LLM: project overrides global when set
         llm_provider: project.llm_provider.or(global.llm_provider),
@@ -356,7 +632,20 @@ fn merge_config(global: GlobalConfig, project: ProjectConfig) -> TuiConfig {
 
         // Runtime-only (not persisted)
         engine_url_override: None,
+        perf_overlay: false,
         api_key: None,
+        engine_auth_token: None,
+
+        #[cfg(feature = "tui")]
+        dashboard_layout: global.dashboard_layout,
+        #[cfg(feature = "tui")]
+        dashboard_grid_mode: global.dashboard_grid_mode,
+        #[cfg(feature = "tui")]
+        registered_projects: global.registered_projects,
+        #[cfg(feature = "tui")]
+        snoozed_suggestions: global.snoozed_suggestions,
+        #[cfg(feature = "tui")]
+        control_socket_enabled: global.control_socket_enabled,
     }
 }
 
@@ -380,12 +669,119 @@ pub fn load_config() -> TuiConfig {
         config.offline_mode = true;
     }
 
+    // Standard proxy env vars take precedence over settings.toml.
+    for var in ["HTTPS_PROXY", "https_proxy", "HTTP_PROXY", "http_proxy"] {
+        if let Ok(proxy) = std::env::var(var)
+            && !proxy.is_empty()
+        {
+            config.http_proxy = Some(proxy);
+            break;
+        }
+    }
+    if let Ok(path) = std::env::var("COMPLIOR_CA_BUNDLE")
+        && !path.is_empty()
+    {
+        config.ca_bundle_path = Some(path);
+    }
+
     // Load API key from credentials file (never stored in TOML)
     config.api_key = load_api_key();
 
+    // Load engine auth token (env takes precedence, then credentials file)
+    config.engine_auth_token = load_engine_auth_token();
+
     config
 }
 
+/// One config value's provenance, for `/config sources`.
+pub struct ConfigSource {
+    pub key: &'static str,
+    pub source: &'static str,
+    pub value: String,
+}
+
+/// Report which layer (env var / project.toml / settings.toml / credentials
+/// file / default) each project-overridable or env-overridable setting
+/// currently comes from. Re-reads `global`/`project` config from disk rather
+/// than reusing the already-merged `TuiConfig`, since the merge discards
+/// which layer won.
+pub fn config_sources() -> Vec<ConfigSource> {
+    let global = load_global_config();
+    let project = load_project_config();
+
+    let mut sources = Vec::new();
+
+    macro_rules! project_override {
+        ($key:literal, $field:ident) => {
+            sources.push(match &project.$field {
+                Some(v) => ConfigSource {
+                    key: $key,
+                    source: "project.toml",
+                    value: format!("{v:?}"),
+                },
+                None => ConfigSource {
+                    key: $key,
+                    source: "settings.toml (default)",
+                    value: format!("{:?}", global.$field),
+                },
+            });
+        };
+    }
+    project_override!("project_api_url", project_api_url);
+    project_override!("offline_mode", offline_mode);
+    project_override!("session_encryption", session_encryption);
+    project_override!("notifications_enabled", notifications_enabled);
+    project_override!("llm_provider", llm_provider);
+    project_override!("llm_model", llm_model);
+
+    sources.push(match std::env::var("OFFLINE_MODE") {
+        Ok(v) if v == "1" => ConfigSource {
+            key: "offline_mode (env)",
+            source: "env OFFLINE_MODE",
+            value: "true".to_string(),
+        },
+        _ => ConfigSource {
+            key: "offline_mode (env)",
+            source: "unset",
+            value: String::new(),
+        },
+    });
+    sources.push(match std::env::var("PROJECT_API_URL") {
+        Ok(v) if !v.is_empty() => ConfigSource {
+            key: "project_api_url (env)",
+            source: "env PROJECT_API_URL",
+            value: v,
+        },
+        _ => ConfigSource {
+            key: "project_api_url (env)",
+            source: "unset",
+            value: String::new(),
+        },
+    });
+    let proxy_env = ["HTTPS_PROXY", "https_proxy", "HTTP_PROXY", "http_proxy"]
+        .into_iter()
+        .find_map(|var| {
+            std::env::var(var)
+                .ok()
+                .filter(|v| !v.is_empty())
+                .map(|v| (var, v))
+        });
+    sources.push(match proxy_env {
+        Some((var, v)) => ConfigSource {
+            key: "http_proxy",
+            source: "env",
+            value: format!("{var}={v}"),
+        },
+        None => ConfigSource {
+            key: "http_proxy",
+            source: "settings.toml (default)",
+            value: format!("{:?}", global.http_proxy),
+        },
+    });
+
+    sources
+}
+
 // ── Save ────────────────────────────────────────────────────────────────────
 
 async fn save_global_config(config: &GlobalConfig) {
@@ -430,6 +826,37 @@ pub async fn save_theme(name: &str) {
     save_global_config(&global).await;
 }
 
+/// Register a project path for the Projects switcher (global, machine-wide).
+/// Moves `path` to the end (most-recently-added) if already registered.
+#[cfg(feature = "tui")]
+pub async fn add_registered_project(path: &str) {
+    let mut global = load_global_config();
+    global.registered_projects.retain(|p| p != path);
+    global.registered_projects.push(path.to_string());
+    save_global_config(&global).await;
+}
+
+/// Remove a project path from the Projects switcher (global).
+#[cfg(feature = "tui")]
+pub async fn remove_registered_project(path: &str) {
+    let mut global = load_global_config();
+    global.registered_projects.retain(|p| p != path);
+    save_global_config(&global).await;
+}
+
+/// Persist a per-kind suggestion snooze (global, machine-wide — mirrors
+/// `/animations`' scope), replacing any existing snooze for that kind.
+#[cfg(feature = "tui")]
+pub async fn save_snoozed_suggestion(
+    kind: crate::components::suggestions::SuggestionKind,
+    until_secs: u64,
+) {
+    let mut global = load_global_config();
+    global.snoozed_suggestions =
+        crate::components::suggestions::snooze_until(&global.snoozed_suggestions, kind, until_secs);
+    save_global_config(&global).await;
+}
+
 /// Mark onboarding as completed in config (project).
 pub async fn mark_onboarding_complete() {
     let mut project = load_project_config();
@@ -494,6 +921,21 @@ pub async fn save_onboarding_results(wizard: &crate::views::onboarding::Onboardi
     save_project_config(&project).await;
 }
 
+/// Save the `/risk-classify` questionnaire result (project): records the
+/// classification and merges its requirement tags into `requirements` so the
+/// active scan profile picks up the stricter obligation set.
+#[cfg(feature = "tui")]
+pub async fn save_risk_classification(level: crate::views::risk_classification::RiskLevel) {
+    let mut project = load_project_config();
+    project.risk_classification = Some(level.config_value().to_string());
+    for tag in level.requirement_tags() {
+        if !project.requirements.iter().any(|r| r == tag) {
+            project.requirements.push((*tag).to_string());
+        }
+    }
+    save_project_config(&project).await;
+}
+
 /// Save LLM config (provider + model to global TOML, API key to credentials file).
 pub async fn save_llm_config(provider: Option<&str>, model: Option<&str>, api_key: Option<&str>) {
     let mut global = load_global_config();
@@ -510,6 +952,24 @@ pub async fn save_llm_config(provider: Option<&str>, model: Option<&str>, api_ke
     }
 }
 
+/// Persist the Settings overlay's runtime preferences (`/settings`) in one
+/// write: animations/auto-scroll/sidebar/tick-rate/toast-duration are global
+/// (like `animations_enabled`), `watch_on_start` is project-scoped, matching
+/// each field's existing home.
+pub async fn save_settings(config: &TuiConfig) {
+    let mut global = load_global_config();
+    global.animations_enabled = config.animations_enabled;
+    global.auto_scroll_enabled = config.auto_scroll_enabled;
+    global.sidebar_visible = config.sidebar_visible;
+    global.tick_rate_ms = config.tick_rate_ms;
+    global.toast_duration_secs = config.toast_duration_secs;
+    save_global_config(&global).await;
+
+    let mut project = load_project_config();
+    project.watch_on_start = config.watch_on_start;
+    save_project_config(&project).await;
+}
+
 // ── Legacy migration ────────────────────────────────────────────────────────
 
 /// If `tui.toml` exists and `settings.toml` doesn't, split the old config
@@ -535,16 +995,36 @@ fn migrate_legacy_config() {
         engine_port: legacy.engine_port,
         engine_host: legacy.engine_host,
         tick_rate_ms: legacy.tick_rate_ms,
+        watch_debounce_ms: legacy.watch_debounce_ms,
         theme: legacy.theme,
+        locale: legacy.locale,
+        timezone: legacy.timezone,
+        telemetry_enabled: legacy.telemetry_enabled,
         navigation: legacy.navigation,
         sidebar_visible: legacy.sidebar_visible,
         animations_enabled: legacy.animations_enabled,
+        auto_scroll_enabled: legacy.auto_scroll_enabled,
+        toast_duration_secs: legacy.toast_duration_secs,
         scroll_acceleration: legacy.scroll_acceleration,
         llm_provider: legacy.llm_provider.clone(),
         llm_model: legacy.llm_model.clone(),
         project_api_url: legacy.project_api_url,
         offline_mode: legacy.offline_mode,
+        session_encryption: legacy.session_encryption,
+        notifications_enabled: legacy.notifications_enabled,
+        http_proxy: legacy.http_proxy,
+        ca_bundle_path: legacy.ca_bundle_path,
         confirmations: legacy.confirmations,
+        #[cfg(feature = "tui")]
+        dashboard_layout: legacy.dashboard_layout,
+        #[cfg(feature = "tui")]
+        dashboard_grid_mode: legacy.dashboard_grid_mode,
+        #[cfg(feature = "tui")]
+        registered_projects: legacy.registered_projects,
+        #[cfg(feature = "tui")]
+        snoozed_suggestions: legacy.snoozed_suggestions,
+        #[cfg(feature = "tui")]
+        control_socket_enabled: legacy.control_socket_enabled,
     };
 
     // Split into project
@@ -558,10 +1038,21 @@ fn migrate_legacy_config() {
         industry: legacy.industry,
         scan_scope: legacy.scan_scope,
         watch_on_start: legacy.watch_on_start,
+        watch_include: legacy.watch_include,
+        watch_exclude: legacy.watch_exclude,
+        watch_roots: legacy.watch_roots,
+        watch_symlinks: legacy.watch_symlinks,
+        watch_symlink_depth: legacy.watch_symlink_depth,
+        watch_poll_interval_ms: legacy.watch_poll_interval_ms,
+        startup_commands: legacy.startup_commands,
         llm_provider: None, // don't duplicate — global is the source for legacy configs
         llm_model: None,
         project_api_url: None,
         offline_mode: None,
+        session_encryption: None,
+        notifications_enabled: None,
+        risk_classification: None, // legacy configs predate the questionnaire
+        team: legacy.team,
     };
 
     // Write global (sync — migration runs before async runtime matters)
@@ -610,6 +1101,36 @@ pub fn load_api_key() -> Option<String> {
     None
 }
 
+/// Read the bearer token for authenticating to a (possibly remote,
+/// `--engine-url`) engine. Checks `COMPLIOR_ENGINE_TOKEN` env var first, then
+/// falls back to `~/.config/complior/credentials` (same `KEY=value` format
+/// as [`load_api_key`]).
+pub fn load_engine_auth_token() -> Option<String> {
+    if let Ok(val) = std::env::var("COMPLIOR_ENGINE_TOKEN")
+        && !val.is_empty()
+    {
+        return Some(val);
+    }
+
+    let path = dirs::config_dir()?.join("complior").join("credentials");
+    let content = std::fs::read_to_string(path).ok()?;
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('#') || trimmed.is_empty() {
+            continue;
+        }
+        if let Some((key, value)) = trimmed.split_once('=')
+            && key.trim() == "COMPLIOR_ENGINE_TOKEN"
+        {
+            let v = value.trim().to_string();
+            if !v.is_empty() {
+                return Some(v);
+            }
+        }
+    }
+    None
+}
+
 /// Validate an API key for a given provider.
 /// Returns `Ok(())` if plausible, or `Err(reason)` if clearly invalid.
 pub fn validate_api_key(provider: &str, key: &str) -> Result<(), String> {
@@ -893,6 +1414,179 @@ pub fn is_authenticated() -> bool {
     }
 }
 
+/// Service/account pair the session key is filed under in the OS keychain.
+const SESSION_KEY_SERVICE: &str = "complior";
+const SESSION_KEY_ACCOUNT: &str = "session-key";
+
+/// Look up the session-encryption key in the OS keychain (macOS Keychain
+/// via `security`, Linux Secret Service via `secret-tool`). Returns `None`
+/// on any platform without a keychain integration, or when the lookup
+/// tool isn't installed or has no entry yet — callers fall back to the
+/// credentials file in that case.
+fn keyring_get_session_key() -> Option<[u8; 32]> {
+    use base64::Engine;
+
+    #[cfg(target_os = "macos")]
+    let output = std::process::Command::new("security")
+        .args([
+            "find-generic-password",
+            "-a",
+            SESSION_KEY_ACCOUNT,
+            "-s",
+            SESSION_KEY_SERVICE,
+            "-w",
+        ])
+        .output()
+        .ok()?;
+
+    #[cfg(target_os = "linux")]
+    let output = std::process::Command::new("secret-tool")
+        .args([
+            "lookup",
+            "service",
+            SESSION_KEY_SERVICE,
+            "account",
+            SESSION_KEY_ACCOUNT,
+        ])
+        .output()
+        .ok()?;
+
+    #[cfg(not(any(target_os = "macos", target_os = "linux")))]
+    return None;
+
+    #[cfg(any(target_os = "macos", target_os = "linux"))]
+    {
+        if !output.status.success() {
+            return None;
+        }
+        let encoded = String::from_utf8(output.stdout).ok()?;
+        let decoded = base64::engine::general_purpose::STANDARD
+            .decode(encoded.trim())
+            .ok()?;
+        decoded.try_into().ok()
+    }
+}
+
+/// Store a freshly-generated session key in the OS keychain. Returns
+/// `false` (never an error) on any platform without a keychain
+/// integration, or when the store tool isn't installed — callers fall
+/// back to the credentials file in that case.
+fn keyring_set_session_key(key: &[u8; 32]) -> bool {
+    use base64::Engine;
+    let encoded = base64::engine::general_purpose::STANDARD.encode(key);
+
+    #[cfg(target_os = "macos")]
+    {
+        std::process::Command::new("security")
+            .args([
+                "add-generic-password",
+                "-a",
+                SESSION_KEY_ACCOUNT,
+                "-s",
+                SESSION_KEY_SERVICE,
+                "-w",
+                &encoded,
+                "-U",
+            ])
+            .output()
+            .is_ok_and(|o| o.status.success())
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        use std::io::Write;
+        let Ok(mut child) = std::process::Command::new("secret-tool")
+            .args([
+                "store",
+                "--label=Complior session key",
+                "service",
+                SESSION_KEY_SERVICE,
+                "account",
+                SESSION_KEY_ACCOUNT,
+            ])
+            .stdin(std::process::Stdio::piped())
+            .spawn()
+        else {
+            return false;
+        };
+        let Some(mut stdin) = child.stdin.take() else {
+            return false;
+        };
+        if stdin.write_all(encoded.as_bytes()).is_err() {
+            return false;
+        }
+        drop(stdin);
+        child.wait().is_ok_and(|s| s.success())
+    }
+
+    #[cfg(not(any(target_os = "macos", target_os = "linux")))]
+    false
+}
+
+/// Get the session-encryption key, generating a fresh random one on first
+/// use.
+///
+/// Tries the OS keychain first (macOS Keychain / Linux Secret Service) so
+/// the key never shares a file with the encrypted session data it
+/// protects. Falls back to a plain `KEY=VALUE` line in
+/// `~/.config/complior/credentials` (0600 on Unix, same file
+/// `save_tokens`/`load_tokens` use) when no keychain is available --
+/// e.g. no `security`/`secret-tool` binary, a headless Linux box with no
+/// Secret Service daemon running, or an unsupported OS.
+pub fn get_or_create_session_key() -> Result<[u8; 32], String> {
+    use base64::Engine;
+
+    if let Some(key) = keyring_get_session_key() {
+        return Ok(key);
+    }
+
+    let path = credentials_path().ok_or("Cannot determine config directory")?;
+    let existing = std::fs::read_to_string(&path).unwrap_or_default();
+
+    for line in existing.lines() {
+        if let Some((key, value)) = line.trim().split_once('=')
+            && key.trim() == "COMPLIOR_SESSION_KEY"
+        {
+            let decoded = base64::engine::general_purpose::STANDARD
+                .decode(value.trim())
+                .map_err(|e| format!("Cannot decode session key: {e}"))?;
+            return decoded
+                .try_into()
+                .map_err(|_| "Session key has wrong length".to_string());
+        }
+    }
+
+    let mut key = [0u8; 32];
+    ring::rand::SystemRandom::new()
+        .fill(&mut key)
+        .map_err(|_| "Cannot generate session key".to_string())?;
+
+    if keyring_set_session_key(&key) {
+        return Ok(key);
+    }
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| format!("Cannot create config dir: {e}"))?;
+    }
+    let encoded = base64::engine::general_purpose::STANDARD.encode(key);
+    let mut content = existing;
+    if !content.is_empty() && !content.ends_with('\n') {
+        content.push('\n');
+    }
+    content.push_str(&format!("COMPLIOR_SESSION_KEY={encoded}\n"));
+    std::fs::write(&path, content).map_err(|e| format!("Cannot write credentials: {e}"))?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let perms = std::fs::Permissions::from_mode(0o600);
+        std::fs::set_permissions(&path, perms)
+            .map_err(|e| format!("Cannot set credentials permissions: {e}"))?;
+    }
+
+    Ok(key)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1017,6 +1711,55 @@ mod tests {
         assert_eq!(project.industry, "general");
         assert_eq!(project.scan_scope, vec!["deps", "env", "source"]);
         assert!(!project.watch_on_start);
+        assert!(project.watch_include.is_empty());
+        assert!(project.watch_exclude.is_empty());
+        assert!(project.watch_roots.is_empty());
+        assert_eq!(project.watch_symlinks, "ignore");
+        assert_eq!(project.watch_symlink_depth, 3);
+        assert!(project.watch_poll_interval_ms.is_none());
+        assert!(project.startup_commands.is_empty());
+    }
+
+    #[test]
+    fn test_project_config_watch_patterns_deserialization() {
+        let toml_str = r#"
+            watch_include = ["src/**/*.rs", "*.toml"]
+            watch_exclude = ["**/*_test.rs"]
+        "#;
+        let config: ProjectConfig = toml::from_str(toml_str).expect("valid toml");
+        assert_eq!(config.watch_include, vec!["src/**/*.rs", "*.toml"]);
+        assert_eq!(config.watch_exclude, vec!["**/*_test.rs"]);
+    }
+
+    #[test]
+    fn test_project_config_watch_roots_deserialization() {
+        let toml_str = r#"
+            watch_roots = ["../infra", "../shared-libs"]
+        "#;
+        let config: ProjectConfig = toml::from_str(toml_str).expect("valid toml");
+        assert_eq!(config.watch_roots, vec!["../infra", "../shared-libs"]);
+    }
+
+    #[test]
+    fn test_project_config_symlink_and_poll_deserialization() {
+        let toml_str = r#"
+            watch_symlinks = "limit"
+            watch_symlink_depth = 5
+            watch_poll_interval_ms = 2000
+        "#;
+        let config: ProjectConfig = toml::from_str(toml_str).expect("valid toml");
+        assert_eq!(config.watch_symlinks, "limit");
+        assert_eq!(config.watch_symlink_depth, 5);
+        assert_eq!(config.watch_poll_interval_ms, Some(2000));
+    }
+
+    #[test]
+    fn test_project_config_startup_commands_deserialization() {
+        let toml_str = r#"
+            startup_commands = ["scan", "view 2"]
+        "#;
+        let config: ProjectConfig = toml::from_str(toml_str).expect("valid toml");
+        assert_eq!(config.startup_commands, vec!["scan", "view 2"]);
     }
 
     #[test]
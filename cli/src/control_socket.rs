@@ -0,0 +1,168 @@
+//! Local control socket for external automation — editor plugins and
+//! scripts drive the running TUI instance over a line-delimited JSON
+//! protocol on a per-project Unix socket. Opt-in via
+//! `control_socket_enabled` (`settings.toml`), since it's a local trust
+//! boundary an operator should choose to open.
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::{mpsc, oneshot};
+
+/// A request read from the socket: `command` is a colon-command line
+/// (`"scan"`, `"view 2"`, `"report"`, ...) — the same syntax as `:cmd` in
+/// Normal mode — except for `"get-score"`, which is a read-only query
+/// answered directly instead of being dispatched as a command.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ControlRequest {
+    /// Echoed back on the response, so callers can match replies to
+    /// in-flight requests over the same connection.
+    #[serde(default)]
+    pub id: Option<serde_json::Value>,
+    pub command: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ControlResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<serde_json::Value>,
+    pub ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// A request paired with the channel its response goes back on — the event
+/// loop owns `App` and answers these inline, the same way `AppCommand`
+/// results flow back through `bg_rx`.
+pub struct ControlMessage {
+    pub request: ControlRequest,
+    pub reply: oneshot::Sender<ControlResponse>,
+}
+
+/// Path to the per-project control socket.
+pub fn socket_path(project_path: &Path) -> PathBuf {
+    project_path.join(".complior").join("control.sock")
+}
+
+/// Spawn the control socket's accept loop. Returns `None` (and logs a
+/// warning) if the socket can't be bound, e.g. another instance already
+/// owns it.
+#[cfg(unix)]
+pub fn spawn_control_server(
+    path: &Path,
+    tx: mpsc::UnboundedSender<ControlMessage>,
+) -> Option<tokio::task::JoinHandle<()>> {
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::UnixListener;
+
+    // Stale socket file from a crashed previous run — bind() fails on an
+    // existing path even if nothing is listening on it.
+    let _ = std::fs::remove_file(path);
+    if let Some(parent) = path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+
+    let listener = match UnixListener::bind(path) {
+        Ok(l) => l,
+        Err(e) => {
+            tracing::warn!("Control socket: failed to bind {}: {e}", path.display());
+            return None;
+        }
+    };
+
+    Some(tokio::spawn(async move {
+        loop {
+            let Ok((stream, _)) = listener.accept().await else {
+                break;
+            };
+            let tx = tx.clone();
+            tokio::spawn(async move {
+                let (read_half, mut write_half) = stream.into_split();
+                let mut lines = BufReader::new(read_half).lines();
+                while let Ok(Some(line)) = lines.next_line().await {
+                    if line.trim().is_empty() {
+                        continue;
+                    }
+                    let response = match serde_json::from_str::<ControlRequest>(&line) {
+                        Ok(request) => {
+                            let (reply_tx, reply_rx) = oneshot::channel();
+                            if tx
+                                .send(ControlMessage {
+                                    request,
+                                    reply: reply_tx,
+                                })
+                                .is_err()
+                            {
+                                break;
+                            }
+                            reply_rx.await.unwrap_or_else(|_| ControlResponse {
+                                id: None,
+                                ok: false,
+                                result: None,
+                                error: Some("control server shut down".to_string()),
+                            })
+                        }
+                        Err(e) => ControlResponse {
+                            id: None,
+                            ok: false,
+                            result: None,
+                            error: Some(format!("invalid request: {e}")),
+                        },
+                    };
+                    let Ok(mut serialized) = serde_json::to_string(&response) else {
+                        break;
+                    };
+                    serialized.push('\n');
+                    if write_half.write_all(serialized.as_bytes()).await.is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+    }))
+}
+
+/// Named pipes aren't wired up yet — the control socket is Unix-only for now.
+#[cfg(not(unix))]
+pub fn spawn_control_server(
+    _path: &Path,
+    _tx: mpsc::UnboundedSender<ControlMessage>,
+) -> Option<tokio::task::JoinHandle<()>> {
+    tracing::warn!("Control socket is not yet supported on this platform");
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn socket_path_is_under_dot_complior() {
+        let path = socket_path(Path::new("/home/user/project"));
+        assert_eq!(
+            path,
+            PathBuf::from("/home/user/project/.complior/control.sock")
+        );
+    }
+
+    #[test]
+    fn control_request_deserializes_without_id() {
+        let req: ControlRequest = serde_json::from_str(r#"{"command":"scan"}"#).unwrap();
+        assert!(req.id.is_none());
+        assert_eq!(req.command, "scan");
+    }
+
+    #[test]
+    fn control_response_omits_absent_fields() {
+        let resp = ControlResponse {
+            id: None,
+            ok: true,
+            result: None,
+            error: None,
+        };
+        let json = serde_json::to_string(&resp).unwrap();
+        assert_eq!(json, r#"{"ok":true}"#);
+    }
+}
@@ -0,0 +1,105 @@
+//! Panic hook that leaves the terminal usable and writes a crash report.
+//!
+//! Without this, a panic mid-TUI-session exits with raw mode still enabled
+//! and the alternate screen still active, leaving the user's shell mangled.
+//! This restores the terminal first, then writes the backtrace plus a trail
+//! of recent activity to `dirs::cache_dir()/complior/` and prints the path,
+//! so a crash is diagnosable after the fact instead of just scrolling past.
+
+use std::collections::VecDeque;
+use std::sync::{Mutex, OnceLock};
+
+const MAX_RECENT_ACTIVITY: usize = 20;
+
+static RECENT_ACTIVITY: OnceLock<Mutex<VecDeque<String>>> = OnceLock::new();
+
+/// Record a line of recent activity to include in a crash report, should one
+/// turn out to be needed. Cheap enough to call alongside every activity-log
+/// entry the TUI already tracks.
+pub fn record_activity(line: impl Into<String>) {
+    let log =
+        RECENT_ACTIVITY.get_or_init(|| Mutex::new(VecDeque::with_capacity(MAX_RECENT_ACTIVITY)));
+    let mut log = log.lock().expect("recent activity lock");
+    log.push_back(line.into());
+    if log.len() > MAX_RECENT_ACTIVITY {
+        log.pop_front();
+    }
+}
+
+/// Install the panic hook. Replaces a bare `color_eyre::install()` call —
+/// still installs eyre's error-report hook for `Result` returns, but wraps
+/// the panic hook itself to restore the terminal and persist a crash report
+/// before printing.
+pub fn install_panic_hook() -> color_eyre::Result<()> {
+    let (panic_hook, eyre_hook) = color_eyre::config::HookBuilder::default().into_hooks();
+    eyre_hook.install()?;
+
+    std::panic::set_hook(Box::new(move |panic_info| {
+        #[cfg(feature = "tui")]
+        restore_terminal();
+
+        let report = panic_hook.panic_report(panic_info).to_string();
+        if let Some(path) = write_crash_report(&report) {
+            eprintln!("Crash report written to {}", path.display());
+        }
+        eprintln!("{report}");
+    }));
+    Ok(())
+}
+
+/// Best-effort terminal restore — leave the alt screen, disable raw mode and
+/// mouse capture, show the cursor. Errors are ignored: we're already
+/// panicking, and there's nothing sensible left to do about a failed
+/// restore.
+#[cfg(feature = "tui")]
+fn restore_terminal() {
+    use crossterm::cursor::Show;
+    use crossterm::event::{DisableBracketedPaste, DisableMouseCapture};
+    use crossterm::execute;
+    use crossterm::terminal::{LeaveAlternateScreen, disable_raw_mode};
+
+    let _ = disable_raw_mode();
+    let _ = execute!(
+        std::io::stdout(),
+        LeaveAlternateScreen,
+        DisableMouseCapture,
+        DisableBracketedPaste,
+        Show
+    );
+}
+
+/// Write `report` plus the recent-activity trail to a timestamped file under
+/// `dirs::cache_dir()/complior/`. Returns `None` (silently) if there's no
+/// cache dir or the write fails — a crash report that can't be written is
+/// not worth panicking over a second time.
+fn write_crash_report(report: &str) -> Option<std::path::PathBuf> {
+    let dir = dirs::cache_dir()?.join("complior");
+    std::fs::create_dir_all(&dir).ok()?;
+
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let path = dir.join(format!("crash-{now}-{}.log", std::process::id()));
+
+    let mut contents = report.to_string();
+    contents.push_str("\n\n-- recent activity --\n");
+    let recent_lines: Vec<String> = RECENT_ACTIVITY.get().map_or_else(Vec::new, |log| {
+        log.lock()
+            .expect("recent activity lock")
+            .iter()
+            .cloned()
+            .collect()
+    });
+    if recent_lines.is_empty() {
+        contents.push_str("(none)\n");
+    } else {
+        for line in &recent_lines {
+            contents.push_str(line);
+            contents.push('\n');
+        }
+    }
+
+    std::fs::write(&path, contents).ok()?;
+    Some(path)
+}
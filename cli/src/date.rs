@@ -0,0 +1,120 @@
+//! Calendar-date arithmetic backed by the `time` crate.
+//!
+//! Replaces the hand-rolled Gregorian day-count formulas that used to be
+//! duplicated across the dashboard, timeline, and findings-state modules —
+//! those drifted around century/leap-year boundaries and silently accepted
+//! invalid dates like `2025-02-30`. Everything here routes through
+//! `time::Date`, which gets both right.
+
+use time::format_description::well_known::Rfc3339;
+use time::{Date, Duration, Month, OffsetDateTime};
+
+/// Julian day number of the Unix epoch (1970-01-01), for converting between
+/// `time::Date` and "days since epoch" the rest of the app works in.
+const UNIX_EPOCH_JULIAN_DAY: i64 = 2_440_588;
+
+/// Today's date, in days since the Unix epoch.
+pub fn today_epoch_days() -> i64 {
+    let secs = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    #[allow(clippy::cast_possible_wrap)]
+    let days = (secs / 86_400) as i64;
+    days
+}
+
+/// Parse a `YYYY-MM-DD` string into a `Date`, `None` if it isn't a real
+/// calendar date.
+fn parse_ymd(s: &str) -> Option<Date> {
+    let mut parts = s.splitn(3, '-');
+    let y: i32 = parts.next()?.parse().ok()?;
+    let m: u8 = parts.next()?.parse().ok()?;
+    let d: u8 = parts.next()?.parse().ok()?;
+    Date::from_calendar_date(y, Month::try_from(m).ok()?, d).ok()
+}
+
+/// Days since the Unix epoch for a `Date`.
+fn epoch_days(date: Date) -> i64 {
+    i64::from(date.to_julian_day()) - UNIX_EPOCH_JULIAN_DAY
+}
+
+/// Parse a `YYYY-MM-DD` string into days since the Unix epoch.
+pub fn parse_ymd_epoch_days(s: &str) -> Option<i64> {
+    parse_ymd(s).map(epoch_days)
+}
+
+/// Parse a `YYYY-MM-DD` string into its `(year, month, day)` parts, for
+/// locale-aware display.
+pub fn parse_ymd_parts(s: &str) -> Option<(i64, u8, u8)> {
+    let date = parse_ymd(s)?;
+    Some((i64::from(date.year()), u8::from(date.month()), date.day()))
+}
+
+/// Days since the Unix epoch for a `(year, month, day)` triple, `None` if
+/// it isn't a real calendar date.
+pub fn ymd_epoch_days(y: u16, m: u8, d: u8) -> Option<i64> {
+    let date = Date::from_calendar_date(i32::from(y), Month::try_from(m).ok()?, d).ok()?;
+    Some(epoch_days(date))
+}
+
+/// Convert a UTC RFC 3339 timestamp (as produced by the engine's audit log)
+/// into a `"YYYY-MM-DD HH:MM"` string in the configured local timezone.
+pub fn format_utc_timestamp_local(iso: &str) -> Option<String> {
+    let utc = OffsetDateTime::parse(iso, &Rfc3339).ok()?;
+    let local = utc + Duration::seconds(crate::timezone::utc_offset_seconds());
+    Some(format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}",
+        local.year(),
+        u8::from(local.month()),
+        local.day(),
+        local.hour(),
+        local.minute()
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_ymd_epoch_days_matches_known_value() {
+        // 1970-01-02 is one day after the epoch.
+        assert_eq!(parse_ymd_epoch_days("1970-01-02"), Some(1));
+    }
+
+    #[test]
+    fn parse_ymd_epoch_days_rejects_invalid_date() {
+        assert_eq!(parse_ymd_epoch_days("2025-02-30"), None);
+    }
+
+    #[test]
+    fn parse_ymd_epoch_days_handles_leap_year() {
+        // 2024 is a leap year: Feb 29 exists, and Mar 1 is one day later.
+        let feb29 = parse_ymd_epoch_days("2024-02-29").expect("leap day should parse");
+        let mar1 = parse_ymd_epoch_days("2024-03-01").expect("should parse");
+        assert_eq!(mar1 - feb29, 1);
+    }
+
+    #[test]
+    fn ymd_epoch_days_agrees_with_parse_ymd() {
+        assert_eq!(
+            ymd_epoch_days(2026, 8, 2),
+            parse_ymd_epoch_days("2026-08-02")
+        );
+    }
+
+    #[test]
+    fn format_utc_timestamp_local_is_utc_with_no_offset() {
+        crate::timezone::init_timezone("utc");
+        assert_eq!(
+            format_utc_timestamp_local("2026-08-02T14:30:00Z"),
+            Some("2026-08-02 14:30".to_string())
+        );
+    }
+
+    #[test]
+    fn format_utc_timestamp_local_rejects_garbage() {
+        assert_eq!(format_utc_timestamp_local("not a timestamp"), None);
+    }
+}
@@ -0,0 +1,193 @@
+//! `--demo` mode: seed the app with a synthetic project so the TUI can be
+//! screenshotted or shown at a conference without a running engine or LLM
+//! provider. No network calls, no file I/O beyond what `App::new` already
+//! does.
+
+use crate::app::App;
+use crate::types::{
+    CategoryScore, CheckResultType, ChatMessage, EngineConnectionStatus, Finding, MessageRole,
+    ScanResult, Severity, Zone,
+};
+
+fn demo_findings() -> Vec<Finding> {
+    vec![
+        Finding {
+            check_id: "l4-raw-api-call".to_string(),
+            r#type: CheckResultType::Fail,
+            message: "Raw OpenAI HTTP call bypasses the compliance-wrapped SDK".to_string(),
+            severity: Severity::Critical,
+            obligation_id: Some("OBL-014".to_string()),
+            article_reference: Some("Art. 50(1)".to_string()),
+            fix: Some("Wrap the call with the Complior SDK client".to_string()),
+            file: Some("src/agents/support_bot.py".to_string()),
+            line: Some(42),
+            code_context: None,
+            fix_diff: None,
+            priority: Some(1),
+            confidence: Some(0.95),
+            confidence_level: Some("high".to_string()),
+            evidence: None,
+            explanation: None,
+            agent_id: Some("support-bot".to_string()),
+            doc_quality: None,
+            l5_analyzed: None,
+        },
+        Finding {
+            check_id: "missing-fria".to_string(),
+            r#type: CheckResultType::Fail,
+            message: "No Fundamental Rights Impact Assessment on file".to_string(),
+            severity: Severity::High,
+            obligation_id: Some("OBL-032".to_string()),
+            article_reference: Some("Art. 27".to_string()),
+            fix: Some("Generate FRIA with `complior fix --doc fria`".to_string()),
+            file: None,
+            line: None,
+            code_context: None,
+            fix_diff: None,
+            priority: Some(2),
+            confidence: None,
+            confidence_level: None,
+            evidence: None,
+            explanation: None,
+            agent_id: None,
+            doc_quality: None,
+            l5_analyzed: None,
+        },
+        Finding {
+            check_id: "l3-dependency-unpinned".to_string(),
+            r#type: CheckResultType::Fail,
+            message: "LLM SDK dependency is unpinned in package.json".to_string(),
+            severity: Severity::Medium,
+            obligation_id: Some("OBL-058".to_string()),
+            article_reference: None,
+            fix: Some("Pin `openai` to an exact version".to_string()),
+            file: Some("package.json".to_string()),
+            line: Some(18),
+            code_context: None,
+            fix_diff: None,
+            priority: Some(3),
+            confidence: None,
+            confidence_level: None,
+            evidence: None,
+            explanation: None,
+            agent_id: Some("support-bot".to_string()),
+            doc_quality: None,
+            l5_analyzed: None,
+        },
+        Finding {
+            check_id: "l2-onboarding-doc-shallow".to_string(),
+            r#type: CheckResultType::Fail,
+            message: "User-facing AI disclosure doc is present but shallow".to_string(),
+            severity: Severity::Low,
+            obligation_id: Some("OBL-009".to_string()),
+            article_reference: Some("Art. 50(1)".to_string()),
+            fix: None,
+            file: Some("docs/ai-disclosure.md".to_string()),
+            line: None,
+            code_context: None,
+            fix_diff: None,
+            priority: Some(4),
+            confidence: None,
+            confidence_level: None,
+            evidence: None,
+            explanation: None,
+            agent_id: None,
+            doc_quality: Some("SHALLOW".to_string()),
+            l5_analyzed: None,
+        },
+    ]
+}
+
+fn demo_scan_result() -> ScanResult {
+    use crate::types::ScoreBreakdown;
+
+    ScanResult {
+        score: ScoreBreakdown {
+            total_score: 68.0,
+            zone: Zone::Yellow,
+            category_scores: vec![
+                CategoryScore {
+                    category: "Transparency".to_string(),
+                    weight: 0.3,
+                    score: 55.0,
+                    obligation_count: 12,
+                    passed_count: 7,
+                },
+                CategoryScore {
+                    category: "Risk Management".to_string(),
+                    weight: 0.4,
+                    score: 72.0,
+                    obligation_count: 20,
+                    passed_count: 15,
+                },
+                CategoryScore {
+                    category: "Documentation".to_string(),
+                    weight: 0.3,
+                    score: 80.0,
+                    obligation_count: 10,
+                    passed_count: 8,
+                },
+            ],
+            critical_cap_applied: true,
+            total_checks: 42,
+            passed_checks: 30,
+            failed_checks: 4,
+            skipped_checks: 8,
+            confidence_summary: None,
+        },
+        findings: demo_findings(),
+        project_path: "demo-project".to_string(),
+        scanned_at: ChatMessage::new(MessageRole::System, String::new()).timestamp,
+        duration: 1834,
+        files_scanned: 127,
+        files_excluded: Some(9),
+        deep_analysis: Some(false),
+        l5_cost: None,
+        regulation_version: None,
+        tier: Some(1),
+        external_tool_results: None,
+        agent_summaries: None,
+        filter_context: None,
+        top_actions: None,
+        disclaimer: None,
+    }
+}
+
+fn demo_chat_transcript() -> Vec<ChatMessage> {
+    vec![
+        ChatMessage::new(
+            MessageRole::System,
+            "Demo mode — synthetic project, no engine or LLM provider connected.".to_string(),
+        ),
+        ChatMessage::new(MessageRole::User, "/scan".to_string()),
+        ChatMessage::new(
+            MessageRole::Assistant,
+            "Scanned 127 files, found 4 open findings. Score: 68/100 (yellow zone)."
+                .to_string(),
+        ),
+        ChatMessage::new(
+            MessageRole::User,
+            "What's the highest priority fix?".to_string(),
+        ),
+        ChatMessage::new(
+            MessageRole::Assistant,
+            "The raw OpenAI HTTP call in support_bot.py bypasses the compliance-wrapped \
+             SDK — that's the Critical finding capping your score. Run /fix to see the \
+             deterministic patch."
+                .to_string(),
+        ),
+    ]
+}
+
+/// Populate `app` with a synthetic project so the dashboard, scan view, fix
+/// queue, and chat transcript all have something to show — no engine or LLM
+/// provider required. Called instead of the normal engine-launch/first-scan
+/// startup path when `--demo` is passed.
+pub fn seed(app: &mut App) {
+    app.project_path = std::path::PathBuf::from("demo-project");
+    app.engine_status = EngineConnectionStatus::Connected;
+    app.last_scan = Some(demo_scan_result());
+    app.score_history = vec![41.0, 52.0, 58.0, 63.0, 68.0];
+    app.messages = demo_chat_transcript();
+    app.config.onboarding_completed = true;
+}
@@ -4,7 +4,7 @@ use crate::config::TuiConfig;
 use crate::error::{Result, TuiError};
 use crate::types::{
     CostEstimateResult, DebtResult, EngineStatus, MultiFrameworkScoreResult, ReadinessResult,
-    ScanResult,
+    RemoteWidget, ScanResult, SuggestionItem, UndoHistoryEntry, UndoResponse,
 };
 
 /// Check whether an error is a transient connection error worth retrying.
@@ -23,23 +23,79 @@ pub struct EngineClient {
     base_url: String,
 }
 
+/// Apply `config.http_proxy`/`config.ca_bundle_path` to a client builder, so
+/// engine/provider requests work behind corporate TLS-intercepting proxies.
+/// Falls back silently to the builder's defaults if a configured proxy URL
+/// or CA bundle can't be parsed/read — a broken proxy config should not
+/// prevent the CLI from starting.
+pub fn apply_proxy_and_ca(
+    mut builder: reqwest::ClientBuilder,
+    config: &TuiConfig,
+) -> reqwest::ClientBuilder {
+    if let Some(ref proxy_url) = config.http_proxy
+        && let Ok(proxy) = reqwest::Proxy::all(proxy_url)
+    {
+        builder = builder.proxy(proxy);
+    }
+    if let Some(ref ca_path) = config.ca_bundle_path
+        && let Ok(pem) = std::fs::read(ca_path)
+        && let Ok(cert) = reqwest::Certificate::from_pem(&pem)
+    {
+        builder = builder.add_root_certificate(cert);
+    }
+    builder
+}
+
+/// Apply `config.engine_auth_token` as a default `Authorization: Bearer`
+/// header, so it's attached to every request the client makes — including
+/// the SSE streaming ones — without touching each call site. Needed for
+/// `--engine-url` against a shared remote engine that sits behind auth.
+fn apply_auth_headers(
+    builder: reqwest::ClientBuilder,
+    config: &TuiConfig,
+) -> reqwest::ClientBuilder {
+    let Some(ref token) = config.engine_auth_token else {
+        return builder;
+    };
+    let Ok(mut value) = reqwest::header::HeaderValue::from_str(&format!("Bearer {token}")) else {
+        return builder;
+    };
+    value.set_sensitive(true);
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.insert(reqwest::header::AUTHORIZATION, value);
+    builder.default_headers(headers)
+}
+
 impl EngineClient {
     pub fn new(config: &TuiConfig) -> Self {
-        let client = Client::builder()
-            .timeout(std::time::Duration::from_secs(30))
-            .build()
-            .expect("HTTP client must be constructable");
+        let client = apply_auth_headers(
+            apply_proxy_and_ca(
+                Client::builder().timeout(std::time::Duration::from_secs(30)),
+                config,
+            ),
+            config,
+        )
+        .build()
+        .expect("HTTP client must be constructable");
         Self {
             client,
             base_url: config.engine_url(),
         }
     }
 
-    pub fn from_url(url: &str) -> Self {
-        let client = Client::builder()
-            .timeout(std::time::Duration::from_secs(30))
-            .build()
-            .expect("HTTP client must be constructable");
+    /// Build a client for a resolved engine URL — the local daemon, or a
+    /// `--engine-url` remote one. `config` supplies proxy/CA settings and the
+    /// bearer token used to authenticate to a shared remote engine.
+    pub fn from_url(url: &str, config: &TuiConfig) -> Self {
+        let client = apply_auth_headers(
+            apply_proxy_and_ca(
+                Client::builder().timeout(std::time::Duration::from_secs(30)),
+                config,
+            ),
+            config,
+        )
+        .build()
+        .expect("HTTP client must be constructable");
         Self {
             client,
             base_url: url.trim_end_matches('/').to_string(),
@@ -96,7 +152,7 @@ impl EngineClient {
         Ok(file_resp.content)
     }
 
-    pub async fn undo(&self, id: Option<u32>) -> Result<serde_json::Value> {
+    pub async fn undo(&self, id: Option<u32>) -> Result<UndoResponse> {
         let mut body = serde_json::json!({});
         if let Some(id) = id {
             body["id"] = serde_json::Value::Number(serde_json::Number::from(id));
@@ -107,29 +163,29 @@ impl EngineClient {
             .json(&body)
             .send()
             .await?;
-        let result = resp.json::<serde_json::Value>().await?;
+        let result = resp.json::<UndoResponse>().await?;
         Ok(result)
     }
 
-    pub async fn undo_history(&self) -> Result<Vec<serde_json::Value>> {
+    pub async fn undo_history(&self) -> Result<Vec<UndoHistoryEntry>> {
         let resp = self
             .client
             .get(format!("{}/fix/history", self.base_url))
             .timeout(std::time::Duration::from_secs(5))
             .send()
             .await?;
-        let result = resp.json::<Vec<serde_json::Value>>().await?;
+        let result = resp.json::<Vec<UndoHistoryEntry>>().await?;
         Ok(result)
     }
 
-    pub async fn suggestions(&self) -> Result<Vec<serde_json::Value>> {
+    pub async fn suggestions(&self) -> Result<Vec<SuggestionItem>> {
         let resp = self
             .client
             .get(format!("{}/suggestions", self.base_url))
             .timeout(std::time::Duration::from_secs(5))
             .send()
             .await?;
-        let result = resp.json::<Vec<serde_json::Value>>().await?;
+        let result = resp.json::<Vec<SuggestionItem>>().await?;
         Ok(result)
     }
 
@@ -349,6 +405,20 @@ impl EngineClient {
         Ok(result)
     }
 
+    /// Fetch server-driven dashboard widgets from engine. Absent/older
+    /// engines don't expose `/widgets`; callers should treat any error here
+    /// the same as an empty list rather than surfacing it loudly.
+    pub async fn dashboard_widgets(&self) -> Result<Vec<RemoteWidget>> {
+        let resp = self
+            .client
+            .get(format!("{}/widgets", self.base_url))
+            .timeout(std::time::Duration::from_secs(10))
+            .send()
+            .await?;
+        let result = resp.json::<Vec<RemoteWidget>>().await?;
+        Ok(result)
+    }
+
     /// Generic GET returning raw bytes — used for binary downloads (e.g. tar.gz).
     pub async fn get_bytes(&self, endpoint: &str) -> Result<Vec<u8>> {
         let url = format!("{}{endpoint}", self.base_url);
@@ -391,9 +461,13 @@ impl EngineClient {
             .send()
             .await?;
         if resp.status() == 429 {
-            return Err(crate::error::TuiError::Engine(
-                "Rate limit exceeded — max chat requests per hour reached".to_string(),
-            ));
+            let retry_after = resp
+                .headers()
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.parse::<u64>().ok())
+                .unwrap_or(5);
+            return Err(crate::error::TuiError::RateLimited(retry_after));
         }
         if !resp.status().is_success() {
             let status = resp.status();
@@ -458,3 +532,108 @@ impl EngineClient {
         Ok(result)
     }
 }
+
+type BoxFuture<'a, T> = std::pin::Pin<Box<dyn std::future::Future<Output = T> + Send + 'a>>;
+
+/// Object-safe abstraction over [`EngineClient`]'s request/response surface,
+/// so code that only needs this common set of endpoints (status, scan, undo,
+/// generic JSON) can be written against `dyn EngineBackend` instead of the
+/// concrete HTTP client. Async fns aren't dyn-compatible on their own, hence
+/// the manually boxed futures.
+///
+/// Only `EngineClient` implements this today — there's no second backend to
+/// swap in yet, so `App.engine_client` stays concretely typed. The trait is
+/// the seam a future backend (a native Rust scanner, a multi-tenant SaaS
+/// proxy) would implement; swapping backends at that point means changing
+/// `App.engine_client` to `Box<dyn EngineBackend>` and selecting the impl
+/// from config/CLI flags in `main.rs`, the same way `EngineManager` already
+/// picks between demo/mock/external/spawned engines.
+///
+/// Streaming (`post_stream*`) and raw-bytes (`get_bytes`) endpoints are left
+/// off the trait: they return `reqwest::Response` values that are inherently
+/// HTTP-specific and wouldn't make sense for a non-HTTP backend.
+pub trait EngineBackend: Send + Sync {
+    fn status(&self) -> BoxFuture<'_, Result<EngineStatus>>;
+    fn scan<'a>(&'a self, path: &'a str) -> BoxFuture<'a, Result<ScanResult>>;
+    fn run_command<'a>(&'a self, command: &'a str) -> BoxFuture<'a, Result<String>>;
+    fn read_file<'a>(&'a self, path: &'a str) -> BoxFuture<'a, Result<String>>;
+    fn undo(&self, id: Option<u32>) -> BoxFuture<'_, Result<UndoResponse>>;
+    fn undo_history(&self) -> BoxFuture<'_, Result<Vec<UndoHistoryEntry>>>;
+    fn suggestions(&self) -> BoxFuture<'_, Result<Vec<SuggestionItem>>>;
+    fn whatif<'a>(&'a self, scenario: &'a str) -> BoxFuture<'a, Result<serde_json::Value>>;
+    fn fix_dry_run(&self, known_score: f64) -> BoxFuture<'_, Result<serde_json::Value>>;
+    fn get_json<'a>(&'a self, endpoint: &'a str) -> BoxFuture<'a, Result<serde_json::Value>>;
+    fn post_json<'a>(
+        &'a self,
+        endpoint: &'a str,
+        body: &'a serde_json::Value,
+    ) -> BoxFuture<'a, Result<serde_json::Value>>;
+    fn framework_scores(&self) -> BoxFuture<'_, Result<MultiFrameworkScoreResult>>;
+    fn cost_estimate(&self) -> BoxFuture<'_, Result<CostEstimateResult>>;
+    fn debt_score(&self) -> BoxFuture<'_, Result<DebtResult>>;
+    fn readiness_score<'a>(
+        &'a self,
+        name: &'a str,
+        path: &'a str,
+    ) -> BoxFuture<'a, Result<ReadinessResult>>;
+    fn dashboard_widgets(&self) -> BoxFuture<'_, Result<Vec<RemoteWidget>>>;
+}
+
+impl EngineBackend for EngineClient {
+    fn status(&self) -> BoxFuture<'_, Result<EngineStatus>> {
+        Box::pin(self.status())
+    }
+    fn scan<'a>(&'a self, path: &'a str) -> BoxFuture<'a, Result<ScanResult>> {
+        Box::pin(self.scan(path))
+    }
+    fn run_command<'a>(&'a self, command: &'a str) -> BoxFuture<'a, Result<String>> {
+        Box::pin(self.run_command(command))
+    }
+    fn read_file<'a>(&'a self, path: &'a str) -> BoxFuture<'a, Result<String>> {
+        Box::pin(self.read_file(path))
+    }
+    fn undo(&self, id: Option<u32>) -> BoxFuture<'_, Result<UndoResponse>> {
+        Box::pin(self.undo(id))
+    }
+    fn undo_history(&self) -> BoxFuture<'_, Result<Vec<UndoHistoryEntry>>> {
+        Box::pin(self.undo_history())
+    }
+    fn suggestions(&self) -> BoxFuture<'_, Result<Vec<SuggestionItem>>> {
+        Box::pin(self.suggestions())
+    }
+    fn whatif<'a>(&'a self, scenario: &'a str) -> BoxFuture<'a, Result<serde_json::Value>> {
+        Box::pin(self.whatif(scenario))
+    }
+    fn fix_dry_run(&self, known_score: f64) -> BoxFuture<'_, Result<serde_json::Value>> {
+        Box::pin(self.fix_dry_run(known_score))
+    }
+    fn get_json<'a>(&'a self, endpoint: &'a str) -> BoxFuture<'a, Result<serde_json::Value>> {
+        Box::pin(self.get_json(endpoint))
+    }
+    fn post_json<'a>(
+        &'a self,
+        endpoint: &'a str,
+        body: &'a serde_json::Value,
+    ) -> BoxFuture<'a, Result<serde_json::Value>> {
+        Box::pin(self.post_json(endpoint, body))
+    }
+    fn framework_scores(&self) -> BoxFuture<'_, Result<MultiFrameworkScoreResult>> {
+        Box::pin(self.framework_scores())
+    }
+    fn cost_estimate(&self) -> BoxFuture<'_, Result<CostEstimateResult>> {
+        Box::pin(self.cost_estimate())
+    }
+    fn debt_score(&self) -> BoxFuture<'_, Result<DebtResult>> {
+        Box::pin(self.debt_score())
+    }
+    fn readiness_score<'a>(
+        &'a self,
+        name: &'a str,
+        path: &'a str,
+    ) -> BoxFuture<'a, Result<ReadinessResult>> {
+        Box::pin(self.readiness_score(name, path))
+    }
+    fn dashboard_widgets(&self) -> BoxFuture<'_, Result<Vec<RemoteWidget>>> {
+        Box::pin(self.dashboard_widgets())
+    }
+}
@@ -218,7 +218,11 @@ impl EngineManager {
         self.start()
     }
 
-    /// Kill the child process group and clean up.
+    /// Kill the child process group and clean up. Immediate — no grace
+    /// period for in-flight requests. Used by `Drop` and `try_restart`,
+    /// where waiting isn't an option (no async context, or the engine is
+    /// already presumed wedged). Prefer [`Self::shutdown_gracefully`] on the
+    /// normal exit path.
     pub fn shutdown(&mut self) {
         if let Some(ref mut child) = self.child {
             #[cfg(unix)]
@@ -247,6 +251,68 @@ impl EngineManager {
         }
     }
 
+    /// Graceful shutdown with a drain phase: signal the engine (SIGTERM) so
+    /// it can stop accepting new work and let a mid-flight scan or fix write
+    /// finish, then poll for it to exit on its own for up to
+    /// `DRAIN_TIMEOUT` before force-killing. Unlike the fixed 200ms sleep in
+    /// [`Self::shutdown`], this only escalates to SIGKILL once the timeout
+    /// is actually exhausted, so a fix that's mid-write to disk isn't cut
+    /// off just because we asked it to stop. No-op for external engines —
+    /// we don't own that process.
+    pub async fn shutdown_gracefully(&mut self) {
+        const DRAIN_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+        const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(100);
+
+        if matches!(self.status, EngineProcessStatus::External) {
+            return;
+        }
+        let Some(ref mut child) = self.child else {
+            return;
+        };
+
+        #[cfg(unix)]
+        {
+            let pid = child.id() as i32;
+            tracing::info!("Signalling engine (pid {pid}) to shut down, draining in-flight requests...");
+            unsafe {
+                libc::kill(-pid, libc::SIGTERM);
+            }
+
+            let mut waited = std::time::Duration::ZERO;
+            while waited < DRAIN_TIMEOUT {
+                if matches!(child.try_wait(), Ok(Some(_))) {
+                    tracing::info!("Engine exited cleanly after draining.");
+                    self.child = None;
+                    if self.status != EngineProcessStatus::External {
+                        self.status = EngineProcessStatus::Stopped;
+                    }
+                    return;
+                }
+                tokio::time::sleep(POLL_INTERVAL).await;
+                waited += POLL_INTERVAL;
+            }
+
+            tracing::warn!(
+                "Engine did not exit within {}s of SIGTERM, force-killing.",
+                DRAIN_TIMEOUT.as_secs()
+            );
+            unsafe {
+                libc::kill(-pid, libc::SIGKILL);
+            }
+            let _ = child.wait();
+        }
+        #[cfg(not(unix))]
+        {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+
+        self.child = None;
+        if self.status != EngineProcessStatus::External {
+            self.status = EngineProcessStatus::Stopped;
+        }
+    }
+
     /// Return the child process PID (if running), for signal handling.
     pub fn child_pid(&self) -> Option<u32> {
         self.child.as_ref().map(Child::id)
@@ -321,6 +387,14 @@ mod tests {
         assert_eq!(mgr.status, EngineProcessStatus::External);
     }
 
+    #[tokio::test]
+    async fn test_external_mode_graceful_shutdown_is_noop() {
+        let mut mgr = EngineManager::external(3099);
+        mgr.shutdown_gracefully().await;
+        // We don't own an external engine's process — should remain External.
+        assert_eq!(mgr.status, EngineProcessStatus::External);
+    }
+
     #[test]
     fn test_start_with_pid_missing_engine_returns_error() {
         let mut mgr = EngineManager::new(std::path::Path::new("/tmp/nonexistent"));
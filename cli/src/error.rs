@@ -11,6 +11,126 @@ pub enum TuiError {
 
     #[error("Engine error: {0}")]
     Engine(String),
+
+    #[error("Rate limited — retry in {0}s")]
+    RateLimited(u64),
+}
+
+/// Broad category of a [`TuiError`], used to pick a toast's remediation
+/// hint and to drive the footer's persistent degraded-mode badge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorCategory {
+    Network,
+    Engine,
+    ProviderAuth,
+    RateLimit,
+    Filesystem,
+}
+
+impl ErrorCategory {
+    /// Short remediation hint appended to the error toast for this category.
+    pub const fn remediation_hint(self) -> &'static str {
+        match self {
+            Self::Network => "Check the engine is running and reachable.",
+            Self::Engine => "Check daemon logs; try restarting the engine.",
+            Self::ProviderAuth => "Check your LLM provider API key (/llm settings).",
+            Self::RateLimit => "Provider rate limit hit -- wait a moment and retry.",
+            Self::Filesystem => "Check file permissions and available disk space.",
+        }
+    }
+
+    /// Label shown in the footer's persistent `[DEGRADED: ...]` badge.
+    pub const fn badge_label(self) -> &'static str {
+        match self {
+            Self::Network => "DEGRADED: NETWORK",
+            Self::Engine => "DEGRADED: ENGINE",
+            Self::ProviderAuth => "DEGRADED: AUTH",
+            Self::RateLimit => "DEGRADED: RATE LIMIT",
+            Self::Filesystem => "DEGRADED: FS",
+        }
+    }
+}
+
+impl TuiError {
+    /// Classify this error for toast remediation hints and the footer's
+    /// degraded-mode badge. `Engine` (a free-form message from the engine
+    /// HTTP API) is classified by keyword, matching the string-matching
+    /// already used for transient-connection detection in `engine_client`.
+    pub fn category(&self) -> ErrorCategory {
+        match self {
+            Self::EngineConnection(e) => {
+                if let Some(status) = e.status() {
+                    if status.as_u16() == 401 || status.as_u16() == 403 {
+                        return ErrorCategory::ProviderAuth;
+                    }
+                    if status.as_u16() == 429 {
+                        return ErrorCategory::RateLimit;
+                    }
+                }
+                if e.is_connect() || e.is_timeout() {
+                    ErrorCategory::Network
+                } else {
+                    ErrorCategory::Engine
+                }
+            }
+            Self::Io(_) => ErrorCategory::Filesystem,
+            Self::Json(_) => ErrorCategory::Engine,
+            Self::RateLimited(_) => ErrorCategory::RateLimit,
+            Self::Engine(msg) => {
+                let lower = msg.to_lowercase();
+                if lower.contains("401")
+                    || lower.contains("unauthorized")
+                    || lower.contains("invalid api key")
+                {
+                    ErrorCategory::ProviderAuth
+                } else if lower.contains("429") || lower.contains("rate limit") {
+                    ErrorCategory::RateLimit
+                } else if lower.contains("connection refused")
+                    || lower.contains("connection reset")
+                    || lower.contains("connect error")
+                {
+                    ErrorCategory::Network
+                } else {
+                    ErrorCategory::Engine
+                }
+            }
+        }
+    }
 }
 
 pub type Result<T> = std::result::Result<T, TuiError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn engine_error_classifies_auth_keywords() {
+        let err = TuiError::Engine("401 Unauthorized: invalid API key".to_string());
+        assert_eq!(err.category(), ErrorCategory::ProviderAuth);
+    }
+
+    #[test]
+    fn engine_error_classifies_rate_limit_keywords() {
+        let err = TuiError::Engine("429 Too Many Requests: rate limit exceeded".to_string());
+        assert_eq!(err.category(), ErrorCategory::RateLimit);
+    }
+
+    #[test]
+    fn engine_error_classifies_network_keywords() {
+        let err = TuiError::Engine("connection refused (os error 111)".to_string());
+        assert_eq!(err.category(), ErrorCategory::Network);
+    }
+
+    #[test]
+    fn engine_error_defaults_to_engine_category() {
+        let err = TuiError::Engine("scan pipeline crashed".to_string());
+        assert_eq!(err.category(), ErrorCategory::Engine);
+    }
+
+    #[test]
+    fn io_error_classifies_as_filesystem() {
+        let err = TuiError::Io(std::io::Error::other("disk full"));
+        assert_eq!(err.category(), ErrorCategory::Filesystem);
+    }
+}
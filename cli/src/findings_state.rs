@@ -0,0 +1,356 @@
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+/// Workflow status of a finding, cycled from the Scan view (`s`) or set via
+/// `/triage <name>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum FindingStatus {
+    Open,
+    InProgress,
+    Remediated,
+    AcceptedRisk,
+}
+
+impl FindingStatus {
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Open => "Open",
+            Self::InProgress => "In Progress",
+            Self::Remediated => "Remediated",
+            Self::AcceptedRisk => "Accepted Risk",
+        }
+    }
+
+    /// Cycle to the next status: Open -> In Progress -> Remediated ->
+    /// Accepted Risk -> Open.
+    pub const fn next(self) -> Self {
+        match self {
+            Self::Open => Self::InProgress,
+            Self::InProgress => Self::Remediated,
+            Self::Remediated => Self::AcceptedRisk,
+            Self::AcceptedRisk => Self::Open,
+        }
+    }
+
+    /// Parse the `/triage <name>` argument.
+    pub fn from_command(arg: &str) -> Option<Self> {
+        match arg.to_ascii_lowercase().as_str() {
+            "open" => Some(Self::Open),
+            "in-progress" | "in_progress" | "inprogress" => Some(Self::InProgress),
+            "remediated" => Some(Self::Remediated),
+            "accepted-risk" | "accepted_risk" | "acceptedrisk" => Some(Self::AcceptedRisk),
+            _ => None,
+        }
+    }
+}
+
+/// Status + due date for a finding, persisted to
+/// `.complior/findings-state.json` so small teams can triage findings in the
+/// TUI without a separate tracker. Shared via git alongside
+/// `tracked-issues.json` and `dismissals.jsonl` (see
+/// `App::handle_team_status`'s `SHARED_FILES`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FindingState {
+    pub check_id: String,
+    pub file: Option<String>,
+    pub status: FindingStatus,
+    /// ISO 8601 date (`YYYY-MM-DD`), free-text from `/due`.
+    pub due_date: Option<String>,
+    /// ISO 8601 date (`YYYY-MM-DD`) the finding is hidden until, from
+    /// `/snooze-until` — e.g. a finding that only matters once an Art. 6
+    /// deadline applies. Auto-resurfaces once today reaches this date.
+    #[serde(default)]
+    pub snoozed_until: Option<String>,
+    pub updated_at: u64,
+}
+
+fn state_path(project_path: &Path) -> PathBuf {
+    project_path.join(".complior").join("findings-state.json")
+}
+
+fn load(project_path: &Path) -> Vec<FindingState> {
+    std::fs::read_to_string(state_path(project_path))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save(project_path: &Path, states: &[FindingState]) -> std::io::Result<()> {
+    let dir = project_path.join(".complior");
+    std::fs::create_dir_all(&dir)?;
+    let json = serde_json::to_string_pretty(states)?;
+    std::fs::write(state_path(project_path), json)
+}
+
+fn now_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/// Find the current entry for `check_id` + `file`, if any.
+pub fn entry_for<'a>(
+    states: &'a [FindingState],
+    check_id: &str,
+    file: Option<&str>,
+) -> Option<&'a FindingState> {
+    states
+        .iter()
+        .find(|s| s.check_id == check_id && s.file.as_deref() == file)
+}
+
+/// Current status of a finding, defaulting to `Open` when untracked.
+pub fn status_for(states: &[FindingState], check_id: &str, file: Option<&str>) -> FindingStatus {
+    entry_for(states, check_id, file).map_or(FindingStatus::Open, |s| s.status)
+}
+
+/// Set the status of a finding, preserving its due date, and persist it.
+/// Returns the refreshed list so the caller can update its in-memory copy
+/// without a second disk read.
+pub fn set_status(
+    project_path: &Path,
+    check_id: &str,
+    file: Option<&str>,
+    status: FindingStatus,
+) -> std::io::Result<Vec<FindingState>> {
+    let mut states = load(project_path);
+    let existing = entry_for(&states, check_id, file);
+    let due_date = existing.and_then(|s| s.due_date.clone());
+    let snoozed_until = existing.and_then(|s| s.snoozed_until.clone());
+    states.retain(|s| !(s.check_id == check_id && s.file.as_deref() == file));
+    states.push(FindingState {
+        check_id: check_id.to_string(),
+        file: file.map(String::from),
+        status,
+        due_date,
+        snoozed_until,
+        updated_at: now_secs(),
+    });
+    save(project_path, &states)?;
+    Ok(states)
+}
+
+/// Set (or, if `due_date` is `None`, clear) the due date of a finding,
+/// preserving its status, and persist it.
+pub fn set_due_date(
+    project_path: &Path,
+    check_id: &str,
+    file: Option<&str>,
+    due_date: Option<String>,
+) -> std::io::Result<Vec<FindingState>> {
+    let mut states = load(project_path);
+    let existing = entry_for(&states, check_id, file);
+    let status = existing.map_or(FindingStatus::Open, |s| s.status);
+    let snoozed_until = existing.and_then(|s| s.snoozed_until.clone());
+    states.retain(|s| !(s.check_id == check_id && s.file.as_deref() == file));
+    states.push(FindingState {
+        check_id: check_id.to_string(),
+        file: file.map(String::from),
+        status,
+        due_date,
+        snoozed_until,
+        updated_at: now_secs(),
+    });
+    save(project_path, &states)?;
+    Ok(states)
+}
+
+/// Set (or, if `snoozed_until` is `None`, clear) the snooze date of a
+/// finding, preserving its status and due date, and persist it.
+pub fn set_snooze(
+    project_path: &Path,
+    check_id: &str,
+    file: Option<&str>,
+    snoozed_until: Option<String>,
+) -> std::io::Result<Vec<FindingState>> {
+    let mut states = load(project_path);
+    let existing = entry_for(&states, check_id, file);
+    let status = existing.map_or(FindingStatus::Open, |s| s.status);
+    let due_date = existing.and_then(|s| s.due_date.clone());
+    states.retain(|s| !(s.check_id == check_id && s.file.as_deref() == file));
+    states.push(FindingState {
+        check_id: check_id.to_string(),
+        file: file.map(String::from),
+        status,
+        due_date,
+        snoozed_until,
+        updated_at: now_secs(),
+    });
+    save(project_path, &states)?;
+    Ok(states)
+}
+
+/// Load all tracked finding states for a project.
+pub fn load_all(project_path: &Path) -> Vec<FindingState> {
+    load(project_path)
+}
+
+/// Whether a finding is overdue: it has a due date in the past and its
+/// status isn't `Remediated`/`AcceptedRisk`.
+pub fn is_overdue(state: &FindingState, today_days: i64) -> bool {
+    if matches!(
+        state.status,
+        FindingStatus::Remediated | FindingStatus::AcceptedRisk
+    ) {
+        return false;
+    }
+    state
+        .due_date
+        .as_deref()
+        .and_then(parse_due_date_days)
+        .is_some_and(|due_days| due_days < today_days)
+}
+
+/// Days since the Unix epoch for a `YYYY-MM-DD` due date, or `None` if it
+/// doesn't parse as a real calendar date.
+pub fn parse_due_date_days(due_date: &str) -> Option<i64> {
+    crate::date::parse_ymd_epoch_days(due_date)
+}
+
+/// Whether a finding is currently snoozed: it has a `snoozed_until` date that
+/// hasn't been reached yet. Once `today_days` reaches that date, the finding
+/// auto-resurfaces.
+pub fn is_snoozed(state: &FindingState, today_days: i64) -> bool {
+    state
+        .snoozed_until
+        .as_deref()
+        .and_then(parse_due_date_days)
+        .is_some_and(|until_days| today_days < until_days)
+}
+
+/// Whether the finding identified by `check_id`/`file` is currently snoozed,
+/// defaulting to not-snoozed when untracked.
+pub fn snoozed_for(
+    states: &[FindingState],
+    check_id: &str,
+    file: Option<&str>,
+    today_days: i64,
+) -> bool {
+    entry_for(states, check_id, file).is_some_and(|s| is_snoozed(s, today_days))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn status_cycles_and_wraps() {
+        assert_eq!(FindingStatus::Open.next(), FindingStatus::InProgress);
+        assert_eq!(FindingStatus::InProgress.next(), FindingStatus::Remediated);
+        assert_eq!(
+            FindingStatus::Remediated.next(),
+            FindingStatus::AcceptedRisk
+        );
+        assert_eq!(FindingStatus::AcceptedRisk.next(), FindingStatus::Open);
+    }
+
+    #[test]
+    fn set_status_roundtrips_and_defaults_to_open() {
+        let dir = std::env::temp_dir().join(format!(
+            "complior-findings-state-test-{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert_eq!(
+            status_for(&load_all(&dir), "l4-hardcoded-key", Some("src/main.rs")),
+            FindingStatus::Open
+        );
+
+        let states = set_status(
+            &dir,
+            "l4-hardcoded-key",
+            Some("src/main.rs"),
+            FindingStatus::InProgress,
+        )
+        .expect("set status");
+        assert_eq!(
+            status_for(&states, "l4-hardcoded-key", Some("src/main.rs")),
+            FindingStatus::InProgress
+        );
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn overdue_ignores_remediated_and_missing_due_date() {
+        let base = FindingState {
+            check_id: "l4-x".into(),
+            file: None,
+            status: FindingStatus::Open,
+            due_date: Some("2020-01-01".into()),
+            snoozed_until: None,
+            updated_at: 0,
+        };
+        assert!(is_overdue(&base, 20_000));
+
+        let remediated = FindingState {
+            status: FindingStatus::Remediated,
+            ..base.clone()
+        };
+        assert!(!is_overdue(&remediated, 20_000));
+
+        let no_due = FindingState {
+            due_date: None,
+            ..base
+        };
+        assert!(!is_overdue(&no_due, 20_000));
+    }
+
+    #[test]
+    fn snooze_hides_until_date_then_resurfaces() {
+        let state = FindingState {
+            check_id: "l4-x".into(),
+            file: None,
+            status: FindingStatus::Open,
+            due_date: None,
+            snoozed_until: Some("2025-06-01".into()),
+            updated_at: 0,
+        };
+        let before = crate::date::parse_ymd_epoch_days("2025-05-01").unwrap();
+        let on = crate::date::parse_ymd_epoch_days("2025-06-01").unwrap();
+        assert!(is_snoozed(&state, before));
+        assert!(!is_snoozed(&state, on));
+
+        let not_snoozed = FindingState {
+            snoozed_until: None,
+            ..state
+        };
+        assert!(!is_snoozed(&not_snoozed, before));
+    }
+
+    #[test]
+    fn set_snooze_roundtrips_and_preserves_status() {
+        let dir = std::env::temp_dir().join(format!(
+            "complior-findings-state-snooze-test-{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        set_status(&dir, "l4-hardcoded-key", None, FindingStatus::InProgress)
+            .expect("set status");
+        let states = set_snooze(
+            &dir,
+            "l4-hardcoded-key",
+            None,
+            Some("2030-01-01".to_string()),
+        )
+        .expect("set snooze");
+
+        let entry = entry_for(&states, "l4-hardcoded-key", None).expect("entry present");
+        assert_eq!(entry.snoozed_until.as_deref(), Some("2030-01-01"));
+        assert_eq!(entry.status, FindingStatus::InProgress);
+
+        let cleared = set_snooze(&dir, "l4-hardcoded-key", None, None).expect("clear snooze");
+        assert_eq!(
+            entry_for(&cleared, "l4-hardcoded-key", None)
+                .expect("entry present")
+                .snoozed_until,
+            None
+        );
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,333 @@
+//! Crash-safe write-ahead journal for batch fix application.
+//!
+//! Before [`AppCommand::ApplyFixes`](crate::app::AppCommand::ApplyFixes)
+//! touches a single file, the whole batch's intent — every file, a hash of
+//! its pre-fix content, and the patch (before/after content) — is written
+//! to `.complior/fix-journal.json`. If the process is killed or the machine
+//! loses power mid-batch, the journal survives on disk; on the next
+//! startup `App::new` finds it and surfaces a recovery prompt so the user
+//! can roll the interrupted batch forward (finish applying) or back
+//! (restore every file to its pre-fix content) instead of being left with
+//! a half-patched project and no record of what changed.
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::views::fix::FixPlan;
+
+/// One file's planned write, recorded before it happens.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JournalEntry {
+    /// Project-relative path.
+    pub file: String,
+    /// SHA-256 hex digest of `before_content` (or of an empty byte string
+    /// when the fix creates a new file). `roll_forward`/`roll_back` compare
+    /// this (and the hash of `after_content`) against the file's current
+    /// on-disk content before writing, so a stale journal can be told
+    /// apart from a file that's since changed again.
+    pub before_hash: String,
+    /// `None` if the fix creates a new file — rollback deletes it instead
+    /// of restoring content.
+    pub before_content: Option<String>,
+    pub after_content: String,
+}
+
+/// A batch of fixes in flight, or interrupted.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct FixJournal {
+    pub entries: Vec<JournalEntry>,
+}
+
+fn journal_path(project_path: &Path) -> PathBuf {
+    project_path.join(".complior").join("fix-journal.json")
+}
+
+fn sha256_hex(content: &str) -> String {
+    use std::fmt::Write as _;
+    let digest = ring::digest::digest(&ring::digest::SHA256, content.as_bytes());
+    digest.as_ref().iter().fold(String::new(), |mut acc, b| {
+        let _ = write!(acc, "{b:02x}");
+        acc
+    })
+}
+
+impl JournalEntry {
+    fn from_plan(plan: &FixPlan) -> Self {
+        Self {
+            file: plan.file_path.clone(),
+            before_hash: sha256_hex(plan.before_content.as_deref().unwrap_or("")),
+            before_content: plan.before_content.clone(),
+            after_content: plan.after_content.clone(),
+        }
+    }
+}
+
+/// Record intent for an entire batch before any file is written.
+pub fn write_journal(project_path: &Path, plans: &[FixPlan]) -> std::io::Result<()> {
+    let journal = FixJournal {
+        entries: plans.iter().map(JournalEntry::from_plan).collect(),
+    };
+    let dir = project_path.join(".complior");
+    std::fs::create_dir_all(&dir)?;
+    let json = serde_json::to_string_pretty(&journal)?;
+    std::fs::write(journal_path(project_path), json)
+}
+
+/// Delete the journal once a batch finishes (successfully or not — a
+/// completed batch, even with some failed entries, isn't "interrupted").
+pub fn clear_journal(project_path: &Path) {
+    let _ = std::fs::remove_file(journal_path(project_path));
+}
+
+/// Load a leftover journal from an interrupted batch, if one exists.
+pub fn load_journal(project_path: &Path) -> Option<FixJournal> {
+    let content = std::fs::read_to_string(journal_path(project_path)).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// Current on-disk content of `path` hashed the same way as `before_hash`,
+/// or `None` if the file doesn't exist (or can't be read).
+fn current_hash(path: &Path) -> Option<String> {
+    std::fs::read_to_string(path).ok().map(|s| sha256_hex(&s))
+}
+
+/// Whether it's safe to write `entry` over whatever's on disk right now:
+/// either disk still matches what the journal recorded before the fix, or
+/// it already matches what the fix would produce (an idempotent re-apply).
+/// Anything else means the file changed some other way since the journal
+/// was written — most likely a manual edit between the crash and running
+/// `/fix-recovery` — and clobbering it would silently discard that edit.
+fn matches_journaled_state(entry: &JournalEntry, path: &Path) -> bool {
+    match current_hash(path) {
+        Some(hash) => hash == entry.before_hash || hash == sha256_hex(&entry.after_content),
+        None => entry.before_content.is_none(),
+    }
+}
+
+/// Roll an interrupted batch forward: finish writing every entry's
+/// `after_content`, skipping any file whose on-disk content no longer
+/// matches what the journal recorded (see [`matches_journaled_state`]).
+/// Returns `(applied, skipped, failed)`.
+pub fn roll_forward(project_path: &Path, journal: &FixJournal) -> (u32, u32, u32) {
+    let mut applied = 0;
+    let mut skipped = 0;
+    let mut failed = 0;
+    for entry in &journal.entries {
+        let abs = project_path.join(&entry.file);
+        if !matches_journaled_state(entry, &abs) {
+            skipped += 1;
+            continue;
+        }
+        if let Some(parent) = abs.parent()
+            && entry.before_content.is_none()
+        {
+            let _ = std::fs::create_dir_all(parent);
+        }
+        match std::fs::write(&abs, &entry.after_content) {
+            Ok(()) => applied += 1,
+            Err(_) => failed += 1,
+        }
+    }
+    clear_journal(project_path);
+    (applied, skipped, failed)
+}
+
+/// Roll an interrupted batch back: restore `before_content` (or delete
+/// files the batch was about to create), skipping any file whose on-disk
+/// content no longer matches what the journal recorded (see
+/// [`matches_journaled_state`]). Returns `(restored, skipped, failed)`.
+pub fn roll_back(project_path: &Path, journal: &FixJournal) -> (u32, u32, u32) {
+    let mut restored = 0;
+    let mut skipped = 0;
+    let mut failed = 0;
+    for entry in &journal.entries {
+        let abs = project_path.join(&entry.file);
+        if !matches_journaled_state(entry, &abs) {
+            skipped += 1;
+            continue;
+        }
+        let result = match &entry.before_content {
+            Some(before) => std::fs::write(&abs, before),
+            None => {
+                std::fs::remove_file(&abs).or_else(|e| if abs.exists() { Err(e) } else { Ok(()) })
+            }
+        };
+        if result.is_ok() {
+            restored += 1;
+        } else {
+            failed += 1;
+        }
+    }
+    clear_journal(project_path);
+    (restored, skipped, failed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::views::fix::FixPlan;
+
+    fn temp_project(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "complior-fix-journal-{name}-{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).expect("create temp project");
+        dir
+    }
+
+    #[test]
+    fn write_then_load_journal_roundtrips() {
+        let dir = temp_project("roundtrip");
+        let plans = vec![FixPlan {
+            file_path: "docs/fria.md".to_string(),
+            before_content: None,
+            after_content: "# FRIA\n".to_string(),
+        }];
+        write_journal(&dir, &plans).expect("write journal");
+
+        let loaded = load_journal(&dir).expect("journal present after interruption");
+        assert_eq!(loaded.entries.len(), 1);
+        assert_eq!(loaded.entries[0].file, "docs/fria.md");
+        assert!(loaded.entries[0].before_content.is_none());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn clear_journal_removes_the_file() {
+        let dir = temp_project("clear");
+        write_journal(
+            &dir,
+            &[FixPlan {
+                file_path: "README.md".to_string(),
+                before_content: Some(String::new()),
+                after_content: "hello".to_string(),
+            }],
+        )
+        .expect("write journal");
+        assert!(load_journal(&dir).is_some());
+
+        clear_journal(&dir);
+        assert!(load_journal(&dir).is_none());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn roll_forward_finishes_interrupted_writes() {
+        let dir = temp_project("forward");
+        let target = dir.join("src/wrapped.ts");
+        std::fs::create_dir_all(target.parent().expect("has parent")).expect("mkdir");
+        std::fs::write(&target, "old content").expect("seed file");
+
+        let journal = FixJournal {
+            entries: vec![JournalEntry {
+                file: "src/wrapped.ts".to_string(),
+                before_hash: sha256_hex("old content"),
+                before_content: Some("old content".to_string()),
+                after_content: "new content".to_string(),
+            }],
+        };
+        let (applied, skipped, failed) = roll_forward(&dir, &journal);
+        assert_eq!((applied, skipped, failed), (1, 0, 0));
+        assert_eq!(
+            std::fs::read_to_string(&target).expect("read"),
+            "new content"
+        );
+        assert!(load_journal(&dir).is_none());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn roll_back_restores_pre_fix_content_and_deletes_created_files() {
+        let dir = temp_project("back");
+        let modified = dir.join("src/wrapped.ts");
+        std::fs::create_dir_all(modified.parent().expect("has parent")).expect("mkdir");
+        std::fs::write(&modified, "new content").expect("seed file");
+        let created = dir.join("docs/fria.md");
+        std::fs::create_dir_all(created.parent().expect("has parent")).expect("mkdir");
+        std::fs::write(&created, "# FRIA\n").expect("seed created file");
+
+        let journal = FixJournal {
+            entries: vec![
+                JournalEntry {
+                    file: "src/wrapped.ts".to_string(),
+                    before_hash: sha256_hex("old content"),
+                    before_content: Some("old content".to_string()),
+                    after_content: "new content".to_string(),
+                },
+                JournalEntry {
+                    file: "docs/fria.md".to_string(),
+                    before_hash: sha256_hex(""),
+                    before_content: None,
+                    after_content: "# FRIA\n".to_string(),
+                },
+            ],
+        };
+        let (restored, skipped, failed) = roll_back(&dir, &journal);
+        assert_eq!((restored, skipped, failed), (2, 0, 0));
+        assert_eq!(
+            std::fs::read_to_string(&modified).expect("read"),
+            "old content"
+        );
+        assert!(!created.exists());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn roll_forward_skips_a_file_manually_edited_since_the_crash() {
+        let dir = temp_project("forward-stale");
+        let target = dir.join("src/wrapped.ts");
+        std::fs::create_dir_all(target.parent().expect("has parent")).expect("mkdir");
+        std::fs::write(&target, "user's manual edit").expect("seed file");
+
+        let journal = FixJournal {
+            entries: vec![JournalEntry {
+                file: "src/wrapped.ts".to_string(),
+                before_hash: sha256_hex("old content"),
+                before_content: Some("old content".to_string()),
+                after_content: "new content".to_string(),
+            }],
+        };
+        let (applied, skipped, failed) = roll_forward(&dir, &journal);
+        assert_eq!((applied, skipped, failed), (0, 1, 0));
+        assert_eq!(
+            std::fs::read_to_string(&target).expect("read"),
+            "user's manual edit",
+            "manual edit must survive, not be clobbered by the stale journal"
+        );
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn roll_back_skips_a_file_manually_edited_since_the_crash() {
+        let dir = temp_project("back-stale");
+        let target = dir.join("src/wrapped.ts");
+        std::fs::create_dir_all(target.parent().expect("has parent")).expect("mkdir");
+        std::fs::write(&target, "user's manual edit").expect("seed file");
+
+        let journal = FixJournal {
+            entries: vec![JournalEntry {
+                file: "src/wrapped.ts".to_string(),
+                before_hash: sha256_hex("old content"),
+                before_content: Some("old content".to_string()),
+                after_content: "new content".to_string(),
+            }],
+        };
+        let (restored, skipped, failed) = roll_back(&dir, &journal);
+        assert_eq!((restored, skipped, failed), (0, 1, 0));
+        assert_eq!(
+            std::fs::read_to_string(&target).expect("read"),
+            "user's manual edit",
+            "manual edit must survive, not be clobbered by the stale journal"
+        );
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,159 @@
+//! Throwaway project copies for `/fix --sandbox`.
+//!
+//! Applying a fix batch to the real working tree, as
+//! [`AppCommand::ApplyFixes`](crate::app::AppCommand::ApplyFixes) does, is a
+//! commitment: the only way back is `/fix-recovery back` or a manual
+//! revert. `AppCommand::FixSandbox` instead copies the project into a temp
+//! directory, applies the same [`FixPlan`](crate::views::fix::FixPlan)s
+//! there, and rescans the copy — so the score delta it reports is real
+//! (measured by the engine against actual patched files), not the
+//! predicted-impact heuristic `/fix --dry-run` shows, and the working tree
+//! is never touched.
+
+use std::path::{Path, PathBuf};
+
+/// Directories not worth copying into the sandbox: build output and VCS
+/// metadata the scanner never looks at. Unlike `watcher::is_relevant`, this
+/// does NOT skip dotfiles in general — `.complior/` (project config) and
+/// files like `.env` are exactly what the scanner needs to see.
+const SKIP_DIRS: &[&str] = &[
+    ".git",
+    "node_modules",
+    "target",
+    "dist",
+    "build",
+    "__pycache__",
+];
+
+/// Copy `project_path` into a fresh temp directory, skipping [`SKIP_DIRS`].
+///
+/// The sandbox holds a full copy of the project, `.env` and other
+/// secret-bearing dotfiles included (see module docs), so on Unix its
+/// directory is locked to `0o700` right after creation -- before anything
+/// is copied into it -- so it's never briefly world-readable under a
+/// permissive umask.
+pub fn create_sandbox_copy(project_path: &Path) -> std::io::Result<PathBuf> {
+    let sandbox = std::env::temp_dir().join(format!(
+        "complior-fix-sandbox-{}-{}",
+        std::process::id(),
+        sandbox_nonce()
+    ));
+    std::fs::create_dir_all(&sandbox)?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&sandbox, std::fs::Permissions::from_mode(0o700))?;
+    }
+    copy_dir_filtered(project_path, &sandbox)?;
+    Ok(sandbox)
+}
+
+/// A cheap per-call disambiguator so two sandboxes created within the same
+/// process (e.g. across two `/fix --sandbox` runs) don't collide — reusing
+/// `std::process::id()` alone isn't unique across calls in the same run.
+fn sandbox_nonce() -> u64 {
+    use std::sync::atomic::{AtomicU64, Ordering};
+    static NEXT: AtomicU64 = AtomicU64::new(0);
+    NEXT.fetch_add(1, Ordering::Relaxed)
+}
+
+fn copy_dir_filtered(src: &Path, dst: &Path) -> std::io::Result<()> {
+    for entry in std::fs::read_dir(src)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        if SKIP_DIRS.iter().any(|d| name == *d) {
+            continue;
+        }
+        let src_path = entry.path();
+        let dst_path = dst.join(&name);
+        if entry.file_type()?.is_dir() {
+            std::fs::create_dir_all(&dst_path)?;
+            copy_dir_filtered(&src_path, &dst_path)?;
+        } else {
+            std::fs::copy(&src_path, &dst_path)?;
+        }
+    }
+    Ok(())
+}
+
+/// Remove a sandbox directory once its rescan has been reported.
+pub fn cleanup_sandbox(sandbox_path: &Path) {
+    let _ = std::fs::remove_dir_all(sandbox_path);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_project(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "complior-fix-sandbox-test-{name}-{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).expect("create temp project");
+        dir
+    }
+
+    #[test]
+    fn copies_source_files_and_dotfiles_but_skips_build_dirs() {
+        let project = temp_project("copies");
+        std::fs::write(project.join("README.md"), "hello").expect("write readme");
+        std::fs::write(project.join(".env"), "KEY=1").expect("write dotfile");
+        std::fs::create_dir_all(project.join(".complior")).expect("mkdir");
+        std::fs::write(
+            project.join(".complior/project.toml"),
+            "jurisdiction = \"eu\"",
+        )
+        .expect("write config");
+        std::fs::create_dir_all(project.join("target/debug")).expect("mkdir build dir");
+        std::fs::write(project.join("target/debug/bin"), "binary").expect("write build output");
+
+        let sandbox = create_sandbox_copy(&project).expect("copy sandbox");
+
+        assert_eq!(
+            std::fs::read_to_string(sandbox.join("README.md")).expect("read"),
+            "hello"
+        );
+        assert_eq!(
+            std::fs::read_to_string(sandbox.join(".env")).expect("read"),
+            "KEY=1"
+        );
+        assert!(sandbox.join(".complior/project.toml").exists());
+        assert!(!sandbox.join("target").exists());
+
+        cleanup_sandbox(&sandbox);
+        assert!(!sandbox.exists());
+        let _ = std::fs::remove_dir_all(&project);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn sandbox_dir_is_owner_only() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let project = temp_project("perms");
+        std::fs::write(project.join(".env"), "SECRET=1").expect("write dotfile");
+
+        let sandbox = create_sandbox_copy(&project).expect("copy sandbox");
+        let mode = std::fs::metadata(&sandbox).expect("stat sandbox").permissions().mode();
+        assert_eq!(mode & 0o777, 0o700);
+
+        cleanup_sandbox(&sandbox);
+        let _ = std::fs::remove_dir_all(&project);
+    }
+
+    #[test]
+    fn two_sandboxes_from_the_same_project_get_distinct_paths() {
+        let project = temp_project("distinct");
+        std::fs::write(project.join("a.txt"), "a").expect("write file");
+
+        let first = create_sandbox_copy(&project).expect("copy sandbox 1");
+        let second = create_sandbox_copy(&project).expect("copy sandbox 2");
+        assert_ne!(first, second);
+
+        cleanup_sandbox(&first);
+        cleanup_sandbox(&second);
+        let _ = std::fs::remove_dir_all(&project);
+    }
+}
@@ -0,0 +1,86 @@
+//! Best-effort detection of terminal graphics protocol support (Kitty,
+//! iTerm2, sixel), used by the Report view to decide how to caption the
+//! score trend chart.
+//!
+//! Actually drawing the trend as a raster image over one of these
+//! protocols means encoding it to a bitmap first, which this crate has no
+//! image codec for -- so `views::report` always renders the trend as a
+//! text sparkline today, and only uses [`GraphicsProtocol::supports_images`]
+//! to note that a richer chart could be drawn here once an encoder is
+//! wired in. Detection itself is real and exercised now so that wiring is
+//! a render-function change, not a plumbing one.
+
+/// Terminal protocols capable of drawing raster images inline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphicsProtocol {
+    Kitty,
+    Iterm2,
+    Sixel,
+    None,
+}
+
+impl GraphicsProtocol {
+    pub const fn supports_images(self) -> bool {
+        !matches!(self, Self::None)
+    }
+}
+
+/// Detect graphics protocol support from environment variables set by known
+/// terminal emulators. Best-effort: a false negative just falls back to the
+/// sparkline, so this favors simple, well-known signals over exhaustive
+/// terminfo probing.
+pub fn detect() -> GraphicsProtocol {
+    detect_from_env(|k| std::env::var(k).ok())
+}
+
+fn detect_from_env(get: impl Fn(&str) -> Option<String>) -> GraphicsProtocol {
+    if get("KITTY_WINDOW_ID").is_some() {
+        return GraphicsProtocol::Kitty;
+    }
+    match get("TERM_PROGRAM").as_deref() {
+        Some("iTerm.app") => return GraphicsProtocol::Iterm2,
+        // WezTerm speaks the Kitty graphics protocol.
+        Some("WezTerm") => return GraphicsProtocol::Kitty,
+        _ => {}
+    }
+    if get("TERM").is_some_and(|t| t.contains("sixel")) {
+        return GraphicsProtocol::Sixel;
+    }
+    GraphicsProtocol::None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_kitty_from_window_id() {
+        let env = |k: &str| (k == "KITTY_WINDOW_ID").then(|| "1".to_string());
+        assert_eq!(detect_from_env(env), GraphicsProtocol::Kitty);
+    }
+
+    #[test]
+    fn detects_iterm2_from_term_program() {
+        let env = |k: &str| (k == "TERM_PROGRAM").then(|| "iTerm.app".to_string());
+        assert_eq!(detect_from_env(env), GraphicsProtocol::Iterm2);
+    }
+
+    #[test]
+    fn detects_wezterm_as_kitty_protocol() {
+        let env = |k: &str| (k == "TERM_PROGRAM").then(|| "WezTerm".to_string());
+        assert_eq!(detect_from_env(env), GraphicsProtocol::Kitty);
+    }
+
+    #[test]
+    fn detects_sixel_from_term() {
+        let env = |k: &str| (k == "TERM").then(|| "xterm-sixel".to_string());
+        assert_eq!(detect_from_env(env), GraphicsProtocol::Sixel);
+    }
+
+    #[test]
+    fn defaults_to_none() {
+        let env = |_: &str| None;
+        assert_eq!(detect_from_env(env), GraphicsProtocol::None);
+        assert!(!GraphicsProtocol::None.supports_images());
+    }
+}
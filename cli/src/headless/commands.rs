@@ -13,20 +13,35 @@ pub fn run_version() {
     println!("https://complior.ai");
 }
 
-/// Run doctor diagnostics — 8 system health checks.
+/// Base URL to probe for LLM provider reachability. `None` for providers
+/// this doctor check doesn't recognize (custom/self-hosted endpoints).
+fn provider_probe_url(provider: &str) -> Option<&'static str> {
+    match provider {
+        "openai" => Some("https://api.openai.com"),
+        "anthropic" => Some("https://api.anthropic.com"),
+        "openrouter" => Some("https://openrouter.ai"),
+        _ => None,
+    }
+}
+
+/// Run doctor diagnostics — 12 system health checks.
 /// Returns 0 if critical checks (engine + Node.js) pass, 1 otherwise.
-pub async fn run_doctor(config: &TuiConfig) -> i32 {
-    println!("Complior Doctor — System Health Check");
-    println!("=====================================");
-    println!();
+/// Build the doctor report text and exit code without printing anywhere —
+/// shared by the `complior doctor` CLI command and the TUI's `/doctor`.
+pub async fn doctor_report(config: &TuiConfig) -> (String, i32) {
+    use std::fmt::Write as _;
+
+    let mut out = String::new();
+    let _ = writeln!(out, "Complior Doctor — System Health Check");
+    let _ = writeln!(out, "=====================================");
+    let _ = writeln!(out);
 
     let mut passed = 0u32;
-    let total = 8u32;
+    let total = 12u32;
 
     // 1. TUI binary
     let version = env!("CARGO_PKG_VERSION");
-    print!("  TUI binary:     v{version}");
-    println!("                            OK");
+    let _ = writeln!(out, "  TUI binary:     v{version}                            OK");
     passed += 1;
 
     // 2. Engine
@@ -34,20 +49,22 @@ pub async fn run_doctor(config: &TuiConfig) -> i32 {
         .engine_url_override
         .clone()
         .unwrap_or_else(|| config.engine_url());
-    print!("  Engine:         ");
-    let client = EngineClient::from_url(&engine_url);
+    let client = EngineClient::from_url(&engine_url, config);
     match client.status().await {
         Ok(status) if status.ready => {
             let ver = status.version.unwrap_or_else(|| "unknown".into());
-            println!("v{ver} ({engine_url})              OK");
+            let _ = writeln!(out, "  Engine:         v{ver} ({engine_url})              OK");
             passed += 1;
         }
-        Ok(_) => println!("NOT READY ({engine_url})           WARN"),
-        Err(_) => println!("UNREACHABLE ({engine_url})         FAIL"),
+        Ok(_) => {
+            let _ = writeln!(out, "  Engine:         NOT READY ({engine_url})           WARN");
+        }
+        Err(_) => {
+            let _ = writeln!(out, "  Engine:         UNREACHABLE ({engine_url})         FAIL");
+        }
     }
 
     // 3. Node.js
-    print!("  Node.js:        ");
     match std::process::Command::new("node").arg("--version").output() {
         Ok(output) if output.status.success() => {
             let ver = String::from_utf8_lossy(&output.stdout).trim().to_string();
@@ -59,64 +76,211 @@ pub async fn run_doctor(config: &TuiConfig) -> i32 {
                 .and_then(|s| s.parse().ok())
                 .unwrap_or(0);
             if major >= 18 {
-                println!("{ver} (required: >=18)              OK");
+                let _ = writeln!(out, "  Node.js:        {ver} (required: >=18)              OK");
                 passed += 1;
             } else {
-                println!("{ver} (required: >=18)              FAIL");
+                let _ = writeln!(out, "  Node.js:        {ver} (required: >=18)              FAIL");
             }
         }
-        _ => println!("Not found                         FAIL  (install: https://nodejs.org)"),
+        _ => {
+            let _ = writeln!(
+                out,
+                "  Node.js:        Not found                         FAIL  (install: https://nodejs.org)"
+            );
+        }
+    }
+
+    // 4. npm (needed alongside Node.js to auto-launch the engine)
+    match std::process::Command::new("npm").arg("--version").output() {
+        Ok(output) if output.status.success() => {
+            let ver = String::from_utf8_lossy(&output.stdout).trim().to_string();
+            let _ = writeln!(out, "  npm:            v{ver}                              OK");
+            passed += 1;
+        }
+        _ => {
+            let _ = writeln!(
+                out,
+                "  npm:            Not found                         FAIL  (install: https://nodejs.org)"
+            );
+        }
     }
 
-    // 4. Disk space
-    print!("  Disk space:     ");
+    // 5. Disk space
     let tmp = std::env::temp_dir();
     if tmp.exists() {
-        println!("OK (temp dir accessible)");
+        let _ = writeln!(out, "  Disk space:     OK (temp dir accessible)");
         passed += 1;
     } else {
-        println!("WARN (temp dir inaccessible)");
+        let _ = writeln!(out, "  Disk space:     WARN (temp dir inaccessible)");
+    }
+
+    // 6. Terminal capabilities
+    let truecolor = matches!(
+        std::env::var("COLORTERM").as_deref(),
+        Ok("truecolor" | "24bit")
+    );
+    match crossterm::terminal::size() {
+        Ok((cols, rows)) if cols >= 80 && rows >= 24 => {
+            let color = if truecolor { "truecolor" } else { "256-color" };
+            let _ = writeln!(out, "  Terminal:       {cols}x{rows}, {color}                 OK");
+            passed += 1;
+        }
+        Ok((cols, rows)) => {
+            let _ = writeln!(
+                out,
+                "  Terminal:       {cols}x{rows} (recommended: 80x24+)      WARN"
+            );
+        }
+        Err(_) => {
+            let _ = writeln!(
+                out,
+                "  Terminal:       Not a terminal                    WARN  (redirected output?)"
+            );
+        }
     }
 
-    // 5. Config
-    print!("  Config:         ");
+    // 7. Config
     let cwd = std::env::current_dir().unwrap_or_default();
+    let project_toml = cwd.join(".complior").join("project.toml");
     if cwd.join(".complior").exists() {
-        println!(".complior/ found                  OK");
-        passed += 1;
+        if let Ok(content) = std::fs::read_to_string(&project_toml) {
+            match content.parse::<toml::Value>() {
+                Ok(_) => {
+                    let _ = writeln!(
+                        out,
+                        "  Config:         .complior/ found, project.toml valid  OK"
+                    );
+                    passed += 1;
+                }
+                Err(e) => {
+                    let _ = writeln!(
+                        out,
+                        "  Config:         project.toml invalid               FAIL  ({e})"
+                    );
+                }
+            }
+        } else {
+            let _ = writeln!(out, "  Config:         .complior/ found                  OK");
+            passed += 1;
+        }
     } else {
-        println!(".complior/ not found              WARN  (run `complior init`)");
+        let _ = writeln!(
+            out,
+            "  Config:         .complior/ not found              WARN  (run `complior init`)"
+        );
     }
 
-    // 6. Network
-    print!("  Network:        ");
+    // 8. Credentials store — this build keeps API keys/tokens in
+    // `~/.config/complior/credentials` rather than the OS keyring, so
+    // "keyring access" here means that file's directory is writable.
+    match dirs::config_dir() {
+        Some(dir) => {
+            let complior_dir = dir.join("complior");
+            match std::fs::create_dir_all(&complior_dir) {
+                Ok(()) => {
+                    let _ = writeln!(
+                        out,
+                        "  Credentials:    {} writable            OK",
+                        complior_dir.display()
+                    );
+                    passed += 1;
+                }
+                Err(e) => {
+                    let _ = writeln!(
+                        out,
+                        "  Credentials:    Not writable                       FAIL  ({e})"
+                    );
+                }
+            }
+        }
+        None => {
+            let _ = writeln!(
+                out,
+                "  Credentials:    No config directory                FAIL  (no $HOME?)"
+            );
+        }
+    }
+
+    // 9. Network
     let net_client = reqwest::Client::builder()
         .timeout(std::time::Duration::from_secs(5))
         .build();
     match net_client {
         Ok(c) => match c.head("https://github.com/complior/complior").send().await {
             Ok(resp) if resp.status().is_success() || resp.status().is_redirection() => {
-                println!("GitHub reachable                  OK");
+                let _ = writeln!(out, "  Network:        GitHub reachable                  OK");
+                passed += 1;
+            }
+            _ => {
+                let _ = writeln!(
+                    out,
+                    "  Network:        GitHub unreachable                WARN  (offline mode OK)"
+                );
+            }
+        },
+        Err(_) => {
+            let _ = writeln!(out, "  Network:        Cannot create HTTP client         WARN");
+        }
+    }
+
+    // 10. LLM provider reachability
+    match config.llm_provider.as_deref() {
+        None => {
+            let _ = writeln!(
+                out,
+                "  LLM provider:   Not configured                     WARN  (optional, run `/llm`)"
+            );
+        }
+        Some(provider) => match provider_probe_url(provider) {
+            None => {
+                let _ = writeln!(
+                    out,
+                    "  LLM provider:   {provider} (custom endpoint)              OK"
+                );
                 passed += 1;
             }
-            _ => println!("GitHub unreachable                WARN  (offline mode OK)"),
+            Some(url) => {
+                let probe = reqwest::Client::builder()
+                    .timeout(std::time::Duration::from_secs(5))
+                    .build();
+                match probe {
+                    Ok(c) => match c.head(url).send().await {
+                        Ok(_) => {
+                            let _ = writeln!(
+                                out,
+                                "  LLM provider:   {provider} reachable ({url})       OK"
+                            );
+                            passed += 1;
+                        }
+                        Err(_) => {
+                            let _ = writeln!(
+                                out,
+                                "  LLM provider:   {provider} unreachable ({url})     WARN"
+                            );
+                        }
+                    },
+                    Err(_) => {
+                        let _ =
+                            writeln!(out, "  LLM provider:   Cannot create HTTP client         WARN");
+                    }
+                }
+            }
         },
-        Err(_) => println!("Cannot create HTTP client         WARN"),
     }
 
-    // 7. MCP
-    print!("  MCP:            ");
+    // 11. MCP
     let mcp_config = dirs::config_dir().map(|d| d.join("complior").join("mcp.json"));
     match mcp_config {
         Some(p) if p.exists() => {
-            println!("Configured                        OK");
+            let _ = writeln!(out, "  MCP:            Configured                        OK");
             passed += 1;
         }
-        _ => println!("Not configured                    WARN  (optional)"),
+        _ => {
+            let _ = writeln!(out, "  MCP:            Not configured                    WARN  (optional)");
+        }
     }
 
-    // 8. SaaS Auth
-    print!("  SaaS Auth:      ");
+    // 12. SaaS Auth
     if let Some(tokens) = crate::config::load_tokens() {
         if crate::config::is_authenticated() {
             let email = tokens.user_email.as_deref().unwrap_or("unknown");
@@ -126,24 +290,38 @@ pub async fn run_doctor(config: &TuiConfig) -> i32 {
                 .unwrap_or_default()
                 .as_secs();
             let mins_left = tokens.expires_at.saturating_sub(now) / 60;
-            println!("{email} ({org})            OK");
-            println!("                  Token expires in {mins_left} minutes");
+            let _ = writeln!(out, "  SaaS Auth:      {email} ({org})            OK");
+            let _ = writeln!(out, "                  Token expires in {mins_left} minutes");
             passed += 1;
         } else {
-            println!("Token expired                     WARN  (run `complior login`)");
+            let _ = writeln!(
+                out,
+                "  SaaS Auth:      Token expired                     WARN  (run `complior login`)"
+            );
         }
     } else {
-        println!("Not authenticated                 WARN  (run `complior login`)");
+        let _ = writeln!(
+            out,
+            "  SaaS Auth:      Not authenticated                 WARN  (run `complior login`)"
+        );
     }
 
-    println!();
-    println!("  Summary: {passed}/{total} checks passed");
+    let _ = writeln!(out);
+    let _ = writeln!(out, "  Summary: {passed}/{total} checks passed");
     if passed >= 3 {
-        println!("  Ready to scan!");
+        let _ = writeln!(out, "  Ready to scan!");
     }
 
-    // Return non-zero if critical checks failed (engine + Node.js = 2 critical)
-    i32::from(passed < 2)
+    // Non-zero if critical checks failed (engine + Node.js = 2 critical).
+    (out, i32::from(passed < 2))
+}
+
+/// Run doctor diagnostics — 12 system health checks.
+/// Returns 0 if critical checks (engine + Node.js) pass, 1 otherwise.
+pub async fn run_doctor(config: &TuiConfig) -> i32 {
+    let (report, code) = doctor_report(config).await;
+    print!("{report}");
+    code
 }
 
 /// Run headless report generation.
@@ -158,7 +336,7 @@ pub async fn run_report(
         .engine_url_override
         .clone()
         .unwrap_or_else(|| config.engine_url());
-    let client = EngineClient::from_url(&engine_url);
+    let client = EngineClient::from_url(&engine_url, config);
 
     match client.status().await {
         Ok(status) if status.ready => {}
@@ -308,6 +486,20 @@ pub async fn run_init(path: Option<&str>, yes: bool, force: bool, config: &TuiCo
         let _ = std::fs::write(&project_toml_path, toml_content);
     }
 
+    // Create a starter compliance README pointing at the scaffolded files
+    let readme_path = complior_dir.join("README.md");
+    if !readme_path.exists() {
+        let readme = "# Complior\n\n\
+            This directory holds this project's compliance configuration.\n\n\
+            - `project.toml` — scan profile: jurisdiction, role, industry, scan scope\n\
+            - `.env` — LLM provider keys, used by `scan --deep`, `eval --llm`, `fix --ai`\n\
+            - `last-scan.json` — most recent `complior scan` result (score, findings)\n\
+            - `passport.json` — Agent Passport(s) for AI systems discovered in this project\n\n\
+            Run `complior scan` to check compliance, or `complior report` to generate a report.\n\
+            See `.complior/project.toml` to change the scan profile without re-running init.\n";
+        let _ = std::fs::write(&readme_path, readme);
+    }
+
     // Create .env template with LLM provider examples
     let env_file_path = complior_dir.join(".env");
     if !env_file_path.exists() {
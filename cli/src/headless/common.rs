@@ -29,13 +29,13 @@ pub fn url_encode(s: &str) -> String {
 pub fn resolve_client_with(config: &TuiConfig, project_path: &std::path::Path) -> EngineClient {
     // 1. Check project path
     if let Some(info) = daemon::find_running_daemon(project_path) {
-        return EngineClient::from_url(&format!("http://127.0.0.1:{}", info.port));
+        return EngineClient::from_url(&format!("http://127.0.0.1:{}", info.port), config);
     }
     // 2. Walk up from CWD
     let mut dir = std::env::current_dir().unwrap_or_default();
     loop {
         if let Some(info) = daemon::find_running_daemon(&dir) {
-            return EngineClient::from_url(&format!("http://127.0.0.1:{}", info.port));
+            return EngineClient::from_url(&format!("http://127.0.0.1:{}", info.port), config);
         }
         if !dir.pop() {
             break;
@@ -72,7 +72,7 @@ pub async fn ensure_engine_for(
 
     // Create client: use project-path daemon port if found, otherwise default port.
     let client = if let Some(info) = &daemon_info {
-        EngineClient::from_url(&format!("http://127.0.0.1:{}", info.port))
+        EngineClient::from_url(&format!("http://127.0.0.1:{}", info.port), config)
     } else {
         EngineClient::new(config)
     };
@@ -119,7 +119,8 @@ pub async fn ensure_engine_for(
         .with_project_path(&project_path);
         match mgr.start_with_pid(&pid_path, false) {
             Ok(port) => {
-                let new_client = EngineClient::from_url(&format!("http://127.0.0.1:{port}"));
+                let new_client =
+                    EngineClient::from_url(&format!("http://127.0.0.1:{port}"), config);
                 if mgr.wait_for_ready(&new_client).await {
                     // Leak the manager so it doesn't get dropped (and killed) when this
                     // function returns. The engine stays alive for the duration of the command.
@@ -29,12 +29,7 @@ pub async fn run_daemon(
 }
 
 /// Start the daemon (foreground). If already running, print info and exit.
-async fn run_daemon_start(
-    watch: bool,
-    port: Option<u16>,
-    project_path: &Path,
-    _config: &TuiConfig,
-) {
+async fn run_daemon_start(watch: bool, port: Option<u16>, project_path: &Path, config: &TuiConfig) {
     // Check for existing daemon
     if let Some(info) = daemon::find_running_daemon(project_path) {
         println!(
@@ -100,7 +95,7 @@ async fn run_daemon_start(
     };
 
     // Wait for engine health
-    let client = EngineClient::from_url(&format!("http://127.0.0.1:{target_port}"));
+    let client = EngineClient::from_url(&format!("http://127.0.0.1:{target_port}"), config);
     let ready = wait_for_engine(&client).await;
 
     if ready {
@@ -149,7 +144,7 @@ async fn run_daemon_status(project_path: &Path, config: &TuiConfig) {
                 .engine_url_override
                 .clone()
                 .unwrap_or_else(|| format!("http://127.0.0.1:{}", info.port));
-            let client = EngineClient::from_url(&url);
+            let client = EngineClient::from_url(&url, config);
             match client.status().await {
                 Ok(status) if status.ready => {
                     println!("  Engine:     ready");
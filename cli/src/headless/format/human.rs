@@ -87,7 +87,10 @@ fn render_scan_info(o: &mut String, result: &ScanResult) {
 
     // Elapsed time
     let elapsed = if result.duration >= 1000 {
-        format!("{:.1}s", result.duration as f64 / 1000.0)
+        format!(
+            "{}s",
+            crate::locale::format_decimal(result.duration as f64 / 1000.0, 1)
+        )
     } else {
         format!("{}ms", result.duration)
     };
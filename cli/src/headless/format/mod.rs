@@ -199,6 +199,80 @@ pub(super) const fn sarif_level(severity: &Severity) -> &'static str {
     }
 }
 
+/// Map Severity to GitHub Checks annotation level.
+const fn github_annotation_level(severity: &Severity) -> &'static str {
+    match severity {
+        Severity::Critical | Severity::High => "failure",
+        Severity::Medium => "warning",
+        Severity::Low | Severity::Info => "notice",
+    }
+}
+
+/// Map Severity to GitLab Code Quality severity.
+const fn gitlab_severity(severity: &Severity) -> &'static str {
+    match severity {
+        Severity::Critical => "blocker",
+        Severity::High => "critical",
+        Severity::Medium => "major",
+        Severity::Low => "minor",
+        Severity::Info => "info",
+    }
+}
+
+/// Format findings as GitHub Checks annotations
+/// (<https://docs.github.com/en/rest/checks/runs#annotations-object>).
+/// Only findings with a `file` are annotatable; the rest are dropped.
+pub fn format_github_annotations(result: &ScanResult) -> String {
+    let annotations: Vec<serde_json::Value> = result
+        .findings
+        .iter()
+        .filter(|f| f.r#type == crate::types::CheckResultType::Fail)
+        .filter_map(|f| {
+            let path = f.file.as_ref()?;
+            let line = f.line.unwrap_or(1);
+            Some(serde_json::json!({
+                "path": path,
+                "start_line": line,
+                "end_line": line,
+                "annotation_level": github_annotation_level(&f.severity),
+                "title": f.check_id,
+                "message": f.message,
+            }))
+        })
+        .collect();
+
+    serde_json::to_string_pretty(&serde_json::json!({ "annotations": annotations }))
+        .unwrap_or_else(|e| format!("{{\"error\": \"{e}\"}}"))
+}
+
+/// Format findings as a GitLab Code Quality report
+/// (<https://docs.gitlab.com/ee/ci/testing/code_quality.html#implement-a-custom-tool>).
+/// Only findings with a `file` get a location; the rest are dropped.
+pub fn format_gitlab_codequality(result: &ScanResult) -> String {
+    let entries: Vec<serde_json::Value> = result
+        .findings
+        .iter()
+        .filter(|f| f.r#type == crate::types::CheckResultType::Fail)
+        .filter_map(|f| {
+            let path = f.file.as_ref()?;
+            let line = f.line.unwrap_or(1);
+            let fingerprint = format!("{}:{path}", f.check_id);
+            Some(serde_json::json!({
+                "description": f.message,
+                "check_name": f.check_id,
+                "fingerprint": fingerprint,
+                "severity": gitlab_severity(&f.severity),
+                "location": {
+                    "path": path,
+                    "lines": { "begin": line }
+                }
+            }))
+        })
+        .collect();
+
+    serde_json::to_string_pretty(&entries).unwrap_or_else(|e| format!("[{{\"error\": \"{e}\"}}]"))
+}
+
 // ── Pager ────────────────────────────────────────────────────────
 
 /// Print text through a pager (`less`) when stdout is a TTY and output is long.
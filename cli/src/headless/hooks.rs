@@ -0,0 +1,153 @@
+use crate::cli::HooksAction;
+
+use super::common::resolve_project_path_buf;
+
+/// Marker line written into every hook script complior installs, so
+/// `uninstall`/`status` can tell a complior-managed hook apart from one the
+/// user wrote by hand.
+const HOOK_MARKER: &str = "# complior-managed-hook: do not edit, run `complior hooks uninstall`";
+
+const KNOWN_STAGES: [&str; 2] = ["pre-commit", "pre-push"];
+
+pub fn run_hooks_command(action: &HooksAction) -> i32 {
+    match action {
+        HooksAction::Install {
+            stage,
+            threshold,
+            path,
+        } => run_hooks_install(stage, *threshold, path.as_deref()),
+        HooksAction::Uninstall { stage, path } => {
+            run_hooks_uninstall(stage.as_deref(), path.as_deref())
+        }
+        HooksAction::Status { path } => run_hooks_status(path.as_deref()),
+    }
+}
+
+/// Resolve `<project>/.git/hooks`, using `git rev-parse --git-dir` so
+/// worktrees (where `.git` is a file, not a directory) resolve correctly.
+fn hooks_dir(project_path: &std::path::Path) -> std::path::PathBuf {
+    let git_dir = std::process::Command::new("git")
+        .args(["rev-parse", "--git-dir"])
+        .current_dir(project_path)
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string());
+
+    match git_dir {
+        Some(dir) => {
+            let dir = std::path::PathBuf::from(dir);
+            if dir.is_absolute() {
+                dir.join("hooks")
+            } else {
+                project_path.join(dir).join("hooks")
+            }
+        }
+        None => project_path.join(".git/hooks"),
+    }
+}
+
+fn hook_script(threshold: u32) -> String {
+    format!("#!/bin/sh\n{HOOK_MARKER}\ncomplior scan --ci --threshold {threshold}\n")
+}
+
+fn run_hooks_install(stage: &str, threshold: u32, path: Option<&str>) -> i32 {
+    if !KNOWN_STAGES.contains(&stage) {
+        eprintln!(
+            "Error: unknown hook stage '{stage}' (expected one of: {})",
+            KNOWN_STAGES.join(", ")
+        );
+        return 1;
+    }
+
+    let project_path = resolve_project_path_buf(path);
+    let dir = hooks_dir(&project_path);
+    if let Err(e) = std::fs::create_dir_all(&dir) {
+        eprintln!("Error: could not create {}: {e}", dir.display());
+        return 1;
+    }
+
+    let hook_path = dir.join(stage);
+    if hook_path.exists() {
+        let existing = std::fs::read_to_string(&hook_path).unwrap_or_default();
+        if !existing.contains(HOOK_MARKER) {
+            eprintln!(
+                "Error: {} already exists and was not installed by complior. Remove it first.",
+                hook_path.display()
+            );
+            return 1;
+        }
+    }
+
+    if let Err(e) = std::fs::write(&hook_path, hook_script(threshold)) {
+        eprintln!("Error: could not write {}: {e}", hook_path.display());
+        return 1;
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        if let Ok(mut perms) = std::fs::metadata(&hook_path).map(|m| m.permissions()) {
+            perms.set_mode(perms.mode() | 0o111);
+            let _ = std::fs::set_permissions(&hook_path, perms);
+        }
+    }
+
+    println!(
+        "Installed {stage} hook at {} (threshold: {threshold})",
+        hook_path.display()
+    );
+    0
+}
+
+fn run_hooks_uninstall(stage: Option<&str>, path: Option<&str>) -> i32 {
+    let project_path = resolve_project_path_buf(path);
+    let dir = hooks_dir(&project_path);
+    let stages: Vec<&str> = stage.map_or_else(|| KNOWN_STAGES.to_vec(), |s| vec![s]);
+
+    let mut removed = 0;
+    for stage in stages {
+        let hook_path = dir.join(stage);
+        let Ok(content) = std::fs::read_to_string(&hook_path) else {
+            continue;
+        };
+        if !content.contains(HOOK_MARKER) {
+            eprintln!(
+                "Skipping {}: not installed by complior.",
+                hook_path.display()
+            );
+            continue;
+        }
+        if let Err(e) = std::fs::remove_file(&hook_path) {
+            eprintln!("Error: could not remove {}: {e}", hook_path.display());
+            return 1;
+        }
+        println!("Removed {}", hook_path.display());
+        removed += 1;
+    }
+
+    if removed == 0 {
+        println!("No complior-managed hooks found.");
+    }
+    0
+}
+
+fn run_hooks_status(path: Option<&str>) -> i32 {
+    let project_path = resolve_project_path_buf(path);
+    let dir = hooks_dir(&project_path);
+
+    println!();
+    println!("  Git Compliance Hooks");
+    println!("  {}", "─".repeat(40));
+    for stage in KNOWN_STAGES {
+        let hook_path = dir.join(stage);
+        let status = match std::fs::read_to_string(&hook_path) {
+            Ok(content) if content.contains(HOOK_MARKER) => "installed (complior)",
+            Ok(_) => "present (not managed by complior)",
+            Err(_) => "not installed",
+        };
+        println!("  {stage:<12} {status}");
+    }
+    println!();
+    0
+}
@@ -7,6 +7,127 @@ pub async fn run_import_command(action: &ImportAction, config: &TuiConfig) -> i3
         ImportAction::Promptfoo { file, json } => {
             run_import_promptfoo(file.as_deref(), *json, config).await
         }
+        ImportAction::Semgrep { file, json } => {
+            run_import_findings("Semgrep", "/import/semgrep", file.as_deref(), *json, config).await
+        }
+        ImportAction::Bandit { file, json } => {
+            run_import_findings("Bandit", "/import/bandit", file.as_deref(), *json, config).await
+        }
+        ImportAction::Trivy { file, json } => {
+            run_import_findings("Trivy", "/import/trivy", file.as_deref(), *json, config).await
+        }
+    }
+}
+
+/// Read a scanner's JSON report from `file` (or stdin), forward it to the
+/// engine's `endpoint` for mapping into Complior's findings model (per the
+/// project's category mapping config), and print the result.
+///
+/// Shared by [`ImportAction::Semgrep`], [`ImportAction::Bandit`], and
+/// [`ImportAction::Trivy`] -- unlike Promptfoo's red-team probe report,
+/// these three tools all report a flat list of findings, so the same
+/// read/forward/format shape applies to all of them.
+async fn run_import_findings(
+    tool_name: &str,
+    endpoint: &str,
+    file: Option<&str>,
+    json: bool,
+    config: &TuiConfig,
+) -> i32 {
+    // Read JSON from file or stdin
+    let input = if let Some(path) = file {
+        match std::fs::read_to_string(path) {
+            Ok(data) => data,
+            Err(e) => {
+                eprintln!("Error reading file {path}: {e}");
+                return 1;
+            }
+        }
+    } else {
+        use std::io::Read;
+        let mut buf = String::new();
+        if let Err(e) = std::io::stdin().read_to_string(&mut buf) {
+            eprintln!("Error reading stdin: {e}");
+            return 1;
+        }
+        buf
+    };
+
+    // Parse JSON
+    let body: serde_json::Value = match serde_json::from_str(&input) {
+        Ok(v) => v,
+        Err(e) => {
+            eprintln!("Error: Invalid JSON: {e}");
+            return 1;
+        }
+    };
+
+    let client = match ensure_engine(config).await {
+        Ok(c) => c,
+        Err(code) => return code,
+    };
+
+    match client.post_json(endpoint, &body).await {
+        Ok(result) => {
+            if let Some(err_msg) = result.get("error").and_then(|v| v.as_str()) {
+                let msg = result
+                    .get("message")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or(err_msg);
+                eprintln!("Error: {msg}");
+                return 1;
+            }
+
+            if json {
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&result).unwrap_or_default()
+                );
+                return 0;
+            }
+
+            // Human-readable output
+            let imported = result
+                .get("findingsImported")
+                .and_then(serde_json::Value::as_u64)
+                .unwrap_or(0);
+            let mapped = result
+                .get("findingsMapped")
+                .and_then(serde_json::Value::as_u64)
+                .unwrap_or(0);
+            let unmapped = imported.saturating_sub(mapped);
+
+            println!();
+            println!("  {tool_name} Import Complete");
+            println!("  {}", "-".repeat(40));
+            println!("  Findings imported: {imported}");
+            println!("  Mapped to checks: {mapped}  Unmapped: {unmapped}");
+
+            // Category mapping breakdown
+            if let Some(categories) = result.get("categoryMapping").and_then(|v| v.as_array()) {
+                println!();
+                println!("  {:<16} {:>8}", "CATEGORY", "COUNT");
+                println!("  {}", "-".repeat(26));
+                for cat in categories {
+                    let cat_id = cat
+                        .get("categoryId")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or("?");
+                    let count = cat
+                        .get("count")
+                        .and_then(serde_json::Value::as_u64)
+                        .unwrap_or(0);
+                    println!("  {cat_id:<16} {count:>8}");
+                }
+            }
+
+            println!();
+            0
+        }
+        Err(e) => {
+            eprintln!("Error: {e}");
+            1
+        }
     }
 }
 
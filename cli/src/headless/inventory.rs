@@ -0,0 +1,62 @@
+use crate::cli::InventoryAction;
+use crate::inventory::discover_inventory;
+
+pub fn run_inventory_command(action: &InventoryAction) -> i32 {
+    match action {
+        InventoryAction::Show { path } => run_show(path.as_deref()),
+        InventoryAction::Gpai { path } => run_gpai(path.as_deref()),
+    }
+}
+
+fn run_show(path: Option<&str>) -> i32 {
+    let project_path = super::common::resolve_project_path_buf(path);
+    let report = discover_inventory(&project_path);
+
+    println!();
+    println!("  Inventory");
+    println!("  {}", "-".repeat(40));
+    println!("  SBOM components: {}", report.components.len());
+    println!(
+        "  Unlicensed components: {}",
+        report.unlicensed_components().len()
+    );
+    println!("  Models: {}", report.models.len());
+    println!("  GPAI models: {}", report.gpai_models().len());
+
+    if !report.models.is_empty() {
+        println!();
+        println!("  {:<24} {:<16} {:>6}", "MODEL", "PROVIDER", "GPAI");
+        println!("  {}", "-".repeat(48));
+        for model in &report.models {
+            println!(
+                "  {:<24} {:<16} {:>6}",
+                model.name,
+                model.provider,
+                if model.gpai { "yes" } else { "no" }
+            );
+        }
+    }
+
+    println!();
+    0
+}
+
+fn run_gpai(path: Option<&str>) -> i32 {
+    let project_path = super::common::resolve_project_path_buf(path);
+    let report = discover_inventory(&project_path);
+    let gpai = report.gpai_models();
+
+    if gpai.is_empty() {
+        println!("No GPAI models declared in .complior/inventory/models.yaml");
+        return 0;
+    }
+
+    println!();
+    println!("  GPAI Models");
+    println!("  {}", "-".repeat(40));
+    for model in gpai {
+        println!("  {} ({})", model.name, model.provider);
+    }
+    println!();
+    0
+}
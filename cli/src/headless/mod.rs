@@ -31,12 +31,18 @@ pub mod debt;
 #[cfg(feature = "extras")]
 pub mod doc;
 #[cfg(feature = "extras")]
+pub mod hooks;
+#[cfg(feature = "extras")]
 pub mod import;
 #[cfg(feature = "extras")]
+pub mod inventory;
+#[cfg(feature = "extras")]
 pub mod jurisdiction;
 #[cfg(feature = "extras")]
 pub mod login;
 #[cfg(feature = "extras")]
+pub mod plugins;
+#[cfg(feature = "extras")]
 pub mod proxy;
 #[cfg(feature = "extras")]
 pub mod redteam;
@@ -48,10 +54,14 @@ pub mod supply_chain;
 pub mod sync;
 #[cfg(feature = "extras")]
 pub mod tools;
+#[cfg(feature = "extras")]
+pub mod track;
 
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "tui")]
+pub use commands::doctor_report;
 pub use commands::{run_doctor, run_init, run_report, run_update, run_version};
 pub use fix::run_headless_fix;
 pub use scan::run_headless_scan;
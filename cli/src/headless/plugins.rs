@@ -0,0 +1,83 @@
+use crate::cli::PluginsAction;
+use crate::plugins::{PluginCapability, discover_plugins};
+
+pub fn run_plugins_command(action: &PluginsAction) -> i32 {
+    match action {
+        PluginsAction::List { path } => run_list(path.as_deref()),
+        PluginsAction::Info { name, path } => run_info(name, path.as_deref()),
+    }
+}
+
+fn run_list(path: Option<&str>) -> i32 {
+    let project_path = super::common::resolve_project_path_buf(path);
+    let plugins = discover_plugins(&project_path);
+
+    if plugins.is_empty() {
+        println!("No plugins found under .complior/plugins/");
+        return 0;
+    }
+
+    println!();
+    println!("  Plugins");
+    println!("  {}", "─".repeat(50));
+    println!("  (discovery/manifest inspection only — WASM loading/sandboxing and");
+    println!("   the /plugins TUI overlay are not implemented yet)");
+    for plugin in &plugins {
+        let status = if plugin.is_compatible() {
+            "ok"
+        } else {
+            "ABI MISMATCH"
+        };
+        println!(
+            "  {} v{}  [{}]  {}",
+            plugin.manifest.name,
+            plugin.manifest.version,
+            capabilities_label(&plugin.manifest.capabilities),
+            status,
+        );
+    }
+    println!();
+    0
+}
+
+fn run_info(name: &str, path: Option<&str>) -> i32 {
+    let project_path = super::common::resolve_project_path_buf(path);
+    let plugins = discover_plugins(&project_path);
+
+    let Some(plugin) = plugins.iter().find(|p| p.manifest.name == name) else {
+        eprintln!("Error: no plugin named {name:?} found under .complior/plugins/");
+        return 1;
+    };
+
+    println!();
+    println!("  {}", plugin.manifest.name);
+    println!("  version:       {}", plugin.manifest.version);
+    println!("  abi_version:   {}", plugin.manifest.abi_version);
+    println!(
+        "  capabilities:  {}",
+        capabilities_label(&plugin.manifest.capabilities)
+    );
+    println!("  entry:         {}", plugin.manifest.entry);
+    println!("  dir:           {}", plugin.dir.display());
+    if !plugin.is_compatible() {
+        eprintln!(
+            "  Warning: this plugin targets ABI v{}, but this build speaks v{}",
+            plugin.manifest.abi_version,
+            crate::plugins::PLUGIN_ABI_VERSION
+        );
+    }
+    println!();
+    0
+}
+
+fn capabilities_label(capabilities: &[PluginCapability]) -> String {
+    capabilities
+        .iter()
+        .map(|c| match c {
+            PluginCapability::Check => "check",
+            PluginCapability::Exporter => "exporter",
+            PluginCapability::ChatTool => "chat-tool",
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -2,13 +2,16 @@ use std::io::IsTerminal as _;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 
-use crate::cli::SeverityLevel;
+use crate::cli::{AnnotationFormat, SeverityLevel};
 use crate::config::TuiConfig;
 use crate::engine_client::EngineClient;
 use crate::types::Severity;
 
 use super::format::colors::{bold, check_mark, dim, green, red, tree_branch, tree_end};
-use super::format::{FormatOptions, format_human, format_json, format_sarif, print_paged};
+use super::format::{
+    FormatOptions, format_github_annotations, format_gitlab_codequality, format_human, format_json,
+    format_sarif, print_paged,
+};
 
 /// Run a headless (non-TUI) scan and print results to stdout.
 /// Returns the exit code: 0 = pass, 1 = fail/error.
@@ -17,6 +20,7 @@ pub async fn run_headless_scan(
     ci: bool,
     json: bool,
     sarif: bool,
+    annotate: Option<AnnotationFormat>,
     _no_tui: bool,
     threshold: u32,
     fail_on: Option<SeverityLevel>,
@@ -32,7 +36,7 @@ pub async fn run_headless_scan(
         .engine_url_override
         .clone()
         .unwrap_or_else(|| config.engine_url());
-    let client = EngineClient::from_url(&engine_url);
+    let client = EngineClient::from_url(&engine_url, config);
 
     // Check engine is reachable
     match client.status().await {
@@ -82,13 +86,13 @@ pub async fn run_headless_scan(
         if !check_uv_available() {
             return 1;
         }
-        if !json && !sarif {
+        if !json && !sarif && annotate.is_none() {
             show_deep_scan_tools();
         }
     }
 
     // Show LLM model info when --llm is used
-    if llm && !json && !sarif {
+    if llm && !json && !sarif && annotate.is_none() {
         if let Ok(info) = client.get_json("/llm/info").await {
             let configured = info
                 .get("configured")
@@ -148,7 +152,8 @@ pub async fn run_headless_scan(
 
     // Start spinner (stderr, only for TTY and non-JSON/SARIF)
     let spinner_active = Arc::new(AtomicBool::new(false));
-    let spinner_handle = if !json && !sarif && std::io::stderr().is_terminal() {
+    let spinner_handle = if !json && !sarif && annotate.is_none() && std::io::stderr().is_terminal()
+    {
         Some(start_spinner(Arc::clone(&spinner_active)))
     } else {
         None
@@ -250,6 +255,16 @@ pub async fn run_headless_scan(
         }
     };
 
+    // Merge findings from user-defined local rule packs (.complior/rules/*.toml)
+    let local_findings = crate::local_rules::scan_local_rules(&scan_path);
+    let result = if local_findings.is_empty() {
+        result
+    } else {
+        let mut findings = result.findings;
+        findings.extend(local_findings);
+        crate::types::ScanResult { findings, ..result }
+    };
+
     // Filter by agent name if --agent is set
     let result = if let Some(agent_name) = agent {
         let filtered_findings: Vec<_> = result
@@ -268,11 +283,23 @@ pub async fn run_headless_scan(
     // Fetch multi-framework scores (includes OWASP/MITRE if redteam data exists)
     let framework_scores = client.framework_scores().await.ok();
 
+    // Record commit hash, branch, and dirty flag with this scan, and warn
+    // when the branch has changed since the last recorded scan.
+    let git_context = capture_git_context(&scan_path);
+    let branch_warning = git_context.as_ref().and_then(|git| {
+        record_git_context(&scan_path, git).filter(|prev_branch| prev_branch != &git.branch)
+    });
+
     // Format output (default: human-readable with pager)
     if json {
         println!("{}", format_json(&result));
     } else if sarif {
         println!("{}", format_sarif(&result));
+    } else if let Some(format) = annotate {
+        match format {
+            AnnotationFormat::Github => println!("{}", format_github_annotations(&result)),
+            AnnotationFormat::Gitlab => println!("{}", format_gitlab_codequality(&result)),
+        }
     } else {
         // Read previous score for delta display
         let prev_score = if deep {
@@ -325,6 +352,24 @@ pub async fn run_headless_scan(
                 dim(&format!("Score: {:.0}/100", result.score.total_score)),
                 dim(&format!("{:.0}s", scan_elapsed.elapsed().as_secs_f64())),
             );
+            if let Some(git) = &git_context {
+                let dirty_suffix = if git.dirty { ", dirty" } else { "" };
+                eprintln!(
+                    "  {}",
+                    dim(&format!(
+                        "Git: {} @ {}{dirty_suffix}",
+                        git.branch, git.commit
+                    ))
+                );
+            }
+            if let Some(prev_branch) = &branch_warning {
+                eprintln!(
+                    "  {}",
+                    super::format::colors::yellow(&format!(
+                        "Warning: comparing against a scan from a different branch ({prev_branch})"
+                    ))
+                );
+            }
             eprintln!();
         }
 
@@ -352,7 +397,7 @@ pub async fn run_headless_scan(
     }
 
     // Hints (non-CI, non-JSON, non-SARIF)
-    if !ci && !json && !sarif {
+    if !ci && !json && !sarif && annotate.is_none() {
         // No AI components detected
         let has_ai_findings = result.findings.iter().any(|f| {
             f.check_id.starts_with("l3-")
@@ -445,7 +490,7 @@ pub async fn run_scan_diff(
         .engine_url_override
         .clone()
         .unwrap_or_else(|| config.engine_url());
-    let client = EngineClient::from_url(&engine_url);
+    let client = EngineClient::from_url(&engine_url, config);
 
     // Check engine
     match client.status().await {
@@ -648,6 +693,69 @@ fn read_last_score(project_path: &str) -> Option<f64> {
     v.get("score")?.get("totalScore")?.as_f64()
 }
 
+/// Git commit metadata attached to a scan, so a scan history browser can
+/// tell which commit/branch a score belongs to.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitContext {
+    pub commit: String,
+    pub branch: String,
+    pub dirty: bool,
+}
+
+/// Capture the current commit hash, branch, and working-tree dirty flag.
+/// Returns `None` outside a git repository.
+pub fn capture_git_context(project_path: &str) -> Option<GitContext> {
+    let run = |args: &[&str]| -> Option<String> {
+        let output = std::process::Command::new("git")
+            .args(args)
+            .current_dir(project_path)
+            .output()
+            .ok()?;
+        output
+            .status
+            .success()
+            .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    let commit = run(&["rev-parse", "--short", "HEAD"])?;
+    let branch = run(&["rev-parse", "--abbrev-ref", "HEAD"])?;
+    let dirty = run(&["status", "--porcelain"]).is_some_and(|s| !s.is_empty());
+
+    Some(GitContext {
+        commit,
+        branch,
+        dirty,
+    })
+}
+
+/// Merge git commit metadata into `.complior/last-scan.json` and return the
+/// previously recorded branch (if any), so callers can warn when the branch
+/// has changed since the last scan.
+fn record_git_context(project_path: &str, git: &GitContext) -> Option<String> {
+    let path = std::path::Path::new(project_path).join(".complior/last-scan.json");
+    let mut value: serde_json::Value = std::fs::read_to_string(&path)
+        .ok()
+        .and_then(|c| serde_json::from_str(&c).ok())
+        .unwrap_or_else(|| serde_json::json!({}));
+
+    let prev_branch = value
+        .get("git")
+        .and_then(|g| g.get("branch"))
+        .and_then(|b| b.as_str())
+        .map(String::from);
+
+    if let Some(obj) = value.as_object_mut() {
+        obj.insert("git".to_string(), serde_json::json!(git));
+    }
+    let _ = std::fs::write(
+        &path,
+        serde_json::to_string_pretty(&value).unwrap_or_default(),
+    );
+
+    prev_branch
+}
+
 fn get_changed_files(base_branch: &str, project_path: &str) -> Vec<String> {
     let output = std::process::Command::new("git")
         .args(["diff", "--name-only", &format!("{base_branch}...HEAD")])
@@ -6,7 +6,7 @@ fn resolve_engine(config: &TuiConfig) -> EngineClient {
         .engine_url_override
         .clone()
         .unwrap_or_else(|| config.engine_url());
-    EngineClient::from_url(&url)
+    EngineClient::from_url(&url, config)
 }
 
 pub async fn run_sync(
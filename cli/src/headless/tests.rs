@@ -1,7 +1,8 @@
 #[cfg(test)]
 mod tests {
     use crate::headless::format::{
-        FormatOptions, format_human, format_json, format_sarif, sarif_level,
+        FormatOptions, format_github_annotations, format_gitlab_codequality, format_human,
+        format_json, format_sarif, sarif_level,
     };
     use crate::types::{
         CategoryScore, CheckResultType, ExternalToolResult, Finding, FindingExplanation,
@@ -151,6 +152,33 @@ mod tests {
         assert_eq!(results[1]["level"], "warning"); // Medium = warning
     }
 
+    #[test]
+    fn format_github_annotations_output() {
+        let result = mock_scan_result();
+        let json = format_github_annotations(&result);
+        let parsed: serde_json::Value = serde_json::from_str(&json).expect("valid JSON");
+        let annotations = parsed["annotations"].as_array().unwrap();
+        // The FRIA finding has no `file` and is dropped; only the second remains.
+        assert_eq!(annotations.len(), 1);
+        assert_eq!(annotations[0]["path"], "src/chat/anthropic.ts");
+        assert_eq!(annotations[0]["start_line"], 8);
+        assert_eq!(annotations[0]["annotation_level"], "warning"); // Medium
+        assert_eq!(annotations[0]["title"], "l4-bare-llm");
+    }
+
+    #[test]
+    fn format_gitlab_codequality_output() {
+        let result = mock_scan_result();
+        let json = format_gitlab_codequality(&result);
+        let parsed: serde_json::Value = serde_json::from_str(&json).expect("valid JSON");
+        let entries = parsed.as_array().unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0]["check_name"], "l4-bare-llm");
+        assert_eq!(entries[0]["severity"], "major"); // Medium
+        assert_eq!(entries[0]["location"]["path"], "src/chat/anthropic.ts");
+        assert_eq!(entries[0]["location"]["lines"]["begin"], 8);
+    }
+
     #[test]
     fn sarif_level_mapping() {
         assert_eq!(sarif_level(&Severity::Critical), "error");
@@ -7,7 +7,7 @@ pub async fn run_tools_command(action: &ToolsAction, config: &TuiConfig) -> i32
         .engine_url_override
         .clone()
         .unwrap_or_else(|| config.engine_url());
-    let client = EngineClient::from_url(&engine_url);
+    let client = EngineClient::from_url(&engine_url, config);
 
     match action {
         ToolsAction::Status => run_tools_status(&client).await,
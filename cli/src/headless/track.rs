@@ -0,0 +1,456 @@
+//! Issue tracker export: file findings from the last scan as Jira/GitHub
+//! issues, recording the created issue key in `.complior/tracked-issues.json`
+//! so re-running `track create` for the same finding doesn't file a
+//! duplicate.
+
+use crate::cli::{TicketProvider, TrackAction};
+use crate::types::Finding;
+
+use super::common::resolve_project_path_buf;
+
+/// One finding filed into an external issue tracker.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TrackedIssue {
+    check_id: String,
+    provider: String,
+    issue_key: String,
+    issue_url: String,
+}
+
+pub async fn run_track_command(action: &TrackAction, config: &crate::config::TuiConfig) -> i32 {
+    match action {
+        TrackAction::Create {
+            check_id,
+            provider,
+            repo,
+            jira_project,
+            json,
+            path,
+        } => {
+            run_track_create(
+                check_id,
+                *provider,
+                repo.as_deref(),
+                jira_project.as_deref(),
+                *json,
+                path.as_deref(),
+                config,
+            )
+            .await
+        }
+        TrackAction::List { json, path } => run_track_list(*json, path.as_deref()),
+    }
+}
+
+fn tracked_issues_path(project_path: &std::path::Path) -> std::path::PathBuf {
+    project_path.join(".complior").join("tracked-issues.json")
+}
+
+fn load_tracked_issues(project_path: &std::path::Path) -> Vec<TrackedIssue> {
+    std::fs::read_to_string(tracked_issues_path(project_path))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_tracked_issues(
+    project_path: &std::path::Path,
+    issues: &[TrackedIssue],
+) -> Result<(), String> {
+    let path = tracked_issues_path(project_path);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    let content = serde_json::to_string_pretty(issues).map_err(|e| e.to_string())?;
+    std::fs::write(&path, content).map_err(|e| e.to_string())
+}
+
+/// Load the finding matching `check_id` from `.complior/last-scan.json`.
+fn find_finding(project_path: &std::path::Path, check_id: &str) -> Option<Finding> {
+    let content =
+        std::fs::read_to_string(project_path.join(".complior").join("last-scan.json")).ok()?;
+    let value: serde_json::Value = serde_json::from_str(&content).ok()?;
+    let findings: Vec<Finding> = serde_json::from_value(value.get("findings")?.clone()).ok()?;
+    findings.into_iter().find(|f| f.check_id == check_id)
+}
+
+async fn run_track_create(
+    check_id: &str,
+    provider: TicketProvider,
+    repo: Option<&str>,
+    jira_project: Option<&str>,
+    json: bool,
+    path: Option<&str>,
+    config: &crate::config::TuiConfig,
+) -> i32 {
+    if config.offline_mode {
+        eprintln!(
+            "Error: `complior track create` requires a network call to {}, which is disabled in offline mode (--offline).",
+            provider.as_str()
+        );
+        return 1;
+    }
+
+    let project_path = resolve_project_path_buf(path);
+
+    let mut tracked = load_tracked_issues(&project_path);
+    if let Some(existing) = tracked
+        .iter()
+        .find(|t| t.check_id == check_id && t.provider == provider.as_str())
+    {
+        if json {
+            println!(
+                "{}",
+                serde_json::json!({ "alreadyTracked": true, "issue": existing })
+            );
+        } else {
+            println!(
+                "Already tracked: {} -> {} ({})",
+                check_id, existing.issue_key, existing.issue_url
+            );
+        }
+        return 0;
+    }
+
+    let Some(finding) = find_finding(&project_path, check_id) else {
+        eprintln!(
+            "Error: no finding with check ID '{check_id}' in the last scan. Run `complior scan` first."
+        );
+        return 1;
+    };
+
+    let title = format!("[Complior] {check_id}: {}", finding.message);
+    let mut body = format!(
+        "Severity: {}\nCheck ID: {check_id}\n",
+        finding.severity.as_str()
+    );
+    if let Some(ref article) = finding.article_reference {
+        body.push_str(&format!("Article reference: {article}\n"));
+    }
+    if let Some(ref file) = finding.file {
+        match finding.line {
+            Some(line) => body.push_str(&format!("Location: {file}:{line}\n")),
+            None => body.push_str(&format!("Location: {file}\n")),
+        }
+    }
+    body.push_str("\nFiled automatically by `complior track create`.");
+
+    let created = match provider {
+        TicketProvider::Github => {
+            let Some(repo) = repo else {
+                eprintln!("Error: --repo <owner/repo> is required for --provider github");
+                return 1;
+            };
+            create_github_issue(repo, &title, &body, config).await
+        }
+        TicketProvider::Jira => {
+            let Some(project) = jira_project else {
+                eprintln!("Error: --jira-project <KEY> is required for --provider jira");
+                return 1;
+            };
+            create_jira_issue(config, project, &title, &body).await
+        }
+    };
+
+    match created {
+        Ok(mut issue) => {
+            issue.check_id = check_id.to_string();
+            if json {
+                println!("{}", serde_json::json!({ "created": true, "issue": issue }));
+            } else {
+                println!(
+                    "Filed {check_id} as {} issue {} ({})",
+                    provider.as_str(),
+                    issue.issue_key,
+                    issue.issue_url
+                );
+            }
+            tracked.push(issue);
+            if let Err(e) = save_tracked_issues(&project_path, &tracked) {
+                eprintln!("Warning: issue was filed but tracking metadata was not saved: {e}");
+            }
+            0
+        }
+        Err(e) => {
+            eprintln!("Error: could not create {} issue: {e}", provider.as_str());
+            1
+        }
+    }
+}
+
+fn run_track_list(json: bool, path: Option<&str>) -> i32 {
+    let project_path = resolve_project_path_buf(path);
+    let tracked = load_tracked_issues(&project_path);
+
+    if json {
+        println!("{}", serde_json::json!(tracked));
+        return 0;
+    }
+
+    if tracked.is_empty() {
+        println!("No findings have been filed as tracker issues yet.");
+        return 0;
+    }
+
+    println!();
+    println!("  Tracked Findings");
+    println!("  {}", "─".repeat(40));
+    for issue in &tracked {
+        println!(
+            "  {:<20} {:<8} {} ({})",
+            issue.check_id, issue.provider, issue.issue_key, issue.issue_url
+        );
+    }
+    println!();
+    0
+}
+
+async fn create_github_issue(
+    repo: &str,
+    title: &str,
+    body: &str,
+    config: &crate::config::TuiConfig,
+) -> Result<TrackedIssue, String> {
+    let token = std::env::var("GITHUB_TOKEN")
+        .map_err(|_| "GITHUB_TOKEN environment variable is not set".to_string())?;
+
+    let client = crate::engine_client::apply_proxy_and_ca(
+        reqwest::Client::builder().timeout(std::time::Duration::from_secs(30)),
+        config,
+    )
+    .build()
+    .map_err(|e| format!("Failed to create HTTP client: {e}"))?;
+
+    let url = format!("https://api.github.com/repos/{repo}/issues");
+    let resp = client
+        .post(&url)
+        .bearer_auth(&token)
+        .header("User-Agent", "complior-cli")
+        .header("Accept", "application/vnd.github+json")
+        .json(&serde_json::json!({ "title": title, "body": body }))
+        .send()
+        .await
+        .map_err(|e| format!("Failed to reach GitHub: {e}"))?;
+
+    if !resp.status().is_success() {
+        let status = resp.status();
+        let text = resp.text().await.unwrap_or_default();
+        return Err(format!("GitHub API returned {status}: {text}"));
+    }
+
+    let value: serde_json::Value = resp
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse GitHub response: {e}"))?;
+    let number = value
+        .get("number")
+        .and_then(serde_json::Value::as_i64)
+        .ok_or("GitHub response missing issue number")?;
+    let html_url = value
+        .get("html_url")
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_string();
+
+    Ok(TrackedIssue {
+        check_id: String::new(),
+        provider: TicketProvider::Github.as_str().to_string(),
+        issue_key: format!("#{number}"),
+        issue_url: html_url,
+    })
+}
+
+async fn create_jira_issue(
+    config: &crate::config::TuiConfig,
+    project_key: &str,
+    title: &str,
+    body: &str,
+) -> Result<TrackedIssue, String> {
+    let base_url = std::env::var("JIRA_BASE_URL")
+        .map_err(|_| "JIRA_BASE_URL environment variable is not set".to_string())?;
+    let email = std::env::var("JIRA_EMAIL")
+        .map_err(|_| "JIRA_EMAIL environment variable is not set".to_string())?;
+    let token = std::env::var("JIRA_API_TOKEN")
+        .map_err(|_| "JIRA_API_TOKEN environment variable is not set".to_string())?;
+
+    let client = crate::engine_client::apply_proxy_and_ca(
+        reqwest::Client::builder().timeout(std::time::Duration::from_secs(30)),
+        config,
+    )
+    .build()
+    .map_err(|e| format!("Failed to create HTTP client: {e}"))?;
+
+    let url = format!("{}/rest/api/2/issue", base_url.trim_end_matches('/'));
+    let resp = client
+        .post(&url)
+        .basic_auth(email, Some(token))
+        .json(&serde_json::json!({
+            "fields": {
+                "project": { "key": project_key },
+                "summary": title,
+                "description": body,
+                "issuetype": { "name": "Bug" }
+            }
+        }))
+        .send()
+        .await
+        .map_err(|e| format!("Failed to reach Jira: {e}"))?;
+
+    if !resp.status().is_success() {
+        let status = resp.status();
+        let text = resp.text().await.unwrap_or_default();
+        return Err(format!("Jira API returned {status}: {text}"));
+    }
+
+    let value: serde_json::Value = resp
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse Jira response: {e}"))?;
+    let key = value
+        .get("key")
+        .and_then(|v| v.as_str())
+        .ok_or("Jira response missing issue key")?
+        .to_string();
+
+    Ok(TrackedIssue {
+        check_id: String::new(),
+        provider: TicketProvider::Jira.as_str().to_string(),
+        issue_key: key.clone(),
+        issue_url: format!("{}/browse/{key}", base_url.trim_end_matches('/')),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_project(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("complior-track-{name}-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join(".complior")).unwrap();
+        dir
+    }
+
+    fn write_last_scan(project_path: &std::path::Path, check_id: &str) {
+        let scan = serde_json::json!({
+            "findings": [{
+                "checkId": check_id,
+                "type": "fail",
+                "message": "example finding",
+                "severity": "high",
+            }]
+        });
+        std::fs::write(
+            project_path.join(".complior").join("last-scan.json"),
+            serde_json::to_string(&scan).unwrap(),
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn dedups_by_check_id_and_provider_without_reaching_the_network() {
+        let project = temp_project("dedup");
+        let existing = TrackedIssue {
+            check_id: "eu-art-9".to_string(),
+            provider: TicketProvider::Github.as_str().to_string(),
+            issue_key: "#42".to_string(),
+            issue_url: "https://github.com/acme/repo/issues/42".to_string(),
+        };
+        save_tracked_issues(&project, std::slice::from_ref(&existing)).unwrap();
+
+        let config = crate::config::TuiConfig::default();
+        let code = run_track_create(
+            "eu-art-9",
+            TicketProvider::Github,
+            None,
+            None,
+            false,
+            Some(project.to_str().unwrap()),
+            &config,
+        )
+        .await;
+
+        assert_eq!(code, 0);
+        let tracked = load_tracked_issues(&project);
+        assert_eq!(tracked.len(), 1);
+        assert_eq!(tracked[0].issue_key, "#42");
+
+        let _ = std::fs::remove_dir_all(&project);
+    }
+
+    #[tokio::test]
+    async fn dispatches_github_provider_and_requires_repo() {
+        let project = temp_project("github-dispatch");
+        write_last_scan(&project, "eu-art-9");
+        let config = crate::config::TuiConfig::default();
+
+        let code = run_track_create(
+            "eu-art-9",
+            TicketProvider::Github,
+            None,
+            None,
+            false,
+            Some(project.to_str().unwrap()),
+            &config,
+        )
+        .await;
+
+        assert_eq!(code, 1);
+        assert!(load_tracked_issues(&project).is_empty());
+
+        let _ = std::fs::remove_dir_all(&project);
+    }
+
+    #[tokio::test]
+    async fn dispatches_jira_provider_and_requires_jira_project() {
+        let project = temp_project("jira-dispatch");
+        write_last_scan(&project, "eu-art-9");
+        let config = crate::config::TuiConfig::default();
+
+        let code = run_track_create(
+            "eu-art-9",
+            TicketProvider::Jira,
+            None,
+            None,
+            false,
+            Some(project.to_str().unwrap()),
+            &config,
+        )
+        .await;
+
+        assert_eq!(code, 1);
+        assert!(load_tracked_issues(&project).is_empty());
+
+        let _ = std::fs::remove_dir_all(&project);
+    }
+
+    #[tokio::test]
+    async fn jira_token_does_not_fall_back_to_the_llm_api_key() {
+        // Regression test: `create_jira_issue` must only ever read
+        // `JIRA_API_TOKEN`. It must never accept `TuiConfig::api_key` (the
+        // user's LLM provider key) as a substitute, which would leak that
+        // key to whatever host `JIRA_BASE_URL` points at.
+        // SAFETY: this test owns these env vars; nothing else in the suite reads them.
+        unsafe {
+            std::env::set_var("JIRA_BASE_URL", "https://example.atlassian.net");
+            std::env::set_var("JIRA_EMAIL", "someone@example.com");
+            std::env::remove_var("JIRA_API_TOKEN");
+        }
+
+        let mut config = crate::config::TuiConfig::default();
+        config.api_key = Some("sk-should-never-be-sent-to-jira".to_string());
+
+        let result = create_jira_issue(&config, "PROJ", "title", "body").await;
+
+        // SAFETY: see above.
+        unsafe {
+            std::env::remove_var("JIRA_BASE_URL");
+            std::env::remove_var("JIRA_EMAIL");
+        }
+
+        assert_eq!(
+            result.unwrap_err(),
+            "JIRA_API_TOKEN environment variable is not set"
+        );
+    }
+}
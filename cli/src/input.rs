@@ -37,6 +37,17 @@ pub enum Action {
     HistoryDown,
     /// Trigger tab completion for commands, @OBL- references, etc.
     TabComplete,
+    /// Move the `@`-mention popup selection up (Arrow Up while mentioning).
+    MentionUp,
+    /// Move the `@`-mention popup selection down (Arrow Down while mentioning).
+    MentionDown,
+    /// Splice the selected mention popup match into the input (Enter/Tab
+    /// while mentioning).
+    MentionAccept,
+    /// Undo the last edit to the chat input line (Ctrl+Z while typing).
+    InputUndo,
+    /// Redo an edit undone via `InputUndo` (Ctrl+Y while typing).
+    InputRedo,
     /// Scroll content up by one line (k / Arrow Up in Normal mode).
     ScrollUp,
     /// Scroll content down by one line (j / Arrow Down in Normal mode).
@@ -65,14 +76,27 @@ pub enum Action {
     SelectionDown,
     /// Send visual selection to AI chat (Ctrl+K in Visual mode).
     SendSelectionToAi,
+    /// Yank the code viewer's visual selection into a register (y in
+    /// Visual mode).
+    Yank,
+    /// Paste the most recent yank register into the chat input (p in
+    /// Normal mode on the code viewer).
+    PasteYank,
     /// Accept a proposed diff.
     AcceptDiff,
     /// Reject a proposed diff.
     RejectDiff,
     /// Toggle expand/collapse of a tree node.
     ToggleExpand,
+    /// Enter/exit the file browser's inline filter (`f` while it's focused).
+    ToggleFileBrowserFilter,
+    /// Toggle "flatten matches" mode for the file browser's inline filter.
+    ToggleFileBrowserFlatten,
     /// Open the selected file in the viewer.
     OpenFile,
+    /// Shell out to `$EDITOR` at the code viewer's current file and line
+    /// (`o` in the code viewer).
+    OpenInEditor,
     /// Open the command palette overlay (Ctrl+P).
     ShowCommandPalette,
     /// Open the file picker overlay.
@@ -101,12 +125,70 @@ pub enum Action {
     CodeSearchNext,
     /// Jump to previous code search match (N).
     CodeSearchPrev,
-    /// Undo the last action (Ctrl+Z).
+    /// Undo the last fix/scan action (Ctrl+Z outside Insert mode; see
+    /// `InputUndo` for the input line's own undo stack).
     Undo,
     /// Show the undo history overlay (U in Normal mode).
     ShowUndoHistory,
+    /// Show the watch-mode changes feed overlay (Ctrl+W).
+    ShowChangesFeed,
+    /// Open the floating AI chat overlay (Ctrl+A).
+    ShowFloatingChat,
+    /// Open the recent-files quick switcher overlay (Ctrl+E).
+    ShowRecentFiles,
+    /// Jump backward one entry in the navigation history (Ctrl+O).
+    JumpBack,
+    /// Jump forward one entry in the navigation history (Ctrl+I / Shift+Tab).
+    ///
+    /// Note: Ctrl+I is indistinguishable from Tab in terminals that don't
+    /// enable the Kitty keyboard protocol (this app doesn't), same class of
+    /// issue as the Ctrl+M/Enter ambiguity noted above — Shift+Tab (BackTab)
+    /// is the binding that reliably works everywhere.
+    JumpForward,
+    /// Toggle a bookmark on the focused finding/file (`M` in Normal mode).
+    ToggleBookmark,
+    /// Open the bookmarks overlay (`'` in Normal mode).
+    ShowBookmarks,
+    /// Open the notification center overlay (`N` in Normal mode).
+    ShowNotifications,
+    /// Cycle the notification center's severity filter.
+    CycleNotificationFilter,
+    /// Open the full activity history overlay (`a` while the Activity Log
+    /// widget is zoomed).
+    ShowActivityHistory,
+    /// Cycle the activity history overlay's kind filter (`Tab`).
+    CycleActivityFilter,
+    /// Open the selected changes-feed entry's file.
+    ChangesFeedOpen,
+    /// Rescan after the selected changes-feed entry.
+    ChangesFeedRescan,
+    /// Add the selected changes-feed entry's directory to `watch_exclude`.
+    ChangesFeedIgnoreDir,
     /// Mouse click at a specific UI target.
     ClickAt(ClickTarget),
+    /// Mouse-drag text selection started at this rendered line index.
+    TextSelectStart(usize),
+    /// Mouse-drag text selection extended to this rendered line index.
+    TextSelectExtend(usize),
+    /// Mouse button released — copy the dragged selection to the clipboard.
+    TextSelectEnd,
+    /// Mouse hovering over a footer status-bar indicator.
+    HoverIndicator(crate::types::FooterIndicator),
+    /// Mouse moved off any hoverable indicator.
+    ClearHover,
+    /// Scrollbar track clicked — jump the given view's scroll/selection to
+    /// this resolved line index (chat) or finding index (findings list).
+    JumpScroll(crate::types::ScrollTarget, usize),
+    /// Move the Dashboard arrange-overlay cursor up one row.
+    ArrangeDashboardCursorUp,
+    /// Move the Dashboard arrange-overlay cursor down one row.
+    ArrangeDashboardCursorDown,
+    /// Toggle the widget under the arrange-overlay cursor in/out of the grid.
+    ArrangeDashboardToggle,
+    /// Move the widget under the arrange-overlay cursor earlier in the order.
+    ArrangeDashboardMoveEarlier,
+    /// Move the widget under the arrange-overlay cursor later in the order.
+    ArrangeDashboardMoveLater,
     /// Mouse scroll by N lines (positive = down, negative = up).
     ScrollLines(i32),
     /// View-specific single-char key press (delegated to active view).
@@ -128,19 +210,37 @@ pub fn handle_key_event(key: KeyEvent, app: &App) -> Action {
             KeyCode::Char('b') => return Action::ToggleSidebar,
             KeyCode::Char('f') => return Action::ToggleFilesPanel,
             KeyCode::Char('p') => return Action::ShowCommandPalette,
-            // Note: Ctrl+M is indistinguishable from Enter in terminals (both send CR).
-            // Model selector is mapped to 'M' (Shift+M) in Normal mode instead.
+            // Note: Ctrl+M is indistinguishable from Enter in terminals (both
+            // send CR) — see `Action::ToggleBookmark`, bound to plain 'M'
+            // (Shift+M) in Normal mode instead.
             KeyCode::Char('s') => return Action::StartScan,
             KeyCode::Char('k') if app.input_mode == InputMode::Visual => {
                 return Action::SendSelectionToAi;
             }
-            KeyCode::Char('z') => return Action::Undo,
+            // In Insert mode, Ctrl+Z/Ctrl+Y edit the input line's own
+            // undo/redo stack instead of the fix/scan undo history — fall
+            // through to `handle_insert_mode` for those.
+            KeyCode::Char('z') if app.input_mode != InputMode::Insert => return Action::Undo,
             KeyCode::Char('d') => return Action::ScrollHalfPageDown,
             KeyCode::Char('u') => return Action::ScrollHalfPageUp,
+            KeyCode::Char('w') => return Action::ShowChangesFeed,
+            KeyCode::Char('a') => return Action::ShowFloatingChat,
+            KeyCode::Char('e') => return Action::ShowRecentFiles,
+            KeyCode::Char('o') => return Action::JumpBack,
+            // See `Action::JumpForward` — rarely reaches here as Char('i');
+            // BackTab (checked below, outside this CONTROL branch) is what
+            // actually fires from a real Shift+Tab keypress.
+            KeyCode::Char('i') => return Action::JumpForward,
             _ => {}
         }
     }
 
+    // Shift+Tab is reported as a distinct keycode (not Tab+SHIFT) by
+    // crossterm, unlike Ctrl+I — the reliable binding for jump-forward.
+    if key.code == KeyCode::BackTab {
+        return Action::JumpForward;
+    }
+
     // Alt+N panel shortcuts
     if key.modifiers.contains(KeyModifiers::ALT) {
         match key.code {
@@ -159,10 +259,10 @@ pub fn handle_key_event(key: KeyEvent, app: &App) -> Action {
     }
 
     match app.input_mode {
-        InputMode::Insert => handle_insert_mode(key),
+        InputMode::Insert => handle_insert_mode(key, app),
         InputMode::Normal => handle_normal_mode(key, app),
         InputMode::Command => handle_command_mode(key),
-        InputMode::Visual => handle_visual_mode(key),
+        InputMode::Visual => handle_visual_mode(key, app),
     }
 }
 
@@ -186,15 +286,94 @@ pub fn handle_mouse_event(event: MouseEvent, app: &App) -> Action {
                     && row >= rect.y
                     && row < rect.y + rect.height
                 {
+                    if *target == ClickTarget::ChatBody
+                        && let Some(line) = chat_line_at_row(app, row)
+                    {
+                        return Action::TextSelectStart(line);
+                    }
+                    if let ClickTarget::ScrollbarTrack(scroll_target) = target
+                        && let Some(value) = scrollbar_jump_value(app, *rect, row, *scroll_target)
+                    {
+                        return Action::JumpScroll(*scroll_target, value);
+                    }
                     return Action::ClickAt(target.clone());
                 }
             }
             Action::None
         }
+        MouseEventKind::Drag(MouseButton::Left) => {
+            if app.chat_selection.is_some()
+                && let Some(line) = chat_line_at_row(app, event.row)
+            {
+                return Action::TextSelectExtend(line);
+            }
+            Action::None
+        }
+        MouseEventKind::Up(MouseButton::Left) => {
+            if app.chat_selection.is_some() {
+                return Action::TextSelectEnd;
+            }
+            Action::None
+        }
+        MouseEventKind::Moved => {
+            if event.row == app.footer_row
+                && let Some(indicator) =
+                    crate::views::dashboard::indicator_at_col(app, event.column)
+            {
+                return Action::HoverIndicator(indicator);
+            }
+            if app.hovered_indicator.is_some() {
+                return Action::ClearHover;
+            }
+            Action::None
+        }
         _ => Action::None,
     }
 }
 
+/// Map a screen row to a rendered chat-line index, accounting for the
+/// current scroll offset. Returns `None` if the chat body isn't currently
+/// a registered click area (e.g. not on the Chat view).
+fn chat_line_at_row(app: &App, row: u16) -> Option<usize> {
+    let rect = app
+        .click_areas
+        .iter()
+        .find_map(|(rect, target)| (*target == ClickTarget::ChatBody).then_some(*rect))?;
+    let total_lines = crate::views::chat::plain_lines(app).len();
+    if total_lines == 0 {
+        return None;
+    }
+    let visible = rect.height as usize;
+    let scroll = if app.chat_auto_scroll {
+        total_lines.saturating_sub(visible)
+    } else {
+        app.chat_scroll.min(total_lines.saturating_sub(visible))
+    };
+    let offset = row.saturating_sub(rect.y) as usize;
+    Some((scroll + offset).min(total_lines - 1))
+}
+
+/// Map a click position on a scrollbar track to a target scroll line (Chat)
+/// or finding index (Findings), proportional to where in the track the
+/// click landed. Returns `None` when the track has no content to scroll.
+fn scrollbar_jump_value(
+    app: &App,
+    track: ratatui::layout::Rect,
+    row: u16,
+    target: crate::types::ScrollTarget,
+) -> Option<usize> {
+    let total = match target {
+        crate::types::ScrollTarget::Chat => crate::views::chat::plain_lines(app).len(),
+        crate::types::ScrollTarget::Findings => crate::views::scan::filtered_findings_count(app),
+    };
+    if total == 0 || track.height == 0 {
+        return None;
+    }
+    let offset = row.saturating_sub(track.y) as usize;
+    let fraction = offset as f64 / track.height.saturating_sub(1).max(1) as f64;
+    Some(((total - 1) as f64 * fraction.clamp(0.0, 1.0)).round() as usize)
+}
+
 /// Compute scroll lines based on recent scroll event frequency (acceleration).
 fn scroll_line_count(app: &App) -> i32 {
     let now = std::time::Instant::now();
@@ -237,7 +416,91 @@ fn handle_overlay_keys(key: KeyEvent, app: &App) -> Action {
                 | Overlay::UndoHistory
                 | Overlay::CommandPalette
                 | Overlay::LlmSettings
+                | Overlay::Settings
+                | Overlay::ChangesFeed
+                | Overlay::ProjectSwitcher
+                | Overlay::Stats
+                | Overlay::CheckDocs
+                | Overlay::ToolResultInspector
+                | Overlay::Bookmarks
+                | Overlay::Notifications
+                | Overlay::CriticalCapDetail
+                | Overlay::Tour
+                | Overlay::RecentFiles
+                | Overlay::FileReloadPrompt
         );
+
+    if *overlay == Overlay::ChangesFeed {
+        return match key.code {
+            KeyCode::Esc => Action::EnterNormalMode,
+            KeyCode::Char('j') | KeyCode::Down => Action::ScrollDown,
+            KeyCode::Char('k') | KeyCode::Up => Action::ScrollUp,
+            KeyCode::Char('o') => Action::ChangesFeedOpen,
+            KeyCode::Char('r') | KeyCode::Enter => Action::ChangesFeedRescan,
+            KeyCode::Char('i') => Action::ChangesFeedIgnoreDir,
+            _ => Action::None,
+        };
+    }
+
+    if *overlay == Overlay::ArrangeDashboard {
+        return match key.code {
+            KeyCode::Esc | KeyCode::Enter => Action::EnterNormalMode,
+            KeyCode::Char('j') | KeyCode::Down => Action::ArrangeDashboardCursorDown,
+            KeyCode::Char('k') | KeyCode::Up => Action::ArrangeDashboardCursorUp,
+            KeyCode::Char(' ') => Action::ArrangeDashboardToggle,
+            KeyCode::Char('<') => Action::ArrangeDashboardMoveEarlier,
+            KeyCode::Char('>') => Action::ArrangeDashboardMoveLater,
+            _ => Action::None,
+        };
+    }
+
+    if *overlay == Overlay::Bookmarks {
+        return match key.code {
+            KeyCode::Esc => Action::EnterNormalMode,
+            KeyCode::Char('j') | KeyCode::Down => Action::ScrollDown,
+            KeyCode::Char('k') | KeyCode::Up => Action::ScrollUp,
+            KeyCode::Enter => Action::SubmitInput,
+            KeyCode::Char('d') | KeyCode::Backspace => Action::DeleteChar,
+            _ => Action::None,
+        };
+    }
+
+    if *overlay == Overlay::Notifications {
+        return match key.code {
+            KeyCode::Esc => Action::EnterNormalMode,
+            KeyCode::Char('j') | KeyCode::Down => Action::ScrollDown,
+            KeyCode::Char('k') | KeyCode::Up => Action::ScrollUp,
+            KeyCode::Char('f') => Action::CycleNotificationFilter,
+            _ => Action::None,
+        };
+    }
+
+    // Activity History is free-text searchable, so j/k/f are search input,
+    // not navigation/filter shortcuts — only arrows and Tab are reserved.
+    if *overlay == Overlay::ActivityHistory {
+        return match key.code {
+            KeyCode::Esc => Action::EnterNormalMode,
+            KeyCode::Down => Action::ScrollDown,
+            KeyCode::Up => Action::ScrollUp,
+            KeyCode::Tab => Action::CycleActivityFilter,
+            KeyCode::Char(c) => Action::InsertChar(c),
+            KeyCode::Backspace => Action::DeleteChar,
+            _ => Action::None,
+        };
+    }
+
+    // Keybindings browser is likewise free-text searchable.
+    if *overlay == Overlay::Keybindings {
+        return match key.code {
+            KeyCode::Esc => Action::EnterNormalMode,
+            KeyCode::Down => Action::ScrollDown,
+            KeyCode::Up => Action::ScrollUp,
+            KeyCode::Char(c) => Action::InsertChar(c),
+            KeyCode::Backspace => Action::DeleteChar,
+            _ => Action::None,
+        };
+    }
+
     match key.code {
         KeyCode::Esc => Action::EnterNormalMode,
         KeyCode::Enter => Action::SubmitInput,
@@ -249,7 +512,10 @@ fn handle_overlay_keys(key: KeyEvent, app: &App) -> Action {
     }
 }
 
-const fn handle_insert_mode(key: KeyEvent) -> Action {
+fn handle_insert_mode(key: KeyEvent, app: &App) -> Action {
+    // While an `@`-mention is being typed, arrows/Tab/Enter drive the
+    // inline mention popup instead of history/command completion/submit.
+    let mention_active = app.mention_query().is_some();
     match key.code {
         // Shift+Enter = newline (requires modifyOtherKeys protocol, works in tmux 3.2+)
         KeyCode::Enter if key.modifiers.contains(KeyModifiers::SHIFT) => Action::InsertChar('\n'),
@@ -257,20 +523,38 @@ const fn handle_insert_mode(key: KeyEvent) -> Action {
         KeyCode::Char('j') if key.modifiers.contains(KeyModifiers::CONTROL) => {
             Action::InsertChar('\n')
         }
+        KeyCode::Char('z') if key.modifiers.contains(KeyModifiers::CONTROL) => Action::InputUndo,
+        KeyCode::Char('y') if key.modifiers.contains(KeyModifiers::CONTROL) => Action::InputRedo,
+        KeyCode::Enter if mention_active => Action::MentionAccept,
         KeyCode::Enter => Action::SubmitInput,
         KeyCode::Char(c) => Action::InsertChar(c),
         KeyCode::Backspace => Action::DeleteChar,
         KeyCode::Left => Action::MoveCursorLeft,
         KeyCode::Right => Action::MoveCursorRight,
+        KeyCode::Up if mention_active => Action::MentionUp,
+        KeyCode::Down if mention_active => Action::MentionDown,
         KeyCode::Up => Action::HistoryUp,
         KeyCode::Down => Action::HistoryDown,
         KeyCode::Esc => Action::EnterNormalMode,
+        KeyCode::Tab if mention_active => Action::MentionAccept,
         KeyCode::Tab => Action::TabComplete,
         _ => Action::None,
     }
 }
 
 fn handle_normal_mode(key: KeyEvent, app: &App) -> Action {
+    // While typing the file browser's inline filter, characters go into
+    // `file_browser_filter` instead of view/panel shortcuts.
+    if app.file_browser_filtering {
+        return match key.code {
+            KeyCode::Esc | KeyCode::Enter => Action::ToggleFileBrowserFilter,
+            KeyCode::Backspace => Action::DeleteChar,
+            KeyCode::Tab => Action::ToggleFileBrowserFlatten,
+            KeyCode::Char(c) => Action::InsertChar(c),
+            _ => Action::None,
+        };
+    }
+
     match key.code {
         KeyCode::Char('q') => Action::Quit,
         KeyCode::Tab => Action::ToggleMode,
@@ -295,7 +579,11 @@ fn handle_normal_mode(key: KeyEvent, app: &App) -> Action {
         KeyCode::Char('v' | 'V') => Action::EnterVisualMode,
         KeyCode::Char(':') => Action::EnterColonMode,
         KeyCode::Char('U') => Action::ShowUndoHistory,
+        KeyCode::Char('M') => Action::ToggleBookmark,
+        KeyCode::Char('\'') => Action::ShowBookmarks,
+        KeyCode::Char('N') => Action::ShowNotifications,
         KeyCode::Char('w') => Action::WatchToggle,
+        KeyCode::Char('?') if app.view_state == ViewState::Scan => Action::ViewKey('?'),
         KeyCode::Char('?') => Action::ShowHelp,
         KeyCode::Char('@') => Action::ShowFilePicker,
         // Uppercase letter-key view switching (avoids conflict with lowercase ViewKey chars)
@@ -315,6 +603,7 @@ fn handle_normal_mode(key: KeyEvent, app: &App) -> Action {
                     | ViewState::Passport
                     | ViewState::Obligations
                     | ViewState::Report
+                    | ViewState::Chat
             ) =>
             {
                 Action::ViewEnter
@@ -322,9 +611,15 @@ fn handle_normal_mode(key: KeyEvent, app: &App) -> Action {
             _ => Action::SubmitInput,
         },
         KeyCode::Char(' ') if app.view_state == ViewState::Fix => Action::ViewKey(' '),
+        KeyCode::Char('s') if app.view_state == ViewState::Fix => Action::ViewKey('s'),
         KeyCode::Char(' ') if app.active_panel == Panel::FileBrowser => Action::ToggleExpand,
+        KeyCode::Char('f') if app.active_panel == Panel::FileBrowser => {
+            Action::ToggleFileBrowserFilter
+        }
         KeyCode::Char('y') if app.active_panel == Panel::DiffPreview => Action::AcceptDiff,
         KeyCode::Char('n') if app.active_panel == Panel::DiffPreview => Action::RejectDiff,
+        KeyCode::Char('p') if app.active_panel == Panel::CodeViewer => Action::PasteYank,
+        KeyCode::Char('o') if app.active_panel == Panel::CodeViewer => Action::OpenInEditor,
         KeyCode::Backspace if app.active_panel == Panel::CodeViewer => Action::CloseFile,
         // View-specific Esc
         KeyCode::Esc
@@ -380,13 +675,15 @@ const fn handle_command_mode(key: KeyEvent) -> Action {
     }
 }
 
-const fn handle_visual_mode(key: KeyEvent) -> Action {
+fn handle_visual_mode(key: KeyEvent, app: &App) -> Action {
     match key.code {
         KeyCode::Esc => Action::EnterNormalMode,
         KeyCode::Char('j') | KeyCode::Down => Action::SelectionDown,
         KeyCode::Char('k') | KeyCode::Up => Action::SelectionUp,
-        KeyCode::Char('y') => Action::AcceptDiff,
-        KeyCode::Char('n') => Action::RejectDiff,
+        KeyCode::Char('y') if app.active_panel == Panel::DiffPreview => Action::AcceptDiff,
+        KeyCode::Char('n') if app.active_panel == Panel::DiffPreview => Action::RejectDiff,
+        // Yank the visual selection into a register (code viewer only).
+        KeyCode::Char('y') if app.active_panel == Panel::CodeViewer => Action::Yank,
         _ => Action::None,
     }
 }
@@ -463,12 +760,82 @@ mod tests {
     }
 
     #[test]
-    fn test_shift_m_no_op_in_normal_mode() {
+    fn test_shift_m_toggles_bookmark_in_normal_mode() {
         let mut app = App::new(crate::config::TuiConfig::default());
         app.input_mode = InputMode::Normal;
 
-        // M is no longer bound (model selector removed in wrapper mode)
+        // M used to be a no-op (model selector removed in wrapper mode);
+        // it's now reused for bookmark toggling.
         let action = handle_key_event(key(KeyCode::Char('M')), &app);
+        assert!(matches!(action, Action::ToggleBookmark));
+    }
+
+    #[test]
+    fn test_apostrophe_shows_bookmarks_in_normal_mode() {
+        let mut app = App::new(crate::config::TuiConfig::default());
+        app.input_mode = InputMode::Normal;
+
+        let action = handle_key_event(key(KeyCode::Char('\'')), &app);
+        assert!(matches!(action, Action::ShowBookmarks));
+    }
+
+    #[test]
+    fn test_shift_n_shows_notifications_in_normal_mode() {
+        let mut app = App::new(crate::config::TuiConfig::default());
+        app.input_mode = InputMode::Normal;
+
+        let action = handle_key_event(key(KeyCode::Char('N')), &app);
+        assert!(matches!(action, Action::ShowNotifications));
+    }
+
+    #[test]
+    fn test_shift_n_still_searches_prev_in_code_viewer() {
+        let mut app = App::new(crate::config::TuiConfig::default());
+        app.input_mode = InputMode::Normal;
+        app.active_panel = Panel::CodeViewer;
+        app.code_search_query = Some("needle".to_string());
+
+        let action = handle_key_event(key(KeyCode::Char('N')), &app);
+        assert!(matches!(action, Action::CodeSearchPrev));
+    }
+
+    #[test]
+    fn test_visual_mode_y_yanks_on_code_viewer_not_diff() {
+        let mut app = App::new(crate::config::TuiConfig::default());
+        app.input_mode = InputMode::Visual;
+        app.active_panel = Panel::CodeViewer;
+
+        let action = handle_key_event(key(KeyCode::Char('y')), &app);
+        assert!(matches!(action, Action::Yank));
+    }
+
+    #[test]
+    fn test_visual_mode_y_accepts_diff_on_diff_preview() {
+        let mut app = App::new(crate::config::TuiConfig::default());
+        app.input_mode = InputMode::Visual;
+        app.active_panel = Panel::DiffPreview;
+
+        let action = handle_key_event(key(KeyCode::Char('y')), &app);
+        assert!(matches!(action, Action::AcceptDiff));
+    }
+
+    #[test]
+    fn test_visual_mode_y_no_op_outside_diff_and_code_viewer() {
+        let mut app = App::new(crate::config::TuiConfig::default());
+        app.input_mode = InputMode::Visual;
+        app.active_panel = Panel::Chat;
+
+        let action = handle_key_event(key(KeyCode::Char('y')), &app);
         assert!(matches!(action, Action::None));
     }
+
+    #[test]
+    fn test_normal_mode_p_pastes_yank_on_code_viewer() {
+        let mut app = App::new(crate::config::TuiConfig::default());
+        app.input_mode = InputMode::Normal;
+        app.active_panel = Panel::CodeViewer;
+
+        let action = handle_key_event(key(KeyCode::Char('p')), &app);
+        assert!(matches!(action, Action::PasteYank));
+    }
 }
@@ -0,0 +1,212 @@
+//! SBOM and model inventory ingestion (`.complior/inventory/`).
+//!
+//! Parses a CycloneDX SBOM (`sbom.json`) and a model-inventory YAML
+//! (`models.yaml`) into structured data so checks about third-party
+//! components, licenses, and GPAI providers have something to evaluate,
+//! rather than relying on free-text project descriptions.
+
+use std::path::Path;
+
+use serde::Deserialize;
+use yaml_rust::YamlLoader;
+
+/// One component listed in a CycloneDX SBOM's `components` array.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SbomComponent {
+    #[serde(rename = "type")]
+    pub component_type: String,
+    pub name: String,
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub licenses: Vec<SbomLicense>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SbomLicense {
+    pub license: SbomLicenseId,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SbomLicenseId {
+    #[serde(default)]
+    pub id: Option<String>,
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+/// Minimal CycloneDX document shape -- only the fields inventory checks need.
+#[derive(Debug, Clone, Deserialize)]
+struct CycloneDxDocument {
+    #[serde(default, rename = "bomFormat")]
+    bom_format: Option<String>,
+    #[serde(default)]
+    components: Vec<SbomComponent>,
+}
+
+/// One entry in `models.yaml`: a third-party or self-hosted model in use.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModelEntry {
+    pub name: String,
+    pub provider: String,
+    /// Whether the provider is a General Purpose AI (GPAI) model provider
+    /// under the EU AI Act, as declared in the inventory (not inferred).
+    pub gpai: bool,
+}
+
+/// Combined result of an inventory scan under `.complior/inventory/`.
+#[derive(Debug, Clone, Default)]
+pub struct InventoryReport {
+    pub components: Vec<SbomComponent>,
+    pub models: Vec<ModelEntry>,
+}
+
+impl InventoryReport {
+    #[must_use]
+    pub fn gpai_models(&self) -> Vec<&ModelEntry> {
+        self.models.iter().filter(|m| m.gpai).collect()
+    }
+
+    #[must_use]
+    pub fn unlicensed_components(&self) -> Vec<&SbomComponent> {
+        self.components
+            .iter()
+            .filter(|c| c.licenses.is_empty())
+            .collect()
+    }
+}
+
+/// Parse a CycloneDX SBOM JSON document. Returns an empty list (rather than
+/// an error) when the file is missing, since inventory is best-effort.
+fn parse_cyclonedx_sbom(path: &Path) -> Vec<SbomComponent> {
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return Vec::new();
+    };
+    match serde_json::from_str::<CycloneDxDocument>(&content) {
+        Ok(doc) => {
+            if doc.bom_format.as_deref() != Some("CycloneDX") {
+                eprintln!(
+                    "Warning: {} is missing bomFormat: CycloneDX",
+                    path.display()
+                );
+            }
+            doc.components
+        }
+        Err(e) => {
+            eprintln!("Warning: failed to parse SBOM {}: {e}", path.display());
+            Vec::new()
+        }
+    }
+}
+
+/// Parse a `models.yaml` inventory file. Expected shape:
+///
+/// ```yaml
+/// - name: gpt-4o
+///   provider: openai
+///   gpai: true
+/// ```
+fn parse_model_inventory(path: &Path) -> Vec<ModelEntry> {
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return Vec::new();
+    };
+    let docs = match YamlLoader::load_from_str(&content) {
+        Ok(docs) => docs,
+        Err(e) => {
+            eprintln!("Warning: failed to parse {}: {e}", path.display());
+            return Vec::new();
+        }
+    };
+    let Some(entries) = docs.first().and_then(yaml_rust::Yaml::as_vec) else {
+        return Vec::new();
+    };
+
+    entries
+        .iter()
+        .filter_map(|entry| {
+            let name = entry["name"].as_str()?.to_string();
+            let provider = entry["provider"].as_str().unwrap_or("unknown").to_string();
+            let gpai = entry["gpai"].as_bool().unwrap_or(false);
+            Some(ModelEntry {
+                name,
+                provider,
+                gpai,
+            })
+        })
+        .collect()
+}
+
+/// Read `.complior/inventory/sbom.json` and `.complior/inventory/models.yaml`
+/// under `project_path`, if present.
+#[must_use]
+pub fn discover_inventory(project_path: &Path) -> InventoryReport {
+    let inventory_dir = project_path.join(".complior/inventory");
+    InventoryReport {
+        components: parse_cyclonedx_sbom(&inventory_dir.join("sbom.json")),
+        models: parse_model_inventory(&inventory_dir.join("models.yaml")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_inventory(root: &Path, sbom: Option<&str>, models: Option<&str>) {
+        let dir = root.join(".complior/inventory");
+        std::fs::create_dir_all(&dir).unwrap();
+        if let Some(sbom) = sbom {
+            std::fs::write(dir.join("sbom.json"), sbom).unwrap();
+        }
+        if let Some(models) = models {
+            std::fs::write(dir.join("models.yaml"), models).unwrap();
+        }
+    }
+
+    #[test]
+    fn returns_empty_when_no_inventory_dir() {
+        let dir =
+            std::env::temp_dir().join(format!("complior-inventory-empty-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let report = discover_inventory(&dir);
+        assert!(report.components.is_empty());
+        assert!(report.models.is_empty());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn parses_sbom_and_model_inventory() {
+        let dir =
+            std::env::temp_dir().join(format!("complior-inventory-parse-{}", std::process::id()));
+        write_inventory(
+            &dir,
+            Some(
+                r#"{
+                    "bomFormat": "CycloneDX",
+                    "components": [
+                        {"type": "library", "name": "openai", "version": "4.0.0", "licenses": [{"license": {"id": "MIT"}}]},
+                        {"type": "library", "name": "unlicensed-pkg", "version": "1.0.0"}
+                    ]
+                }"#,
+            ),
+            Some(
+                r#"
+                - name: gpt-4o
+                  provider: openai
+                  gpai: true
+                - name: internal-classifier
+                  provider: self-hosted
+                  gpai: false
+                "#,
+            ),
+        );
+
+        let report = discover_inventory(&dir);
+        assert_eq!(report.components.len(), 2);
+        assert_eq!(report.unlicensed_components().len(), 1);
+        assert_eq!(report.models.len(), 2);
+        assert_eq!(report.gpai_models().len(), 1);
+        assert_eq!(report.gpai_models()[0].name, "gpt-4o");
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
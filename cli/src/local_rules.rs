@@ -0,0 +1,264 @@
+//! User-defined local rule packs (`.complior/rules/*.toml`), evaluated
+//! client-side against the project's files and merged into scan results.
+//! Lets organizations enforce internal AI policies beyond the engine's
+//! built-in checks without waiting on an engine release.
+
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::types::{CheckResultType, Finding, Severity};
+
+/// One rule loaded from a `.complior/rules/*.toml` file.
+#[derive(Debug, Clone, Deserialize)]
+struct RuleDef {
+    id: String,
+    message: String,
+    #[serde(default = "default_severity")]
+    severity: Severity,
+    /// Glob patterns (relative to the project root) a file must match to be
+    /// checked. Empty means every scanned file.
+    #[serde(default)]
+    files: Vec<String>,
+    /// Regex the file's contents must match for the rule to fail.
+    #[serde(rename = "match")]
+    pattern: String,
+}
+
+const fn default_severity() -> Severity {
+    Severity::Medium
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct RulePackFile {
+    #[serde(default)]
+    rule: Vec<RuleDef>,
+}
+
+/// A rule pack file, compiled and ready to evaluate against project files.
+struct CompiledRule {
+    id: String,
+    message: String,
+    severity: Severity,
+    files: Vec<regex::Regex>,
+    pattern: regex::Regex,
+}
+
+/// Load and compile every rule pack under `<project>/.complior/rules/`.
+/// Missing directory or unparsable files are skipped with a warning rather
+/// than failing the scan.
+fn load_rules(project_path: &Path) -> Vec<CompiledRule> {
+    let rules_dir = project_path.join(".complior/rules");
+    let Ok(entries) = std::fs::read_dir(&rules_dir) else {
+        return Vec::new();
+    };
+
+    let mut compiled = Vec::new();
+    for entry in entries.filter_map(std::result::Result::ok) {
+        let path = entry.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("toml") {
+            continue;
+        }
+
+        let Ok(content) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        let pack: RulePackFile = match toml::from_str(&content) {
+            Ok(pack) => pack,
+            Err(e) => {
+                eprintln!("Warning: invalid rule pack {}: {e}", path.display());
+                continue;
+            }
+        };
+
+        for rule in pack.rule {
+            let pattern = match regex::Regex::new(&rule.pattern) {
+                Ok(re) => re,
+                Err(e) => {
+                    eprintln!(
+                        "Warning: invalid pattern in rule {:?} ({}): {e}",
+                        rule.id,
+                        path.display()
+                    );
+                    continue;
+                }
+            };
+            let files = rule.files.iter().filter_map(|p| compile_glob(p)).collect();
+
+            compiled.push(CompiledRule {
+                id: rule.id,
+                message: rule.message,
+                severity: rule.severity,
+                files,
+                pattern,
+            });
+        }
+    }
+
+    compiled
+}
+
+/// Translate a shell-style glob (`*`, `**`, `?`) into an anchored regex.
+/// Mirrors `watcher::compile_glob` -- duplicated here because this module
+/// runs in the headless (non-`tui`) build, where `watcher` isn't compiled.
+fn compile_glob(pattern: &str) -> Option<regex::Regex> {
+    let mut regex_str = String::from("^");
+    let mut chars = pattern.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '*' => {
+                if chars.peek() == Some(&'*') {
+                    chars.next();
+                    regex_str.push_str(".*");
+                } else {
+                    regex_str.push_str("[^/]*");
+                }
+            }
+            '?' => regex_str.push_str("[^/]"),
+            _ => regex_str.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    regex_str.push('$');
+
+    regex::Regex::new(&regex_str).ok()
+}
+
+/// Skip hidden files/dirs and common non-source directories.
+fn is_relevant(path: &Path) -> bool {
+    let skip_dirs = ["node_modules", "target", "dist", "build", "__pycache__"];
+    for component in path.components() {
+        if let std::path::Component::Normal(name) = component {
+            let name = name.to_string_lossy();
+            if name.starts_with('.') || skip_dirs.iter().any(|d| *d == &*name) {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+fn collect_files(dir: &Path, root: &Path, out: &mut Vec<std::path::PathBuf>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.filter_map(std::result::Result::ok) {
+        let path = entry.path();
+        let Ok(relative) = path.strip_prefix(root) else {
+            continue;
+        };
+        if !is_relevant(relative) {
+            continue;
+        }
+        if path.is_dir() {
+            collect_files(&path, root, out);
+        } else {
+            out.push(relative.to_path_buf());
+        }
+    }
+}
+
+/// Load every rule pack under `.complior/rules/` and evaluate them against
+/// the project's files, returning one `Finding` per matching (rule, file)
+/// pair. Returns an empty list when no rule packs are configured.
+pub fn scan_local_rules(project_path: &str) -> Vec<Finding> {
+    let root = Path::new(project_path);
+    let rules = load_rules(root);
+    if rules.is_empty() {
+        return Vec::new();
+    }
+
+    let mut files = Vec::new();
+    collect_files(root, root, &mut files);
+
+    let mut findings = Vec::new();
+    for relative in &files {
+        let relative_str = relative.to_string_lossy();
+        let Ok(content) = std::fs::read_to_string(root.join(relative)) else {
+            continue;
+        };
+
+        for rule in &rules {
+            if !rule.files.is_empty() && !rule.files.iter().any(|re| re.is_match(&relative_str)) {
+                continue;
+            }
+            let Some(line) = content
+                .lines()
+                .position(|l| rule.pattern.is_match(l))
+                .map(|idx| idx as u32 + 1)
+            else {
+                continue;
+            };
+
+            findings.push(Finding {
+                check_id: format!("local-{}", rule.id),
+                r#type: CheckResultType::Fail,
+                message: rule.message.clone(),
+                severity: rule.severity,
+                obligation_id: None,
+                article_reference: None,
+                fix: None,
+                file: Some(relative_str.to_string()),
+                line: Some(line),
+                code_context: None,
+                fix_diff: None,
+                priority: None,
+                confidence: None,
+                confidence_level: None,
+                evidence: None,
+                explanation: None,
+                agent_id: None,
+                doc_quality: None,
+                l5_analyzed: None,
+            });
+        }
+    }
+
+    findings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_rule_pack(dir: &Path, contents: &str) {
+        std::fs::create_dir_all(dir.join(".complior/rules")).unwrap();
+        std::fs::write(dir.join(".complior/rules/policy.toml"), contents).unwrap();
+    }
+
+    #[test]
+    fn returns_empty_when_no_rules_dir() {
+        let dir = std::env::temp_dir().join(format!("complior-local-rules-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        assert!(scan_local_rules(dir.to_str().unwrap()).is_empty());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn flags_files_matching_a_custom_rule() {
+        let dir =
+            std::env::temp_dir().join(format!("complior-local-rules-match-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        write_rule_pack(
+            &dir,
+            r#"
+            [[rule]]
+            id = "no-raw-openai-key"
+            message = "Hardcoded OpenAI key"
+            severity = "critical"
+            files = ["*.py"]
+            match = "sk-[A-Za-z0-9]{10,}"
+            "#,
+        );
+        std::fs::write(dir.join("agent.py"), "key = \"sk-abcdefghijklmnop\"").unwrap();
+        std::fs::write(dir.join("agent.md"), "sk-abcdefghijklmnop").unwrap();
+
+        let findings = scan_local_rules(dir.to_str().unwrap());
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].check_id, "local-no-raw-openai-key");
+        assert_eq!(findings[0].file.as_deref(), Some("agent.py"));
+        assert_eq!(findings[0].severity, Severity::Critical);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
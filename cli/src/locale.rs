@@ -0,0 +1,101 @@
+//! Locale-aware date and number formatting.
+//!
+//! Detected from `settings.toml`'s `locale` field (`"auto"` by default) or,
+//! failing that, `LC_ALL`/`LC_NUMERIC`/`LANG`. European locales get
+//! `DD.MM.YYYY` dates and decimal commas; everything else keeps the
+//! `Mon D, YYYY` / decimal-point formatting this app always used.
+
+use std::sync::{Mutex, OnceLock};
+
+/// Which regional convention to format dates and numbers with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LocaleStyle {
+    Us,
+    Eu,
+}
+
+/// ISO 639-1 language codes that conventionally use `DD.MM.YYYY` dates and
+/// decimal commas. Not exhaustive — covers the EU AI Act's home markets.
+const EU_STYLE_LANGS: &[&str] = &[
+    "de", "fr", "it", "es", "pt", "nl", "pl", "ru", "cs", "sv", "da", "fi", "el", "ro", "hu", "sk",
+    "bg", "hr", "sl", "et", "lv", "lt",
+];
+
+const MONTHS: [&str; 13] = [
+    "", "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+static LOCALE: OnceLock<Mutex<LocaleStyle>> = OnceLock::new();
+
+/// Set the global locale style from a config value: `"eu"`/`"us"` force a
+/// style directly, an explicit locale tag (`"de-DE"`) is matched by language,
+/// and `"auto"` detects from the environment.
+pub fn init_locale(name: &str) {
+    let style = resolve(name);
+    if let Some(mutex) = LOCALE.get() {
+        *mutex.lock().expect("locale lock") = style;
+    } else {
+        let _ = LOCALE.set(Mutex::new(style));
+    }
+}
+
+pub fn locale_style() -> LocaleStyle {
+    LOCALE
+        .get()
+        .map_or(LocaleStyle::Us, |m| *m.lock().expect("locale lock"))
+}
+
+fn resolve(name: &str) -> LocaleStyle {
+    match name.to_ascii_lowercase().as_str() {
+        "eu" => LocaleStyle::Eu,
+        "us" => LocaleStyle::Us,
+        "auto" => detect_from_env(),
+        tag => style_for_lang(lang_prefix(tag)),
+    }
+}
+
+fn detect_from_env() -> LocaleStyle {
+    for var in ["LC_ALL", "LC_NUMERIC", "LANG"] {
+        if let Ok(val) = std::env::var(var)
+            && !val.is_empty()
+            && val != "C"
+            && val != "POSIX"
+        {
+            return style_for_lang(lang_prefix(&val));
+        }
+    }
+    LocaleStyle::Us
+}
+
+fn lang_prefix(tag: &str) -> &str {
+    tag.split(['_', '-', '.']).next().unwrap_or(tag)
+}
+
+fn style_for_lang(lang: &str) -> LocaleStyle {
+    let lang = lang.to_ascii_lowercase();
+    if EU_STYLE_LANGS.contains(&lang.as_str()) {
+        LocaleStyle::Eu
+    } else {
+        LocaleStyle::Us
+    }
+}
+
+/// Format a calendar date per the active locale style.
+pub fn format_date(y: i64, m: u8, d: u8) -> String {
+    match locale_style() {
+        LocaleStyle::Eu => format!("{d:02}.{m:02}.{y:04}"),
+        LocaleStyle::Us => {
+            let month = MONTHS.get(m as usize).copied().unwrap_or("???");
+            format!("{month} {d}, {y}")
+        }
+    }
+}
+
+/// Format a decimal number per the active locale style (`.` vs `,`).
+pub fn format_decimal(value: f64, decimals: usize) -> String {
+    let s = format!("{value:.decimals$}");
+    match locale_style() {
+        LocaleStyle::Eu => s.replace('.', ","),
+        LocaleStyle::Us => s,
+    }
+}
@@ -4,18 +4,52 @@ mod animation;
 #[cfg(feature = "tui")]
 mod app;
 #[cfg(feature = "tui")]
+mod assignments;
+#[cfg(feature = "tui")]
+mod attachments;
+#[cfg(feature = "tui")]
 mod chat_stream;
 #[cfg(feature = "tui")]
+mod clipboard;
+#[cfg(feature = "tui")]
 mod components;
 #[cfg(feature = "tui")]
+mod control_socket;
+#[cfg(feature = "tui")]
+mod date;
+#[cfg(feature = "tui")]
+mod demo;
+#[cfg(feature = "tui")]
+mod findings_state;
+#[cfg(feature = "tui")]
+mod fix_journal;
+#[cfg(feature = "tui")]
+mod fix_sandbox;
+#[cfg(feature = "tui")]
+mod graphics;
+#[cfg(feature = "tui")]
 mod input;
 #[cfg(feature = "tui")]
 mod layout;
 #[cfg(feature = "tui")]
+mod mock_engine;
+#[cfg(feature = "tui")]
 mod obligations;
 #[cfg(feature = "tui")]
+mod report_sections;
+#[cfg(feature = "tui")]
+mod response_cache;
+#[cfg(feature = "tui")]
+mod secrets_redact;
+#[cfg(feature = "tui")]
 mod session;
 #[cfg(feature = "tui")]
+mod stats;
+#[cfg(feature = "tui")]
+mod syntax;
+#[cfg(feature = "tui")]
+mod telemetry;
+#[cfg(feature = "tui")]
 mod theme;
 #[cfg(feature = "tui")]
 mod theme_picker;
@@ -28,23 +62,36 @@ mod widgets;
 
 // Extras-only modules
 #[cfg(feature = "extras")]
+mod inventory;
+#[cfg(feature = "extras")]
+mod plugins;
+#[cfg(feature = "extras")]
 mod saas_client;
 
 // Core modules (always available)
 mod cli;
 mod config;
 mod contract_test;
+mod crash_report;
 mod daemon;
 mod engine_client;
 mod engine_process;
 mod error;
 mod headless;
+mod local_rules;
+mod locale;
+mod notify_desktop;
+mod timezone;
 mod types;
 
 // LLM settings (TUI overlay + types)
 #[cfg(feature = "tui")]
 mod llm_settings;
 
+// Settings overlay (TUI overlay + types)
+#[cfg(feature = "tui")]
+mod settings_overlay;
+
 use std::io;
 #[cfg(feature = "tui")]
 use std::io::Write as _;
@@ -59,7 +106,10 @@ use app::executor::execute_command;
 #[cfg(feature = "tui")]
 use app::{App, AppCommand};
 #[cfg(feature = "tui")]
-use crossterm::event::{DisableMouseCapture, EnableMouseCapture, Event, EventStream};
+use crossterm::event::{
+    DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture, Event,
+    EventStream,
+};
 #[cfg(feature = "tui")]
 use crossterm::execute;
 #[cfg(feature = "tui")]
@@ -82,19 +132,31 @@ use views::dashboard::render_dashboard;
 #[tokio::main]
 #[allow(clippy::too_many_lines)]
 async fn main() -> color_eyre::Result<()> {
-    color_eyre::install()?;
+    crash_report::install_panic_hook()?;
     tracing_subscriber::fmt()
         .with_env_filter("complior_cli=info")
         .with_writer(io::stderr)
         .init();
 
     let mut config = load_config();
+    locale::init_locale(&config.locale);
+    timezone::init_timezone(&config.timezone);
+    #[cfg(feature = "tui")]
+    telemetry::set_enabled(config.telemetry_enabled);
 
     // Parse CLI args with clap
     let parsed_cli = cli::Cli::parse();
     #[cfg(feature = "tui")]
     let resume = parsed_cli.resume;
     config.engine_url_override = parsed_cli.engine_url.clone();
+    config.perf_overlay = parsed_cli.perf_overlay;
+    if parsed_cli.offline {
+        config.offline_mode = true;
+    }
+    if parsed_cli.demo {
+        config.offline_mode = true;
+        config.onboarding_completed = true;
+    }
 
     // Apply --no-color: set env var so OnceLock picks it up
     if parsed_cli.no_color {
@@ -144,8 +206,10 @@ async fn main() -> color_eyre::Result<()> {
             match start_result {
                 Ok(port) => {
                     config.engine_url_override = Some(format!("http://127.0.0.1:{port}"));
-                    let client =
-                        engine_client::EngineClient::from_url(&format!("http://127.0.0.1:{port}"));
+                    let client = engine_client::EngineClient::from_url(
+                        &format!("http://127.0.0.1:{port}"),
+                        &config,
+                    );
                     if !cli::wants_quiet_startup(&parsed_cli) {
                         eprintln!("Starting engine on port {port}...");
                     }
@@ -188,6 +252,7 @@ async fn main() -> color_eyre::Result<()> {
                 ci,
                 json,
                 sarif,
+                annotate,
                 no_tui,
                 threshold,
                 fail_on,
@@ -216,6 +281,7 @@ async fn main() -> color_eyre::Result<()> {
                         *ci,
                         *json,
                         *sarif,
+                        *annotate,
                         *no_tui,
                         *threshold,
                         *fail_on,
@@ -565,6 +631,20 @@ async fn main() -> color_eyre::Result<()> {
                 headless::tools::run_tools_command(action, &config).await
             }
             #[cfg(feature = "extras")]
+            Some(cli::Command::Plugins { action }) => {
+                headless::plugins::run_plugins_command(action)
+            }
+            #[cfg(feature = "extras")]
+            Some(cli::Command::Inventory { action }) => {
+                headless::inventory::run_inventory_command(action)
+            }
+            #[cfg(feature = "extras")]
+            Some(cli::Command::Hooks { action }) => headless::hooks::run_hooks_command(action),
+            #[cfg(feature = "extras")]
+            Some(cli::Command::Track { action }) => {
+                headless::track::run_track_command(action, &config).await
+            }
+            #[cfg(feature = "extras")]
             Some(cli::Command::Audit {
                 target,
                 agent,
@@ -583,6 +663,37 @@ async fn main() -> color_eyre::Result<()> {
             None => unreachable!(),
         };
 
+        if let Some(cmd) = &parsed_cli.command {
+            let notification = match cmd {
+                cli::Command::Scan { quiet, .. } if !*quiet => Some(if code == 0 {
+                    (
+                        "Complior scan passed",
+                        "Compliance scan finished — no threshold failures.".to_string(),
+                    )
+                } else {
+                    (
+                        "Complior scan failed",
+                        format!("Compliance scan finished with exit code {code}."),
+                    )
+                }),
+                cli::Command::Fix { .. } => Some(if code == 0 {
+                    (
+                        "Complior fix complete",
+                        "Batch fix finished successfully.".to_string(),
+                    )
+                } else {
+                    (
+                        "Complior fix finished with errors",
+                        format!("Batch fix finished with exit code {code}."),
+                    )
+                }),
+                _ => None,
+            };
+            if let Some((title, body)) = notification {
+                notify_desktop::notify(config.notifications_enabled, title, &body);
+            }
+        }
+
         drop(engine_guard);
         std::process::exit(code);
     }
@@ -608,8 +719,21 @@ async fn main() -> color_eyre::Result<()> {
             .parent()
             .unwrap_or_else(|| std::path::Path::new("."));
 
+        // Also used to key per-project session storage (see `session::project_key`).
+        let project_path = cli::explicit_project_path(&parsed_cli)
+            .unwrap_or_else(|| std::env::current_dir().unwrap_or_default());
+
         #[allow(clippy::option_if_let_else)]
-        let mut engine_mgr = if let Some(ref url) = config.engine_url_override {
+        let mut engine_mgr = if parsed_cli.demo {
+            // Demo mode never talks to a real engine.
+            EngineManager::external(0)
+        } else if parsed_cli.mock_engine {
+            let port = mock_engine::spawn()
+                .await
+                .expect("mock engine must be able to bind a local port");
+            tracing::info!("Mock engine listening on port {port}");
+            EngineManager::external(port)
+        } else if let Some(ref url) = config.engine_url_override {
             // External mode — extract port for display
             let port = url
                 .rsplit(':')
@@ -619,7 +743,6 @@ async fn main() -> color_eyre::Result<()> {
             EngineManager::external(port)
         } else {
             // Check for existing daemon before auto-launching
-            let project_path = std::env::current_dir().unwrap_or_default();
             if let Some(info) = daemon::find_running_daemon(&project_path) {
                 // Reuse existing daemon (External mode — won't be killed on TUI exit)
                 tracing::info!("Found daemon on port {} (PID {})", info.port, info.pid);
@@ -652,12 +775,19 @@ async fn main() -> color_eyre::Result<()> {
 
         let mut app = App::new(config.clone());
         // Override engine client with the effective URL
-        app.engine_client = engine_client::EngineClient::from_url(&effective_url);
+        app.engine_client = engine_client::EngineClient::from_url(&effective_url, &config);
         // Start splash animation (only in production, not in tests)
         app.animation.start_splash();
 
+        if parsed_cli.demo {
+            demo::seed(&mut app);
+        }
+
         // Check for --resume flag
-        if resume && let Ok(data) = session::load_session("latest").await {
+        if resume
+            && !parsed_cli.demo
+            && let Ok(data) = session::load_session("latest", &project_path).await
+        {
             app.load_session_data(data);
             tracing::info!("Resumed session 'latest'");
         }
@@ -681,13 +811,21 @@ async fn main() -> color_eyre::Result<()> {
             app.config.onboarding_completed = true;
         }
 
-        // Build initial file tree
-        app.load_file_tree().await;
+        // Build initial file tree (demo mode ships its own fake project, not
+        // the real filesystem).
+        if !parsed_cli.demo {
+            app.load_file_tree().await;
+        }
 
         // Setup terminal
         enable_raw_mode()?;
         let mut stdout = io::stdout();
-        execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+        execute!(
+            stdout,
+            EnterAlternateScreen,
+            EnableMouseCapture,
+            EnableBracketedPaste
+        )?;
         // Enable xterm modifyOtherKeys mode 2 — makes Shift+Enter distinguishable
         // Works in tmux 3.2+ (unlike Kitty CSI u protocol)
         let _ = stdout.write_all(b"\x1b[>4;2m");
@@ -709,6 +847,7 @@ async fn main() -> color_eyre::Result<()> {
 
             if engine_mgr.wait_for_ready(&app.engine_client).await {
                 app.engine_status = types::EngineConnectionStatus::Connected;
+                app.engine_info = app.engine_client.status().await.ok();
                 app.messages.push(types::ChatMessage::new(
                     types::MessageRole::System,
                     format!("Engine ready on port {}.", engine_mgr.port),
@@ -722,16 +861,41 @@ async fn main() -> color_eyre::Result<()> {
             }
         }
 
+        // Background persistence writer — session saves go through this
+        // channel instead of being awaited inline, so they never stall the
+        // render/event loop.
+        let (persist_tx, persist_handle) = session::spawn_writer();
+
         // Run the event loop
-        let result = run_event_loop(&mut terminal, &mut app, &mut engine_mgr).await;
+        let result = run_event_loop(
+            &mut terminal,
+            &mut app,
+            &mut engine_mgr,
+            &persist_tx,
+            parsed_cli.exec.as_deref(),
+            parsed_cli.open.as_deref(),
+        )
+        .await;
 
-        // Shutdown engine
-        engine_mgr.shutdown();
+        // Shutdown engine — graceful, so a mid-flight scan or fix write
+        // isn't cut off by an immediate kill.
+        engine_mgr.shutdown_gracefully().await;
 
-        // Auto-save session on exit
-        if let Err(e) = session::save_session(&app.to_session_data(), "latest").await {
-            tracing::warn!("Failed to save session: {e}");
+        // Auto-save session on exit. Dropping the sender after enqueuing the
+        // final job closes the writer's channel, so awaiting its handle here
+        // blocks only until that last save actually reaches disk. Skipped in
+        // demo mode — there's no real session to persist and it would
+        // clobber the current project's `latest` save.
+        if !parsed_cli.demo {
+            let _ = persist_tx.send(session::SaveJob {
+                data: app.to_session_data(),
+                name: "latest".to_string(),
+                project_path: project_path.clone(),
+                encrypt: config.session_encryption,
+            });
         }
+        drop(persist_tx);
+        let _ = persist_handle.await;
 
         // Restore terminal
         disable_raw_mode()?;
@@ -743,7 +907,8 @@ async fn main() -> color_eyre::Result<()> {
         execute!(
             terminal.backend_mut(),
             LeaveAlternateScreen,
-            DisableMouseCapture
+            DisableMouseCapture,
+            DisableBracketedPaste
         )?;
         terminal.show_cursor()?;
 
@@ -759,6 +924,9 @@ async fn run_event_loop(
     terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
     app: &mut App,
     engine_mgr: &mut EngineManager,
+    persist_tx: &mpsc::UnboundedSender<session::SaveJob>,
+    exec_arg: Option<&str>,
+    open_arg: Option<&str>,
 ) -> color_eyre::Result<()> {
     let mut event_stream = EventStream::new();
     let tick_rate = Duration::from_millis(app.config.tick_rate_ms);
@@ -768,17 +936,57 @@ async fn run_event_loop(
     let mut anim_interval = tokio::time::interval(Duration::from_millis(50));
 
     // Watch mode channel + handle
-    let (watch_tx, mut watch_rx) = mpsc::unbounded_channel::<std::path::PathBuf>();
+    let (watch_tx, mut watch_rx) =
+        mpsc::unbounded_channel::<Vec<(std::path::PathBuf, watcher::ChangeKind)>>();
     let mut watch_handle: Option<tokio::task::JoinHandle<()>> = None;
 
     // Background command results channel (non-blocking async operations)
     let mut bg_rx = app.take_bg_rx();
 
+    // Control socket for external automation (opt-in — see `control_socket`).
+    // `control_tx` itself must stay alive for the rest of this function even
+    // when disabled — passing a clone into the conditional spawn keeps the
+    // channel open (and `control_rx.recv()` pending, not immediately
+    // `None`) instead of busy-looping the select! below on a closed channel.
+    let (control_tx, mut control_rx) = mpsc::unbounded_channel::<control_socket::ControlMessage>();
+    let _control_handle = app.config.control_socket_enabled.then(|| {
+        control_socket::spawn_control_server(
+            &control_socket::socket_path(&app.project_path),
+            control_tx.clone(),
+        )
+    });
+
+    // Ctrl+Z: intercept SIGTSTP so we can leave the alternate screen and
+    // disable raw mode before actually stopping, then restore both on
+    // SIGCONT — otherwise the shell is left with a broken terminal. Bridged
+    // through a channel (rather than selecting on the `Signal` stream
+    // directly) so there's a single, always-present receiver type here
+    // regardless of platform; `sigtstp_tx` must stay alive for the
+    // function's duration even on platforms that never send into it, or the
+    // closed channel would make `sigtstp_rx.recv()` resolve immediately and
+    // busy-loop the select! below.
+    #[cfg_attr(not(unix), allow(unused_variables))]
+    let (sigtstp_tx, mut sigtstp_rx) = mpsc::unbounded_channel::<()>();
+    #[cfg(unix)]
+    {
+        let mut sigtstp =
+            tokio::signal::unix::signal(tokio::signal::unix::SignalKind::from_raw(libc::SIGTSTP))?;
+        let tx = sigtstp_tx.clone();
+        tokio::spawn(async move {
+            while sigtstp.recv().await.is_some() {
+                if tx.send(()).is_err() {
+                    break;
+                }
+            }
+        });
+    }
+
     // Try to connect to engine (if we haven't already from auto-launch)
     if app.engine_status != types::EngineConnectionStatus::Connected {
         match app.engine_client.status().await {
             Ok(status) if status.ready => {
                 app.engine_status = types::EngineConnectionStatus::Connected;
+                app.engine_info = Some(status);
                 app.messages.push(types::ChatMessage::new(
                     types::MessageRole::System,
                     "Connected to engine.".to_string(),
@@ -797,8 +1005,12 @@ async fn run_event_loop(
     // Auto-start watch if configured
     if app.config.watch_on_start {
         watch_handle = Some(watcher::spawn_watcher(
-            app.project_path.clone(),
+            app.watch_roots(),
             watch_tx.clone(),
+            app.config.watch_debounce_ms,
+            watcher::PatternSet::new(&app.config.watch_include, &app.config.watch_exclude),
+            app.watch_suppressor.clone(),
+            app.watch_options(),
         ));
         app.watch_active = true;
         app.messages.push(types::ChatMessage::new(
@@ -807,6 +1019,29 @@ async fn run_event_loop(
         ));
     }
 
+    // Scriptable startup: `--open <file>:<line>` first (so a subsequent
+    // `--exec "view 2"` sees the file already focused), then
+    // `startup_commands` from project.toml, then the one-off `--exec
+    // "scan; view 2"` flag, all run as colon-commands.
+    let startup_commands: Vec<String> = open_arg
+        .map(|arg| format!("open {arg}"))
+        .into_iter()
+        .chain(app.config.startup_commands.iter().cloned())
+        .chain(
+            exec_arg
+                .unwrap_or_default()
+                .split(';')
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(str::to_string),
+        )
+        .collect();
+    for cmd in startup_commands {
+        if let Some(app_cmd) = app.handle_colon_command(&cmd) {
+            execute_command(app, app_cmd, &watch_tx, &mut watch_handle, persist_tx).await;
+        }
+    }
+
     // Load framework scores + dashboard metrics on startup if connected
     if app.engine_status == types::EngineConnectionStatus::Connected {
         execute_command(
@@ -814,6 +1049,7 @@ async fn run_event_loop(
             AppCommand::LoadFrameworkScores,
             &watch_tx,
             &mut watch_handle,
+            persist_tx,
         )
         .await;
         execute_command(
@@ -821,6 +1057,15 @@ async fn run_event_loop(
             AppCommand::LoadDashboardMetrics,
             &watch_tx,
             &mut watch_handle,
+            persist_tx,
+        )
+        .await;
+        execute_command(
+            app,
+            AppCommand::LoadDashboardWidgets,
+            &watch_tx,
+            &mut watch_handle,
+            persist_tx,
         )
         .await;
     }
@@ -835,8 +1080,26 @@ async fn run_event_loop(
             app.rebuild_click_areas(size.0, size.1);
         }
 
-        // Render
-        terminal.draw(|frame| render_dashboard(frame, app))?;
+        // Render — only when something visible actually changed, so an idle
+        // TUI sitting in watch mode drops to near-zero CPU between events.
+        if app.dirty {
+            if app.needs_terminal_reset {
+                terminal.clear()?;
+                app.needs_terminal_reset = false;
+            }
+            let frame_start = std::time::Instant::now();
+            let mut view_render = Duration::ZERO;
+            terminal.draw(|frame| {
+                let view_start = std::time::Instant::now();
+                render_dashboard(frame, app);
+                view_render = view_start.elapsed();
+            })?;
+            if let Some(perf) = &mut app.perf {
+                perf.record_frame(frame_start.elapsed());
+                perf.record_view_render(view_render);
+            }
+            app.dirty = false;
+        }
 
         // Event multiplexing
         tokio::select! {
@@ -844,19 +1107,37 @@ async fn run_event_loop(
             maybe_event = event_stream.next() => {
                 match maybe_event {
                     Some(Ok(Event::Key(key))) => {
+                        if let Some(perf) = &mut app.perf {
+                            perf.record_event();
+                        }
                         let action = input::handle_key_event(key, app);
                         if let Some(cmd) = app.apply_action(action) {
-                            execute_command(app, cmd, &watch_tx, &mut watch_handle).await;
+                            execute_command(app, cmd, &watch_tx, &mut watch_handle, persist_tx).await;
                         }
+                        app.dirty = true;
                     }
                     Some(Ok(Event::Mouse(mouse))) => {
+                        if let Some(perf) = &mut app.perf {
+                            perf.record_event();
+                        }
                         let action = input::handle_mouse_event(mouse, app);
                         if let Some(cmd) = app.apply_action(action) {
-                            execute_command(app, cmd, &watch_tx, &mut watch_handle).await;
+                            execute_command(app, cmd, &watch_tx, &mut watch_handle, persist_tx).await;
+                        }
+                        app.dirty = true;
+                    }
+                    Some(Ok(Event::Resize(w, h))) => {
+                        if let Some(perf) = &mut app.perf {
+                            perf.record_event();
                         }
+                        app.handle_resize(w, h);
                     }
-                    Some(Ok(Event::Resize(_w, _h))) => {
-                        // Resize handled naturally by ratatui on next render
+                    Some(Ok(Event::Paste(text))) => {
+                        if let Some(perf) = &mut app.perf {
+                            perf.record_event();
+                        }
+                        app.handle_paste(text);
+                        app.dirty = true;
                     }
                     _ => {
                         // Other events — terminal re-renders on next loop
@@ -866,19 +1147,87 @@ async fn run_event_loop(
 
             // Background command results (non-blocking async operations)
             Some(bg_cmd) = bg_rx.recv() => {
-                execute_command(app, bg_cmd, &watch_tx, &mut watch_handle).await;
+                if let Some(perf) = &mut app.perf {
+                    perf.record_event();
+                }
+                execute_command(app, bg_cmd, &watch_tx, &mut watch_handle, persist_tx).await;
+                app.dirty = true;
+            }
+
+            // Control socket requests (external automation)
+            Some(msg) = control_rx.recv() => {
+                if let Some(perf) = &mut app.perf {
+                    perf.record_event();
+                }
+                let response = handle_control_request(app, &msg.request, &watch_tx, &mut watch_handle, persist_tx).await;
+                let _ = msg.reply.send(response);
+                app.dirty = true;
+            }
+
+            // Ctrl+Z: suspend to shell with a clean terminal, restore on resume.
+            Some(()) = sigtstp_rx.recv() => {
+                #[cfg(unix)]
+                {
+                    suspend_to_shell(terminal)?;
+                    app.needs_terminal_reset = true;
+                    app.dirty = true;
+                }
             }
 
             // File watch events
-            Some(path) = watch_rx.recv(), if app.watch_active => {
-                app.push_activity(types::ActivityKind::Watch, path.display().to_string());
-                execute_command(app, AppCommand::AutoScan, &watch_tx, &mut watch_handle).await;
+            Some(paths) = watch_rx.recv(), if app.watch_active => {
+                if let Some(perf) = &mut app.perf {
+                    perf.record_event();
+                }
+                let is_git_switch = paths
+                    .iter()
+                    .any(|(_, kind)| *kind == watcher::ChangeKind::GitRef);
+                if is_git_switch {
+                    if let Some((branch, commit)) = watcher::git_head_summary(&app.project_path) {
+                        app.messages.push(types::ChatMessage::new(
+                            types::MessageRole::System,
+                            format!("Git: now on {branch} ({commit}) — rescanning."),
+                        ));
+                    }
+                }
+                let summary = match paths.as_slice() {
+                    [(single, _)] => single.display().to_string(),
+                    _ => format!("{} files", paths.len()),
+                };
+                app.push_activity(types::ActivityKind::Watch, summary);
+                let now = std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_secs();
+                let timestamp = timezone::format_hm(now);
+                app.changes.push_batch(&paths, &timestamp);
+                if let Some(open_path) = app.open_file_path.clone()
+                    && paths
+                        .iter()
+                        .any(|(p, _)| p == std::path::Path::new(&open_path))
+                {
+                    execute_command(
+                        app,
+                        AppCommand::CheckOpenFileChanged(open_path),
+                        &watch_tx,
+                        &mut watch_handle,
+                        persist_tx,
+                    )
+                    .await;
+                }
+                app.dirty = true;
+                execute_command(app, AppCommand::AutoScan, &watch_tx, &mut watch_handle, persist_tx).await;
             }
 
             // Tick for general state + health checks (250ms)
             _ = tick_interval.tick() => {
-                if let Some(cmd) = app.tick() {
-                    execute_command(app, cmd, &watch_tx, &mut watch_handle).await;
+                let (dirty, cmd) = app.tick();
+                if dirty {
+                    app.dirty = true;
+                }
+                if let Some(cmd) = cmd {
+                    execute_command(app, cmd, &watch_tx, &mut watch_handle, persist_tx).await;
+                    app.dirty = true;
                 }
 
                 tick_count += 1;
@@ -889,10 +1238,12 @@ async fn run_event_loop(
                     && engine_mgr.status == engine_process::EngineProcessStatus::Stopped
                 {
                     tracing::warn!("Engine process died, attempting restart");
+                    app.dirty = true;
                     match engine_mgr.try_restart() {
                         Ok(port) => {
                             app.engine_client = engine_client::EngineClient::from_url(
                                 &format!("http://127.0.0.1:{port}"),
+                                &app.config,
                             );
                             app.engine_status = types::EngineConnectionStatus::Connecting;
                             app.messages.push(types::ChatMessage::new(
@@ -914,6 +1265,7 @@ async fn run_event_loop(
             // Animation tick (50ms, 20fps) — only when animations active
             _ = anim_interval.tick(), if app.animation.active() => {
                 app.animation.step();
+                app.dirty = true;
             }
         }
     }
@@ -925,3 +1277,82 @@ async fn run_event_loop(
 
     Ok(())
 }
+
+/// Leave the alternate screen and disable raw mode, actually stop the
+/// process (`SIGSTOP`, which can't be intercepted or ignored), then restore
+/// both once the shell resumes it with `SIGCONT`. Caller is responsible for
+/// forcing a full redraw afterwards — the screen was touched outside
+/// ratatui's control while stopped.
+#[cfg(all(feature = "tui", unix))]
+fn suspend_to_shell(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+) -> color_eyre::Result<()> {
+    disable_raw_mode()?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture
+    )?;
+
+    // SAFETY: raise(2) with a valid, non-negative signal number is documented
+    // as safe to call from any context; it takes no pointers and cannot fail
+    // in a way that leaves process state inconsistent.
+    unsafe {
+        libc::raise(libc::SIGSTOP);
+    }
+    // Execution resumes here once the shell sends SIGCONT.
+
+    execute!(
+        terminal.backend_mut(),
+        EnterAlternateScreen,
+        EnableMouseCapture
+    )?;
+    enable_raw_mode()?;
+    Ok(())
+}
+
+/// Answer one control-socket request. `"get-score"` is a read-only query
+/// answered directly; everything else is treated as a colon-command line
+/// (`"scan"`, `"view 2"`, `"report"`, ...) and dispatched the same way
+/// typing `:cmd` in Normal mode would be — the response confirms dispatch,
+/// it doesn't wait for async commands like `scan` to finish.
+#[cfg(feature = "tui")]
+async fn handle_control_request(
+    app: &mut App,
+    request: &control_socket::ControlRequest,
+    watch_tx: &mpsc::UnboundedSender<Vec<(std::path::PathBuf, watcher::ChangeKind)>>,
+    watch_handle: &mut Option<tokio::task::JoinHandle<()>>,
+    persist_tx: &mpsc::UnboundedSender<session::SaveJob>,
+) -> control_socket::ControlResponse {
+    use control_socket::ControlResponse;
+
+    if request.command.trim() == "get-score" {
+        let result = app.last_scan.as_ref().map_or_else(
+            || serde_json::json!({ "scanned": false }),
+            |scan| {
+                serde_json::json!({
+                    "scanned": true,
+                    "score": scan.score.total_score,
+                    "zone": scan.score.zone.label(),
+                    "findings": scan.findings.len(),
+                })
+            },
+        );
+        return ControlResponse {
+            id: request.id.clone(),
+            ok: true,
+            result: Some(result),
+            error: None,
+        };
+    }
+
+    if let Some(cmd) = app.handle_colon_command(&request.command) {
+        execute_command(app, cmd, watch_tx, watch_handle, persist_tx).await;
+    }
+    ControlResponse {
+        id: request.id.clone(),
+        ok: true,
+        result: Some(serde_json::json!({ "dispatched": request.command })),
+        error: None,
+    }
+}
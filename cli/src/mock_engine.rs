@@ -0,0 +1,144 @@
+//! `--mock-engine`: a tiny in-process HTTP server that serves canned
+//! scan/chat/undo/status responses shaped exactly like the real TS engine.
+//!
+//! This is a genuine HTTP server, not a fake [`crate::engine_client::EngineClient`] —
+//! `EngineClient` is pointed at it over `http://127.0.0.1:<port>` completely
+//! unmodified, so everything downstream (SSE chat streaming, typed response
+//! deserialization, retry logic) exercises the real code path. That's what
+//! makes it useful for full end-to-end TUI development and testing on a
+//! machine without Node installed.
+//!
+//! It's deliberately minimal: request bodies are never parsed (every route's
+//! response is static, so there's nothing to branch on) and only the request
+//! line is read before responding. Fine for canned local development traffic;
+//! not a general-purpose HTTP server.
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+/// Start the mock engine on an OS-assigned local port and return it. Runs
+/// for the lifetime of the process — like the real engine subprocess, it's
+/// killed on exit rather than shut down cleanly.
+pub async fn spawn() -> std::io::Result<u16> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let port = listener.local_addr()?.port();
+    tokio::spawn(async move {
+        loop {
+            let Ok((stream, _)) = listener.accept().await else {
+                continue;
+            };
+            tokio::spawn(handle_connection(stream));
+        }
+    });
+    Ok(port)
+}
+
+async fn handle_connection(mut stream: TcpStream) {
+    let Some((method, path)) = read_request_line(&mut stream).await else {
+        return;
+    };
+    let (status_line, content_type, body) = route(&method, &path);
+    let response = format!(
+        "HTTP/1.1 {status_line}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    let _ = stream.write_all(response.as_bytes()).await;
+    let _ = stream.shutdown().await;
+}
+
+/// Read up to the end of the request headers and pull out the method + path
+/// from the request line. The body (if any) is left unread — no route needs it.
+async fn read_request_line(stream: &mut TcpStream) -> Option<(String, String)> {
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 512];
+    loop {
+        let n = stream.read(&mut chunk).await.ok()?;
+        if n == 0 || buf.len() > 8192 {
+            break;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+        if buf.windows(4).any(|w| w == b"\r\n\r\n") {
+            break;
+        }
+    }
+    let text = String::from_utf8_lossy(&buf);
+    let request_line = text.lines().next()?;
+    let mut parts = request_line.split_whitespace();
+    Some((parts.next()?.to_string(), parts.next()?.to_string()))
+}
+
+fn route(method: &str, path: &str) -> (&'static str, &'static str, &'static str) {
+    match (method, path) {
+        ("GET", "/status") => ("200 OK", "application/json", STATUS_BODY),
+        ("POST", "/scan") => ("200 OK", "application/json", SCAN_BODY),
+        ("POST", "/chat") => ("200 OK", "text/event-stream", CHAT_BODY),
+        ("POST", "/fix/undo") => ("200 OK", "application/json", UNDO_BODY),
+        ("GET", "/fix/history") => ("200 OK", "application/json", UNDO_HISTORY_BODY),
+        ("GET", "/suggestions") => ("200 OK", "application/json", SUGGESTIONS_BODY),
+        _ => ("404 Not Found", "application/json", "{}"),
+    }
+}
+
+const STATUS_BODY: &str = r#"{"ready":true,"version":"mock","mode":"mock","uptime":0,"apiVersion":"1","capabilities":["/scan","/chat","/suggestions","/undo"]}"#;
+
+const SCAN_BODY: &str = r#"{"score":{"totalScore":82,"zone":"green","categoryScores":[],"criticalCapApplied":false,"totalChecks":10,"passedChecks":9,"failedChecks":1,"skippedChecks":0},"findings":[],"projectPath":"(mock)","scannedAt":"1970-01-01T00:00:00.000Z","duration":42,"filesScanned":12}"#;
+
+const CHAT_BODY: &str = "event: text\ndata: {\"content\":\"This is a mocked engine response for offline development (--mock-engine).\"}\n\nevent: done\ndata: {}\n\n";
+
+const UNDO_BODY: &str = r#"{"message":"Mock undo applied"}"#;
+
+const UNDO_HISTORY_BODY: &str = r#"[{"id":1,"timestamp":"1970-01-01T00:00:00.000Z","action":"Mock fix","status":"applied","scoreDelta":5.0}]"#;
+
+const SUGGESTIONS_BODY: &str =
+    r#"[{"kind":"tip","text":"This suggestion is from --mock-engine.","detail":null}]"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn status_body_parses_as_engine_status() {
+        let status: crate::types::EngineStatus = serde_json::from_str(STATUS_BODY).unwrap();
+        assert!(status.ready);
+        assert!(status.supports("/scan"));
+    }
+
+    #[test]
+    fn scan_body_parses_as_scan_result() {
+        let result: crate::types::ScanResult = serde_json::from_str(SCAN_BODY).unwrap();
+        assert!((result.score.total_score - 82.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn undo_body_parses_as_undo_response() {
+        let result: crate::types::UndoResponse = serde_json::from_str(UNDO_BODY).unwrap();
+        assert_eq!(result.message.as_deref(), Some("Mock undo applied"));
+    }
+
+    #[test]
+    fn undo_history_body_parses() {
+        let entries: Vec<crate::types::UndoHistoryEntry> =
+            serde_json::from_str(UNDO_HISTORY_BODY).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].status, "applied");
+    }
+
+    #[test]
+    fn suggestions_body_parses() {
+        let items: Vec<crate::types::SuggestionItem> =
+            serde_json::from_str(SUGGESTIONS_BODY).unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].kind.as_deref(), Some("tip"));
+    }
+
+    #[tokio::test]
+    async fn serves_status_over_real_http() {
+        let port = spawn().await.unwrap();
+        let resp = reqwest::get(format!("http://127.0.0.1:{port}/status"))
+            .await
+            .unwrap();
+        assert!(resp.status().is_success());
+        let status: crate::types::EngineStatus = resp.json().await.unwrap();
+        assert!(status.ready);
+    }
+}
@@ -0,0 +1,35 @@
+//! Native desktop notifications for long-running headless operations
+//! (`scan`, `fix`), so a user who has tabbed away from the terminal still
+//! sees the result. No notification crate is vendored — this shells out to
+//! the platform's own notifier (`notify-send` on Linux, `osascript` on
+//! macOS) and is a silent no-op everywhere else (Windows, CI, minimal
+//! containers without a notification daemon).
+
+/// Send a desktop notification. Does nothing when `enabled` is `false`, the
+/// platform has no supported notifier, or the underlying command fails —
+/// notifications are best-effort and must never affect the exit code.
+pub fn notify(enabled: bool, title: &str, body: &str) {
+    if !enabled {
+        return;
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        let _ = std::process::Command::new("notify-send")
+            .args([title, body])
+            .output();
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        let script = format!("display notification {body:?} with title {title:?}");
+        let _ = std::process::Command::new("osascript")
+            .args(["-e", &script])
+            .output();
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "macos")))]
+    {
+        let _ = (title, body);
+    }
+}
@@ -0,0 +1,185 @@
+//! Third-party plugin ABI (checks, exporters, chat tools shipped as WASM
+//! modules under `.complior/plugins/<name>/plugin.toml`).
+//!
+//! Scope note: this only covers the manifest contract and local discovery,
+//! exposed via `complior plugins list|info`. Two safety-relevant parts of
+//! the original request are NOT delivered here and need their own
+//! follow-up work:
+//!   - Actually loading and capability-sandboxing a module's WASM bytecode
+//!     requires a `wasmtime` runtime, which is not yet wired into the
+//!     CLI's dependency tree — [`Plugin::load`] returns
+//!     [`PluginError::RuntimeUnavailable`] until that lands.
+//!   - There is no `/plugins` TUI overlay; plugins are only visible via
+//!     the CLI subcommand above.
+//! Discovery, manifest validation, and the ABI/capability contract below
+//! are real and exercised by `complior plugins list`. Tracked as
+//! open (discovery-only) in `docs/tech-debt.md` (TD-56) — this module
+//! does not close the original plugin-system request on its own.
+
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+/// ABI version this CLI build speaks. A plugin whose manifest declares a
+/// different `abi_version` is listed but flagged as incompatible rather
+/// than silently loaded.
+pub const PLUGIN_ABI_VERSION: u32 = 1;
+
+/// What a plugin is allowed to extend. Capability-gated: a plugin only
+/// gets the host-side hooks for the capabilities it declares.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PluginCapability {
+    /// Adds one or more checks merged into scan results, like
+    /// [`crate::local_rules`] but backed by arbitrary WASM logic.
+    Check,
+    /// Adds a `complior report --format` exporter.
+    Exporter,
+    /// Adds a tool the chat view's AI can call (`!cmd`-style extension).
+    ChatTool,
+}
+
+/// Parsed `.complior/plugins/<name>/plugin.toml`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PluginManifest {
+    pub name: String,
+    pub version: String,
+    pub abi_version: u32,
+    pub capabilities: Vec<PluginCapability>,
+    /// Path to the `.wasm` entry point, relative to the plugin's directory.
+    pub entry: String,
+}
+
+/// A discovered plugin: its manifest plus where it lives on disk.
+#[derive(Debug, Clone)]
+pub struct Plugin {
+    pub manifest: PluginManifest,
+    pub dir: PathBuf,
+}
+
+impl Plugin {
+    /// Whether this plugin's declared ABI matches [`PLUGIN_ABI_VERSION`].
+    pub const fn is_compatible(&self) -> bool {
+        self.manifest.abi_version == PLUGIN_ABI_VERSION
+    }
+
+    /// Load and instantiate the plugin's WASM module.
+    ///
+    /// Not yet implemented: the CLI doesn't depend on `wasmtime` yet. Kept
+    /// as a method (not an associated fn) since the eventual runtime will
+    /// need `self.dir`/`self.manifest.entry` to locate the module.
+    #[allow(clippy::unused_self)]
+    pub fn load(&self) -> Result<(), PluginError> {
+        Err(PluginError::RuntimeUnavailable)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PluginError {
+    #[error("plugin runtime not available in this build (WASM execution is not yet wired in)")]
+    RuntimeUnavailable,
+    #[error("invalid plugin manifest at {path}: {source}")]
+    InvalidManifest {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+}
+
+/// Discover every plugin under `<project>/.complior/plugins/`. Directories
+/// without a `plugin.toml`, or with one that fails to parse, are skipped
+/// with a warning rather than failing discovery for the rest.
+pub fn discover_plugins(project_path: &Path) -> Vec<Plugin> {
+    let plugins_dir = project_path.join(".complior/plugins");
+    let Ok(entries) = std::fs::read_dir(&plugins_dir) else {
+        return Vec::new();
+    };
+
+    let mut plugins = Vec::new();
+    for entry in entries.filter_map(std::result::Result::ok) {
+        let dir = entry.path();
+        if !dir.is_dir() {
+            continue;
+        }
+
+        let manifest_path = dir.join("plugin.toml");
+        let Ok(content) = std::fs::read_to_string(&manifest_path) else {
+            continue;
+        };
+
+        match toml::from_str::<PluginManifest>(&content) {
+            Ok(manifest) => plugins.push(Plugin { manifest, dir }),
+            Err(source) => {
+                eprintln!(
+                    "Warning: {}",
+                    PluginError::InvalidManifest {
+                        path: manifest_path,
+                        source,
+                    }
+                );
+            }
+        }
+    }
+
+    plugins
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_plugin(root: &Path, name: &str, contents: &str) {
+        let dir = root.join(".complior/plugins").join(name);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("plugin.toml"), contents).unwrap();
+    }
+
+    #[test]
+    fn returns_empty_when_no_plugins_dir() {
+        let dir =
+            std::env::temp_dir().join(format!("complior-plugins-empty-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        assert!(discover_plugins(&dir).is_empty());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn discovers_a_valid_plugin_and_flags_abi_mismatch() {
+        let dir =
+            std::env::temp_dir().join(format!("complior-plugins-discover-{}", std::process::id()));
+        write_plugin(
+            &dir,
+            "acme-checks",
+            r#"
+            name = "acme-checks"
+            version = "0.1.0"
+            abi_version = 1
+            capabilities = ["check"]
+            entry = "checks.wasm"
+            "#,
+        );
+        write_plugin(
+            &dir,
+            "future-checks",
+            r#"
+            name = "future-checks"
+            version = "2.0.0"
+            abi_version = 99
+            capabilities = ["exporter"]
+            entry = "exporter.wasm"
+            "#,
+        );
+
+        let mut plugins = discover_plugins(&dir);
+        plugins.sort_by(|a, b| a.manifest.name.cmp(&b.manifest.name));
+
+        assert_eq!(plugins.len(), 2);
+        assert!(plugins[0].is_compatible());
+        assert!(!plugins[1].is_compatible());
+        assert!(matches!(
+            plugins[0].load(),
+            Err(PluginError::RuntimeUnavailable)
+        ));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
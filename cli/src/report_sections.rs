@@ -0,0 +1,77 @@
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+/// A section of the compliance report, toggled and reordered from the
+/// Report view's composer (`c`) and applied to every export format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ReportSection {
+    Summary,
+    ScoreTrend,
+    FindingsByArticle,
+    Dismissals,
+    Evidence,
+    Timeline,
+}
+
+impl ReportSection {
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Summary => "Executive Summary",
+            Self::ScoreTrend => "Score Trend",
+            Self::FindingsByArticle => "Findings by Article",
+            Self::Dismissals => "Dismissals",
+            Self::Evidence => "Evidence",
+            Self::Timeline => "Timeline",
+        }
+    }
+}
+
+/// A report section plus whether it's included in the export, persisted in
+/// order so the composer's ordering feeds every export format directly.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SectionConfig {
+    pub section: ReportSection,
+    pub enabled: bool,
+}
+
+/// Default section list — every section on, in the order the report has
+/// always rendered them.
+pub fn default_sections() -> Vec<SectionConfig> {
+    [
+        ReportSection::Summary,
+        ReportSection::ScoreTrend,
+        ReportSection::FindingsByArticle,
+        ReportSection::Dismissals,
+        ReportSection::Evidence,
+        ReportSection::Timeline,
+    ]
+    .into_iter()
+    .map(|section| SectionConfig {
+        section,
+        enabled: true,
+    })
+    .collect()
+}
+
+fn sections_path(project_path: &Path) -> PathBuf {
+    project_path.join(".complior").join("report-sections.json")
+}
+
+/// Load the persisted section list, falling back to [`default_sections`]
+/// when nothing has been saved yet.
+pub fn load(project_path: &Path) -> Vec<SectionConfig> {
+    std::fs::read_to_string(sections_path(project_path))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_else(default_sections)
+}
+
+/// Persist the section list (order and enabled flags).
+pub fn save(project_path: &Path, sections: &[SectionConfig]) -> std::io::Result<()> {
+    let dir = project_path.join(".complior");
+    std::fs::create_dir_all(&dir)?;
+    let json = serde_json::to_string_pretty(sections)?;
+    std::fs::write(sections_path(project_path), json)
+}
@@ -0,0 +1,57 @@
+//! Small keyed cache for engine responses that stay valid for the lifetime
+//! of the current scan (background `/suggestions` and `/obligations`
+//! lookups) -- avoids re-fetching (and, for LLM-backed endpoints,
+//! re-billing) identical data when the user navigates back and forth.
+
+use std::collections::HashMap;
+
+#[derive(Debug, Default)]
+pub struct ResponseCache {
+    entries: HashMap<(&'static str, String), serde_json::Value>,
+}
+
+impl ResponseCache {
+    /// Look up a cached response for `call`, valid for `scan_key`.
+    pub fn get(&self, call: &'static str, scan_key: &str) -> Option<&serde_json::Value> {
+        self.entries.get(&(call, scan_key.to_string()))
+    }
+
+    /// Store a response for `call`, valid for `scan_key`.
+    pub fn put(&mut self, call: &'static str, scan_key: String, value: serde_json::Value) {
+        self.entries.insert((call, scan_key), value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn miss_then_hit_after_put() {
+        let mut cache = ResponseCache::default();
+        assert!(cache.get("suggestions", "scan-1").is_none());
+        cache.put(
+            "suggestions",
+            "scan-1".to_string(),
+            serde_json::json!({"text": "tip"}),
+        );
+        assert_eq!(
+            cache.get("suggestions", "scan-1"),
+            Some(&serde_json::json!({"text": "tip"}))
+        );
+    }
+
+    #[test]
+    fn entries_are_scoped_per_scan_key() {
+        let mut cache = ResponseCache::default();
+        cache.put("obligations", "scan-1".to_string(), serde_json::json!(1));
+        assert!(cache.get("obligations", "scan-2").is_none());
+    }
+
+    #[test]
+    fn entries_are_scoped_per_call() {
+        let mut cache = ResponseCache::default();
+        cache.put("suggestions", "scan-1".to_string(), serde_json::json!(1));
+        assert!(cache.get("obligations", "scan-1").is_none());
+    }
+}
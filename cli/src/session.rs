@@ -1,16 +1,70 @@
-use std::path::PathBuf;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
 
 use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
 
-use crate::types::{ChatMessage, ScanResult};
+use crate::types::{ActivityEntry, Bookmark, ChatMessage, ScanResult};
+
+/// Current on-disk `SessionData` format version.
+///
+/// Bump this whenever the shape of `SessionData` changes, and add a
+/// migration arm in `migrate` so older saved sessions keep loading instead
+/// of silently failing or losing fields.
+pub const SESSION_VERSION: u32 = 4;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SessionData {
+    #[serde(default = "default_session_version")]
+    pub version: u32,
     pub messages: Vec<ChatMessage>,
     pub score_history: Vec<f64>,
     pub open_file_path: Option<String>,
     pub terminal_output: Vec<String>,
     pub last_scan: Option<ScanResult>,
+    /// Flagged findings/files (`M` to mark, `'` to browse). Absent in
+    /// sessions saved before v2.
+    #[serde(default)]
+    pub bookmarks: Vec<Bookmark>,
+    /// Full activity history (`a` while the Activity Log widget is zoomed
+    /// to browse). Absent in sessions saved before v3.
+    #[serde(default)]
+    pub activity_history: Vec<ActivityEntry>,
+    /// Most-recently-opened files, newest first (`Ctrl+E` quick switcher).
+    /// Absent in sessions saved before v4.
+    #[serde(default)]
+    pub recent_files: Vec<String>,
+}
+
+/// Sessions saved before the `version` field existed have no value to
+/// default from; treat them as version 0 so `migrate` can upgrade them.
+fn default_session_version() -> u32 {
+    0
+}
+
+/// Upgrade a possibly-old `SessionData` to the current version in place.
+///
+/// Each arm moves the data forward exactly one version; this is a no-op
+/// once `data.version == SESSION_VERSION`.
+fn migrate(mut data: SessionData) -> SessionData {
+    if data.version == 0 {
+        // v0 -> v1: no field changes yet, just adopt the version tag.
+        data.version = 1;
+    }
+    if data.version == 1 {
+        // v1 -> v2: added `bookmarks`, already defaulted to empty by serde.
+        data.version = 2;
+    }
+    if data.version == 2 {
+        // v2 -> v3: added `activity_history`, already defaulted to empty by serde.
+        data.version = 3;
+    }
+    if data.version == 3 {
+        // v3 -> v4: added `recent_files`, already defaulted to empty by serde.
+        data.version = 4;
+    }
+    data
 }
 
 fn sessions_dir() -> PathBuf {
@@ -20,30 +74,194 @@ fn sessions_dir() -> PathBuf {
         .join("sessions")
 }
 
-pub async fn save_session(data: &SessionData, name: &str) -> Result<(), String> {
-    let dir = sessions_dir();
+/// Stable, short key identifying a project by its canonicalized path.
+///
+/// Falls back to the non-canonicalized path if the directory doesn't exist
+/// yet (e.g. a fresh project), so sessions still isolate correctly.
+pub(crate) fn project_key(project_path: &Path) -> String {
+    let canonical = project_path
+        .canonicalize()
+        .unwrap_or_else(|_| project_path.to_path_buf());
+    let mut hasher = DefaultHasher::new();
+    canonical.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+/// Per-project sessions directory, migrating any legacy flat-file session
+/// that predates per-project isolation into this project's namespace.
+async fn project_sessions_dir(project_path: &Path) -> PathBuf {
+    let base = sessions_dir();
+    let dir = base.join(project_key(project_path));
+
+    if !dir.exists() {
+        let legacy = base.join("latest.json");
+        if legacy.exists() {
+            let _ = tokio::fs::create_dir_all(&dir).await;
+            let _ = tokio::fs::rename(&legacy, dir.join("latest.json")).await;
+        }
+    }
+
+    dir
+}
+
+pub async fn save_session(
+    data: &SessionData,
+    name: &str,
+    project_path: &Path,
+    encrypt: bool,
+) -> Result<(), String> {
+    let dir = project_sessions_dir(project_path).await;
     tokio::fs::create_dir_all(&dir)
         .await
         .map_err(|e| format!("mkdir: {e}"))?;
 
-    let path = dir.join(format!("{name}.json"));
     let json = serde_json::to_string_pretty(data).map_err(|e| format!("serialize: {e}"))?;
-    tokio::fs::write(&path, json)
-        .await
-        .map_err(|e| format!("write: {e}"))?;
+
+    if encrypt {
+        let key = crate::config::get_or_create_session_key()?;
+        let ciphertext = crypto::encrypt(&key, json.as_bytes())?;
+        tokio::fs::write(dir.join(format!("{name}.json.enc")), ciphertext)
+            .await
+            .map_err(|e| format!("write: {e}"))?;
+        // Remove a stale plaintext copy so a toggle to encryption doesn't
+        // leave chat content readable alongside the encrypted file.
+        let _ = tokio::fs::remove_file(dir.join(format!("{name}.json"))).await;
+    } else {
+        tokio::fs::write(dir.join(format!("{name}.json")), json)
+            .await
+            .map_err(|e| format!("write: {e}"))?;
+        let _ = tokio::fs::remove_file(dir.join(format!("{name}.json.enc"))).await;
+    }
     Ok(())
 }
 
-pub async fn load_session(name: &str) -> Result<SessionData, String> {
-    let path = sessions_dir().join(format!("{name}.json"));
-    let content = tokio::fs::read_to_string(&path)
-        .await
-        .map_err(|e| format!("read: {e}"))?;
-    serde_json::from_str(&content).map_err(|e| format!("parse: {e}"))
+/// A save request handed off to the background persistence writer.
+///
+/// `save_session` serializes and writes the whole history to disk; on a
+/// long-running session that can be big enough to be felt as a dropped
+/// frame if awaited inline on the render/event loop, so callers enqueue a
+/// `SaveJob` instead and let the writer task spawned by [`spawn_writer`]
+/// handle it off to the side.
+pub struct SaveJob {
+    pub data: SessionData,
+    pub name: String,
+    pub project_path: PathBuf,
+    pub encrypt: bool,
 }
 
-pub async fn list_sessions() -> Vec<String> {
-    let dir = sessions_dir();
+/// Spawn the background writer that drains `SaveJob`s and persists them via
+/// [`save_session`]. Returns the sender callers enqueue jobs on and the
+/// task's `JoinHandle`, which resolves once the sender side is dropped and
+/// every queued job has been written — await it at shutdown to make sure
+/// the final autosave actually reaches disk before the process exits.
+pub fn spawn_writer() -> (mpsc::UnboundedSender<SaveJob>, tokio::task::JoinHandle<()>) {
+    let (tx, mut rx) = mpsc::unbounded_channel::<SaveJob>();
+    let handle = tokio::spawn(async move {
+        while let Some(job) = rx.recv().await {
+            if let Err(e) = save_session(&job.data, &job.name, &job.project_path, job.encrypt).await
+            {
+                tracing::warn!("Failed to save session: {e}");
+            }
+        }
+    });
+    (tx, handle)
+}
+
+pub async fn load_session(name: &str, project_path: &Path) -> Result<SessionData, String> {
+    let dir = project_sessions_dir(project_path).await;
+
+    let encrypted_path = dir.join(format!("{name}.json.enc"));
+    let json = if encrypted_path.exists() {
+        let ciphertext = tokio::fs::read(&encrypted_path)
+            .await
+            .map_err(|e| format!("read: {e}"))?;
+        let key = crate::config::get_or_create_session_key()?;
+        let plaintext = crypto::decrypt(&key, &ciphertext)?;
+        String::from_utf8(plaintext).map_err(|e| format!("decode: {e}"))?
+    } else {
+        tokio::fs::read_to_string(dir.join(format!("{name}.json")))
+            .await
+            .map_err(|e| format!("read: {e}"))?
+    };
+
+    let data: SessionData = serde_json::from_str(&json).map_err(|e| format!("parse: {e}"))?;
+    Ok(migrate(data))
+}
+
+/// `ChaCha20-Poly1305` at-rest encryption for session files.
+///
+/// Key comes from `config::get_or_create_session_key` (credentials file,
+/// see `docs/contributing/CODING-STANDARDS.md` "no secrets"). Nonces are
+/// generated fresh per encryption and stored alongside the ciphertext.
+mod crypto {
+    use ring::aead::{Aad, CHACHA20_POLY1305, LessSafeKey, Nonce, UnboundKey};
+    use ring::rand::{SecureRandom, SystemRandom};
+
+    const NONCE_LEN: usize = 12;
+
+    pub fn encrypt(key_bytes: &[u8; 32], plaintext: &[u8]) -> Result<Vec<u8>, String> {
+        let unbound = UnboundKey::new(&CHACHA20_POLY1305, key_bytes)
+            .map_err(|_| "invalid key".to_string())?;
+        let key = LessSafeKey::new(unbound);
+
+        let mut nonce_bytes = [0u8; NONCE_LEN];
+        SystemRandom::new()
+            .fill(&mut nonce_bytes)
+            .map_err(|_| "rng failure".to_string())?;
+        let nonce = Nonce::assume_unique_for_key(nonce_bytes);
+
+        let mut in_out = plaintext.to_vec();
+        key.seal_in_place_append_tag(nonce, Aad::empty(), &mut in_out)
+            .map_err(|_| "encryption failed".to_string())?;
+
+        let mut out = nonce_bytes.to_vec();
+        out.extend(in_out);
+        Ok(out)
+    }
+
+    pub fn decrypt(key_bytes: &[u8; 32], data: &[u8]) -> Result<Vec<u8>, String> {
+        if data.len() < NONCE_LEN {
+            return Err("ciphertext too short".to_string());
+        }
+        let (nonce_bytes, ciphertext) = data.split_at(NONCE_LEN);
+
+        let unbound = UnboundKey::new(&CHACHA20_POLY1305, key_bytes)
+            .map_err(|_| "invalid key".to_string())?;
+        let key = LessSafeKey::new(unbound);
+        let nonce = Nonce::try_assume_unique_for_key(nonce_bytes)
+            .map_err(|_| "invalid nonce".to_string())?;
+
+        let mut in_out = ciphertext.to_vec();
+        let plaintext = key
+            .open_in_place(nonce, Aad::empty(), &mut in_out)
+            .map_err(|_| "decryption failed (wrong or missing key?)".to_string())?;
+        Ok(plaintext.to_vec())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_encrypt_decrypt_roundtrip() {
+            let key = [7u8; 32];
+            let plaintext = b"{\"messages\":[]}";
+            let ciphertext = encrypt(&key, plaintext).expect("encrypt");
+            assert_ne!(ciphertext, plaintext);
+            let decrypted = decrypt(&key, &ciphertext).expect("decrypt");
+            assert_eq!(decrypted, plaintext);
+        }
+
+        #[test]
+        fn test_decrypt_with_wrong_key_fails() {
+            let ciphertext = encrypt(&[1u8; 32], b"secret").expect("encrypt");
+            assert!(decrypt(&[2u8; 32], &ciphertext).is_err());
+        }
+    }
+}
+
+pub async fn list_sessions(project_path: &Path) -> Vec<String> {
+    let dir = project_sessions_dir(project_path).await;
     let Ok(mut entries) = tokio::fs::read_dir(&dir).await else {
         return Vec::new();
     };
@@ -62,6 +280,70 @@ pub async fn list_sessions() -> Vec<String> {
     names
 }
 
+/// Synchronous counterpart to [`list_sessions`], for callers on the
+/// non-async input path (e.g. `/load` tab completion) that can't await a
+/// task. Skips the legacy-session migration `list_sessions` performs --
+/// that side effect only matters when a session is actually loaded.
+pub fn list_sessions_sync(project_path: &Path) -> Vec<String> {
+    let dir = sessions_dir().join(project_key(project_path));
+    let Ok(entries) = std::fs::read_dir(&dir) else {
+        return Vec::new();
+    };
+
+    let mut names = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.extension().is_some_and(|ext| ext == "json")
+            && let Some(stem) = path.file_stem()
+        {
+            names.push(stem.to_string_lossy().to_string());
+        }
+    }
+
+    names.sort();
+    names
+}
+
+/// A saved arrangement of panel/split/view state, switched between with
+/// `:layout save <name>` / `:layout load <name>` (e.g. a "review" layout vs.
+/// a "coding" layout). Deliberately separate from [`SessionData`], which
+/// captures conversation history rather than UI arrangement.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LayoutPreset {
+    pub view_state: crate::types::ViewState,
+    pub sidebar_visible: bool,
+    pub files_panel_visible: bool,
+    pub terminal_visible: bool,
+    pub fix_split_pct: u16,
+    pub scan_split_pct: u16,
+}
+
+fn layouts_dir() -> PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("complior")
+        .join("layouts")
+}
+
+pub async fn save_layout(preset: &LayoutPreset, name: &str) -> Result<(), String> {
+    let dir = layouts_dir();
+    tokio::fs::create_dir_all(&dir)
+        .await
+        .map_err(|e| format!("mkdir: {e}"))?;
+    let json = serde_json::to_string_pretty(preset).map_err(|e| format!("serialize: {e}"))?;
+    tokio::fs::write(dir.join(format!("{name}.json")), json)
+        .await
+        .map_err(|e| format!("write: {e}"))
+}
+
+pub async fn load_layout(name: &str) -> Result<LayoutPreset, String> {
+    let path = layouts_dir().join(format!("{name}.json"));
+    let json = tokio::fs::read_to_string(&path)
+        .await
+        .map_err(|e| format!("read: {e}"))?;
+    serde_json::from_str(&json).map_err(|e| format!("parse: {e}"))
+}
+
 pub async fn mark_first_run_done() {
     let dir = sessions_dir();
     let _ = tokio::fs::create_dir_all(&dir).await;
@@ -77,11 +359,15 @@ mod tests {
     #[test]
     fn test_session_roundtrip() {
         let data = SessionData {
+            version: SESSION_VERSION,
             messages: vec![ChatMessage::new(MessageRole::System, "test".to_string())],
             score_history: vec![42.0, 65.0],
             open_file_path: Some("src/main.rs".to_string()),
             terminal_output: vec!["$ ls".to_string()],
             last_scan: None,
+            bookmarks: Vec::new(),
+            activity_history: Vec::new(),
+            recent_files: Vec::new(),
         };
 
         let json = serde_json::to_string(&data).expect("serialize");
@@ -89,4 +375,28 @@ mod tests {
         assert_eq!(loaded.messages.len(), 1);
         assert_eq!(loaded.score_history.len(), 2);
     }
+
+    #[test]
+    fn test_load_migrates_unversioned_session() {
+        // Sessions saved before the `version` field existed lack it entirely.
+        let legacy_json = r#"{
+            "messages": [],
+            "score_history": [],
+            "open_file_path": null,
+            "terminal_output": [],
+            "last_scan": null
+        }"#;
+        let data: SessionData = serde_json::from_str(legacy_json).expect("deserialize legacy");
+        assert_eq!(data.version, 0);
+        let migrated = migrate(data);
+        assert_eq!(migrated.version, SESSION_VERSION);
+    }
+
+    #[test]
+    fn test_project_key_is_stable_and_distinct() {
+        let a = project_key(Path::new("/tmp/project-a"));
+        let b = project_key(Path::new("/tmp/project-b"));
+        assert_eq!(a, project_key(Path::new("/tmp/project-a")));
+        assert_ne!(a, b);
+    }
 }
@@ -0,0 +1,239 @@
+//! Settings overlay — runtime preferences buried in config (`/settings`).
+
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+use crate::config::TuiConfig;
+use crate::theme;
+
+/// Focused field in the Settings overlay.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingsField {
+    Animations,
+    WatchOnStart,
+    AutoScroll,
+    SidebarDefault,
+    TickRate,
+    ToastDuration,
+}
+
+/// State for the Settings overlay. Edits apply to the in-memory `TuiConfig`
+/// immediately on close; `AppCommand::SaveConfig` persists them afterwards.
+pub struct SettingsState {
+    pub focused_field: SettingsField,
+    pub animations_enabled: bool,
+    pub watch_on_start: bool,
+    pub auto_scroll_enabled: bool,
+    pub sidebar_visible: bool,
+    pub tick_rate_input: String,
+    pub toast_duration_input: String,
+    pub editing: bool,
+}
+
+impl SettingsState {
+    pub fn new(config: &TuiConfig) -> Self {
+        Self {
+            focused_field: SettingsField::Animations,
+            animations_enabled: config.animations_enabled,
+            watch_on_start: config.watch_on_start,
+            auto_scroll_enabled: config.auto_scroll_enabled,
+            sidebar_visible: config.sidebar_visible,
+            tick_rate_input: config.tick_rate_ms.to_string(),
+            toast_duration_input: config.toast_duration_secs.to_string(),
+            editing: false,
+        }
+    }
+}
+
+/// Render the Settings overlay as a centered modal.
+pub fn render_settings(frame: &mut Frame, state: &SettingsState) {
+    let t = theme::theme();
+    let area = centered_rect(52, 15, frame.area());
+
+    frame.render_widget(Clear, area);
+
+    let block = Block::default()
+        .title(" Settings ")
+        .title_style(Style::default().fg(t.accent).add_modifier(Modifier::BOLD))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(t.accent));
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1), // Animations
+            Constraint::Length(1), // Watch on start
+            Constraint::Length(1), // Auto-scroll
+            Constraint::Length(1), // Sidebar default
+            Constraint::Length(1), // Tick rate
+            Constraint::Length(1), // Toast duration
+            Constraint::Min(1),    // Footer
+        ])
+        .split(inner);
+
+    render_toggle_field(
+        frame,
+        chunks[0],
+        "Animations",
+        state.animations_enabled,
+        state.focused_field == SettingsField::Animations,
+        &t,
+    );
+    render_toggle_field(
+        frame,
+        chunks[1],
+        "Watch on start",
+        state.watch_on_start,
+        state.focused_field == SettingsField::WatchOnStart,
+        &t,
+    );
+    render_toggle_field(
+        frame,
+        chunks[2],
+        "Auto-scroll",
+        state.auto_scroll_enabled,
+        state.focused_field == SettingsField::AutoScroll,
+        &t,
+    );
+    render_toggle_field(
+        frame,
+        chunks[3],
+        "Sidebar default",
+        state.sidebar_visible,
+        state.focused_field == SettingsField::SidebarDefault,
+        &t,
+    );
+    render_numeric_field(
+        frame,
+        chunks[4],
+        "Tick rate (ms)",
+        &state.tick_rate_input,
+        state.focused_field == SettingsField::TickRate,
+        state.editing,
+        &t,
+    );
+    render_numeric_field(
+        frame,
+        chunks[5],
+        "Toast duration (s)",
+        &state.toast_duration_input,
+        state.focused_field == SettingsField::ToastDuration,
+        state.editing,
+        &t,
+    );
+
+    let footer = Line::from(vec![
+        Span::styled("j/k", Style::default().fg(t.accent)),
+        Span::styled(":navigate ", Style::default().fg(t.muted)),
+        Span::styled("Enter/Space", Style::default().fg(t.accent)),
+        Span::styled(":toggle/edit ", Style::default().fg(t.muted)),
+        Span::styled("Esc", Style::default().fg(t.accent)),
+        Span::styled(":save & close", Style::default().fg(t.muted)),
+    ]);
+    frame.render_widget(Paragraph::new(footer), chunks[6]);
+}
+
+fn render_toggle_field(
+    frame: &mut Frame,
+    area: Rect,
+    label: &str,
+    value: bool,
+    focused: bool,
+    t: &theme::ThemeColors,
+) {
+    let marker = if value { "[x]" } else { "[ ]" };
+    let label_style = if focused {
+        Style::default().fg(t.accent).add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(t.fg)
+    };
+    let marker_style = if value {
+        Style::default().fg(t.zone_green)
+    } else {
+        Style::default().fg(t.muted)
+    };
+    let line = Line::from(vec![
+        Span::styled(format!(" {marker} "), marker_style),
+        Span::styled(label.to_string(), label_style),
+    ]);
+    frame.render_widget(Paragraph::new(line), area);
+}
+
+fn render_numeric_field(
+    frame: &mut Frame,
+    area: Rect,
+    label: &str,
+    value: &str,
+    focused: bool,
+    editing: bool,
+    t: &theme::ThemeColors,
+) {
+    let label_style = if focused {
+        Style::default().fg(t.accent).add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(t.fg)
+    };
+    let value_display = if focused && editing {
+        format!("{value}\u{258c}")
+    } else {
+        value.to_string()
+    };
+    let value_style = if focused && editing {
+        Style::default().fg(t.zone_green)
+    } else {
+        Style::default().fg(t.muted)
+    };
+    let line = Line::from(vec![
+        Span::styled(format!("     {label}: "), label_style),
+        Span::styled(value_display, value_style),
+    ]);
+    frame.render_widget(Paragraph::new(line), area);
+}
+
+fn centered_rect(width: u16, height: u16, area: Rect) -> Rect {
+    let x = area.x + area.width.saturating_sub(width) / 2;
+    let y = area.y + area.height.saturating_sub(height) / 2;
+    Rect::new(x, y, width.min(area.width), height.min(area.height))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_settings_state_default() {
+        let config = TuiConfig::default();
+        let state = SettingsState::new(&config);
+        assert!(state.animations_enabled);
+        assert!(!state.watch_on_start);
+        assert!(state.auto_scroll_enabled);
+        assert!(state.sidebar_visible);
+        assert_eq!(state.tick_rate_input, "250");
+        assert_eq!(state.toast_duration_input, "3");
+        assert!(!state.editing);
+    }
+
+    #[test]
+    fn test_settings_state_with_config() {
+        let config = TuiConfig {
+            animations_enabled: false,
+            watch_on_start: true,
+            auto_scroll_enabled: false,
+            tick_rate_ms: 100,
+            toast_duration_secs: 10,
+            ..Default::default()
+        };
+        let state = SettingsState::new(&config);
+        assert!(!state.animations_enabled);
+        assert!(state.watch_on_start);
+        assert!(!state.auto_scroll_enabled);
+        assert_eq!(state.tick_rate_input, "100");
+        assert_eq!(state.toast_duration_input, "10");
+    }
+}
@@ -0,0 +1,156 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::ScanResult;
+
+/// One calendar day's aggregated usage for a project, persisted alongside
+/// sessions so teams can report on tool usage over time.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DayStats {
+    /// `YYYY-MM-DD`, local time.
+    pub date: String,
+    pub scans: u32,
+    pub fixes_applied: u32,
+    /// Running sum of each scan's `total_score` on this day; divide by
+    /// `scans` for the average rather than storing the average directly, so
+    /// later scans on the same day merge in without needing the prior mean.
+    score_sum: f64,
+    /// Sum of `ScanResult.l5_cost` (LLM analysis cost) across the day's
+    /// scans, in USD. `None` until a scan reports a cost.
+    pub cost_usd: Option<f64>,
+}
+
+impl DayStats {
+    fn new(date: String) -> Self {
+        Self {
+            date,
+            scans: 0,
+            fixes_applied: 0,
+            score_sum: 0.0,
+            cost_usd: None,
+        }
+    }
+
+    pub fn average_score(&self) -> f64 {
+        if self.scans == 0 {
+            0.0
+        } else {
+            self.score_sum / f64::from(self.scans)
+        }
+    }
+}
+
+fn stats_dir() -> PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("complior")
+        .join("stats")
+}
+
+fn stats_path(project_path: &std::path::Path) -> PathBuf {
+    stats_dir().join(format!(
+        "{}.json",
+        crate::session::project_key(project_path)
+    ))
+}
+
+fn load(project_path: &std::path::Path) -> Vec<DayStats> {
+    std::fs::read_to_string(stats_path(project_path))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save(project_path: &std::path::Path, days: &[DayStats]) {
+    let dir = stats_dir();
+    if std::fs::create_dir_all(&dir).is_err() {
+        return;
+    }
+    if let Ok(json) = serde_json::to_string_pretty(days) {
+        let _ = std::fs::write(stats_path(project_path), json);
+    }
+}
+
+/// Today's date as `YYYY-MM-DD`, derived from the Unix epoch (no `chrono`
+/// dependency in this crate) — enough precision for a daily rollup.
+fn today() -> String {
+    let secs = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let days_since_epoch = secs / 86400;
+    // Civil-from-days (Howard Hinnant's algorithm), avoids a chrono dep for
+    // a single date-formatting need.
+    let z = i64::try_from(days_since_epoch).unwrap_or(0) + 719_468;
+    let era = if z >= 0 { z } else { z - 146_096 } / 146_097;
+    let doe = (z - era * 146_097) as u64;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let y = i64::try_from(yoe).unwrap_or(0) + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = doy - (153 * mp + 2) / 5 + 1;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 };
+    let y = if m <= 2 { y + 1 } else { y };
+    format!("{y:04}-{m:02}-{d:02}")
+}
+
+fn day_mut<'a>(days: &'a mut Vec<DayStats>, date: &str) -> &'a mut DayStats {
+    if let Some(idx) = days.iter().position(|d| d.date == date) {
+        &mut days[idx]
+    } else {
+        days.push(DayStats::new(date.to_string()));
+        days.last_mut().expect("just pushed")
+    }
+}
+
+/// Record a completed scan's contribution to today's stats.
+pub fn record_scan(project_path: &std::path::Path, result: &ScanResult) {
+    let mut days = load(project_path);
+    let date = today();
+    let entry = day_mut(&mut days, &date);
+    entry.scans += 1;
+    entry.score_sum += result.score.total_score;
+    if let Some(cost) = result.l5_cost {
+        entry.cost_usd = Some(entry.cost_usd.unwrap_or(0.0) + cost);
+    }
+    save(project_path, &days);
+}
+
+/// Record a batch of applied fixes' contribution to today's stats.
+pub fn record_fixes(project_path: &std::path::Path, applied: u32) {
+    if applied == 0 {
+        return;
+    }
+    let mut days = load(project_path);
+    let date = today();
+    day_mut(&mut days, &date).fixes_applied += applied;
+    save(project_path, &days);
+}
+
+/// Load all recorded days for a project, most recent first.
+pub fn load_history(project_path: &std::path::Path) -> Vec<DayStats> {
+    let mut days = load(project_path);
+    days.sort_by(|a, b| b.date.cmp(&a.date));
+    days
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day_mut_creates_then_reuses_entry() {
+        let mut days = Vec::new();
+        day_mut(&mut days, "2026-08-08").scans += 1;
+        day_mut(&mut days, "2026-08-08").scans += 1;
+        assert_eq!(days.len(), 1);
+        assert_eq!(days[0].scans, 2);
+    }
+
+    #[test]
+    fn average_score_handles_zero_scans() {
+        let day = DayStats::new("2026-08-08".to_string());
+        assert_eq!(day.average_score(), 0.0);
+    }
+}
@@ -0,0 +1,192 @@
+//! Syntect-backed syntax highlighting for diff hunks (`views/scan/shared.rs`)
+//! so reviewing an engine-proposed patch isn't a wall of monochrome
+//! red/green text.
+
+use std::sync::OnceLock;
+
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Span;
+use syntect::easy::HighlightLines;
+use syntect::highlighting::ThemeSet;
+use syntect::parsing::SyntaxSet;
+
+fn syntax_set() -> &'static SyntaxSet {
+    static SET: OnceLock<SyntaxSet> = OnceLock::new();
+    SET.get_or_init(SyntaxSet::load_defaults_newlines)
+}
+
+fn theme_set() -> &'static ThemeSet {
+    static SET: OnceLock<ThemeSet> = OnceLock::new();
+    SET.get_or_init(ThemeSet::load_defaults)
+}
+
+/// Highlight one line of `file_path`-typed source under `syntect_theme`
+/// (see [`crate::theme::ThemeColors::syntect`]), tinting every resulting
+/// span with `bg`. Falls back to a single `fg`-colored, `bg`-tinted span
+/// when the extension or theme name isn't recognized -- the plain look
+/// diff hunks had before syntax highlighting existed.
+pub fn highlighted_spans(
+    line: &str,
+    file_path: &str,
+    syntect_theme: &str,
+    fg: Color,
+    bg: Color,
+) -> Vec<Span<'static>> {
+    let plain = || {
+        vec![Span::styled(
+            line.to_string(),
+            Style::default().fg(fg).bg(bg),
+        )]
+    };
+
+    let ss = syntax_set();
+    let Some(syntax) = std::path::Path::new(file_path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .and_then(|ext| ss.find_syntax_by_extension(ext))
+    else {
+        return plain();
+    };
+    let Some(theme) = theme_set().themes.get(syntect_theme) else {
+        return plain();
+    };
+
+    let mut highlighter = HighlightLines::new(syntax, theme);
+    let Ok(ranges) = highlighter.highlight_line(line, ss) else {
+        return plain();
+    };
+
+    ranges
+        .into_iter()
+        .map(|(style, text)| {
+            let color = Color::Rgb(style.foreground.r, style.foreground.g, style.foreground.b);
+            Span::styled(text.to_string(), Style::default().fg(color).bg(bg))
+        })
+        .collect()
+}
+
+/// Muted version of a theme color, suitable as a background tint behind
+/// syntax-highlighted text -- a full-brightness diff color would fight
+/// with the foreground token colors.
+pub fn tint(color: Color) -> Color {
+    match color {
+        Color::Rgb(r, g, b) => Color::Rgb(r / 6, g / 6, b / 6),
+        other => other,
+    }
+}
+
+/// Re-apply `Modifier::BOLD`/`UNDERLINED` to the spans covering
+/// `[start, end)` chars of a line already split into `spans` (as produced
+/// by [`highlighted_spans`]), splitting spans at the boundary as needed.
+/// Used to emphasize the part of a line that actually changed on top of
+/// syntax coloring.
+pub fn bold_range(spans: Vec<Span<'static>>, start: usize, end: usize) -> Vec<Span<'static>> {
+    if start >= end {
+        return spans;
+    }
+    let mut out = Vec::new();
+    let mut pos = 0usize;
+    for span in spans {
+        let text = span.content.into_owned();
+        let len = text.chars().count();
+        let span_start = pos;
+        let span_end = pos + len;
+        pos = span_end;
+
+        let lo = start.max(span_start) - span_start;
+        let hi = end.min(span_end).saturating_sub(span_start);
+        if lo >= hi || lo >= len {
+            out.push(Span::styled(text, span.style));
+            continue;
+        }
+        let chars: Vec<char> = text.chars().collect();
+        if lo > 0 {
+            out.push(Span::styled(
+                chars[..lo].iter().collect::<String>(),
+                span.style,
+            ));
+        }
+        out.push(Span::styled(
+            chars[lo..hi].iter().collect::<String>(),
+            span.style
+                .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+        ));
+        if hi < len {
+            out.push(Span::styled(
+                chars[hi..].iter().collect::<String>(),
+                span.style,
+            ));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn falls_back_to_plain_span_for_unknown_extension() {
+        let spans = highlighted_spans(
+            "hello",
+            "notes.unknownext",
+            "base16-ocean.dark",
+            Color::White,
+            Color::Black,
+        );
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].content, "hello");
+    }
+
+    #[test]
+    fn falls_back_to_plain_span_for_unknown_theme() {
+        let spans = highlighted_spans(
+            "fn main() {}",
+            "main.rs",
+            "not-a-real-theme",
+            Color::White,
+            Color::Black,
+        );
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].content, "fn main() {}");
+    }
+
+    #[test]
+    fn highlights_known_extension_and_theme() {
+        let spans = highlighted_spans(
+            "fn main() {}",
+            "main.rs",
+            "base16-ocean.dark",
+            Color::White,
+            Color::Black,
+        );
+        assert!(!spans.is_empty());
+        let joined: String = spans.iter().map(|s| s.content.as_ref()).collect();
+        assert_eq!(joined, "fn main() {}");
+    }
+
+    #[test]
+    fn tint_darkens_rgb_colors() {
+        assert_eq!(tint(Color::Rgb(120, 60, 30)), Color::Rgb(20, 10, 5));
+        assert_eq!(tint(Color::Reset), Color::Reset);
+    }
+
+    #[test]
+    fn bold_range_splits_span_at_boundaries() {
+        let spans = vec![Span::raw("hello world")];
+        let out = bold_range(spans, 6, 11);
+        let joined: String = out.iter().map(|s| s.content.as_ref()).collect();
+        assert_eq!(joined, "hello world");
+        assert!(
+            out.iter()
+                .any(|s| s.style.add_modifier.contains(Modifier::BOLD))
+        );
+    }
+
+    #[test]
+    fn bold_range_noop_when_start_ge_end() {
+        let spans = vec![Span::raw("hello")];
+        let out = bold_range(spans.clone(), 3, 3);
+        assert_eq!(out.len(), spans.len());
+    }
+}
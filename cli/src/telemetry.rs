@@ -0,0 +1,130 @@
+//! Opt-in anonymous usage telemetry.
+//!
+//! Off by default (`telemetry_enabled = false` in `settings.toml`). When on,
+//! this only ever counts *what* happened — which slash commands ran, how
+//! many toast errors surfaced, by coarse category — never the content of a
+//! command's arguments, a chat message, or a file. `/telemetry show` renders
+//! the exact JSON payload a future upload would send, so a user can verify
+//! that before enabling anything, or at any time after.
+//!
+//! No network client exists yet — this module only tracks and displays.
+
+use std::collections::BTreeMap;
+use std::sync::{Mutex, OnceLock};
+
+use serde::Serialize;
+
+static ENABLED: OnceLock<Mutex<bool>> = OnceLock::new();
+static FEATURE_COUNTS: OnceLock<Mutex<BTreeMap<String, u32>>> = OnceLock::new();
+static ERROR_COUNTS: OnceLock<Mutex<BTreeMap<String, u32>>> = OnceLock::new();
+
+/// Set whether telemetry recording is active, from config.
+pub fn set_enabled(enabled: bool) {
+    let mutex = ENABLED.get_or_init(|| Mutex::new(false));
+    *mutex.lock().expect("telemetry enabled lock") = enabled;
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED
+        .get()
+        .is_some_and(|m| *m.lock().expect("telemetry enabled lock"))
+}
+
+/// Count one use of a named feature (e.g. a slash command). No-op when
+/// telemetry is disabled.
+pub fn record_feature(name: &str) {
+    if !is_enabled() {
+        return;
+    }
+    let counts = FEATURE_COUNTS.get_or_init(|| Mutex::new(BTreeMap::new()));
+    *counts
+        .lock()
+        .expect("telemetry feature counts lock")
+        .entry(name.to_string())
+        .or_insert(0) += 1;
+}
+
+/// Count one error in a coarse category (never the error message itself).
+/// No-op when telemetry is disabled.
+pub fn record_error(category: &str) {
+    if !is_enabled() {
+        return;
+    }
+    let counts = ERROR_COUNTS.get_or_init(|| Mutex::new(BTreeMap::new()));
+    *counts
+        .lock()
+        .expect("telemetry error counts lock")
+        .entry(category.to_string())
+        .or_insert(0) += 1;
+}
+
+/// The exact payload a future telemetry upload would send.
+#[derive(Debug, Serialize)]
+pub struct TelemetryPayload {
+    pub enabled: bool,
+    pub feature_counts: BTreeMap<String, u32>,
+    pub error_counts: BTreeMap<String, u32>,
+}
+
+pub fn snapshot() -> TelemetryPayload {
+    TelemetryPayload {
+        enabled: is_enabled(),
+        feature_counts: FEATURE_COUNTS.get().map_or_else(BTreeMap::new, |m| {
+            m.lock().expect("telemetry feature counts lock").clone()
+        }),
+        error_counts: ERROR_COUNTS.get().map_or_else(BTreeMap::new, |m| {
+            m.lock().expect("telemetry error counts lock").clone()
+        }),
+    }
+}
+
+/// `/telemetry show`: pretty-print exactly what would be sent.
+pub fn render_show() -> String {
+    let payload = snapshot();
+    let json = serde_json::to_string_pretty(&payload).unwrap_or_else(|_| "{}".to_string());
+    if payload.enabled {
+        format!("Telemetry is ON. This is what would be sent:\n{json}")
+    } else {
+        format!(
+            "Telemetry is OFF (opt in with `/telemetry on`). Nothing is recorded or sent. Preview of an empty payload:\n{json}"
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Both cases live in one test (rather than two `#[test]` fns) since they
+    // mutate the same process-global counters and `cargo test` runs tests
+    // concurrently by default.
+    #[test]
+    fn disabled_records_nothing_enabled_records_counts() {
+        set_enabled(false);
+        record_feature("disabled-probe");
+        record_error("disabled-probe");
+        assert!(!snapshot().feature_counts.contains_key("disabled-probe"));
+        assert!(!snapshot().error_counts.contains_key("disabled-probe"));
+
+        set_enabled(true);
+        record_feature("scan");
+        record_feature("scan");
+        record_error("engine_unreachable");
+        let payload = snapshot();
+        assert_eq!(payload.feature_counts.get("scan"), Some(&2));
+        assert_eq!(payload.error_counts.get("engine_unreachable"), Some(&1));
+        set_enabled(false);
+    }
+
+    #[test]
+    fn payload_never_contains_free_text_fields() {
+        let payload = snapshot();
+        let json = serde_json::to_string(&payload).expect("serializes");
+        // The schema is a fixed shape: bool + two count maps — no message
+        // or content field exists to leak through.
+        assert!(json.contains("\"feature_counts\""));
+        assert!(json.contains("\"error_counts\""));
+        assert!(!json.contains("\"message\""));
+        assert!(!json.contains("\"content\""));
+    }
+}
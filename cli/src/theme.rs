@@ -6,6 +6,9 @@ use crate::types::{FindingType, Severity, Zone};
 #[derive(Debug, Clone)]
 pub struct ThemeColors {
     pub name: &'static str,
+    /// Name of the bundled syntect theme to pair with this Complior theme
+    /// for code/diff syntax highlighting — see [`crate::syntax`].
+    pub syntect: String,
     pub bg: Color,
     pub fg: Color,
     pub border: Color,
@@ -43,7 +46,6 @@ const THEMES_JSON: &str = include_str!("../data/themes.json");
 
 /// Parsed theme entry from JSON.
 #[derive(serde::Deserialize)]
-#[allow(dead_code)]
 struct ThemeEntry {
     name: String,
     aliases: Vec<String>,
@@ -111,6 +113,7 @@ impl ThemeColors {
         Self {
             // Leak the name string to get a &'static str — themes are loaded once
             name: Box::leak(entry.name.clone().into_boxed_str()),
+            syntect: entry.syntect.clone(),
             bg: rgb(entry.bg),
             fg: rgb(entry.fg),
             border: rgb(entry.border),
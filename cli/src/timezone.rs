@@ -0,0 +1,100 @@
+//! Local-timezone offset for activity, chat, and changes-feed timestamps.
+//!
+//! By default detects the system's local UTC offset (unix only, via
+//! `libc::localtime_r`; other targets fall back to UTC). Can be pinned in
+//! `settings.toml`'s `timezone` field: `"auto"`, `"utc"`, or an explicit
+//! `+HH:MM`/`-HH:MM` offset, for users who want daemon/session times to
+//! stay consistent regardless of what machine the TUI runs on.
+
+use std::sync::{Mutex, OnceLock};
+
+static OFFSET_SECONDS: OnceLock<Mutex<i64>> = OnceLock::new();
+
+/// Set the global UTC offset from a config value.
+pub fn init_timezone(name: &str) {
+    let offset = resolve(name);
+    if let Some(mutex) = OFFSET_SECONDS.get() {
+        *mutex.lock().expect("timezone lock") = offset;
+    } else {
+        let _ = OFFSET_SECONDS.set(Mutex::new(offset));
+    }
+}
+
+/// The active UTC offset, in seconds (defaults to `0`/UTC before `init_timezone`).
+pub fn utc_offset_seconds() -> i64 {
+    OFFSET_SECONDS
+        .get()
+        .map_or(0, |m| *m.lock().expect("timezone lock"))
+}
+
+fn resolve(name: &str) -> i64 {
+    match name.to_ascii_lowercase().as_str() {
+        "auto" => system_offset_seconds(),
+        "utc" => 0,
+        tag => parse_offset(tag).unwrap_or_else(system_offset_seconds),
+    }
+}
+
+/// Parse a `+HH:MM`/`-HH:MM` (or bare `+HH`) offset into seconds.
+fn parse_offset(tag: &str) -> Option<i64> {
+    let (sign, rest) = match tag.strip_prefix('-') {
+        Some(rest) => (-1_i64, rest),
+        None => (1_i64, tag.strip_prefix('+').unwrap_or(tag)),
+    };
+    let mut parts = rest.splitn(2, ':');
+    let hours: i64 = parts.next()?.parse().ok()?;
+    let mins: i64 = match parts.next() {
+        Some(m) => m.parse().ok()?,
+        None => 0,
+    };
+    Some(sign * (hours * 3600 + mins * 60))
+}
+
+#[cfg(unix)]
+fn system_offset_seconds() -> i64 {
+    // SAFETY: `now` and `tm` are valid, non-null out-params for the
+    // corresponding POSIX calls; `localtime_r` never retains either pointer.
+    unsafe {
+        let now = libc::time(std::ptr::null_mut());
+        let mut tm: libc::tm = std::mem::zeroed();
+        libc::localtime_r(&raw const now, &raw mut tm);
+        tm.tm_gmtoff
+    }
+}
+
+#[cfg(not(unix))]
+fn system_offset_seconds() -> i64 {
+    0
+}
+
+/// Format Unix seconds as a local `HH:MM` clock time.
+pub fn format_hm(unix_secs: u64) -> String {
+    #[allow(clippy::cast_possible_wrap)]
+    let secs = unix_secs as i64 + utc_offset_seconds();
+    let local_secs = secs.rem_euclid(86_400);
+    format!("{:02}:{:02}", local_secs / 3600, (local_secs % 3600) / 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_offset_handles_sign_and_minutes() {
+        assert_eq!(parse_offset("+02:00"), Some(7200));
+        assert_eq!(parse_offset("-05:30"), Some(-19_800));
+        assert_eq!(parse_offset("+09"), Some(32_400));
+    }
+
+    #[test]
+    fn format_hm_wraps_across_midnight_with_offset() {
+        // 1970-01-01T00:30:00Z minus a 1h offset wraps to the previous day.
+        let secs = 30 * 60;
+        assert_eq!(format_hm_with_offset(secs, -3600), "23:30");
+    }
+
+    fn format_hm_with_offset(unix_secs: i64, offset: i64) -> String {
+        let local_secs = (unix_secs + offset).rem_euclid(86_400);
+        format!("{:02}:{:02}", local_secs / 3600, (local_secs % 3600) / 60)
+    }
+}
@@ -1,5 +1,5 @@
 /// Activity log entry for the Dashboard widget.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ActivityEntry {
     pub timestamp: String,
     pub kind: ActivityKind,
@@ -7,7 +7,7 @@ pub struct ActivityEntry {
 }
 
 /// Kind of activity logged to the Dashboard.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum ActivityKind {
     Scan,
     Fix,
@@ -0,0 +1,31 @@
+/// An item flagged for revisiting later during a long triage session (`M` to
+/// mark, `'` to open the bookmarks overlay), persisted in the session so it
+/// survives restarts.
+///
+/// Findings are referenced by identity (`check_id` + `file`) rather than by
+/// embedding a [`crate::types::Finding`] — `Finding` only derives
+/// `Deserialize`, so it can't round-trip through `SessionData` (same
+/// reasoning as `findings_state::FindingState`).
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum Bookmark {
+    Finding {
+        check_id: String,
+        file: Option<String>,
+    },
+    File {
+        path: String,
+    },
+}
+
+impl Bookmark {
+    /// Short label for the bookmarks overlay list.
+    pub fn label(&self) -> String {
+        match self {
+            Self::Finding { check_id, file } => match file {
+                Some(f) => format!("{check_id} ({f})"),
+                None => check_id.clone(),
+            },
+            Self::File { path } => path.clone(),
+        }
+    }
+}
@@ -20,14 +20,11 @@ impl ChatMessage {
 }
 
 pub fn chrono_now() -> String {
-    // Simple HH:MM format from system time
     let now = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
         .unwrap_or_default()
         .as_secs();
-    let hours = (now % 86400) / 3600;
-    let mins = (now % 3600) / 60;
-    format!("{hours:02}:{mins:02}")
+    crate::timezone::format_hm(now)
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -51,6 +48,35 @@ pub enum ChatBlock {
         result: String,
         is_error: bool,
     },
+    /// A `@file` mention resolved to file content and sent alongside the
+    /// message. `chunk_count > 1` means the file was too large for a single
+    /// chunk and was split (see `crate::attachments`).
+    Attachment {
+        path: String,
+        size_bytes: usize,
+        chunk_count: usize,
+    },
+}
+
+/// A user's decision on a pending write/execute tool call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToolApprovalDecision {
+    Approve,
+    Deny,
+    /// Approve this call and auto-approve this tool for the rest of the session.
+    AlwaysAllow,
+}
+
+/// Wraps the oneshot the chat stream reader is blocked on while a tool
+/// call awaits approval. `tokio::sync::oneshot::Sender` doesn't derive
+/// `Debug`, so this newtype carries a manual impl to satisfy `AppCommand`'s
+/// `#[derive(Debug)]`.
+pub struct ToolApprovalResponder(pub tokio::sync::oneshot::Sender<ToolApprovalDecision>);
+
+impl std::fmt::Debug for ToolApprovalResponder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ToolApprovalResponder(..)")
+    }
 }
 
 /// State of an in-progress SSE stream from the LLM.
@@ -63,6 +89,24 @@ pub struct StreamingState {
     pub stream_start: Option<std::time::Instant>,
 }
 
+/// A rate-limited chat request queued for automatic retry, with the
+/// original request body preserved so the retry is a verbatim resend.
+#[derive(Debug, Clone)]
+pub struct ChatRateLimitState {
+    pub resume_at: std::time::Instant,
+    pub total_secs: u64,
+    pub body: serde_json::Value,
+}
+
+impl ChatRateLimitState {
+    /// Seconds remaining until the retry fires, for the footer countdown.
+    pub fn remaining_secs(&self) -> u64 {
+        self.resume_at
+            .saturating_duration_since(std::time::Instant::now())
+            .as_secs()
+    }
+}
+
 /// LLM config passed per-request (provider/model/apiKey).
 #[derive(Debug, Clone, Default)]
 pub struct LlmSessionConfig {
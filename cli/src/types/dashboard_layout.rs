@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+
+/// A widget that can be placed in the Dashboard's configurable grid
+/// (opt-in via `dashboard_grid_mode`, arranged via the Arrange overlay).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DashboardWidget {
+    ScoreGauge,
+    Deadlines,
+    Activity,
+    Sparkline,
+    FindingsSummary,
+    WatchFeed,
+    Heatmap,
+}
+
+impl DashboardWidget {
+    /// All widgets, in their default display order.
+    pub const ALL: [Self; 7] = [
+        Self::ScoreGauge,
+        Self::Deadlines,
+        Self::Activity,
+        Self::Sparkline,
+        Self::FindingsSummary,
+        Self::WatchFeed,
+        Self::Heatmap,
+    ];
+
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::ScoreGauge => "Score Gauge",
+            Self::Deadlines => "Deadlines",
+            Self::Activity => "Activity Log",
+            Self::Sparkline => "Score History",
+            Self::FindingsSummary => "Findings Summary",
+            Self::WatchFeed => "Watch Feed",
+            Self::Heatmap => "Severity Heatmap",
+        }
+    }
+}
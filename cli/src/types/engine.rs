@@ -620,6 +620,32 @@ pub struct ReadinessResult {
     pub unmet_requirements: u32,
 }
 
+/// A single entry in a GET /widgets response — server-driven dashboard
+/// extensions (e.g. org-wide aggregate score, open policy tasks) that the
+/// engine declares without the CLI knowing about them ahead of time.
+/// Rendered generically by `crate::components::remote_widgets` based on
+/// `kind` alone.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct RemoteWidget {
+    pub id: String,
+    pub title: String,
+    pub kind: RemoteWidgetKind,
+}
+
+/// How a `RemoteWidget` should be rendered — the CLI supports these three
+/// generic shapes; anything else the engine sends is skipped rather than
+/// failing the whole `/widgets` response.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "type")]
+#[allow(dead_code)]
+pub enum RemoteWidgetKind {
+    KeyValue { value: String },
+    Gauge { value: f64, max: f64 },
+    List { items: Vec<String> },
+}
+
 /// Readiness category from engine (matches TS `Aiuc1CategoryScore`).
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -649,6 +675,70 @@ pub struct EngineStatus {
     pub uptime: Option<u64>,
     #[serde(default)]
     pub last_scan: Option<serde_json::Value>,
+    /// Handshake API version, distinct from `version` (the engine's release
+    /// version) — bumped only when the `/status` contract itself changes.
+    #[serde(default)]
+    pub api_version: Option<String>,
+    /// Optional endpoints this engine build exposes (e.g. `/suggestions`,
+    /// `/undo`, `/explain`). `None` means the engine predates this handshake
+    /// and didn't report capabilities at all — see [`EngineStatus::supports`].
+    #[serde(default)]
+    pub capabilities: Option<Vec<String>>,
+}
+
+impl EngineStatus {
+    /// Whether the connected engine has advertised support for `endpoint`
+    /// (e.g. `"/suggestions"`). Engines that don't report `capabilities` at
+    /// all — every engine before this handshake existed — are treated as
+    /// supporting everything, since we genuinely can't tell; only an engine
+    /// that explicitly lists capabilities and omits one is gated.
+    pub fn supports(&self, endpoint: &str) -> bool {
+        self.capabilities
+            .as_ref()
+            .is_none_or(|caps| caps.iter().any(|c| c == endpoint))
+    }
+}
+
+/// POST /fix/undo response.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct UndoResponse {
+    #[serde(default)]
+    pub message: Option<String>,
+}
+
+/// One entry of the GET /fix/history response.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct UndoHistoryEntry {
+    pub id: u32,
+    #[serde(default)]
+    pub timestamp: String,
+    #[serde(default)]
+    pub action: String,
+    #[serde(default = "default_undo_history_status")]
+    pub status: String,
+    #[serde(default)]
+    pub score_delta: Option<f64>,
+}
+
+fn default_undo_history_status() -> String {
+    "applied".to_string()
+}
+
+/// One entry of the GET /suggestions response.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct SuggestionItem {
+    #[serde(default)]
+    pub kind: Option<String>,
+    #[serde(default)]
+    pub text: Option<String>,
+    #[serde(default)]
+    pub detail: Option<String>,
 }
 
 // ── V1-M10: Score Transparency types ──────────────────────────────
@@ -4,8 +4,12 @@ pub mod sync; // Sync contract — mirrors engine/core/src/types/sync.types.ts
 #[cfg(feature = "tui")]
 mod activity;
 #[cfg(feature = "tui")]
+mod bookmarks;
+#[cfg(feature = "tui")]
 mod chat;
 #[cfg(feature = "tui")]
+mod dashboard_layout;
+#[cfg(feature = "tui")]
 mod file_tree;
 #[cfg(feature = "tui")]
 mod navigation;
@@ -17,8 +21,12 @@ pub use engine::*;
 #[cfg(feature = "tui")]
 pub use activity::*;
 #[cfg(feature = "tui")]
+pub use bookmarks::*;
+#[cfg(feature = "tui")]
 pub use chat::*;
 #[cfg(feature = "tui")]
+pub use dashboard_layout::*;
+#[cfg(feature = "tui")]
 pub use file_tree::*;
 #[cfg(feature = "tui")]
 pub use navigation::*;
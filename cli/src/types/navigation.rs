@@ -1,5 +1,5 @@
 /// Top-level view (screen).
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum ViewState {
     Dashboard,   // D, index 0
     Scan,        // S, index 1
@@ -90,6 +90,16 @@ impl ViewState {
     ];
 }
 
+/// A snapshot of navigation-relevant state, recorded on the jump list
+/// (`App::nav_history`) on every view switch and file open/close, so
+/// Ctrl+O / Ctrl+I can jump backward/forward through them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NavPoint {
+    pub view_state: ViewState,
+    pub open_file_path: Option<String>,
+    pub selected_finding: Option<usize>,
+}
+
 /// Operating mode — cycles with Tab in Normal mode.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Mode {
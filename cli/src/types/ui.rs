@@ -32,6 +32,66 @@ pub enum Overlay {
     DismissModal,
     UndoHistory,
     LlmSettings,
+    ChangesFeed,
+    ArrangeDashboard,
+    /// Multi-project switcher (`/projects`): lists registered project paths
+    /// with last score/zone/findings, Enter re-points the active project.
+    ProjectSwitcher,
+    /// Chat summoned as a floating overlay over any view (Ctrl+A), so users
+    /// can ask the AI about what they're looking at without leaving it.
+    FloatingChat,
+    /// Confirm inserting a large bracketed paste as a fenced code block.
+    PasteConfirm,
+    /// Per-day usage stats (`/stats`): scans, fixes applied, average score,
+    /// and LLM analysis cost, persisted alongside sessions.
+    Stats,
+    /// EU AI Act risk classification questionnaire (`/risk-classify`):
+    /// Annex III + GPAI systemic-risk y/n walkthrough.
+    RiskClassification,
+    /// Runtime preferences (`/settings`): animations, watch-on-start,
+    /// auto-scroll, sidebar default, tick rate, toast duration.
+    Settings,
+    /// Per-check documentation browser (`?` on a finding in Scan view):
+    /// what the check verifies, why it matters, article/penalty/deadline,
+    /// remediation guidance, and links.
+    CheckDocs,
+    /// A write/execute tool call from the chat agent is awaiting approval
+    /// (view args, approve/deny/always-allow) before the engine proceeds.
+    ToolCallApproval,
+    /// Full-screen view of a tool call/result pair (`Enter` on the focused
+    /// block in Chat view), for inspecting output too large for the
+    /// truncated inline preview.
+    ToolResultInspector,
+    /// Flagged findings/files for a triage session (`'`), toggled with `M`.
+    Bookmarks,
+    /// Last 100 toasts plus system chat messages, with severity filter
+    /// (`N` to open, `f` to cycle filter) — toasts auto-dismiss, this is
+    /// where to check what was missed while typing.
+    Notifications,
+    /// Full, searchable/filterable activity history (`a` while the Activity
+    /// Log widget is zoomed) — the Dashboard widget itself only shows the
+    /// last 10 entries.
+    ActivityHistory,
+    /// Drill-down for a capped score (`c` in Dashboard when
+    /// `critical_cap_applied`): which critical findings trigger the cap,
+    /// the category-weighted score estimate without it, and what to fix.
+    CriticalCapDetail,
+    /// Scripted guided tour (`/tour`): steps through views, spotlighting one
+    /// region per step and dimming the rest of the screen. Dismissible with
+    /// `Esc`; the current step is kept in `App::tour` so reopening resumes
+    /// where it left off.
+    Tour,
+    /// Searchable keybinding browser (`/keys`), replacing the static Help
+    /// overlay's scroll-only text — type to filter by context, keys, or
+    /// action. `/keys export` writes the same table to a file instead.
+    Keybindings,
+    /// Recently-opened-files quick switcher (`Ctrl+E`): the last
+    /// `App::recent_files` entries, newest first, Enter reopens the
+    /// selected one in the code viewer.
+    RecentFiles,
+    /// The open file changed on disk (watcher event) while loaded in the
+    /// code viewer: reload, keep the in-memory version, or view the diff.
+    FileReloadPrompt,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -49,4 +109,38 @@ pub enum ClickTarget {
     FindingRow(usize),
     FixCheckbox(usize),
     SidebarToggle,
+    /// The chat/log message body — click-drag here selects lines for copy.
+    ChatBody,
+    /// A scrollbar track — click a position to jump the scroll offset there.
+    ScrollbarTrack(ScrollTarget),
+}
+
+/// A view that renders a scrollbar with click-to-jump support.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollTarget {
+    Chat,
+    Findings,
+}
+
+/// A footer status-bar indicator, hovered via mouse-move to show a tooltip.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FooterIndicator {
+    Score,
+    View,
+    Ctx,
+    Engine,
+}
+
+impl FooterIndicator {
+    /// Explanation shown in the hover tooltip, plus the related command.
+    pub const fn tooltip(self) -> &'static str {
+        match self {
+            Self::Score => "Compliance score (0-100) \u{2014} Ctrl+S to rescan",
+            Self::View => "Current view \u{2014} 1-9 to switch, Ctrl+P for palette",
+            Self::Ctx => "Chat context usage \u{2014} resets on a new session",
+            Self::Engine => {
+                "Engine connection \u{2014} \u{25cf} connected \u{25cb} connecting/idle \u{2717} error"
+            }
+        }
+    }
 }
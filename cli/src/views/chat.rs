@@ -2,7 +2,9 @@ use ratatui::Frame;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
+use ratatui::widgets::{
+    Block, Borders, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState, Wrap,
+};
 
 use crate::app::App;
 use crate::theme;
@@ -68,7 +70,7 @@ pub fn render_chat_view(frame: &mut Frame, area: Rect, app: &App) {
     // ── Messages ──────────────────────────────────────────────────────────
     let mut lines: Vec<Line<'_>> = Vec::new();
 
-    for msg in &app.messages {
+    for (msg_index, msg) in app.messages.iter().enumerate() {
         match msg.role {
             MessageRole::System => {
                 // System: ◦ prefix, muted text
@@ -132,7 +134,8 @@ pub fn render_chat_view(frame: &mut Frame, area: Rect, app: &App) {
         }
 
         // Render blocks (thinking, tool_call, tool_result)
-        for blk in &msg.blocks {
+        for (blk_index, blk) in msg.blocks.iter().enumerate() {
+            let is_focused = app.chat_tool_focus == Some((msg_index, blk_index));
             match blk {
                 ChatBlock::Thinking(text) => {
                     let preview = if text.len() > 80 { &text[..80] } else { text };
@@ -167,17 +170,14 @@ pub fn render_chat_view(frame: &mut Frame, area: Rect, app: &App) {
                     result,
                     is_error,
                 } => {
-                    let result_preview = if result.len() > 200 {
-                        &result[..200]
-                    } else {
-                        result
-                    };
+                    let truncated = result.len() > 200;
+                    let result_preview = if truncated { &result[..200] } else { result };
                     let (icon, color) = if *is_error {
                         ("\u{2717} ", t.tool_result_err) // ✗
                     } else {
                         ("\u{2713} ", t.tool_result_ok) // ✓
                     };
-                    lines.push(Line::from(vec![
+                    let mut spans = vec![
                         Span::raw(INDENT),
                         Span::styled(icon, Style::default().fg(color)),
                         Span::styled(
@@ -185,6 +185,35 @@ pub fn render_chat_view(frame: &mut Frame, area: Rect, app: &App) {
                             Style::default().fg(color).add_modifier(Modifier::BOLD),
                         ),
                         Span::styled(result_preview.to_string(), Style::default().fg(t.muted)),
+                    ];
+                    if truncated {
+                        spans.push(Span::styled(
+                            "... [Enter to expand]",
+                            Style::default().fg(t.accent),
+                        ));
+                    } else if is_focused {
+                        spans.push(Span::styled(
+                            " [Enter to inspect]",
+                            Style::default().fg(t.accent),
+                        ));
+                    }
+                    lines.push(Line::from(spans));
+                }
+                ChatBlock::Attachment {
+                    path,
+                    size_bytes,
+                    chunk_count,
+                } => {
+                    let suffix = if *chunk_count > 1 {
+                        format!(" ({size_bytes} bytes, {chunk_count} chunks)")
+                    } else {
+                        format!(" ({size_bytes} bytes)")
+                    };
+                    lines.push(Line::from(vec![
+                        Span::raw(INDENT),
+                        Span::styled("\u{1F4CE} ", Style::default().fg(t.accent)),
+                        Span::styled(path.clone(), Style::default().fg(t.muted)),
+                        Span::styled(suffix, Style::default().fg(t.muted)),
                     ]));
                 }
                 ChatBlock::Text(_) => {}
@@ -245,6 +274,14 @@ pub fn render_chat_view(frame: &mut Frame, area: Rect, app: &App) {
         .scroll((u16::try_from(scroll).unwrap_or(u16::MAX), 0));
     frame.render_widget(paragraph, msg_area);
 
+    if total_lines > visible {
+        let mut scrollbar_state = ScrollbarState::new(total_lines).position(scroll);
+        let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .track_style(Style::default().fg(t.muted))
+            .thumb_style(Style::default().fg(t.accent));
+        frame.render_stateful_widget(scrollbar, msg_area, &mut scrollbar_state);
+    }
+
     // ── "Unread above" indicator ──────────────────────────────────────────
     if scroll > 0 && !app.chat_auto_scroll && msg_area.height > 0 {
         let indicator = Paragraph::new(Line::from(vec![Span::styled(
@@ -272,7 +309,23 @@ pub fn render_chat_view(frame: &mut Frame, area: Rect, app: &App) {
     let input_inner = input_block.inner(input_area);
     frame.render_widget(input_block, input_area);
 
-    if app.streaming.active {
+    if app.input_mode == InputMode::Insert {
+        let matches = app.mention_matches();
+        crate::components::mention_popup::render_mention_popup(
+            frame,
+            input_area,
+            &matches,
+            app.mention_index,
+        );
+    }
+
+    if app.config.offline_mode {
+        let banner = Paragraph::new(Line::from(vec![Span::styled(
+            "Chat is disabled in offline mode (--offline). Only the local engine is used.",
+            Style::default().fg(t.muted),
+        )]));
+        frame.render_widget(banner, input_inner);
+    } else if app.streaming.active {
         let elapsed = app
             .streaming
             .stream_start
@@ -310,6 +363,13 @@ pub fn render_chat_view(frame: &mut Frame, area: Rect, app: &App) {
             }
             if is_last && app.input_mode == InputMode::Insert {
                 spans.push(Span::styled("\u{258c}", Style::default().fg(t.accent)));
+                if *line_text == app.input.as_str()
+                    && let Some(ghost) = app
+                        .completion_preview()
+                        .and_then(|p| p.strip_prefix(&app.input).map(str::to_string))
+                {
+                    spans.push(Span::styled(ghost, Style::default().fg(t.muted)));
+                }
             }
             prompt_lines.push(Line::from(spans));
         }
@@ -322,6 +382,55 @@ pub fn render_chat_view(frame: &mut Frame, area: Rect, app: &App) {
     }
 }
 
+/// Plain-text mirror of the message lines `render_chat_view` draws, in the
+/// same order, for mouse-selection copy. Excludes the ephemeral streaming
+/// indicator since it isn't stable text to select. Long lines that ratatui
+/// soft-wraps on screen aren't split here, so a drag-selection spanning a
+/// wrapped line copies it as a single line — an accepted approximation,
+/// consistent with the other hand-rolled row estimates in `click_areas`.
+pub fn plain_lines(app: &App) -> Vec<String> {
+    use crate::types::ChatBlock;
+
+    let mut lines: Vec<String> = Vec::new();
+
+    for msg in &app.messages {
+        let prefix = match msg.role {
+            MessageRole::System => "\u{25E6} ",
+            MessageRole::User => "YOU ",
+            MessageRole::Assistant => "\u{25CF} ",
+        };
+        let mut content_lines = msg.content.lines();
+        lines.push(format!("{prefix}{}", content_lines.next().unwrap_or("")));
+        for content_line in content_lines {
+            lines.push(format!("{INDENT}{content_line}"));
+        }
+
+        for blk in &msg.blocks {
+            match blk {
+                ChatBlock::Thinking(text) => {
+                    lines.push(format!("{INDENT}\u{25CC} {text}"));
+                }
+                ChatBlock::ToolCall { tool_name, args } => {
+                    lines.push(format!("{INDENT}\u{2699} {tool_name}({args})"));
+                }
+                ChatBlock::ToolResult {
+                    tool_name, result, ..
+                } => {
+                    lines.push(format!("{INDENT}{tool_name}: {result}"));
+                }
+                ChatBlock::Attachment {
+                    path, size_bytes, ..
+                } => {
+                    lines.push(format!("{INDENT}\u{1F4CE} {path} ({size_bytes} bytes)"));
+                }
+                ChatBlock::Text(_) => {}
+            }
+        }
+    }
+
+    lines
+}
+
 /// Render only System-role messages as the status log.
 ///
 /// US-S0211: When the log is empty (no system events yet), renders an
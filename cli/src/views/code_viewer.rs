@@ -1,14 +1,93 @@
+/// Files larger than this are truncated (to the last full line before the
+/// cut) when opened in the code viewer. Past this point, holding the whole
+/// file as one `String` and re-scanning it on every search/selection starts
+/// to compete with the 250ms tick for CPU, so we cap it instead of loading
+/// multi-MB generated files in full.
+pub const MAX_BUFFER_BYTES: usize = 2 * 1024 * 1024;
+
+/// An opened file's contents plus a byte-offset line index, built once so
+/// scrolling, search, and selection index into it directly instead of
+/// re-splitting the whole file with `.lines()` on every keystroke.
+#[derive(Debug, Clone)]
+pub struct CodeBuffer {
+    content: String,
+    line_offsets: Vec<(usize, usize)>,
+    /// `true` if the source was larger than [`MAX_BUFFER_BYTES`] and got cut off.
+    pub truncated: bool,
+}
+
+impl CodeBuffer {
+    /// Build a buffer from an already-read file, truncating to
+    /// [`MAX_BUFFER_BYTES`] (rounded down to the last full line) if needed.
+    pub fn new(mut content: String) -> Self {
+        let truncated = content.len() > MAX_BUFFER_BYTES;
+        if truncated {
+            let mut cut = MAX_BUFFER_BYTES;
+            while cut > 0 && !content.is_char_boundary(cut) {
+                cut -= 1;
+            }
+            cut = content[..cut].rfind('\n').map_or(0, |nl| nl + 1);
+            content.truncate(cut);
+        }
+        let line_offsets = index_lines(&content);
+        Self {
+            content,
+            line_offsets,
+            truncated,
+        }
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.line_offsets.len()
+    }
+
+    pub fn line(&self, index: usize) -> Option<&str> {
+        self.line_offsets
+            .get(index)
+            .map(|&(start, end)| &self.content[start..end])
+    }
+
+    /// Lines `start..end` (clamped to the buffer), for viewport-limited
+    /// rendering and highlighting instead of walking the whole file.
+    pub fn lines_in(&self, start: usize, end: usize) -> impl Iterator<Item = &str> {
+        let end = end.min(self.line_offsets.len());
+        (start.min(end)..end).filter_map(move |i| self.line(i))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.content
+    }
+}
+
+/// Byte-range of each line in `content`, matching `str::lines()` semantics
+/// (no entry for a trailing empty string after a final `\n`).
+fn index_lines(content: &str) -> Vec<(usize, usize)> {
+    let mut offsets = Vec::new();
+    let mut start = 0;
+    for (i, b) in content.bytes().enumerate() {
+        if b == b'\n' {
+            offsets.push((start, i));
+            start = i + 1;
+        }
+    }
+    if start < content.len() {
+        offsets.push((start, content.len()));
+    }
+    offsets
+}
+
 /// Find all line indices matching a search query.
-pub fn find_search_matches(content: &str, query: &str) -> Vec<usize> {
+pub fn find_search_matches(buffer: &CodeBuffer, query: &str) -> Vec<usize> {
     if query.is_empty() {
         return Vec::new();
     }
     let lower_query = query.to_lowercase();
-    content
-        .lines()
-        .enumerate()
-        .filter(|(_, line)| line.to_lowercase().contains(&lower_query))
-        .map(|(i, _)| i)
+    (0..buffer.line_count())
+        .filter(|&i| {
+            buffer
+                .line(i)
+                .is_some_and(|line| line.to_lowercase().contains(&lower_query))
+        })
         .collect()
 }
 
@@ -18,21 +97,49 @@ mod tests {
 
     #[test]
     fn test_find_search_matches() {
-        let content = "hello world\nfoo bar\nhello again";
-        let matches = find_search_matches(content, "hello");
+        let buffer = CodeBuffer::new("hello world\nfoo bar\nhello again".to_string());
+        let matches = find_search_matches(&buffer, "hello");
         assert_eq!(matches, vec![0, 2]);
     }
 
     #[test]
     fn test_find_search_matches_empty() {
-        let matches = find_search_matches("hello", "");
+        let buffer = CodeBuffer::new("hello".to_string());
+        let matches = find_search_matches(&buffer, "");
         assert!(matches.is_empty());
     }
 
     #[test]
     fn test_find_search_case_insensitive() {
-        let content = "Hello World\nhello world";
-        let matches = find_search_matches(content, "HELLO");
+        let buffer = CodeBuffer::new("Hello World\nhello world".to_string());
+        let matches = find_search_matches(&buffer, "HELLO");
         assert_eq!(matches.len(), 2);
     }
+
+    #[test]
+    fn test_code_buffer_line_access() {
+        let buffer = CodeBuffer::new("first\nsecond\nthird".to_string());
+        assert_eq!(buffer.line_count(), 3);
+        assert_eq!(buffer.line(1), Some("second"));
+        assert_eq!(buffer.line(3), None);
+        assert!(!buffer.truncated);
+    }
+
+    #[test]
+    fn test_code_buffer_lines_in_clamps_to_len() {
+        let buffer = CodeBuffer::new("a\nb\nc".to_string());
+        let slice: Vec<&str> = buffer.lines_in(1, 100).collect();
+        assert_eq!(slice, vec!["b", "c"]);
+    }
+
+    #[test]
+    fn test_code_buffer_truncates_oversized_content() {
+        let line = "x".repeat(100);
+        let content = std::iter::repeat_n(line, (MAX_BUFFER_BYTES / 100) + 100)
+            .collect::<Vec<_>>()
+            .join("\n");
+        let buffer = CodeBuffer::new(content);
+        assert!(buffer.truncated);
+        assert!(buffer.as_str().len() <= MAX_BUFFER_BYTES);
+    }
 }
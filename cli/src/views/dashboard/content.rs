@@ -10,7 +10,7 @@ use crate::types::Panel;
 
 use super::panels::{
     render_activity_log, render_focused_framework_gauge, render_framework_cards, render_info_panel,
-    render_score_gauge,
+    render_score_gauge, render_score_gauge_with_categories,
 };
 
 /// Dashboard content area -- two-column layout.
@@ -28,15 +28,28 @@ use super::panels::{
 pub(super) fn render_dashboard_content(frame: &mut Frame, area: Rect, app: &App) {
     use crate::components::zoom::ZoomedWidget;
 
+    // Opt-in configurable widget grid (arranged via the Arrange overlay),
+    // takes priority over the fixed default layout below.
+    if app.config.dashboard_grid_mode {
+        render_dashboard_grid(frame, area, app);
+        return;
+    }
+
     // T702: If a widget is zoomed, render it full-screen
     if let Some(zoomed) = app.zoom.zoomed {
         match zoomed {
-            ZoomedWidget::ScoreGauge => render_score_gauge(frame, area, app),
+            ZoomedWidget::ScoreGauge => render_score_gauge_with_categories(frame, area, app),
             ZoomedWidget::DeadlineCountdown => {
                 super::panels::render_deadline_countdown(frame, area);
             }
             ZoomedWidget::ActivityLog => render_activity_log(frame, area, app),
-            ZoomedWidget::ScoreSparkline => render_score_history_line(frame, area, app),
+            ZoomedWidget::ScoreSparkline => {
+                if crate::graphics::detect().supports_images() {
+                    render_score_history_line(frame, area, app);
+                } else {
+                    render_score_history_canvas(frame, area, app);
+                }
+            }
         }
         return;
     }
@@ -106,6 +119,41 @@ pub(super) fn render_dashboard_content(frame: &mut Frame, area: Rect, app: &App)
     render_info_panel(frame, h_split[1], app);
 }
 
+/// Configurable 2x2 widget grid, shown instead of the fixed layout above
+/// when `dashboard_grid_mode` is enabled. Widgets and their order come from
+/// `dashboard_layout`; only the first four visible widgets fit the grid.
+fn render_dashboard_grid(frame: &mut Frame, area: Rect, app: &App) {
+    use crate::types::DashboardWidget;
+
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(area);
+    let top = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(rows[0]);
+    let bottom = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(rows[1]);
+    let cells = [top[0], top[1], bottom[0], bottom[1]];
+
+    for (widget, cell) in app.config.dashboard_layout.iter().zip(cells) {
+        match widget {
+            DashboardWidget::ScoreGauge => render_score_gauge(frame, cell, app),
+            DashboardWidget::Deadlines => super::panels::render_deadline_countdown(frame, cell),
+            DashboardWidget::Activity => render_activity_log(frame, cell, app),
+            DashboardWidget::Sparkline => render_score_history_line(frame, cell, app),
+            DashboardWidget::FindingsSummary => {
+                super::panels::render_findings_summary_widget(frame, cell, app);
+            }
+            DashboardWidget::WatchFeed => super::panels::render_watch_feed_widget(frame, cell, app),
+            DashboardWidget::Heatmap => super::panels::render_directory_heatmap(frame, cell, app),
+        }
+    }
+}
+
 /// Agent strip widget — shows all discovered agents with their autonomy level and score.
 fn render_agent_strip(frame: &mut Frame, area: Rect, app: &App) {
     let t = theme::theme();
@@ -212,3 +260,90 @@ pub(super) fn render_score_history_line(frame: &mut Frame, area: Rect, app: &App
 
     frame.render_widget(Paragraph::new(lines), inner);
 }
+
+/// Score history as a braille line chart with axes and zone-boundary
+/// reference lines, used in place of [`render_score_history_line`] when the
+/// widget is zoomed on a terminal without graphics protocol support (see
+/// `crate::graphics`) -- terminals that do have one still get the compact
+/// text sparkline, since this crate has no image codec to draw a real
+/// chart image over them yet.
+///
+/// `score_history` doesn't carry a timestamp per scan (see `App::score_history`),
+/// so the x-axis is labeled by recency ("oldest"/"latest") rather than dates.
+pub(super) fn render_score_history_canvas(frame: &mut Frame, area: Rect, app: &App) {
+    use ratatui::symbols::Marker;
+    use ratatui::widgets::{Axis, Chart, Dataset, GraphType};
+
+    let t = theme::theme();
+
+    let block = Block::default()
+        .title(" Score History ")
+        .title_style(theme::title_style())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(t.border));
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    if app.score_history.is_empty() {
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                " No history yet \u{2014} run /scan",
+                Style::default().fg(t.muted),
+            ))),
+            inner,
+        );
+        return;
+    }
+
+    let last_index = app.score_history.len().saturating_sub(1) as f64;
+    let points: Vec<(f64, f64)> = app
+        .score_history
+        .iter()
+        .enumerate()
+        .map(|(i, &score)| (i as f64, score))
+        .collect();
+
+    // Zone-boundary reference lines (red/yellow at 50, yellow/green at 80),
+    // matching `views::score_zone_color`'s thresholds.
+    let red_yellow_boundary = vec![(0.0, 50.0), (last_index, 50.0)];
+    let yellow_green_boundary = vec![(0.0, 80.0), (last_index, 80.0)];
+
+    let last_score = app.score_history.last().copied().unwrap_or(0.0);
+    let color = crate::views::score_zone_color(last_score, &t);
+
+    let datasets = vec![
+        Dataset::default()
+            .name("score")
+            .marker(Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(color))
+            .data(&points),
+        Dataset::default()
+            .marker(Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(t.zone_yellow))
+            .data(&red_yellow_boundary),
+        Dataset::default()
+            .marker(Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(t.zone_green))
+            .data(&yellow_green_boundary),
+    ];
+
+    let chart = Chart::new(datasets)
+        .x_axis(
+            Axis::default()
+                .style(Style::default().fg(t.muted))
+                .bounds([0.0, last_index.max(1.0)])
+                .labels(vec!["oldest", "latest"]),
+        )
+        .y_axis(
+            Axis::default()
+                .style(Style::default().fg(t.muted))
+                .bounds([0.0, 100.0])
+                .labels(vec!["0", "50", "80", "100"]),
+        );
+
+    frame.render_widget(chart, inner);
+}
@@ -70,15 +70,45 @@ pub(super) fn render_view_footer(frame: &mut Frame, app: &App) {
         Style::default().fg(ctx_color),
     ));
 
-    // Show elapsed time if operation in progress
+    // Show elapsed time if operation in progress — a determinate bar with
+    // ETA when the operation reports a current/total (currently only the
+    // Fix apply queue; Scan and Export are single round-trips with no
+    // intermediate progress to report), the bare spinner otherwise.
     if let Some(secs) = app.elapsed_secs() {
+        if let Some((current, total)) = footer_progress(app) {
+            spans.push(Span::raw(" ["));
+            spans.push(Span::styled(
+                progress_bar(current, total, 10),
+                Style::default().fg(t.accent),
+            ));
+            spans.push(Span::styled(
+                format!("] {current}/{total}"),
+                Style::default().fg(t.muted),
+            ));
+            if let Some(eta) = eta_secs(secs, current, total) {
+                spans.push(Span::styled(
+                    format!(" ETA {eta}s"),
+                    Style::default().fg(t.muted),
+                ));
+            }
+        } else {
+            spans.push(Span::styled(
+                format!(" {secs}s "),
+                Style::default().fg(t.muted),
+            ));
+            spans.push(Span::styled(
+                app.spinner.frame(),
+                Style::default().fg(t.accent),
+            ));
+        }
+    }
+
+    // Rate-limited chat request queued for auto-retry: show countdown.
+    if let Some(retry) = &app.chat_retry {
+        spans.push(Span::raw(" "));
         spans.push(Span::styled(
-            format!(" {secs}s "),
-            Style::default().fg(t.muted),
-        ));
-        spans.push(Span::styled(
-            app.spinner.frame(),
-            Style::default().fg(t.accent),
+            format!("[retry in {}s]", retry.remaining_secs()),
+            Style::default().fg(t.zone_yellow),
         ));
     }
 
@@ -99,6 +129,24 @@ pub(super) fn render_view_footer(frame: &mut Frame, app: &App) {
     };
     spans.push(engine_indicator);
 
+    if app.config.offline_mode {
+        spans.push(Span::raw(" "));
+        spans.push(Span::styled(
+            "[OFFLINE]",
+            Style::default()
+                .fg(t.zone_yellow)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+
+    if let Some(category) = app.degraded_mode {
+        spans.push(Span::raw(" "));
+        spans.push(Span::styled(
+            format!("[{}]", category.badge_label()),
+            Style::default().fg(t.zone_red).add_modifier(Modifier::BOLD),
+        ));
+    }
+
     frame.render_widget(Paragraph::new(Line::from(spans)), line1_area);
 
     // -- Line 2: Input mode + view-specific hints --
@@ -164,6 +212,104 @@ pub(super) fn render_view_footer(frame: &mut Frame, app: &App) {
     frame.render_widget(Paragraph::new(Line::from(hint_spans)), line2_area);
 }
 
+/// `(current, total)` for the operation currently reporting elapsed time,
+/// when it has a determinate total — currently only the Fix apply queue
+/// (`FixViewState::applying_current`/`applying_total`, advanced one fix at
+/// a time by `AppCommand::FixProgress`). `None` falls back to a bare spinner.
+fn footer_progress(app: &App) -> Option<(u32, u32)> {
+    if app.fix_view.applying && app.fix_view.applying_total > 0 {
+        Some((app.fix_view.applying_current, app.fix_view.applying_total))
+    } else {
+        None
+    }
+}
+
+/// Render a `width`-character block-gauge for `current`/`total`, matching
+/// the mini progress bars in `views::scan::progress`.
+fn progress_bar(current: u32, total: u32, width: usize) -> String {
+    let ratio = f64::from(current) / f64::from(total.max(1));
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let filled = (ratio * width as f64).round() as usize;
+    let filled = filled.min(width);
+    format!(
+        "{}{}",
+        "\u{2588}".repeat(filled),
+        "\u{2591}".repeat(width - filled)
+    )
+}
+
+/// Estimate seconds remaining from the rate observed so far (`elapsed_secs
+/// / current`), or `None` before the first item completes.
+fn eta_secs(elapsed_secs: u64, current: u32, total: u32) -> Option<u64> {
+    if current == 0 || current >= total {
+        return None;
+    }
+    let rate = elapsed_secs as f64 / f64::from(current);
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let eta = (rate * f64::from(total - current)).round() as u64;
+    Some(eta)
+}
+
+/// Map a column on the status-bar line (line 1) to the indicator rendered
+/// there, mirroring the span order/lengths built in `render_view_footer`.
+/// Approximate for the trailing elapsed-time/spinner segment, which isn't
+/// itself hoverable and is skipped when computing offsets.
+pub(crate) fn indicator_at_col(app: &App, col: u16) -> Option<crate::types::FooterIndicator> {
+    use crate::types::FooterIndicator;
+
+    let col = col as usize;
+    let mut pos = 0usize;
+
+    let score_text = app.last_scan.as_ref().map_or_else(
+        || "[--]".to_string(),
+        |scan| format!("[{:.0}]", scan.score.total_score),
+    );
+    if col < pos + score_text.len() {
+        return Some(FooterIndicator::Score);
+    }
+    pos += score_text.len() + 1; // trailing space
+
+    let view_text = format!(
+        "[{} {}]",
+        app.view_state.index() + 1,
+        app.view_state.short_name()
+    );
+    if col < pos + view_text.len() {
+        return Some(FooterIndicator::View);
+    }
+    pos += view_text.len() + 1;
+
+    let ctx_pct = (app.messages.len() as u32).saturating_mul(100) / 32;
+    let ctx_text = format!("[ctx:{ctx_pct}%]");
+    if col < pos + ctx_text.len() {
+        return Some(FooterIndicator::Ctx);
+    }
+    pos += ctx_text.len();
+
+    // Elapsed time + spinner (or progress bar) only appear while an
+    // operation is running.
+    if let Some(secs) = app.elapsed_secs() {
+        pos += if let Some((current, total)) = footer_progress(app) {
+            let mut len = format!(" [{}] {current}/{total}", progress_bar(current, total, 10))
+                .chars()
+                .count();
+            if let Some(eta) = eta_secs(secs, current, total) {
+                len += format!(" ETA {eta}s").len();
+            }
+            len
+        } else {
+            format!(" {secs}s ").len() + app.spinner.frame().chars().count()
+        };
+    }
+
+    // Engine dot: " " + one glyph.
+    if col < pos + 2 {
+        return Some(FooterIndicator::Engine);
+    }
+
+    None
+}
+
 /// View-specific footer hints (line 2).
 pub const fn footer_hints_for_view(view: ViewState) -> &'static str {
     match view {
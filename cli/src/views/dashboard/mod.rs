@@ -44,12 +44,17 @@ use crate::types::{Overlay, ViewState};
 
 use content::render_dashboard_content;
 use footer::render_view_footer;
-use overlays::{render_dismiss_modal, render_getting_started_overlay, render_help_overlay};
+use overlays::{
+    render_arrange_dashboard_overlay, render_check_docs, render_dismiss_modal,
+    render_floating_chat_overlay, render_getting_started_overlay, render_help_overlay,
+    render_paste_confirm, render_tool_approval, render_tool_result_inspector,
+};
 use panels::render_detail_panel;
 
 // Re-export items used outside dashboard module.
 // NOTE: footer_hints_for_view, deadline_label, score_zone_info are only used
 // within the dashboard module (panels, footer, tests), so no pub re-export needed.
+pub(crate) use footer::indicator_at_col;
 
 /// Top-level render entry point -- dispatches to view-specific renderer.
 pub fn render_dashboard(frame: &mut Frame, app: &App) {
@@ -142,6 +147,14 @@ pub fn render_dashboard(frame: &mut Frame, app: &App) {
 
     // Overlay on top of everything
     render_overlay(frame, app);
+
+    if let Some(perf) = &app.perf {
+        crate::components::perf_overlay::render_perf_overlay(frame, frame.area(), perf);
+    }
+
+    if let Some(indicator) = app.hovered_indicator {
+        crate::components::tooltip::render_tooltip(frame, frame.area(), indicator);
+    }
 }
 
 /// Full-screen splash with owl mascot, fades in during startup (500ms).
@@ -404,6 +417,8 @@ fn render_overlay(frame: &mut Frame, app: &App) {
                 frame,
                 &app.overlay_filter,
                 app.palette_index,
+                &app.palette_contextual_commands(),
+                &app.recent_commands,
             );
         }
         Overlay::FilePicker => {
@@ -436,14 +451,96 @@ fn render_overlay(frame: &mut Frame, app: &App) {
                 render_dismiss_modal(frame, modal);
             }
         }
+        Overlay::PasteConfirm => {
+            if let Some(paste) = &app.pending_paste {
+                render_paste_confirm(frame, paste);
+            }
+        }
         Overlay::UndoHistory => {
             crate::components::undo_history::render_undo_history(frame, &app.undo_history);
         }
+        Overlay::ProjectSwitcher => {
+            let active = app.project_path.to_string_lossy();
+            crate::components::project_switcher::render_project_switcher(
+                frame,
+                &app.project_switcher,
+                &active,
+            );
+        }
+        Overlay::Stats => {
+            crate::components::stats::render_stats(frame, &app.stats);
+        }
+        Overlay::RecentFiles => {
+            crate::components::recent_files::render_recent_files(frame, &app.recent_files_view);
+        }
+        Overlay::FileReloadPrompt => {
+            if let Some(prompt) = &app.file_reload_prompt {
+                crate::components::file_reload_prompt::render_file_reload_prompt(frame, prompt);
+            }
+        }
+        Overlay::RiskClassification => {
+            if let Some(wizard) = &app.risk_wizard {
+                crate::views::risk_classification::render_risk_classification(frame, wizard);
+            }
+        }
         Overlay::LlmSettings => {
             if let Some(state) = &app.llm_settings {
                 crate::llm_settings::render_llm_settings(frame, state);
             }
         }
+        Overlay::Settings => {
+            if let Some(state) = &app.settings_overlay {
+                crate::settings_overlay::render_settings(frame, state);
+            }
+        }
+        Overlay::ChangesFeed => {
+            crate::components::changes_feed::render_changes_feed(frame, &app.changes);
+        }
+        Overlay::ArrangeDashboard => render_arrange_dashboard_overlay(frame, app),
+        Overlay::FloatingChat => render_floating_chat_overlay(frame, app),
+        Overlay::CheckDocs => {
+            if let Some(docs) = &app.check_docs {
+                render_check_docs(frame, docs);
+            }
+        }
+        Overlay::ToolCallApproval => {
+            if let Some(pending) = &app.pending_tool_approval {
+                render_tool_approval(frame, pending);
+            }
+        }
+        Overlay::ToolResultInspector => render_tool_result_inspector(frame, app),
+        Overlay::Bookmarks => {
+            crate::components::bookmarks::render_bookmarks(frame, &app.bookmarks);
+        }
+        Overlay::Notifications => {
+            crate::components::notifications::render_notification_center(
+                frame,
+                &app.notification_center,
+            );
+        }
+        Overlay::ActivityHistory => {
+            crate::components::activity_history::render_activity_history(
+                frame,
+                &app.activity_history_view,
+                &app.overlay_filter,
+            );
+        }
+        Overlay::CriticalCapDetail => {
+            crate::components::critical_cap_detail::render_critical_cap_detail(
+                frame,
+                &app.critical_cap_detail,
+            );
+        }
+        Overlay::Tour => {
+            crate::components::tour::render_tour(frame, &app.tour);
+        }
+        Overlay::Keybindings => {
+            crate::components::keybindings::render_keybindings(
+                frame,
+                &app.keybindings,
+                &app.overlay_filter,
+            );
+        }
     }
 
     // Always render toasts on top of everything
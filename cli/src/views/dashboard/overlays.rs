@@ -2,7 +2,7 @@ use ratatui::Frame;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, Paragraph};
+use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
 
 use crate::app::App;
 use crate::theme;
@@ -61,6 +61,8 @@ pub(super) fn render_help_overlay(frame: &mut Frame, app: &App) {
     lines.push(shortcut_line("  Ctrl+D/U", "Half-page down/up", &t));
     lines.push(shortcut_line("  g/G", "Top/bottom", &t));
     lines.push(shortcut_line("  Up/Down", "History (insert mode)", &t));
+    lines.push(shortcut_line("  Ctrl+O", "Jump back", &t));
+    lines.push(shortcut_line("  Shift+Tab", "Jump forward", &t));
     lines.push(Line::raw(""));
     lines.push(Line::from(Span::styled(
         " Features",
@@ -75,6 +77,10 @@ pub(super) fn render_help_overlay(frame: &mut Frame, app: &App) {
     lines.push(shortcut_line("  !cmd", "Run shell command", &t));
     lines.push(shortcut_line("  V", "Visual select", &t));
     lines.push(shortcut_line("  Ctrl+K", "Send selection to AI", &t));
+    lines.push(shortcut_line("  Ctrl+A", "Floating chat overlay", &t));
+    lines.push(shortcut_line("  M", "Bookmark finding/file", &t));
+    lines.push(shortcut_line("  '", "Bookmarks overlay", &t));
+    lines.push(shortcut_line("  N", "Notification center", &t));
     lines.push(Line::raw(""));
     lines.push(Line::from(Span::styled(
         " j/k to scroll, Esc to close",
@@ -94,6 +100,8 @@ fn help_section_for_view(view: ViewState, t: &theme::ThemeColors) -> Vec<Line<'_
             shortcut_line("  D/S/F/P/T/R/L", "Switch view", t),
             shortcut_line("  Tab", "Toggle mode", t),
             shortcut_line("  e", "Zoom/expand widget", t),
+            shortcut_line("  a", "Full activity history (Activity Log zoomed)", t),
+            shortcut_line("  c", "Critical cap drill-down (when capped)", t),
             shortcut_line("  w", "Toggle watch", t),
             shortcut_line("  ^B", "Toggle sidebar", t),
         ],
@@ -101,11 +109,14 @@ fn help_section_for_view(view: ViewState, t: &theme::ThemeColors) -> Vec<Line<'_
             shortcut_line("  a", "Show all findings", t),
             shortcut_line("  c/h/m/l", "Filter by severity", t),
             shortcut_line("  p", "Toggle show passed", t),
+            shortcut_line("  z", "Toggle show snoozed", t),
             shortcut_line("  Enter", "Open/close detail", t),
             shortcut_line("  f", "Apply fix (inline)", t),
             shortcut_line("  x", "Explain finding", t),
+            shortcut_line("  ?", "Check docs", t),
             shortcut_line("  d", "Dismiss finding", t),
             shortcut_line("  o", "Open related file", t),
+            shortcut_line("  v", "Toggle live code pane", t),
             shortcut_line("  n/N", "Next/prev finding (detail)", t),
             shortcut_line("  </>", "Resize split panel", t),
             shortcut_line("  j/k", "Navigate findings", t),
@@ -115,6 +126,8 @@ fn help_section_for_view(view: ViewState, t: &theme::ThemeColors) -> Vec<Line<'_
             shortcut_line("  a", "Select all fixes", t),
             shortcut_line("  n", "Deselect all", t),
             shortcut_line("  d", "Toggle diff preview", t),
+            shortcut_line("  s", "Side-by-side diff", t),
+            shortcut_line("  g", "Generate AI-customized template", t),
             shortcut_line("  </> ", "Resize split panel", t),
             shortcut_line("  Enter", "Apply selected fixes", t),
         ],
@@ -124,6 +137,7 @@ fn help_section_for_view(view: ViewState, t: &theme::ThemeColors) -> Vec<Line<'_
             shortcut_line("  @OBL-xxx", "Reference obligation", t),
             shortcut_line("  !cmd", "Run shell command", t),
             shortcut_line("  Enter", "Send message", t),
+            shortcut_line("  Enter (normal)", "Inspect last tool result", t),
         ],
         ViewState::Passport => vec![
             shortcut_line("  e", "Edit selected field", t),
@@ -141,7 +155,10 @@ fn help_section_for_view(view: ViewState, t: &theme::ThemeColors) -> Vec<Line<'_
         ViewState::Timeline => vec![shortcut_line("  j/k", "Scroll timeline", t)],
         ViewState::Report => vec![
             shortcut_line("  e", "Export report", t),
-            shortcut_line("  j/k", "Scroll report", t),
+            shortcut_line("  c", "Compose report sections", t),
+            shortcut_line("  j/k", "Scroll report / move cursor", t),
+            shortcut_line("  Space", "Toggle section (composer)", t),
+            shortcut_line("  J/K", "Reorder section (composer)", t),
         ],
     }
 }
@@ -284,3 +301,345 @@ pub(super) fn render_dismiss_modal(
 
     frame.render_widget(Paragraph::new(all_lines), inner);
 }
+
+/// Render the per-check documentation browser (`?` on a finding in Scan view).
+pub(super) fn render_check_docs(
+    frame: &mut Frame,
+    docs: &crate::components::check_docs::CheckDocsState,
+) {
+    use ratatui::widgets::Clear;
+
+    let t = theme::theme();
+    let area = centered_rect(70, 70, frame.area());
+
+    frame.render_widget(Clear, area);
+
+    let block = Block::default()
+        .title(format!(" Check Docs: {} ", docs.check_id))
+        .title_style(theme::title_style())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(t.accent));
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            "What it checks",
+            Style::default().fg(t.accent).add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::styled(
+            &docs.what_it_checks,
+            Style::default().fg(t.fg),
+        )),
+        Line::raw(""),
+        Line::from(Span::styled(
+            "Why it matters",
+            Style::default().fg(t.accent).add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::styled(
+            &docs.why_it_matters,
+            Style::default().fg(t.fg),
+        )),
+        Line::raw(""),
+        Line::from(vec![
+            Span::styled("Article: ", Style::default().fg(t.muted)),
+            Span::styled(&docs.article, Style::default().fg(t.fg)),
+        ]),
+        Line::from(vec![
+            Span::styled("Penalty: ", Style::default().fg(t.muted)),
+            Span::styled(&docs.penalty, Style::default().fg(t.fg)),
+        ]),
+        Line::from(vec![
+            Span::styled("Deadline: ", Style::default().fg(t.muted)),
+            Span::styled(&docs.deadline, Style::default().fg(t.fg)),
+        ]),
+        Line::raw(""),
+        Line::from(Span::styled(
+            "Remediation",
+            Style::default().fg(t.accent).add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::styled(&docs.remediation, Style::default().fg(t.fg))),
+    ];
+
+    if !docs.links.is_empty() {
+        lines.push(Line::raw(""));
+        lines.push(Line::from(Span::styled(
+            "Links",
+            Style::default().fg(t.accent).add_modifier(Modifier::BOLD),
+        )));
+        for link in &docs.links {
+            lines.push(Line::from(Span::styled(
+                format!("- {link}"),
+                Style::default().fg(t.fg),
+            )));
+        }
+    }
+
+    lines.push(Line::raw(""));
+    lines.push(Line::from(Span::styled(
+        "j/k:scroll  Esc:close",
+        Style::default().fg(t.muted),
+    )));
+
+    let scroll = docs.scroll.min(lines.len().saturating_sub(1)) as u16;
+    frame.render_widget(Paragraph::new(lines).scroll((scroll, 0)), inner);
+}
+
+/// Render the tool-call approval prompt: shown when the chat agent wants
+/// to run a write/execute tool, pausing the stream until the user decides.
+pub(super) fn render_tool_approval(
+    frame: &mut Frame,
+    pending: &crate::components::tool_approval::PendingToolApproval,
+) {
+    use ratatui::widgets::Clear;
+
+    let t = theme::theme();
+    let area = centered_rect(60, 40, frame.area());
+
+    frame.render_widget(Clear, area);
+
+    let block = Block::default()
+        .title(" Tool Call Approval ")
+        .title_style(
+            Style::default()
+                .fg(t.zone_yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(t.zone_yellow))
+        .style(Style::default().bg(t.bg));
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let lines = vec![
+        Line::raw(""),
+        Line::from(vec![
+            Span::styled("  Tool: ", Style::default().fg(t.muted)),
+            Span::styled(
+                &pending.tool_name,
+                Style::default().fg(t.fg).add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::raw(""),
+        Line::from(Span::styled("  Arguments:", Style::default().fg(t.muted))),
+        Line::from(Span::styled(
+            format!("  {}", pending.args),
+            Style::default().fg(t.fg),
+        )),
+        Line::raw(""),
+        Line::from(Span::styled(
+            "  The agent wants to run this write/execute tool.",
+            Style::default().fg(t.fg),
+        )),
+    ];
+
+    frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), inner);
+
+    let footer_area = Rect {
+        x: area.x,
+        y: area.y + area.height.saturating_sub(1),
+        width: area.width,
+        height: 1,
+    };
+    let footer = Paragraph::new(Line::from(vec![
+        Span::styled("  [y]", Style::default().fg(t.zone_green)),
+        Span::styled(" Approve  ", Style::default().fg(t.muted)),
+        Span::styled("[a]", Style::default().fg(t.accent)),
+        Span::styled(" Always allow  ", Style::default().fg(t.muted)),
+        Span::styled("[n]", Style::default().fg(t.zone_red)),
+        Span::styled(" Deny", Style::default().fg(t.muted)),
+    ]));
+    frame.render_widget(footer, footer_area);
+}
+
+/// Full-screen inspector for a tool call/result pair (`Enter` on the
+/// focused block in Chat view) -- shows the untruncated args/result the
+/// inline preview clips.
+pub(super) fn render_tool_result_inspector(frame: &mut Frame, app: &App) {
+    use crate::types::ChatBlock;
+    use ratatui::widgets::Clear;
+
+    let t = theme::theme();
+    let area = centered_rect(80, 80, frame.area());
+
+    frame.render_widget(Clear, area);
+
+    let Some((mi, bi)) = app.chat_tool_focus else {
+        return;
+    };
+    let Some(blocks) = app.messages.get(mi).map(|m| &m.blocks) else {
+        return;
+    };
+
+    let (tool_name, args, result) = match blocks.get(bi) {
+        Some(ChatBlock::ToolResult {
+            tool_name, result, ..
+        }) => {
+            let args = blocks[..bi].iter().rev().find_map(|b| match b {
+                ChatBlock::ToolCall { tool_name: n, args } if n == tool_name => Some(args.clone()),
+                _ => None,
+            });
+            (tool_name.clone(), args, Some(result.clone()))
+        }
+        Some(ChatBlock::ToolCall { tool_name, args }) => {
+            (tool_name.clone(), Some(args.clone()), None)
+        }
+        _ => return,
+    };
+
+    let block = Block::default()
+        .title(format!(" Tool Result: {tool_name} "))
+        .title_style(theme::title_style())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(t.accent));
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let mut lines: Vec<Line<'_>> = Vec::new();
+    if let Some(args) = &args {
+        lines.push(Line::from(Span::styled(
+            "Arguments",
+            Style::default().fg(t.accent).add_modifier(Modifier::BOLD),
+        )));
+        lines.extend(
+            args.lines()
+                .map(|l| Line::from(Span::styled(l.to_string(), Style::default().fg(t.fg)))),
+        );
+        lines.push(Line::raw(""));
+    }
+    lines.push(Line::from(Span::styled(
+        "Result",
+        Style::default().fg(t.accent).add_modifier(Modifier::BOLD),
+    )));
+    match &result {
+        Some(result) => lines.extend(
+            result
+                .lines()
+                .map(|l| Line::from(Span::styled(l.to_string(), Style::default().fg(t.fg)))),
+        ),
+        None => lines.push(Line::from(Span::styled(
+            "(no result yet)",
+            Style::default().fg(t.muted),
+        ))),
+    }
+    lines.push(Line::raw(""));
+    lines.push(Line::from(Span::styled(
+        " j/k to scroll, Esc to close",
+        Style::default().fg(t.muted),
+    )));
+
+    let scroll = app.tool_inspector_scroll.min(lines.len().saturating_sub(1));
+    let paragraph = Paragraph::new(lines)
+        .wrap(Wrap { trim: false })
+        .scroll((u16::try_from(scroll).unwrap_or(u16::MAX), 0));
+    frame.render_widget(paragraph, inner);
+}
+
+/// Render the confirmation prompt shown before a large bracketed paste is
+/// fenced and inserted into the chat input.
+pub(super) fn render_paste_confirm(frame: &mut Frame, paste: &crate::app::commands::PendingPaste) {
+    use ratatui::widgets::Clear;
+
+    let t = theme::theme();
+    let area = centered_rect(50, 20, frame.area());
+
+    frame.render_widget(Clear, area);
+
+    let block = Block::default()
+        .title(" Paste ")
+        .title_style(theme::title_style())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(t.accent));
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let lines = vec![
+        Line::raw(""),
+        Line::from(Span::styled(
+            format!(
+                "  Insert pasted text ({} lines) as a code block?",
+                paste.line_count
+            ),
+            Style::default().fg(t.fg).add_modifier(Modifier::BOLD),
+        )),
+        Line::raw(""),
+        Line::from(vec![
+            Span::styled("  [y]", Style::default().fg(t.accent)),
+            Span::styled(" Confirm  ", Style::default().fg(t.muted)),
+            Span::styled("[N]", Style::default().fg(t.accent)),
+            Span::styled(" Cancel (default)", Style::default().fg(t.muted)),
+        ]),
+    ];
+
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+/// Render the Dashboard "arrange" overlay -- lets the user toggle which
+/// widgets appear in the configurable grid (`dashboard_grid_mode`) and
+/// reorder the visible ones.
+pub(super) fn render_arrange_dashboard_overlay(frame: &mut Frame, app: &App) {
+    use ratatui::widgets::Clear;
+
+    let t = theme::theme();
+    let area = centered_rect(50, 50, frame.area());
+
+    frame.render_widget(Clear, area);
+
+    let block = Block::default()
+        .title(" Arrange Dashboard Widgets ")
+        .title_style(theme::title_style())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(t.accent));
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let order = app.arrange_dashboard_display_order();
+
+    let mut lines: Vec<Line<'_>> = vec![
+        Line::from(Span::styled(
+            " Visible widgets are shown in the grid, in this order.",
+            Style::default().fg(t.fg),
+        )),
+        Line::raw(""),
+    ];
+
+    for (i, widget) in order.iter().enumerate() {
+        let is_selected = i == app.arrange_dashboard_cursor;
+        let visible = app.config.dashboard_layout.contains(widget);
+        let marker = if is_selected { ">" } else { " " };
+        let checkbox = if visible { "[x]" } else { "[ ]" };
+        let color = if is_selected { t.accent } else { t.fg };
+        lines.push(Line::from(Span::styled(
+            format!("{marker} {checkbox} {}", widget.label()),
+            Style::default().fg(color).add_modifier(if is_selected {
+                Modifier::BOLD
+            } else {
+                Modifier::empty()
+            }),
+        )));
+    }
+
+    lines.push(Line::raw(""));
+    lines.push(Line::from(Span::styled(
+        " j/k:move  Space:toggle  </>: reorder  Esc:close",
+        Style::default().fg(t.muted),
+    )));
+
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+/// Render the chat as a floating overlay (Ctrl+A) -- reuses the full Chat
+/// view's rendering so users get history, streaming, and an input box
+/// without switching `view_state` away from whatever they were looking at.
+pub(super) fn render_floating_chat_overlay(frame: &mut Frame, app: &App) {
+    use ratatui::widgets::Clear;
+
+    let area = centered_rect(70, 70, frame.area());
+    frame.render_widget(Clear, area);
+    crate::views::chat::render_chat_view(frame, area, app);
+}
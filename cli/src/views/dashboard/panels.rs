@@ -1,5 +1,5 @@
 use ratatui::Frame;
-use ratatui::layout::Rect;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Paragraph};
@@ -8,8 +8,8 @@ use crate::app::App;
 use crate::theme;
 
 use super::utils::{
-    current_epoch_days, deadline_label, derive_categories_from_findings, parse_epoch_days,
-    score_zone_info,
+    category_score_bar, current_epoch_days, deadline_label, derive_categories_from_findings,
+    parse_epoch_days, score_zone_info, ymd_parts,
 };
 
 /// Right-side info panel with project info, deadlines, quick actions, and sync status.
@@ -428,6 +428,68 @@ pub(super) fn render_score_gauge(frame: &mut Frame, area: Rect, app: &App) {
     frame.render_widget(gauge, area);
 }
 
+/// Zoomed score gauge (`e` on the Score Gauge widget): the aggregate gauge
+/// plus a per-category bar breakdown filling the rest of the screen —
+/// `category_scores` is parsed from the engine but too cramped to show in
+/// the 3-line grid cell, so the full-screen zoom is where it belongs.
+pub(super) fn render_score_gauge_with_categories(frame: &mut Frame, area: Rect, app: &App) {
+    let t = theme::theme();
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(0)])
+        .split(area);
+
+    render_score_gauge(frame, chunks[0], app);
+
+    let block = Block::default()
+        .title(" By Category ")
+        .title_style(theme::title_style())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(t.border));
+    let inner = block.inner(chunks[1]);
+    frame.render_widget(block, chunks[1]);
+
+    let Some(scan) = &app.last_scan else {
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                " Run /scan to see category scores",
+                Style::default().fg(t.muted),
+            ))),
+            inner,
+        );
+        return;
+    };
+
+    if scan.score.category_scores.is_empty() {
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                " Engine did not return category scores for this scan",
+                Style::default().fg(t.muted),
+            ))),
+            inner,
+        );
+        return;
+    }
+
+    let bar_width = 30usize;
+    let name_w = inner.width.saturating_sub(2 + bar_width as u16 + 6) as usize;
+    let lines: Vec<Line<'_>> = scan
+        .score
+        .category_scores
+        .iter()
+        .map(|cat| {
+            let (bar, color) = category_score_bar(cat.score, bar_width, &t);
+            let name = crate::views::truncate_str(&cat.category, name_w);
+            Line::from(vec![
+                Span::styled(format!(" {name:<name_w$} "), Style::default().fg(t.fg)),
+                Span::styled(bar, Style::default().fg(color)),
+                Span::styled(format!(" {:>3.0}", cat.score), Style::default().fg(color)),
+            ])
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
 /// Deadline countdown widget -- computes days from now, colors by urgency.
 pub(super) fn render_deadline_countdown(frame: &mut Frame, area: Rect) {
     let t = theme::theme();
@@ -455,9 +517,16 @@ pub(super) fn render_deadline_countdown(frame: &mut Frame, area: Rect) {
             let deadline_days = parse_epoch_days(date_str);
             let diff = deadline_days - now;
             let (label, color) = deadline_label(diff, &t);
+            let formatted_date = ymd_parts(date_str)
+                .map(|(y, m, d)| crate::locale::format_date(y, m, d))
+                .unwrap_or_default();
             Line::from(vec![
                 Span::styled(format!(" {label:<14}"), Style::default().fg(color)),
                 Span::styled(*desc, Style::default().fg(t.fg)),
+                Span::styled(
+                    format!("  ({formatted_date})"),
+                    Style::default().fg(t.muted),
+                ),
             ])
         })
         .collect();
@@ -654,11 +723,21 @@ pub(super) fn render_detail_panel(frame: &mut Frame, area: Rect, app: &App) {
                 format!(" Failed: {}", scan.score.failed_checks),
                 Style::default().fg(t.zone_red),
             )),
-            Line::from(Span::styled(
-                format!(" Categories: {}", scan.score.category_scores.len()),
-                Style::default().fg(t.fg),
-            )),
         ];
+        if scan.score.category_scores.is_empty() {
+            l.push(Line::from(Span::styled(" Categories: 0", Style::default().fg(t.fg))));
+        } else {
+            let name_w = inner.width.saturating_sub(2 + 10 + 5) as usize;
+            for cat in &scan.score.category_scores {
+                let (bar, color) = category_score_bar(cat.score, 10, &t);
+                let name = crate::views::truncate_str(&cat.category, name_w);
+                l.push(Line::from(vec![
+                    Span::styled(format!(" {name:<name_w$} "), Style::default().fg(t.fg)),
+                    Span::styled(bar, Style::default().fg(color)),
+                    Span::styled(format!(" {:>3.0}", cat.score), Style::default().fg(color)),
+                ]));
+            }
+        }
         if scan.score.critical_cap_applied {
             l.push(Line::from(Span::styled(
                 " Critical cap applied",
@@ -674,3 +753,233 @@ pub(super) fn render_detail_panel(frame: &mut Frame, area: Rect, app: &App) {
     };
     frame.render_widget(Paragraph::new(lines), inner);
 }
+
+/// Grid widget: findings counts by severity for the last scan.
+pub(super) fn render_findings_summary_widget(frame: &mut Frame, area: Rect, app: &App) {
+    use crate::types::Severity;
+
+    let t = theme::theme();
+    let block = Block::default()
+        .title(" Findings Summary ")
+        .title_style(theme::title_style())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(t.border));
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let Some(scan) = &app.last_scan else {
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                " Run a scan to see findings",
+                Style::default().fg(t.muted),
+            ))),
+            inner,
+        );
+        return;
+    };
+
+    let count = |sev: Severity| scan.findings.iter().filter(|f| f.severity == sev).count();
+    let lines = vec![
+        Line::from(Span::styled(
+            format!(" Critical: {}", count(Severity::Critical)),
+            Style::default().fg(theme::severity_color(Severity::Critical)),
+        )),
+        Line::from(Span::styled(
+            format!(" High:     {}", count(Severity::High)),
+            Style::default().fg(theme::severity_color(Severity::High)),
+        )),
+        Line::from(Span::styled(
+            format!(" Medium:   {}", count(Severity::Medium)),
+            Style::default().fg(theme::severity_color(Severity::Medium)),
+        )),
+        Line::from(Span::styled(
+            format!(
+                " Low/Info: {}",
+                count(Severity::Low) + count(Severity::Info)
+            ),
+            Style::default().fg(theme::severity_color(Severity::Low)),
+        )),
+    ];
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+/// Grid widget: most recent watch-mode file-change events.
+pub(super) fn render_watch_feed_widget(frame: &mut Frame, area: Rect, app: &App) {
+    let t = theme::theme();
+    let block = Block::default()
+        .title(" Watch Feed ")
+        .title_style(theme::title_style())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(t.border));
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    if app.changes.entries.is_empty() {
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                if app.watch_active {
+                    " Watching \u{2014} no changes yet"
+                } else {
+                    " Press w to start watch mode"
+                },
+                Style::default().fg(t.muted),
+            ))),
+            inner,
+        );
+        return;
+    }
+
+    let lines: Vec<Line<'_>> = app
+        .changes
+        .entries
+        .iter()
+        .take(inner.height as usize)
+        .map(|entry| {
+            Line::from(Span::styled(
+                format!(
+                    " {} {} {}",
+                    entry.timestamp,
+                    entry.kind.label(),
+                    entry.path.display()
+                ),
+                Style::default().fg(t.fg),
+            ))
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+/// One top-level directory's finding counts by severity, for the heatmap
+/// widget below.
+struct DirHeat {
+    dir: String,
+    critical: usize,
+    high: usize,
+    medium: usize,
+    low: usize,
+}
+
+impl DirHeat {
+    const fn total(&self) -> usize {
+        self.critical + self.high + self.medium + self.low
+    }
+
+    /// Highest severity present, used to color the heatmap bar.
+    fn dominant_severity(&self) -> crate::types::Severity {
+        use crate::types::Severity;
+        if self.critical > 0 {
+            Severity::Critical
+        } else if self.high > 0 {
+            Severity::High
+        } else if self.medium > 0 {
+            Severity::Medium
+        } else {
+            Severity::Low
+        }
+    }
+}
+
+/// Group `findings` by the top-level directory of `Finding.file` (e.g.
+/// `engine/core/src/x.ts` -> `engine`), sorted by total finding count
+/// descending. Findings with no file, or a file with no directory
+/// component, land in `(root)`.
+fn group_by_top_level_dir(findings: &[crate::types::Finding]) -> Vec<DirHeat> {
+    use crate::types::Severity;
+    use std::collections::BTreeMap;
+
+    let mut by_dir: BTreeMap<String, DirHeat> = BTreeMap::new();
+    for f in findings {
+        let dir = f
+            .file
+            .as_deref()
+            .and_then(|path| path.split('/').next().filter(|s| !s.is_empty()))
+            .unwrap_or("(root)")
+            .to_string();
+        let entry = by_dir.entry(dir.clone()).or_insert_with(|| DirHeat {
+            dir,
+            critical: 0,
+            high: 0,
+            medium: 0,
+            low: 0,
+        });
+        match f.severity {
+            Severity::Critical => entry.critical += 1,
+            Severity::High => entry.high += 1,
+            Severity::Medium => entry.medium += 1,
+            Severity::Low | Severity::Info => entry.low += 1,
+        }
+    }
+
+    let mut dirs: Vec<DirHeat> = by_dir.into_values().collect();
+    dirs.sort_by(|a, b| b.total().cmp(&a.total()));
+    dirs
+}
+
+/// Grid widget: findings aggregated per top-level directory as a colored
+/// bar heatmap, so a large project can see at a glance which subsystems
+/// carry the compliance debt.
+pub(super) fn render_directory_heatmap(frame: &mut Frame, area: Rect, app: &App) {
+    let t = theme::theme();
+    let block = Block::default()
+        .title(" Severity Heatmap ")
+        .title_style(theme::title_style())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(t.border));
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let Some(scan) = &app.last_scan else {
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                " Run a scan to see the heatmap",
+                Style::default().fg(t.muted),
+            ))),
+            inner,
+        );
+        return;
+    };
+
+    let dirs = group_by_top_level_dir(&scan.findings);
+    if dirs.is_empty() {
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                " No findings \u{2014} nothing to show",
+                Style::default().fg(t.zone_green),
+            ))),
+            inner,
+        );
+        return;
+    }
+
+    let name_width = dirs.iter().map(|d| d.dir.len()).max().unwrap_or(8).min(20);
+    let max_total = dirs.iter().map(DirHeat::total).max().unwrap_or(1).max(1);
+    let bar_width = (inner.width as usize)
+        .saturating_sub(name_width + 8)
+        .clamp(4, 30);
+
+    let lines: Vec<Line<'_>> = dirs
+        .iter()
+        .take(inner.height as usize)
+        .map(|d| {
+            let filled = (d.total() * bar_width) / max_total;
+            let color = theme::severity_color(d.dominant_severity());
+            Line::from(vec![
+                Span::styled(
+                    format!(" {:<name_width$} ", d.dir),
+                    Style::default().fg(t.fg),
+                ),
+                Span::styled("\u{2588}".repeat(filled.max(1)), Style::default().fg(color)),
+                Span::styled(
+                    "\u{2591}".repeat(bar_width.saturating_sub(filled.max(1))),
+                    Style::default().fg(t.muted),
+                ),
+                Span::styled(format!(" {}", d.total()), Style::default().fg(t.muted)),
+            ])
+        })
+        .collect();
+
+    frame.render_widget(Paragraph::new(lines), inner);
+}
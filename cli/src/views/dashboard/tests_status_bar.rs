@@ -119,3 +119,29 @@ fn e2e_t504_status_bar_engine_indicator() {
         "Error engine should show cross mark"
     );
 }
+
+#[test]
+fn e2e_t504_status_bar_shows_offline_indicator() {
+    crate::theme::init_theme("dark");
+    let mut config = crate::config::TuiConfig::default();
+    config.offline_mode = true;
+    let app = App::new(config);
+
+    let buf = render_to_string(&app, 120, 40);
+    assert!(
+        buf.contains("[OFFLINE]"),
+        "Status bar should show [OFFLINE] when offline_mode is set"
+    );
+}
+
+#[test]
+fn e2e_t504_status_bar_hides_offline_indicator_by_default() {
+    crate::theme::init_theme("dark");
+    let app = App::new(crate::config::TuiConfig::default());
+
+    let buf = render_to_string(&app, 120, 40);
+    assert!(
+        !buf.contains("[OFFLINE]"),
+        "Status bar should not show [OFFLINE] when offline_mode is unset"
+    );
+}
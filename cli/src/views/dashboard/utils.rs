@@ -5,34 +5,19 @@ use crate::types::Finding;
 // Date helpers for deadline countdown
 // =========================================================================
 
-/// Approximate current epoch days from system time.
+/// Current epoch days from system time.
 pub(super) fn current_epoch_days() -> i64 {
-    let secs = std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_secs();
-    (secs / 86400) as i64
+    crate::date::today_epoch_days()
 }
 
-/// Parse "YYYY-MM-DD" into approximate epoch days.
+/// Parse "YYYY-MM-DD" into epoch days, `0` if it isn't a real calendar date.
 pub(super) fn parse_epoch_days(date: &str) -> i64 {
-    let parts: Vec<&str> = date.split('-').collect();
-    if parts.len() != 3 {
-        return 0;
-    }
-    let y: i64 = parts[0].parse().unwrap_or(2025);
-    let m: i64 = parts[1].parse().unwrap_or(1);
-    let d: i64 = parts[2].parse().unwrap_or(1);
-    // Approximate: 365.25 * year + 30.44 * month + day from epoch
-    // More accurate: days from 1970-01-01
+    crate::date::parse_ymd_epoch_days(date).unwrap_or(0)
+}
 
-    (y - 1970) * 365 + (y - 1969) / 4 - (y - 1901) / 100
-        + (y - 1601) / 400
-        + (m - 1) * 30
-        + (m + 1) / 2
-        - if m > 2 { 2 } else { 0 }
-        + d
-        - 1
+/// Parse "YYYY-MM-DD" into `(year, month, day)`, for locale-aware display.
+pub(super) fn ymd_parts(date: &str) -> Option<(i64, u8, u8)> {
+    crate::date::parse_ymd_parts(date)
 }
 
 /// Format deadline diff into human-readable label with urgency color.
@@ -63,6 +48,23 @@ pub fn score_zone_info(
     (color, label)
 }
 
+/// Render a `score` (0-100) as a `width`-cell unicode bar, colored by zone —
+/// shared by the detail panel and the zoomed score gauge's per-category
+/// breakdown.
+pub fn category_score_bar(
+    score: f64,
+    width: usize,
+    t: &theme::ThemeColors,
+) -> (String, ratatui::style::Color) {
+    let filled = ((score / 100.0).clamp(0.0, 1.0) * width as f64).round() as usize;
+    let bar = format!(
+        "{}{}",
+        "\u{2588}".repeat(filled),
+        "\u{2591}".repeat(width.saturating_sub(filled))
+    );
+    (bar, crate::views::score_zone_color(score, t))
+}
+
 /// Derive category breakdown from findings when engine doesn't provide `category_scores`.
 ///
 /// Maps obligation IDs / article references to 5 high-level categories:
@@ -75,27 +77,17 @@ pub(super) fn derive_categories_from_findings(findings: &[Finding]) -> Vec<(&'st
     let mut technical = 0u32;
 
     for f in findings {
-        let art = f.article_reference.as_deref().unwrap_or("");
-        let obl = f.obligation_id.as_deref().unwrap_or("");
-        if art.contains("Art. 5") || obl.contains("prohibited") {
-            prohibited += 1;
-        } else if art.contains("Art. 9") || art.contains("Art. 27") || obl.contains("risk") {
-            risk_mgmt += 1;
-        } else if art.contains("Art. 11")
-            || art.contains("Art. 12")
-            || art.contains("Art. 18")
-            || obl.contains("doc")
-            || f.finding_type() == crate::types::FindingType::B
-        {
-            documentation += 1;
-        } else if art.contains("Art. 50")
-            || art.contains("Art. 13")
-            || art.contains("Art. 52")
-            || obl.contains("transp")
-        {
-            transparency += 1;
-        } else {
-            technical += 1;
+        let category = crate::views::classify_finding_category(
+            f.article_reference.as_deref(),
+            f.obligation_id.as_deref(),
+            f.finding_type() == crate::types::FindingType::B,
+        );
+        match category {
+            "prohibited" => prohibited += 1,
+            "risk_mgmt" => risk_mgmt += 1,
+            "documentation" => documentation += 1,
+            "transparency" => transparency += 1,
+            _ => technical += 1,
         }
     }
 
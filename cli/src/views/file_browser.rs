@@ -6,6 +6,32 @@ pub fn build_file_tree(root: &std::path::Path) -> Vec<FileEntry> {
     entries
 }
 
+/// Build a tree spanning multiple filesystem roots (`TuiConfig::watch_roots`),
+/// each shown as its own top-level, pre-expanded node — for a project split
+/// across sibling directories with no common ancestor. Falls back to
+/// [`build_file_tree`]'s flat, root-less layout when only one root is given,
+/// so the common single-root case is unaffected.
+pub fn build_file_tree_multi(roots: &[std::path::PathBuf]) -> Vec<FileEntry> {
+    let [root] = roots else {
+        let mut entries = Vec::new();
+        for root in roots {
+            let name = root
+                .file_name()
+                .map_or_else(|| root.display().to_string(), |n| n.to_string_lossy().to_string());
+            entries.push(FileEntry {
+                path: root.clone(),
+                name,
+                is_dir: true,
+                depth: 0,
+                expanded: true,
+            });
+            collect_entries(root, 1, &mut entries);
+        }
+        return entries;
+    };
+    build_file_tree(root)
+}
+
 fn collect_entries(dir: &std::path::Path, depth: usize, entries: &mut Vec<FileEntry>) {
     let Ok(read_dir) = std::fs::read_dir(dir) else {
         return;
@@ -49,6 +75,41 @@ fn collect_entries(dir: &std::path::Path, depth: usize, entries: &mut Vec<FileEn
     }
 }
 
+/// Indices into `tree` whose name matches `filter` (case-insensitive
+/// substring), for the browser panel's inline filter (`f` while it's
+/// focused) — independent of the fuzzy `@`-mention matcher in
+/// [`crate::components::file_picker`], which searches files only and feeds
+/// a different overlay. An empty filter matches everything.
+pub fn filter_indices(tree: &[FileEntry], filter: &str) -> Vec<usize> {
+    if filter.is_empty() {
+        return (0..tree.len()).collect();
+    }
+    let needle = filter.to_lowercase();
+    tree.iter()
+        .enumerate()
+        .filter(|(_, entry)| entry.name.to_lowercase().contains(&needle))
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// "Flatten matches" mode: matching entries as a single depth-0 list,
+/// dropping the tree's directory nesting — lets a filter like "config"
+/// surface every `config.rs` in a deep monorepo without expanding each
+/// intermediate directory by hand.
+pub fn flatten_matches(tree: &[FileEntry], filter: &str) -> Vec<FileEntry> {
+    filter_indices(tree, filter)
+        .into_iter()
+        .filter_map(|i| tree.get(i))
+        .map(|entry| FileEntry {
+            path: entry.path.clone(),
+            name: entry.name.clone(),
+            is_dir: entry.is_dir,
+            depth: 0,
+            expanded: false,
+        })
+        .collect()
+}
+
 pub fn toggle_expand(tree: &mut Vec<FileEntry>, index: usize) {
     let Some(entry) = tree.get(index).cloned() else {
         return;
@@ -82,3 +143,55 @@ pub fn toggle_expand(tree: &mut Vec<FileEntry>, index: usize) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(name: &str, is_dir: bool, depth: usize) -> FileEntry {
+        FileEntry {
+            path: std::path::PathBuf::from(name),
+            name: name.to_string(),
+            is_dir,
+            depth,
+            expanded: false,
+        }
+    }
+
+    #[test]
+    fn test_filter_indices_empty_matches_all() {
+        let tree = vec![entry("src", true, 0), entry("main.rs", false, 1)];
+        assert_eq!(filter_indices(&tree, ""), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_filter_indices_case_insensitive_substring() {
+        let tree = vec![
+            entry("Config.rs", false, 0),
+            entry("main.rs", false, 0),
+            entry("config_loader.rs", false, 0),
+        ];
+        assert_eq!(filter_indices(&tree, "config"), vec![0, 2]);
+    }
+
+    #[test]
+    fn test_filter_indices_no_match() {
+        let tree = vec![entry("main.rs", false, 0)];
+        assert!(filter_indices(&tree, "nonexistent").is_empty());
+    }
+
+    #[test]
+    fn test_flatten_matches_drops_depth_and_expansion() {
+        let tree = vec![
+            entry("src", true, 0),
+            entry("config.rs", false, 1),
+            entry("deep", true, 1),
+            entry("config_deep.rs", false, 2),
+        ];
+        let flat = flatten_matches(&tree, "config");
+        assert_eq!(flat.len(), 2);
+        assert!(flat.iter().all(|e| e.depth == 0 && !e.expanded));
+        assert_eq!(flat[0].name, "config.rs");
+        assert_eq!(flat[1].name, "config_deep.rs");
+    }
+}
@@ -8,57 +8,48 @@ pub struct ApplyResult {
     pub detail: String,
 }
 
-/// Apply a single finding's fix to the filesystem.
+/// A computed fix, ready to write — split out from [`apply_fix_to_file`] so
+/// callers can journal the before/after content ([`crate::fix_journal`])
+/// before touching disk.
+#[derive(Debug, Clone)]
+pub struct FixPlan {
+    /// Project-relative path.
+    pub file_path: String,
+    /// `None` if the fix creates a new file (Type B).
+    pub before_content: Option<String>,
+    pub after_content: String,
+}
+
+/// Compute the file write a finding's fix would perform, without touching
+/// disk. `Err` carries the same human-readable detail `apply_fix_to_file`
+/// would otherwise have returned as a failed [`ApplyResult`].
 ///
-/// - Type A/C with `fix_diff`: replaces lines in existing file, adds import if needed.
-/// - Type B (missing doc): creates the file with proposed content.
-/// - Fallback: returns error if no structured fix data available.
-pub fn apply_fix_to_file(project_path: &Path, finding: &Finding) -> ApplyResult {
+/// `template_override` supplies LLM-customized content for a Type B finding
+/// (see `FixViewState::template_override`, generated on demand with `g`),
+/// taking precedence over the finding's default `fix` text when present.
+pub fn plan_fix(
+    project_path: &Path,
+    finding: &Finding,
+    template_override: Option<&str>,
+) -> Result<FixPlan, String> {
     let check_id = finding.check_id.clone();
     let ft = finding.finding_type();
 
-    // Type B: create new document
     if ft == FindingType::B && finding.file.is_none() {
         let rel = infer_doc_path(&check_id);
         let abs = project_path.join(&rel);
         if abs.exists() {
-            return ApplyResult {
-                success: false,
-                detail: format!("{rel} already exists"),
-            };
-        }
-        // Ensure parent dir exists
-        if let Some(parent) = abs.parent()
-            && let Err(e) = std::fs::create_dir_all(parent)
-        {
-            return ApplyResult {
-                success: false,
-                detail: format!("mkdir failed: {e}"),
-            };
-        }
-        let content = finding.fix.as_deref().unwrap_or("");
-        match std::fs::write(&abs, content) {
-            Ok(()) => ApplyResult {
-                success: true,
-                detail: format!("Created {rel}"),
-            },
-            Err(e) => ApplyResult {
-                success: false,
-                detail: format!("write failed: {e}"),
-            },
+            return Err(format!("{rel} already exists"));
         }
+        let content = template_override.or(finding.fix.as_deref()).unwrap_or("");
+        Ok(FixPlan {
+            file_path: rel,
+            before_content: None,
+            after_content: content.to_string(),
+        })
     } else if let Some(diff) = &finding.fix_diff {
-        // Type A/C: apply structured diff
         let abs = project_path.join(&diff.file_path);
-        let content = match std::fs::read_to_string(&abs) {
-            Ok(c) => c,
-            Err(e) => {
-                return ApplyResult {
-                    success: false,
-                    detail: format!("read failed: {e}"),
-                };
-            }
-        };
+        let content = std::fs::read_to_string(&abs).map_err(|e| format!("read failed: {e}"))?;
 
         let mut lines: Vec<String> = content.lines().map(String::from).collect();
         let start = (diff.start_line as usize).saturating_sub(1);
@@ -66,18 +57,12 @@ pub fn apply_fix_to_file(project_path: &Path, finding: &Finding) -> ApplyResult
 
         // Validate that before-lines match the file content
         if end > lines.len() {
-            return ApplyResult {
-                success: false,
-                detail: "Line range out of bounds".to_string(),
-            };
+            return Err("Line range out of bounds".to_string());
         }
         let file_slice: Vec<&str> = lines[start..end].iter().map(|s| s.trim()).collect();
         let expected: Vec<&str> = diff.before.iter().map(|s| s.trim()).collect();
         if file_slice != expected {
-            return ApplyResult {
-                success: false,
-                detail: "File content changed since scan — re-scan first".to_string(),
-            };
+            return Err("File content changed since scan — re-scan first".to_string());
         }
 
         // Replace lines
@@ -85,42 +70,82 @@ pub fn apply_fix_to_file(project_path: &Path, finding: &Finding) -> ApplyResult
         lines.splice(start..end, after);
 
         // Add import line if needed
-        if let Some(import) = &diff.import_line {
-            // Check it's not already present
-            if !lines.iter().any(|l| l.contains(import.as_str())) {
-                // Insert after the last existing import
-                let insert_at = lines
-                    .iter()
-                    .rposition(|l| l.starts_with("import "))
-                    .map_or(0, |i| i + 1);
-                lines.insert(insert_at, import.clone());
-            }
+        if let Some(import) = &diff.import_line
+            && !lines.iter().any(|l| l.contains(import.as_str()))
+        {
+            // Insert after the last existing import
+            let insert_at = lines
+                .iter()
+                .rposition(|l| l.starts_with("import "))
+                .map_or(0, |i| i + 1);
+            lines.insert(insert_at, import.clone());
         }
 
         // Write back
         let output = lines.join("\n");
         // Preserve trailing newline if original had one
-        let final_output = if content.ends_with('\n') && !output.ends_with('\n') {
+        let after_content = if content.ends_with('\n') && !output.ends_with('\n') {
             output + "\n"
         } else {
             output
         };
 
-        match std::fs::write(&abs, final_output) {
-            Ok(()) => ApplyResult {
-                success: true,
-                detail: format!("Modified {}", diff.file_path),
-            },
-            Err(e) => ApplyResult {
-                success: false,
-                detail: format!("write failed: {e}"),
-            },
-        }
+        Ok(FixPlan {
+            file_path: diff.file_path.clone(),
+            before_content: Some(content),
+            after_content,
+        })
     } else {
-        ApplyResult {
+        Err("No structured fix available — manual action required".to_string())
+    }
+}
+
+/// Write a computed [`FixPlan`] to disk.
+pub fn write_plan(project_path: &Path, plan: &FixPlan) -> ApplyResult {
+    let abs = project_path.join(&plan.file_path);
+    if plan.before_content.is_none()
+        && let Some(parent) = abs.parent()
+        && let Err(e) = std::fs::create_dir_all(parent)
+    {
+        return ApplyResult {
             success: false,
-            detail: "No structured fix available — manual action required".to_string(),
-        }
+            detail: format!("mkdir failed: {e}"),
+        };
+    }
+    match std::fs::write(&abs, &plan.after_content) {
+        Ok(()) => ApplyResult {
+            success: true,
+            detail: if plan.before_content.is_none() {
+                format!("Created {}", plan.file_path)
+            } else {
+                format!("Modified {}", plan.file_path)
+            },
+        },
+        Err(e) => ApplyResult {
+            success: false,
+            detail: format!("write failed: {e}"),
+        },
+    }
+}
+
+/// Apply a single finding's fix to the filesystem.
+///
+/// - Type A/C with `fix_diff`: replaces lines in existing file, adds import if needed.
+/// - Type B (missing doc): creates the file with proposed content.
+/// - Fallback: returns error if no structured fix data available.
+///
+/// Journals nothing on its own — batch application journals every plan up
+/// front via [`crate::fix_journal`] before calling [`write_plan`] for each.
+/// This standalone entry point (used by tests and any single-fix caller)
+/// just plans and writes without a journal, since there's no batch to
+/// recover if it's interrupted.
+pub fn apply_fix_to_file(project_path: &Path, finding: &Finding) -> ApplyResult {
+    match plan_fix(project_path, finding, None) {
+        Ok(plan) => write_plan(project_path, &plan),
+        Err(detail) => ApplyResult {
+            success: false,
+            detail,
+        },
     }
 }
 
@@ -3,7 +3,9 @@ use super::apply::infer_doc_path;
 use crate::app::App;
 use crate::theme;
 use crate::types::Finding;
-use crate::views::scan::{render_code_block, render_fix_diff, render_fix_text};
+use crate::views::scan::{
+    render_code_block, render_fix_diff, render_fix_diff_side_by_side, render_fix_text,
+};
 use ratatui::Frame;
 use ratatui::layout::Rect;
 use ratatui::style::{Modifier, Style};
@@ -155,7 +157,11 @@ pub(super) fn render_diff_preview(frame: &mut Frame, area: Rect, app: &App) {
                 " -- Suggested Fix ──────────",
                 Style::default().fg(t.accent).add_modifier(Modifier::BOLD),
             )));
-            render_fix_diff(&mut lines, diff, &t);
+            if fix.diff_side_by_side {
+                render_fix_diff_side_by_side(&mut lines, diff, w, &t);
+            } else {
+                render_fix_diff(&mut lines, diff, &t);
+            }
         } else if let Some(fix_text) = &finding.fix {
             render_fix_text(&mut lines, fix_text, finding.finding_type(), &t);
         }
@@ -208,13 +214,13 @@ pub(super) fn render_diff_preview_single(frame: &mut Frame, area: Rect, app: &Ap
 
     match item.finding_type {
         crate::types::FindingType::A => {
-            render_type_a(frame, area, item, finding, w, &t);
+            render_type_a(frame, area, item, finding, w, &t, fix.diff_side_by_side);
         }
         crate::types::FindingType::B => {
             render_type_b(frame, area, item, finding, w, &t);
         }
         crate::types::FindingType::C => {
-            render_type_c(frame, area, item, finding, w, &t);
+            render_type_c(frame, area, item, finding, w, &t, fix.diff_side_by_side);
         }
     }
 }
@@ -227,6 +233,7 @@ fn render_type_a(
     finding: &Finding,
     w: usize,
     t: &theme::ThemeColors,
+    side_by_side: bool,
 ) {
     let file_path = item.file_path.as_deref().unwrap_or("unknown");
     let block = Block::default()
@@ -298,7 +305,11 @@ fn render_type_a(
                 Style::default().fg(t.accent),
             ),
         ]));
-        render_fix_diff(&mut lines, diff, t);
+        if side_by_side {
+            render_fix_diff_side_by_side(&mut lines, diff, w, t);
+        } else {
+            render_fix_diff(&mut lines, diff, t);
+        }
 
         // Import addition (if needed)
         if let Some(import) = &diff.import_line {
@@ -437,6 +448,7 @@ fn render_type_c(
     finding: &Finding,
     w: usize,
     t: &theme::ThemeColors,
+    side_by_side: bool,
 ) {
     let file_path = item.file_path.as_deref().unwrap_or("config");
     let block = Block::default()
@@ -485,7 +497,11 @@ fn render_type_c(
                 Style::default().fg(t.accent),
             ),
         ]));
-        render_fix_diff(&mut lines, diff, t);
+        if side_by_side {
+            render_fix_diff_side_by_side(&mut lines, diff, w, t);
+        } else {
+            render_fix_diff(&mut lines, diff, t);
+        }
     } else if let Some(fix_text) = &finding.fix {
         let has_diff_lines = fix_text
             .lines()
@@ -16,6 +16,10 @@ use crate::theme;
 use crate::types::Finding;
 
 // Re-export public API (same paths as before the split).
+pub use apply::{FixPlan, plan_fix, write_plan};
+// `apply_fix_to_file` (plan + write in one call, no journal) is only used by
+// tests now that `AppCommand::ApplyFixes` journals the batch itself.
+#[cfg(test)]
 pub use apply::apply_fix_to_file;
 
 /// Status of a single fix item.
@@ -56,10 +60,28 @@ pub struct FixViewState {
     pub fixable_findings: Vec<FixableItem>,
     pub selected_index: usize,
     pub diff_visible: bool,
+    /// Diff overlay rendering mode: unified (default) or side-by-side.
+    /// Toggled with `s` while the Fix view is focused.
+    pub diff_side_by_side: bool,
     pub applying: bool,
     pub results: Option<FixResults>,
     /// When set, Fix view shows only this finding (single-fix mode from Scan).
     pub focus_check_id: Option<String>,
+    /// LLM-customized template content for Type B (missing document)
+    /// findings, keyed by `check_id`, generated on demand with `g`. When
+    /// present, `plan_fix` writes this instead of the finding's default
+    /// `fix` text.
+    pub template_overrides: std::collections::HashMap<String, String>,
+    /// `check_id`s currently awaiting a `GenerateFixTemplate` response, so
+    /// the checklist can show a "Generating..." status instead of double-firing.
+    pub generating_templates: std::collections::HashSet<String>,
+    /// Number of selected fixes written to disk so far, while `applying` is
+    /// set — updated by `AppCommand::FixProgress` as the background apply
+    /// task works through the queue. Drives the footer's progress bar.
+    pub applying_current: u32,
+    /// Total fixes queued for the in-progress apply, set once when the
+    /// batch is planned. `0` means no determinate total is known yet.
+    pub applying_total: u32,
 }
 
 impl Default for FixViewState {
@@ -68,9 +90,14 @@ impl Default for FixViewState {
             fixable_findings: Vec::new(),
             selected_index: 0,
             diff_visible: true,
+            diff_side_by_side: false,
             applying: false,
             results: None,
             focus_check_id: None,
+            template_overrides: std::collections::HashMap::new(),
+            generating_templates: std::collections::HashSet::new(),
+            applying_current: 0,
+            applying_total: 0,
         }
     }
 }
@@ -100,12 +127,23 @@ impl FixViewState {
             fixable_findings: fixable,
             selected_index: 0,
             diff_visible: true,
+            diff_side_by_side: false,
             applying: false,
             results: None,
             focus_check_id: None,
+            template_overrides: std::collections::HashMap::new(),
+            generating_templates: std::collections::HashSet::new(),
+            applying_current: 0,
+            applying_total: 0,
         }
     }
 
+    /// LLM-customized content for `check_id`, if one has been generated with
+    /// `g`, overriding the default template from the finding's `fix` text.
+    pub fn template_override(&self, check_id: &str) -> Option<&str> {
+        self.template_overrides.get(check_id).map(String::as_str)
+    }
+
     pub const fn is_single_fix(&self) -> bool {
         self.focus_check_id.is_some()
     }
@@ -122,6 +160,40 @@ impl FixViewState {
             .sum()
     }
 
+    /// Score delta if the selected fixes apply, weighted by each fix's EU AI
+    /// Act category weight from the last scan's `category_scores` — a fix in
+    /// a heavier-weighted category counts for more of the total than the
+    /// flat per-severity sum would, so staging tracks actual score impact
+    /// rather than severity alone. Falls back to the flat sum when weights
+    /// aren't available (no scan yet, or engine omitted category scores).
+    #[allow(clippy::cast_precision_loss)]
+    pub fn total_predicted_impact_weighted(
+        &self,
+        category_scores: &[crate::types::CategoryScore],
+    ) -> f64 {
+        let total_weight: f64 = category_scores.iter().map(|c| c.weight).sum();
+        if category_scores.is_empty() || total_weight <= 0.0 {
+            return f64::from(self.total_predicted_impact());
+        }
+        let category_count = category_scores.len() as f64;
+        self.fixable_findings
+            .iter()
+            .filter(|f| f.selected)
+            .map(|f| {
+                let category = crate::views::classify_finding_category(
+                    f.article_reference.as_deref(),
+                    f.obligation_id.as_deref(),
+                    f.finding_type == crate::types::FindingType::B,
+                );
+                let weight = category_scores
+                    .iter()
+                    .find(|c| c.category == category)
+                    .map_or(1.0, |c| c.weight);
+                f64::from(f.predicted_impact) * weight * category_count / total_weight
+            })
+            .sum()
+    }
+
     pub fn toggle_current(&mut self) {
         if let Some(item) = self.fixable_findings.get_mut(self.selected_index) {
             item.selected = !item.selected;
@@ -7,7 +7,7 @@ use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
 use crate::app::App;
 use crate::theme;
 
-use super::{FixItemStatus, FixableItem};
+use super::{FixItemStatus, FixableItem, FixViewState};
 
 /// Render the fix checklist (left pane or full area).
 pub(super) fn render_checklist(frame: &mut Frame, area: Rect, app: &App) {
@@ -21,9 +21,13 @@ pub(super) fn render_checklist(frame: &mut Frame, area: Rect, app: &App) {
 
     let total = fix.fixable_findings.len();
     let current_score = app.last_scan.as_ref().map_or(0.0, |s| s.score.total_score);
+    let category_scores = app
+        .last_scan
+        .as_ref()
+        .map_or(&[][..], |s| s.score.category_scores.as_slice());
 
-    #[allow(clippy::cast_precision_loss)]
-    let predicted_score = (current_score + f64::from(fix.total_predicted_impact())).min(100.0);
+    let predicted_score =
+        (current_score + fix.total_predicted_impact_weighted(category_scores)).min(100.0);
 
     // Score color for predicted
     let pred_color = if predicted_score < 50.0 {
@@ -53,7 +57,7 @@ pub(super) fn render_checklist(frame: &mut Frame, area: Rect, app: &App) {
 
     // Score prediction header: Score: 32 → 47 (+15) | 5/9 selected
     let selected_count = fix.selected_count();
-    let impact = fix.total_predicted_impact();
+    let impact = predicted_score - current_score;
     lines.push(Line::from(vec![
         Span::styled("  Score: ", Style::default().fg(t.muted)),
         Span::styled(
@@ -65,7 +69,7 @@ pub(super) fn render_checklist(frame: &mut Frame, area: Rect, app: &App) {
             format!("{predicted_score:.0}"),
             Style::default().fg(pred_color).add_modifier(Modifier::BOLD),
         ),
-        Span::styled(format!("  (+{impact})"), Style::default().fg(t.zone_green)),
+        Span::styled(format!("  (+{impact:.0})"), Style::default().fg(t.zone_green)),
         Span::styled(
             format!("  |  {selected_count}/{total} selected"),
             Style::default().fg(t.muted),
@@ -108,7 +112,7 @@ pub(super) fn render_checklist(frame: &mut Frame, area: Rect, app: &App) {
         ]));
 
         for (i, item) in &staged {
-            render_fix_item(&mut lines, *i, item, fix.selected_index, &t, false);
+            render_fix_item(&mut lines, *i, item, fix.selected_index, &t, false, fix);
         }
     }
 
@@ -130,7 +134,7 @@ pub(super) fn render_checklist(frame: &mut Frame, area: Rect, app: &App) {
         ]));
 
         for (i, item) in &not_staged {
-            render_fix_item(&mut lines, *i, item, fix.selected_index, &t, true);
+            render_fix_item(&mut lines, *i, item, fix.selected_index, &t, true, fix);
         }
     }
 
@@ -267,6 +271,19 @@ pub(super) fn render_checklist_single(frame: &mut Frame, area: Rect, app: &App)
         Span::styled(item.message.clone(), Style::default().fg(t.fg)),
     ]));
 
+    // Template generation state (Type B only — see `g` key in view_keys.rs)
+    if fix.generating_templates.contains(&item.check_id) {
+        lines.push(Line::from(vec![
+            Span::styled("  ", Style::default()),
+            Span::styled("Generating AI-customized template...", Style::default().fg(t.zone_yellow)),
+        ]));
+    } else if fix.template_override(&item.check_id).is_some() {
+        lines.push(Line::from(vec![
+            Span::styled("  ", Style::default()),
+            Span::styled("AI-customized template ready", Style::default().fg(t.accent)),
+        ]));
+    }
+
     // Article reference + file path
     if !art.is_empty() {
         lines.push(Line::from(vec![
@@ -321,6 +338,7 @@ pub(super) fn render_fix_item<'a>(
     cursor_idx: usize,
     t: &theme::ThemeColors,
     muted: bool,
+    fix: &FixViewState,
 ) {
     let is_cursor = idx == cursor_idx;
     let obl = item.obligation_id.as_deref().unwrap_or("—");
@@ -348,6 +366,15 @@ pub(super) fn render_fix_item<'a>(
         FixItemStatus::Failed => t.zone_red,
     };
 
+    // Template generation state (Type B only — see `g` key in view_keys.rs)
+    let template_note = if fix.generating_templates.contains(&item.check_id) {
+        Some((" GENERATING...", t.zone_yellow))
+    } else if fix.template_override(&item.check_id).is_some() {
+        Some((" AI-CUSTOMIZED", t.accent))
+    } else {
+        None
+    };
+
     let text_color = if muted { t.muted } else { t.fg };
     let sel_style = if is_cursor {
         Style::default().fg(text_color).add_modifier(Modifier::BOLD)
@@ -376,6 +403,9 @@ pub(super) fn render_fix_item<'a>(
     if !status_text.is_empty() {
         spans.push(Span::styled(status_text, Style::default().fg(status_color)));
     }
+    if let Some((note, color)) = template_note {
+        spans.push(Span::styled(note, Style::default().fg(color)));
+    }
     lines.push(Line::from(spans));
 
     // Line 2: message + file path
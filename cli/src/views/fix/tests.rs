@@ -580,6 +580,43 @@ fn test_apply_type_b_creates_file() {
     let _ = std::fs::remove_dir_all(&dir);
 }
 
+#[test]
+fn test_plan_fix_template_override_takes_precedence() {
+    let dir = std::env::temp_dir().join("complior_test_plan_fix_override");
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let finding = Finding {
+        check_id: "l2-fria".to_string(),
+        r#type: crate::types::CheckResultType::Fail,
+        message: "Missing FRIA".to_string(),
+        severity: Severity::High,
+        obligation_id: None,
+        article_reference: None,
+        fix: Some("default template".to_string()),
+        file: None,
+        line: None,
+        code_context: None,
+        fix_diff: None,
+        priority: None,
+        confidence: None,
+        confidence_level: None,
+        evidence: None,
+        explanation: None,
+        agent_id: None,
+        doc_quality: None,
+        l5_analyzed: None,
+    };
+
+    let plan = plan_fix(&dir, &finding, Some("AI-customized template")).unwrap();
+    assert_eq!(plan.after_content, "AI-customized template");
+
+    let plan = plan_fix(&dir, &finding, None).unwrap();
+    assert_eq!(plan.after_content, "default template");
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
 #[test]
 fn test_apply_rejects_stale_diff() {
     use crate::types::FixDiff;
@@ -7,6 +7,7 @@ pub mod obligations;
 pub mod onboarding;
 pub mod passport;
 pub mod report;
+pub mod risk_classification;
 pub mod scan;
 pub mod sidebar;
 pub mod timeline;
@@ -32,6 +33,37 @@ pub fn truncate_str(s: &str, max_chars: usize) -> String {
     format!("{truncated}...")
 }
 
+/// Classify a finding into one of the five EU AI Act categories the engine
+/// reports in `category_scores` ("prohibited", "risk_mgmt", "documentation",
+/// "transparency", "technical"), by article/obligation heuristics — used
+/// where a client-side category estimate is needed (dashboard's derived
+/// category counts, the Fix view's weighted score projection) since findings
+/// themselves don't carry a category field.
+pub fn classify_finding_category(
+    article_reference: Option<&str>,
+    obligation_id: Option<&str>,
+    is_type_b: bool,
+) -> &'static str {
+    let art = article_reference.unwrap_or("");
+    let obl = obligation_id.unwrap_or("");
+    if art.contains("Art. 5") || obl.contains("prohibited") {
+        "prohibited"
+    } else if art.contains("Art. 9") || art.contains("Art. 27") || obl.contains("risk") {
+        "risk_mgmt"
+    } else if art.contains("Art. 11")
+        || art.contains("Art. 12")
+        || art.contains("Art. 18")
+        || obl.contains("doc")
+        || is_type_b
+    {
+        "documentation"
+    } else if art.contains("Art. 50") || art.contains("Art. 13") || art.contains("Art. 52") || obl.contains("transp") {
+        "transparency"
+    } else {
+        "technical"
+    }
+}
+
 /// Word-wrap text into lines that fit within the given width.
 /// Splits on whitespace boundaries. Returns at least one (possibly empty) line.
 pub fn wrap_text_lines(text: &str, width: usize) -> Vec<String> {
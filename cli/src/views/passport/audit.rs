@@ -80,12 +80,10 @@ pub(super) fn render_audit_panel(frame: &mut Frame, area: Rect, app: &App) {
                     .and_then(|v| v.as_str())
                     .unwrap_or("");
 
-                // Format timestamp to short form
-                let time_display = if timestamp.len() >= 16 {
-                    &timestamp[..16]
-                } else {
-                    timestamp
-                };
+                // Format timestamp to short local form, falling back to a
+                // truncated raw string if it isn't parseable RFC 3339.
+                let time_display = crate::date::format_utc_timestamp_local(timestamp)
+                    .unwrap_or_else(|| timestamp.chars().take(16).collect());
 
                 let icon = event_icon(event_type);
 
@@ -76,14 +76,78 @@ pub const fn zone_label(zone: crate::types::Zone) -> &'static str {
     }
 }
 
-/// Generate compliance report as Markdown.
-pub fn generate_report_markdown(scan: &ScanResult) -> String {
+/// Generate compliance report as Markdown. `assignments` adds an "Assignee"
+/// column to the findings table so exported reports reflect TUI triage
+/// (`/assign`) instead of dropping it on export. `finding_states` supplies
+/// `/snooze-until` dates so snoozed findings are counted separately from the
+/// rest, rather than being silently lumped in with open findings.
+/// `score_history`, `dismissals`, and `sections` back the Score Trend and
+/// Dismissals sections and the enabled/order choices made in the Report
+/// view's composer (`c`) — every export format renders through this same
+/// function, so the composer's choices apply everywhere.
+pub fn generate_report_markdown(
+    scan: &ScanResult,
+    assignments: &[crate::assignments::TrackedIssue],
+    finding_states: &[crate::findings_state::FindingState],
+    score_history: &[f64],
+    dismissals: &[crate::app::commands::DismissalEntry],
+    sections: &[crate::report_sections::SectionConfig],
+) -> String {
     let mut md = String::new();
-    let zone = zone_label(scan.score.zone);
-
     md.push_str("# Compliance Report\n\n");
 
-    // Executive Summary
+    for cfg in sections {
+        if !cfg.enabled {
+            continue;
+        }
+        match cfg.section {
+            crate::report_sections::ReportSection::Summary => {
+                render_summary_section(&mut md, scan, finding_states);
+            }
+            crate::report_sections::ReportSection::ScoreTrend => {
+                render_score_trend_section(&mut md, score_history);
+            }
+            crate::report_sections::ReportSection::FindingsByArticle => {
+                render_findings_by_article_section(&mut md, scan, assignments);
+            }
+            crate::report_sections::ReportSection::Dismissals => {
+                render_dismissals_section(&mut md, dismissals);
+            }
+            crate::report_sections::ReportSection::Evidence => {
+                render_evidence_section(&mut md, scan);
+            }
+            crate::report_sections::ReportSection::Timeline => {
+                render_timeline_section(&mut md);
+            }
+        }
+    }
+
+    md.push_str("---\n\n");
+    md.push_str("*Generated by Complior — EU AI Act Compliance Tool*\n");
+
+    md
+}
+
+fn render_summary_section(
+    md: &mut String,
+    scan: &ScanResult,
+    finding_states: &[crate::findings_state::FindingState],
+) {
+    let zone = zone_label(scan.score.zone);
+    let today = crate::date::today_epoch_days();
+    let snoozed_count = scan
+        .findings
+        .iter()
+        .filter(|f| {
+            crate::findings_state::snoozed_for(
+                finding_states,
+                &f.check_id,
+                f.file.as_deref(),
+                today,
+            )
+        })
+        .count();
+
     md.push_str("## Executive Summary\n\n");
     md.push_str(&format!("- **Score:** {:.0}/100\n", scan.score.total_score));
     md.push_str(&format!("- **Zone:** {zone}\n"));
@@ -92,16 +156,18 @@ pub fn generate_report_markdown(scan: &ScanResult) -> String {
     md.push_str(&format!("- **Files scanned:** {}\n", scan.files_scanned));
     md.push_str(&format!("- **Duration:** {}ms\n", scan.duration));
     md.push_str(&format!(
-        "- **Checks:** {} total, {} passed, {} failed, {} skipped\n\n",
+        "- **Checks:** {} total, {} passed, {} failed, {} skipped\n",
         scan.score.total_checks,
         scan.score.passed_checks,
         scan.score.failed_checks,
         scan.score.skipped_checks,
     ));
+    md.push_str(&format!(
+        "- **Snoozed:** {snoozed_count} (hidden from the TUI until their `/snooze-until` date)\n\n"
+    ));
 
-    // Category Scores
     if !scan.score.category_scores.is_empty() {
-        md.push_str("## Category Scores\n\n");
+        md.push_str("### Category Scores\n\n");
         md.push_str("| Category | Score | Passed | Failed |\n");
         md.push_str("|----------|------:|-------:|-------:|\n");
         for cat in &scan.score.category_scores {
@@ -114,19 +180,17 @@ pub fn generate_report_markdown(scan: &ScanResult) -> String {
         md.push('\n');
     }
 
-    // Critical Findings
     let critical: Vec<_> = scan
         .findings
         .iter()
         .filter(|f| matches!(f.severity, crate::types::Severity::Critical))
         .collect();
-
     if !critical.is_empty() {
-        md.push_str("## Critical Findings\n\n");
+        md.push_str("### Critical Findings\n\n");
         for f in &critical {
             let obl = f.obligation_id.as_deref().unwrap_or("N/A");
             let art = f.article_reference.as_deref().unwrap_or("N/A");
-            md.push_str(&format!("### {obl}: {}\n\n", f.message));
+            md.push_str(&format!("#### {obl}: {}\n\n", f.message));
             md.push_str(&format!("- **Article:** {art}\n"));
             md.push_str("- **Severity:** CRITICAL\n");
             if let Some(fix) = &f.fix {
@@ -135,51 +199,156 @@ pub fn generate_report_markdown(scan: &ScanResult) -> String {
             md.push('\n');
         }
     }
+}
+
+/// Score history as a run-over-run table — `score_history` is the same
+/// rolling window (last 20 scans) the Dashboard sparkline reads from.
+fn render_score_trend_section(md: &mut String, score_history: &[f64]) {
+    md.push_str("## Score Trend\n\n");
+    if score_history.is_empty() {
+        md.push_str("No scan history yet.\n\n");
+        return;
+    }
+    md.push_str("| Run | Score | Change |\n");
+    md.push_str("|----:|------:|-------:|\n");
+    let mut prev: Option<f64> = None;
+    for (i, score) in score_history.iter().enumerate() {
+        let change = prev.map_or_else(String::new, |p| format!("{:+.0}", score - p));
+        md.push_str(&format!("| {} | {score:.0} | {change} |\n", i + 1));
+        prev = Some(*score);
+    }
+    md.push('\n');
+}
 
-    // All Findings
-    md.push_str("## All Findings\n\n");
+/// Findings grouped by `article_reference` (falling back to "Unmapped" when
+/// absent), each with its assignee and remediation text inline.
+fn render_findings_by_article_section(
+    md: &mut String,
+    scan: &ScanResult,
+    assignments: &[crate::assignments::TrackedIssue],
+) {
+    md.push_str("## Findings by Article\n\n");
     if scan.findings.is_empty() {
         md.push_str("No findings. All checks passed.\n\n");
-    } else {
-        md.push_str("| # | Check ID | Severity | Message |\n");
-        md.push_str("|--:|----------|----------|--------|\n");
-        for (i, f) in scan.findings.iter().enumerate() {
+        return;
+    }
+
+    let mut articles: Vec<&str> = scan
+        .findings
+        .iter()
+        .map(|f| f.article_reference.as_deref().unwrap_or("Unmapped"))
+        .collect();
+    articles.sort_unstable();
+    articles.dedup();
+
+    for article in articles {
+        md.push_str(&format!("### {article}\n\n"));
+        md.push_str("| Check ID | Severity | Message | Assignee | Fix |\n");
+        md.push_str("|----------|----------|---------|----------|-----|\n");
+        for f in scan
+            .findings
+            .iter()
+            .filter(|f| f.article_reference.as_deref().unwrap_or("Unmapped") == article)
+        {
+            let assignee =
+                crate::assignments::assignee_for(assignments, &f.check_id, f.file.as_deref())
+                    .unwrap_or("-");
             md.push_str(&format!(
-                "| {} | {} | {:?} | {} |\n",
-                i + 1,
+                "| {} | {:?} | {} | {} | {} |\n",
                 f.check_id,
                 f.severity,
                 f.message,
+                assignee,
+                f.fix.as_deref().unwrap_or("-"),
             ));
         }
         md.push('\n');
     }
+}
 
-    // Remediation Plan
-    let fixable: Vec<_> = scan.findings.iter().filter(|f| f.fix.is_some()).collect();
-    if !fixable.is_empty() {
-        md.push_str("## Remediation Plan\n\n");
-        for (i, f) in fixable.iter().enumerate() {
-            let obl = f.obligation_id.as_deref().unwrap_or("N/A");
+fn render_dismissals_section(md: &mut String, dismissals: &[crate::app::commands::DismissalEntry]) {
+    md.push_str("## Dismissals\n\n");
+    if dismissals.is_empty() {
+        md.push_str("No findings have been dismissed.\n\n");
+        return;
+    }
+    md.push_str("| Check ID | File | Reason | Dismissed At |\n");
+    md.push_str("|----------|------|--------|-------------:|\n");
+    for d in dismissals {
+        md.push_str(&format!(
+            "| {} | {} | {} | {} |\n",
+            d.check_id,
+            d.file.as_deref().unwrap_or("-"),
+            d.reason,
+            d.dismissed_at,
+        ));
+    }
+    md.push('\n');
+}
+
+/// Findings carrying structured `evidence` (screenshots, log excerpts, etc.
+/// gathered by L4/L5 scanners) — the raw JSON is included for auditors to
+/// inspect since evidence shape varies by check.
+fn render_evidence_section(md: &mut String, scan: &ScanResult) {
+    md.push_str("## Evidence\n\n");
+    let with_evidence: Vec<_> = scan
+        .findings
+        .iter()
+        .filter(|f| f.evidence.as_ref().is_some_and(|e| !e.is_empty()))
+        .collect();
+    if with_evidence.is_empty() {
+        md.push_str("No findings carry attached evidence.\n\n");
+        return;
+    }
+    for f in with_evidence {
+        md.push_str(&format!("### {}\n\n", f.check_id));
+        for item in f.evidence.as_ref().unwrap() {
             md.push_str(&format!(
-                "{}. **{obl}** — {} -> {}\n",
-                i + 1,
-                f.message,
-                f.fix.as_deref().unwrap_or(""),
+                "```json\n{}\n```\n\n",
+                serde_json::to_string_pretty(item).unwrap_or_default()
             ));
         }
-        md.push('\n');
     }
+}
 
-    md.push_str("---\n\n");
-    md.push_str("*Generated by Complior — EU AI Act Compliance Tool*\n");
-
-    md
+/// EU AI Act regulatory milestones, marked past/upcoming — the same list
+/// the Timeline view renders (`crate::views::timeline::MILESTONES`).
+fn render_timeline_section(md: &mut String) {
+    md.push_str("## Timeline\n\n");
+    md.push_str("| Date | Milestone | Articles | Status |\n");
+    md.push_str("|------|-----------|----------|--------|\n");
+    for m in crate::views::timeline::MILESTONES {
+        let (y, mo, d) = m.date;
+        let status = if crate::views::timeline::is_past(m.date) {
+            "Past"
+        } else {
+            "Upcoming"
+        };
+        md.push_str(&format!(
+            "| {y}-{mo:02}-{d:02} | {} | {} | {status} |\n",
+            m.title, m.articles,
+        ));
+    }
+    md.push('\n');
 }
 
 /// Export report to a Markdown file.
-pub async fn export_report(scan: &ScanResult) -> Result<String, String> {
-    let md = generate_report_markdown(scan);
+pub async fn export_report(
+    scan: &ScanResult,
+    assignments: &[crate::assignments::TrackedIssue],
+    finding_states: &[crate::findings_state::FindingState],
+    score_history: &[f64],
+    dismissals: &[crate::app::commands::DismissalEntry],
+    sections: &[crate::report_sections::SectionConfig],
+) -> Result<String, String> {
+    let md = generate_report_markdown(
+        scan,
+        assignments,
+        finding_states,
+        score_history,
+        dismissals,
+        sections,
+    );
 
     // Generate filename with date
     let now = std::time::SystemTime::now()
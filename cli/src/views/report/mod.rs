@@ -31,6 +31,10 @@ pub struct ReportViewState {
     pub selected_generator: usize,
     /// Whether viewing a generated report detail (vs the menu).
     pub viewing_report: bool,
+    /// Whether the section composer overlay (`c`) is open.
+    pub composer_open: bool,
+    /// Cursor row within the composer's section list.
+    pub composer_cursor: usize,
 }
 
 impl Default for ReportViewState {
@@ -40,6 +44,8 @@ impl Default for ReportViewState {
             export_status: ExportStatus::None,
             selected_generator: 0,
             viewing_report: false,
+            composer_open: false,
+            composer_cursor: 0,
         }
     }
 }
@@ -54,6 +60,11 @@ pub fn render_report_view(frame: &mut Frame, area: Rect, app: &App) {
         return;
     }
 
+    if app.report_view.composer_open {
+        render_composer(frame, area, app);
+        return;
+    }
+
     let block = Block::default()
         .title(" Reports & Exports ")
         .title_style(theme::title_style())
@@ -183,13 +194,82 @@ pub fn render_report_view(frame: &mut Frame, area: Rect, app: &App) {
         ]));
         lines.push(Line::from(vec![
             Span::styled("  [r] ", Style::default().fg(t.accent)),
-            Span::styled("Full regulator details", Style::default().fg(t.fg)),
+            Span::styled("Full regulator details  ", Style::default().fg(t.fg)),
+            Span::styled("[c] ", Style::default().fg(t.accent)),
+            Span::styled("Compose report sections", Style::default().fg(t.fg)),
         ]));
 
         frame.render_widget(Paragraph::new(lines), sections[2]);
     }
 }
 
+/// Render the report composer overlay — checkbox list of sections with
+/// ordering, persisted to `.complior/report-sections.json` and applied to
+/// every export format (`Space` toggle, `J`/`K` reorder, `Esc` save+close).
+fn render_composer(frame: &mut Frame, area: Rect, app: &App) {
+    let t = theme::theme();
+    let block = Block::default()
+        .title(" Compose Report Sections ")
+        .title_style(theme::title_style())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(t.border));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let mut lines: Vec<Line<'_>> = vec![
+        Line::from(Span::styled(
+            "  Toggle sections and set the order applied to every export.",
+            Style::default().fg(t.muted),
+        )),
+        Line::raw(""),
+    ];
+
+    for (i, cfg) in app.report_sections.iter().enumerate() {
+        let is_cursor = i == app.report_view.composer_cursor;
+        let prefix = if is_cursor { "> " } else { "  " };
+        let checkbox = if cfg.enabled { "[x]" } else { "[ ]" };
+        let cb_color = if cfg.enabled { t.zone_green } else { t.muted };
+        let name_style = if is_cursor {
+            Style::default().fg(t.fg).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(t.fg)
+        };
+        lines.push(Line::from(vec![
+            Span::styled(prefix, Style::default().fg(t.accent)),
+            Span::styled(format!("{checkbox} "), Style::default().fg(cb_color)),
+            Span::styled(cfg.section.label(), name_style),
+        ]));
+    }
+
+    lines.push(Line::raw(""));
+    lines.push(Line::from(vec![
+        Span::styled("  Space", Style::default().fg(t.accent)),
+        Span::styled(":toggle  ", Style::default().fg(t.muted)),
+        Span::styled("j/k", Style::default().fg(t.accent)),
+        Span::styled(":move cursor  ", Style::default().fg(t.muted)),
+        Span::styled("J/K", Style::default().fg(t.accent)),
+        Span::styled(":reorder  ", Style::default().fg(t.muted)),
+        Span::styled("Esc", Style::default().fg(t.accent)),
+        Span::styled(":save & close", Style::default().fg(t.muted)),
+    ]));
+
+    frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), inner);
+}
+
+/// One block character per day, scaled to `average_score()` out of 100 —
+/// the score-trend chart used until this crate has an image codec to draw
+/// something richer over a detected graphics protocol.
+fn score_sparkline(history: &[crate::stats::DayStats]) -> String {
+    const LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    history
+        .iter()
+        .map(|day| {
+            let ratio = (day.average_score() / 100.0).clamp(0.0, 1.0);
+            LEVELS[(ratio * (LEVELS.len() - 1) as f64).round() as usize]
+        })
+        .collect()
+}
+
 /// Render the Report detail view — scrollable report content (invoked when `viewing_report` is true).
 fn render_report_detail_view(frame: &mut Frame, area: Rect, app: &App) {
     let t = theme::theme();
@@ -258,6 +338,29 @@ fn render_report_detail_view(frame: &mut Frame, area: Rect, app: &App) {
                 .add_modifier(Modifier::BOLD),
         ),
     ]));
+    // Score trend — text sparkline over recorded daily averages, since
+    // this crate has no image codec to draw a real chart over a detected
+    // graphics protocol (see `crate::graphics`).
+    let history = crate::stats::load_history(&app.project_path);
+    if history.len() > 1 {
+        let mut chronological = history.clone();
+        chronological.reverse();
+        let days = chronological.len();
+        lines.push(Line::from(vec![
+            Span::styled("  Trend:      ", Style::default().fg(t.muted)),
+            Span::styled(score_sparkline(&chronological), Style::default().fg(score_color)),
+            Span::styled(
+                format!("  ({days} day{})", if days == 1 { "" } else { "s" }),
+                Style::default().fg(t.muted),
+            ),
+        ]));
+        if crate::graphics::detect().supports_images() {
+            lines.push(Line::from(Span::styled(
+                "  (terminal graphics detected — chart images not yet supported, showing sparkline)",
+                Style::default().fg(t.muted),
+            )));
+        }
+    }
     lines.push(Line::raw(""));
 
     // Key metrics
@@ -272,7 +375,10 @@ fn render_report_detail_view(frame: &mut Frame, area: Rect, app: &App) {
     lines.push(Line::from(vec![
         Span::styled("  Duration:   ", Style::default().fg(t.muted)),
         Span::styled(
-            format!("{:.1}s", scan.duration as f64 / 1000.0),
+            format!(
+                "{}s",
+                crate::locale::format_decimal(scan.duration as f64 / 1000.0, 1)
+            ),
             Style::default().fg(t.fg),
         ),
     ]));
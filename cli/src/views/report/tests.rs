@@ -54,15 +54,58 @@ mod tests {
         }
     }
 
+    fn default_sections() -> Vec<crate::report_sections::SectionConfig> {
+        crate::report_sections::default_sections()
+    }
+
     #[test]
     fn test_generate_report_has_sections() {
         let scan = make_scan();
-        let report = generate_report_markdown(&scan);
+        let report =
+            generate_report_markdown(&scan, &[], &[], &[], &[], &default_sections());
         assert!(report.contains("# Compliance Report"));
         assert!(report.contains("## Executive Summary"));
-        assert!(report.contains("## Critical Findings"));
-        assert!(report.contains("## All Findings"));
-        assert!(report.contains("## Remediation Plan"));
+        assert!(report.contains("### Critical Findings"));
+        assert!(report.contains("## Findings by Article"));
+        assert!(report.contains("## Score Trend"));
+        assert!(report.contains("## Dismissals"));
+        assert!(report.contains("## Evidence"));
+        assert!(report.contains("## Timeline"));
+    }
+
+    #[test]
+    fn test_generate_report_counts_snoozed_separately() {
+        let scan = make_scan();
+        let snoozed_far_future = crate::findings_state::FindingState {
+            check_id: "OBL-001".to_string(),
+            file: None,
+            status: crate::findings_state::FindingStatus::Open,
+            due_date: None,
+            snoozed_until: Some("2999-01-01".to_string()),
+            updated_at: 0,
+        };
+        let report = generate_report_markdown(
+            &scan,
+            &[],
+            &[snoozed_far_future],
+            &[],
+            &[],
+            &default_sections(),
+        );
+        assert!(report.contains("**Snoozed:** 1"));
+    }
+
+    #[test]
+    fn test_generate_report_respects_disabled_sections() {
+        let scan = make_scan();
+        let mut sections = default_sections();
+        for cfg in &mut sections {
+            if cfg.section == crate::report_sections::ReportSection::Timeline {
+                cfg.enabled = false;
+            }
+        }
+        let report = generate_report_markdown(&scan, &[], &[], &[], &[], &sections);
+        assert!(!report.contains("## Timeline"));
     }
 
     #[test]
@@ -0,0 +1,323 @@
+//! Interactive risk classification questionnaire (`/risk-classify`).
+//!
+//! Walks the user through the EU AI Act's Annex III high-risk use-case
+//! categories and the Art. 51 GPAI systemic-risk threshold, re-using the
+//! same step-by-step wizard shape as [`crate::views::onboarding`] (a fixed
+//! question list, one cursor, y/n answers) but scoped to a single yes/no
+//! questionnaire rather than onboarding's mixed radio/checkbox/text steps.
+
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Gauge, Paragraph, Wrap};
+
+use crate::theme;
+
+/// One Annex III category or GPAI-threshold question.
+pub struct RiskQuestion {
+    pub id: &'static str,
+    pub text: &'static str,
+}
+
+fn build_questions() -> Vec<RiskQuestion> {
+    vec![
+        RiskQuestion {
+            id: "biometric",
+            text: "Does the system perform biometric identification, categorization, or emotion recognition? (Annex III.1)",
+        },
+        RiskQuestion {
+            id: "critical_infrastructure",
+            text: "Is the system used as a safety component in critical infrastructure (energy, water, transport, digital)? (Annex III.2)",
+        },
+        RiskQuestion {
+            id: "education",
+            text: "Does the system determine access to education or evaluate learning outcomes/exam integrity? (Annex III.3)",
+        },
+        RiskQuestion {
+            id: "employment",
+            text: "Is the system used for recruitment, promotion, termination, or work allocation decisions? (Annex III.4)",
+        },
+        RiskQuestion {
+            id: "essential_services",
+            text: "Does the system decide eligibility for essential public/private services (credit, insurance, benefits)? (Annex III.5)",
+        },
+        RiskQuestion {
+            id: "law_enforcement",
+            text: "Is the system used by law enforcement for risk assessment, evidence evaluation, or crime prediction? (Annex III.6)",
+        },
+        RiskQuestion {
+            id: "migration",
+            text: "Is the system used for migration, asylum, or border control decisions? (Annex III.7)",
+        },
+        RiskQuestion {
+            id: "justice_democracy",
+            text: "Does the system assist judicial decision-making or influence elections/voting behavior? (Annex III.8)",
+        },
+        RiskQuestion {
+            id: "gpai_systemic",
+            text: "Is this a general-purpose AI model trained with more than 10^25 FLOPs of compute? (Art. 51 GPAI systemic risk)",
+        },
+    ]
+}
+
+/// Outcome of the questionnaire, driving both the summary message and the
+/// requirement tags pushed onto `project.toml`'s `requirements` list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RiskLevel {
+    HighRisk,
+    GpaiSystemic,
+    MinimalRisk,
+}
+
+impl RiskLevel {
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::HighRisk => "High-risk (Annex III)",
+            Self::GpaiSystemic => "GPAI systemic risk (Art. 51)",
+            Self::MinimalRisk => "Minimal/limited risk",
+        }
+    }
+
+    /// Config value stored in `project.toml`'s `risk_classification` field.
+    pub const fn config_value(self) -> &'static str {
+        match self {
+            Self::HighRisk => "high-risk",
+            Self::GpaiSystemic => "gpai-systemic",
+            Self::MinimalRisk => "minimal-risk",
+        }
+    }
+
+    /// Requirement tags to merge into `project.toml`'s `requirements` list,
+    /// so the scan profile picks up the stricter obligation set.
+    pub const fn requirement_tags(self) -> &'static [&'static str] {
+        match self {
+            Self::HighRisk => &["eu-ai-act-annex-iii"],
+            Self::GpaiSystemic => &["eu-ai-act-gpai-systemic"],
+            Self::MinimalRisk => &[],
+        }
+    }
+}
+
+/// Classify from yes/no answers, in `build_questions()` order. The last
+/// question (`gpai_systemic`) is checked independently of the Annex III
+/// categories, since a system can be both high-risk and GPAI.
+fn classify(questions: &[RiskQuestion], answers: &[Option<bool>]) -> RiskLevel {
+    let gpai_idx = questions.iter().position(|q| q.id == "gpai_systemic");
+    if gpai_idx.and_then(|i| answers.get(i).copied().flatten()) == Some(true) {
+        return RiskLevel::GpaiSystemic;
+    }
+    let any_annex_iii = questions
+        .iter()
+        .enumerate()
+        .filter(|(_, q)| q.id != "gpai_systemic")
+        .any(|(i, _)| answers.get(i).copied().flatten() == Some(true));
+    if any_annex_iii {
+        RiskLevel::HighRisk
+    } else {
+        RiskLevel::MinimalRisk
+    }
+}
+
+pub struct RiskWizard {
+    pub questions: Vec<RiskQuestion>,
+    pub current: usize,
+    pub answers: Vec<Option<bool>>,
+    pub completed: bool,
+    pub result: Option<RiskLevel>,
+}
+
+impl RiskWizard {
+    pub fn new() -> Self {
+        let questions = build_questions();
+        let answers = vec![None; questions.len()];
+        Self {
+            questions,
+            current: 0,
+            answers,
+            completed: false,
+            result: None,
+        }
+    }
+
+    pub fn current_question(&self) -> Option<&RiskQuestion> {
+        self.questions.get(self.current)
+    }
+
+    /// Record yes/no for the current question and advance, or finish and
+    /// classify once the last question is answered.
+    pub fn answer(&mut self, yes: bool) {
+        if let Some(slot) = self.answers.get_mut(self.current) {
+            *slot = Some(yes);
+        }
+        if self.current + 1 < self.questions.len() {
+            self.current += 1;
+        } else {
+            self.completed = true;
+            self.result = Some(classify(&self.questions, &self.answers));
+        }
+    }
+
+    pub fn prev(&mut self) {
+        if self.completed {
+            self.completed = false;
+            self.result = None;
+        } else if self.current > 0 {
+            self.current -= 1;
+        }
+    }
+}
+
+/// Render the risk classification wizard as a full-screen centered overlay.
+pub fn render_risk_classification(frame: &mut Frame, wizard: &RiskWizard) {
+    let t = theme::theme();
+    let area = centered_rect(70, 34, frame.area());
+
+    frame.render_widget(Clear, area);
+
+    let block = Block::default()
+        .title(" Risk Classification ")
+        .title_style(Style::default().fg(t.accent).add_modifier(Modifier::BOLD))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(t.border_focused))
+        .style(Style::default().bg(t.bg));
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    if wizard.completed {
+        let level = wizard.result.unwrap_or(RiskLevel::MinimalRisk);
+        let lines = vec![
+            Line::from(Span::styled(
+                "Classification complete",
+                Style::default().fg(t.accent).add_modifier(Modifier::BOLD),
+            )),
+            Line::from(""),
+            Line::from(Span::styled(
+                level.label(),
+                Style::default()
+                    .fg(t.zone_green)
+                    .add_modifier(Modifier::BOLD),
+            )),
+            Line::from(""),
+            Line::from(Span::styled(
+                "Saved to .complior/project.toml. Press Enter to close.",
+                Style::default().fg(t.muted),
+            )),
+        ];
+        frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: true }), inner);
+        return;
+    }
+
+    let Some(question) = wizard.current_question() else {
+        return;
+    };
+
+    let chunks = Layout::vertical([
+        Constraint::Length(2),
+        Constraint::Length(1),
+        Constraint::Length(1),
+        Constraint::Min(3),
+        Constraint::Length(1),
+    ])
+    .split(inner);
+
+    let header = Paragraph::new(Line::from(Span::styled(
+        format!(
+            "Question {} of {}",
+            wizard.current + 1,
+            wizard.questions.len()
+        ),
+        Style::default().fg(t.fg),
+    )));
+    frame.render_widget(header, chunks[0]);
+
+    let pct = ((wizard.current + 1) as f64 / wizard.questions.len() as f64 * 100.0) as u16;
+    let gauge = Gauge::default()
+        .gauge_style(Style::default().fg(t.accent).bg(t.muted))
+        .percent(pct)
+        .label(format!("{pct}%"));
+    frame.render_widget(gauge, chunks[1]);
+
+    let text = Paragraph::new(Span::styled(question.text, Style::default().fg(t.fg)))
+        .wrap(Wrap { trim: true });
+    frame.render_widget(text, chunks[3]);
+
+    let hint = Paragraph::new(Line::from(Span::styled(
+        "[y] Yes   [n] No   [Esc] Back",
+        Style::default().fg(t.muted),
+    )));
+    frame.render_widget(hint, chunks[4]);
+}
+
+fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let v = Layout::vertical([
+        Constraint::Percentage((100 - percent_y) / 2),
+        Constraint::Percentage(percent_y),
+        Constraint::Percentage((100 - percent_y) / 2),
+    ])
+    .split(r);
+    Layout::horizontal([
+        Constraint::Percentage((100 - percent_x) / 2),
+        Constraint::Percentage(percent_x),
+        Constraint::Percentage((100 - percent_x) / 2),
+    ])
+    .split(v[1])[1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_answers_classify_minimal() {
+        let questions = build_questions();
+        let answers = vec![Some(false); questions.len()];
+        assert_eq!(classify(&questions, &answers), RiskLevel::MinimalRisk);
+    }
+
+    #[test]
+    fn annex_iii_yes_classifies_high_risk() {
+        let questions = build_questions();
+        let mut answers = vec![Some(false); questions.len()];
+        answers[0] = Some(true); // biometric
+        assert_eq!(classify(&questions, &answers), RiskLevel::HighRisk);
+    }
+
+    #[test]
+    fn gpai_threshold_yes_classifies_gpai_systemic() {
+        let questions = build_questions();
+        let mut answers = vec![Some(false); questions.len()];
+        let idx = questions
+            .iter()
+            .position(|q| q.id == "gpai_systemic")
+            .unwrap();
+        answers[idx] = Some(true);
+        assert_eq!(classify(&questions, &answers), RiskLevel::GpaiSystemic);
+    }
+
+    #[test]
+    fn wizard_answer_advances_then_completes() {
+        let mut wizard = RiskWizard::new();
+        let total = wizard.questions.len();
+        for _ in 0..total - 1 {
+            wizard.answer(false);
+            assert!(!wizard.completed);
+        }
+        wizard.answer(false);
+        assert!(wizard.completed);
+        assert_eq!(wizard.result, Some(RiskLevel::MinimalRisk));
+    }
+
+    #[test]
+    fn e2e_risk_classification_render() {
+        crate::theme::init_theme("dark");
+        let backend = ratatui::backend::TestBackend::new(120, 40);
+        let mut terminal = ratatui::Terminal::new(backend).expect("terminal");
+
+        let wizard = RiskWizard::new();
+        terminal
+            .draw(|frame| render_risk_classification(frame, &wizard))
+            .expect("render risk classification question");
+    }
+}
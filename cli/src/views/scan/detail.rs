@@ -24,6 +24,15 @@ pub(super) fn render_finding_detail(frame: &mut Frame, area: Rect, app: &App) {
         .findings
         .iter()
         .filter(|f| app.scan_view.findings_filter.matches(f.severity))
+        .filter(|f| {
+            app.scan_view.show_snoozed
+                || !crate::findings_state::snoozed_for(
+                    &app.finding_states,
+                    &f.check_id,
+                    f.file.as_deref(),
+                    crate::date::today_epoch_days(),
+                )
+        })
         .collect();
     let file_agent_map = build_file_agent_map(&app.passport_view.loaded_passports);
     super::sort_findings_for_display(&mut filtered, &file_agent_map);
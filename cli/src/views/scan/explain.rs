@@ -1,5 +1,5 @@
 /// Penalty info for EU AI Act articles.
-pub(super) fn penalty_for_article(article: &str) -> &'static str {
+pub fn penalty_for_article(article: &str) -> &'static str {
     match article {
         "Art. 5" => "Up to \u{20ac}35M / 7% turnover",
         "Art. 6" | "Art. 9" | "Art. 10" | "Art. 12" | "Art. 13" | "Art. 14" | "Art. 15"
@@ -10,7 +10,7 @@ pub(super) fn penalty_for_article(article: &str) -> &'static str {
 }
 
 /// Deadline context for an article reference.
-pub(super) fn deadline_for_article(art: &str) -> String {
+pub fn deadline_for_article(art: &str) -> String {
     if art.contains("Art. 5") {
         "Feb 2, 2025 (already in effect)".to_string()
     } else if art.contains("Art. 50") || art.contains("Art. 53") {
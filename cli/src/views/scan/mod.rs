@@ -8,10 +8,12 @@ mod shared;
 mod tests;
 
 // Re-export pub(crate) shared rendering helpers used by the fix view.
-pub use shared::{render_code_block, render_fix_diff, render_fix_text};
+pub use shared::{
+    render_code_block, render_fix_diff, render_fix_diff_side_by_side, render_fix_text,
+};
 
 // Re-export public items for external use.
-pub use explain::explain_finding;
+pub use explain::{deadline_for_article, explain_check, explain_finding, penalty_for_article};
 
 use ratatui::Frame;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
@@ -78,6 +80,11 @@ pub struct ScanViewState {
     pub findings_filter: FindingsFilter,
     pub selected_finding: Option<usize>,
     pub detail_open: bool,
+    /// Split mode: right pane shows a live view of the selected finding's
+    /// file (via `App::code_buffer`) instead of the default preview,
+    /// re-centering as the selection moves -- for triage against real
+    /// surrounding code rather than the `code_context` snippet alone.
+    pub code_view_open: bool,
     pub scanning: bool,
     pub show_passed: bool,
     /// Preview panel scroll offset.
@@ -88,6 +95,12 @@ pub struct ScanViewState {
     pub progress_collapsed: bool,
     /// Last scan error message (shown on Scan tab instead of chat only).
     pub scan_error: Option<String>,
+    /// Filter findings by assignee (`/assignee <name>`). `Some("unassigned")`
+    /// matches findings with no assignee; `None` shows everything.
+    pub assignee_filter: Option<String>,
+    /// Whether findings snoozed via `/snooze-until` are shown early, before
+    /// their `snoozed_until` date. Toggled with `z`.
+    pub show_snoozed: bool,
 }
 
 impl Default for ScanViewState {
@@ -133,12 +146,15 @@ impl Default for ScanViewState {
             findings_filter: FindingsFilter::All,
             selected_finding: None,
             detail_open: false,
+            code_view_open: false,
             scanning: false,
             show_passed: false,
             preview_scroll: 0,
             scan_split_pct: 45,
             progress_collapsed: false,
             scan_error: None,
+            assignee_filter: None,
+            show_snoozed: false,
         }
     }
 }
@@ -228,15 +244,67 @@ pub(super) fn sort_findings_for_display(
 /// This is needed because `selected_finding` is an index into the
 /// sorted-by-severity, filtered list shown on screen -- NOT the original
 /// `scan.findings` array.
+/// Count of findings currently shown in the list under the active filter.
+/// Used to map a scrollbar click position back to a finding index.
+pub fn filtered_findings_count(app: &App) -> usize {
+    let today = crate::date::today_epoch_days();
+    app.last_scan.as_ref().map_or(0, |scan| {
+        scan.findings
+            .iter()
+            .filter(|f| app.scan_view.findings_filter.matches(f.severity))
+            .filter(|f| {
+                crate::assignments::matches(
+                    &app.assignments,
+                    app.scan_view.assignee_filter.as_deref(),
+                    &f.check_id,
+                    f.file.as_deref(),
+                )
+            })
+            .filter(|f| {
+                app.scan_view.show_snoozed
+                    || !crate::findings_state::snoozed_for(
+                        &app.finding_states,
+                        &f.check_id,
+                        f.file.as_deref(),
+                        today,
+                    )
+            })
+            .count()
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn resolve_selected_finding<'a>(
     findings: &'a [crate::types::Finding],
     filter: FindingsFilter,
     selected_index: usize,
     passports: &[serde_json::Value],
+    assignments: &[crate::assignments::TrackedIssue],
+    assignee_filter: Option<&str>,
+    finding_states: &[crate::findings_state::FindingState],
+    show_snoozed: bool,
 ) -> Option<&'a crate::types::Finding> {
+    let today = crate::date::today_epoch_days();
     let mut filtered: Vec<&crate::types::Finding> = findings
         .iter()
         .filter(|f| filter.matches(f.severity))
+        .filter(|f| {
+            crate::assignments::matches(
+                assignments,
+                assignee_filter,
+                &f.check_id,
+                f.file.as_deref(),
+            )
+        })
+        .filter(|f| {
+            show_snoozed
+                || !crate::findings_state::snoozed_for(
+                    finding_states,
+                    &f.check_id,
+                    f.file.as_deref(),
+                    today,
+                )
+        })
         .collect();
     let file_agent_map = render::build_file_agent_map(passports);
     sort_findings_for_display(&mut filtered, &file_agent_map);
@@ -298,6 +366,8 @@ pub fn render_scan_view(frame: &mut Frame, area: Rect, app: &App) {
 
         if app.scan_view.detail_open {
             detail::render_finding_detail(frame, split[1], app);
+        } else if app.scan_view.code_view_open {
+            preview::render_scan_code_view(frame, split[1], app);
         } else {
             preview::render_scan_preview(frame, split[1], app);
         }
@@ -11,6 +11,107 @@ use super::explain::{explain_check, wrap_text};
 use super::render::build_file_agent_map;
 use super::shared::{render_code_block, render_fix_diff, render_fix_text};
 
+/// Live code view -- shows a window of `app.code_buffer` (the file opened
+/// via the `o` key) centered on the selected finding's line, re-centering
+/// as the selection moves. Falls back to a prompt when no file is open yet
+/// or the open file doesn't match the selected finding.
+pub(super) fn render_scan_code_view(frame: &mut Frame, area: Rect, app: &App) {
+    let t = theme::theme();
+
+    let mut filtered: Vec<&crate::types::Finding> = app
+        .last_scan
+        .iter()
+        .flat_map(|scan| scan.findings.iter())
+        .filter(|f| app.scan_view.findings_filter.matches(f.severity))
+        .filter(|f| {
+            app.scan_view.show_snoozed
+                || !crate::findings_state::snoozed_for(
+                    &app.finding_states,
+                    &f.check_id,
+                    f.file.as_deref(),
+                    crate::date::today_epoch_days(),
+                )
+        })
+        .collect();
+    let file_agent_map = build_file_agent_map(&app.passport_view.loaded_passports);
+    super::sort_findings_for_display(&mut filtered, &file_agent_map);
+
+    let idx = app.scan_view.selected_finding.unwrap_or(0);
+    let finding = filtered.get(idx).copied();
+
+    let title = finding
+        .and_then(|f| f.file.as_deref())
+        .unwrap_or("Code View");
+    let block = Block::default()
+        .title(format!(" {title} "))
+        .title_style(theme::title_style())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(t.border));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let Some(finding) = finding else {
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                "  Select a finding to view its code.",
+                Style::default().fg(t.muted),
+            ))),
+            inner,
+        );
+        return;
+    };
+
+    let is_current_file = app.open_file_path.as_deref() == finding.file.as_deref();
+    let Some(buffer) = app.code_buffer.as_ref().filter(|_| is_current_file) else {
+        frame.render_widget(
+            Paragraph::new(vec![
+                Line::from(Span::styled(
+                    "  Not open yet.",
+                    Style::default().fg(t.muted),
+                )),
+                Line::from(Span::styled(
+                    "  Press [o] to open this finding's file here.",
+                    Style::default().fg(t.muted),
+                )),
+            ]),
+            inner,
+        );
+        return;
+    };
+
+    let highlight_line = finding.line.map(|n| n.saturating_sub(1) as usize);
+    let center = highlight_line.unwrap_or(0);
+    let half = (inner.height as usize / 2).max(1);
+    let start = center.saturating_sub(half);
+    let end = start + inner.height as usize;
+
+    let mut lines: Vec<Line<'_>> = Vec::new();
+    for (offset, content) in buffer.lines_in(start, end).enumerate() {
+        let line_no = start + offset;
+        let is_highlight = highlight_line == Some(line_no);
+        let num = format!("{:>5} ", line_no + 1);
+        if is_highlight {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    num,
+                    Style::default().fg(t.zone_red).add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    content.to_string(),
+                    Style::default().fg(t.fg).add_modifier(Modifier::BOLD),
+                ),
+            ]));
+        } else {
+            lines.push(Line::from(vec![
+                Span::styled(num, Style::default().fg(t.muted)),
+                Span::styled(content.to_string(), Style::default().fg(t.fg)),
+            ]));
+        }
+    }
+
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
 /// Preview panel -- content based on the selected finding's type.
 pub(super) fn render_scan_preview(frame: &mut Frame, area: Rect, app: &App) {
     let t = theme::theme();
@@ -23,6 +124,15 @@ pub(super) fn render_scan_preview(frame: &mut Frame, area: Rect, app: &App) {
         .findings
         .iter()
         .filter(|f| app.scan_view.findings_filter.matches(f.severity))
+        .filter(|f| {
+            app.scan_view.show_snoozed
+                || !crate::findings_state::snoozed_for(
+                    &app.finding_states,
+                    &f.check_id,
+                    f.file.as_deref(),
+                    crate::date::today_epoch_days(),
+                )
+        })
         .collect();
     let file_agent_map = build_file_agent_map(&app.passport_view.loaded_passports);
     super::sort_findings_for_display(&mut filtered, &file_agent_map);
@@ -2,7 +2,9 @@ use ratatui::Frame;
 use ratatui::layout::Rect;
 use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, Paragraph};
+use ratatui::widgets::{
+    Block, Borders, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState,
+};
 
 use crate::app::App;
 use crate::theme;
@@ -26,6 +28,14 @@ pub(super) fn render_filter_bar(frame: &mut Frame, area: Rect, app: &App) {
         Style::default().fg(t.fg),
     )];
 
+    if let Some(git) = &app.last_scan_git {
+        let dirty_marker = if git.dirty { "*" } else { "" };
+        spans.push(Span::styled(
+            format!("{}@{}{dirty_marker}  ", git.branch, git.commit),
+            Style::default().fg(t.muted),
+        ));
+    }
+
     for (key, filter, label) in &filters {
         if *filter == active {
             spans.push(Span::styled(
@@ -101,6 +111,15 @@ pub(super) fn render_findings_list(frame: &mut Frame, area: Rect, app: &App) {
         .findings
         .iter()
         .filter(|f| app.scan_view.findings_filter.matches(f.severity))
+        .filter(|f| {
+            app.scan_view.show_snoozed
+                || !crate::findings_state::snoozed_for(
+                    &app.finding_states,
+                    &f.check_id,
+                    f.file.as_deref(),
+                    crate::date::today_epoch_days(),
+                )
+        })
         .collect();
 
     // Pre-compute file→agent map once (empty if no passports)
@@ -328,6 +347,15 @@ pub(super) fn render_findings_list(frame: &mut Frame, area: Rect, app: &App) {
     let approx_line = (selected as f64 * 2.5) as usize + 1;
     let scroll = approx_line.saturating_sub(visible_height / 2);
 
+    let total_lines = lines.len();
     let paragraph = Paragraph::new(lines).scroll((u16::try_from(scroll).unwrap_or(u16::MAX), 0));
     frame.render_widget(paragraph, inner);
+
+    if total_lines > visible_height {
+        let mut scrollbar_state = ScrollbarState::new(total_lines).position(scroll);
+        let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .track_style(Style::default().fg(t.muted))
+            .thumb_style(Style::default().fg(t.accent));
+        frame.render_stateful_widget(scrollbar, inner, &mut scrollbar_state);
+    }
 }
@@ -39,33 +39,191 @@ pub fn render_code_block(
     }
 }
 
-/// Render before/after fix diff with red removed / green added lines.
+/// Render before/after fix diff with red removed / green added lines,
+/// syntax-highlighted per `diff.file_path` with a muted removed/added
+/// background tint layered underneath.
 pub fn render_fix_diff(
     lines: &mut Vec<Line<'_>>,
     diff: &crate::types::FixDiff,
     t: &theme::ThemeColors,
 ) {
+    let bg_removed = crate::syntax::tint(t.diff_removed);
+    let bg_added = crate::syntax::tint(t.diff_added);
     for (i, before_line) in diff.before.iter().enumerate() {
         let line_num = diff.start_line + i as u32;
-        lines.push(Line::from(vec![
+        let mut spans = vec![
             Span::styled(
                 format!("{line_num:>4}"),
                 Style::default().fg(t.diff_removed),
             ),
             Span::styled(" - ", Style::default().fg(t.diff_removed)),
-            Span::styled(before_line.clone(), Style::default().fg(t.diff_removed)),
-        ]));
+        ];
+        spans.extend(crate::syntax::highlighted_spans(
+            before_line,
+            &diff.file_path,
+            &t.syntect,
+            t.diff_removed,
+            bg_removed,
+        ));
+        lines.push(Line::from(spans));
     }
     for (i, after_line) in diff.after.iter().enumerate() {
         let line_num = diff.start_line + i as u32;
-        lines.push(Line::from(vec![
+        let mut spans = vec![
             Span::styled(format!("{line_num:>4}"), Style::default().fg(t.diff_added)),
             Span::styled(" + ", Style::default().fg(t.diff_added)),
-            Span::styled(after_line.clone(), Style::default().fg(t.diff_added)),
-        ]));
+        ];
+        spans.extend(crate::syntax::highlighted_spans(
+            after_line,
+            &diff.file_path,
+            &t.syntect,
+            t.diff_added,
+            bg_added,
+        ));
+        lines.push(Line::from(spans));
     }
 }
 
+/// Render before/after fix diff as two columns (old | new) instead of
+/// stacked unified hunks, with the changed middle of each paired line
+/// bolded so a long line's actual edit is easy to spot. Rows without a
+/// counterpart on the other side (unequal before/after line counts) show
+/// their one side in full and leave the other blank.
+pub fn render_fix_diff_side_by_side(
+    lines: &mut Vec<Line<'_>>,
+    diff: &crate::types::FixDiff,
+    width: usize,
+    t: &theme::ThemeColors,
+) {
+    let col_width = width.saturating_sub(3) / 2;
+    let bg_removed = crate::syntax::tint(t.diff_removed);
+    let bg_added = crate::syntax::tint(t.diff_added);
+    let rows = diff.before.len().max(diff.after.len());
+    for i in 0..rows {
+        let before_line = diff.before.get(i).map(String::as_str);
+        let after_line = diff.after.get(i).map(String::as_str);
+        let mut spans = Vec::new();
+
+        match (before_line, after_line) {
+            (Some(before), Some(after)) => {
+                let (prefix, suffix) = common_affixes(
+                    &before.chars().collect::<Vec<_>>(),
+                    &after.chars().collect::<Vec<_>>(),
+                );
+                let before_mid_end = before.chars().count().saturating_sub(suffix).max(prefix);
+                let after_mid_end = after.chars().count().saturating_sub(suffix).max(prefix);
+
+                spans.extend(diff_column(
+                    before,
+                    &diff.file_path,
+                    t,
+                    t.diff_removed,
+                    bg_removed,
+                    col_width,
+                    prefix,
+                    before_mid_end,
+                ));
+                spans.push(Span::styled(" \u{2502} ", Style::default().fg(t.border)));
+                spans.extend(diff_column(
+                    after,
+                    &diff.file_path,
+                    t,
+                    t.diff_added,
+                    bg_added,
+                    col_width,
+                    prefix,
+                    after_mid_end,
+                ));
+            }
+            (Some(before), None) => {
+                spans.extend(diff_column(
+                    before,
+                    &diff.file_path,
+                    t,
+                    t.diff_removed,
+                    bg_removed,
+                    col_width,
+                    0,
+                    0,
+                ));
+                spans.push(Span::styled(" \u{2502} ", Style::default().fg(t.border)));
+            }
+            (None, Some(after)) => {
+                spans.push(Span::styled(
+                    " ".repeat(col_width),
+                    Style::default().bg(bg_added),
+                ));
+                spans.push(Span::styled(" \u{2502} ", Style::default().fg(t.border)));
+                spans.extend(diff_column(
+                    after,
+                    &diff.file_path,
+                    t,
+                    t.diff_added,
+                    bg_added,
+                    col_width,
+                    0,
+                    0,
+                ));
+            }
+            (None, None) => {}
+        }
+        lines.push(Line::from(spans));
+    }
+}
+
+/// Build one side's spans for the side-by-side diff: syntax-highlighted
+/// (falling back to a flat `fg` color if the file/theme isn't recognized),
+/// tinted with `bg`, with `[bold_start, bold_end)` chars re-bolded to mark
+/// the part of the line that actually changed, then padded/truncated to
+/// `width` total chars so both columns line up.
+#[allow(clippy::too_many_arguments)]
+fn diff_column(
+    text: &str,
+    file_path: &str,
+    t: &theme::ThemeColors,
+    fg: ratatui::style::Color,
+    bg: ratatui::style::Color,
+    width: usize,
+    bold_start: usize,
+    bold_end: usize,
+) -> Vec<Span<'static>> {
+    let shown = truncate_chars(text, width);
+    let shown_len = shown.chars().count();
+    let mut spans = crate::syntax::highlighted_spans(&shown, file_path, &t.syntect, fg, bg);
+    if bold_end > bold_start {
+        spans = crate::syntax::bold_range(spans, bold_start, bold_end);
+    }
+    if shown_len < width {
+        spans.push(Span::styled(
+            " ".repeat(width - shown_len),
+            Style::default().bg(bg),
+        ));
+    }
+    spans
+}
+
+fn truncate_chars(text: &str, width: usize) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    if chars.len() > width {
+        chars[..width].iter().collect()
+    } else {
+        text.to_string()
+    }
+}
+
+/// Count of characters `a` and `b` share as a matching prefix, and
+/// (non-overlapping) matching suffix — used to isolate just the changed
+/// middle of a line for bolding.
+fn common_affixes(a: &[char], b: &[char]) -> (usize, usize) {
+    let max_common = a.len().min(b.len());
+    let prefix = a.iter().zip(b.iter()).take_while(|(x, y)| x == y).count();
+    let mut suffix = 0;
+    while suffix < max_common - prefix && a[a.len() - 1 - suffix] == b[b.len() - 1 - suffix] {
+        suffix += 1;
+    }
+    (prefix, suffix)
+}
+
 /// Render fix text as diff lines (fallback when no structured fixDiff).
 pub fn render_fix_text<'a>(
     lines: &mut Vec<Line<'a>>,
@@ -6,7 +6,7 @@ use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph};
 
 use crate::app::App;
 use crate::theme;
-use crate::types::Zone;
+use crate::types::{RemoteWidget, RemoteWidgetKind, Zone};
 
 pub fn render_sidebar(frame: &mut Frame, area: Rect, app: &App) {
     let t = theme::theme();
@@ -22,22 +22,25 @@ pub fn render_sidebar(frame: &mut Frame, area: Rect, app: &App) {
 
     // Divide sidebar into sections
     let has_scan = app.last_scan.is_some();
-    let constraints = if has_scan {
+    let has_remote_widgets = !app.dashboard_widgets.is_empty();
+    let mut constraints = if has_scan {
         vec![
             Constraint::Length(5), // Project
             Constraint::Length(6), // Scan Summary
             Constraint::Length(3), // Context + Zen
             Constraint::Length(3), // Deadlines
-            Constraint::Min(3),    // Quick Actions
         ]
     } else {
         vec![
             Constraint::Length(5), // Project
             Constraint::Length(3), // Context + Zen
             Constraint::Length(3), // Deadlines
-            Constraint::Min(3),    // Quick Actions
         ]
     };
+    if has_remote_widgets {
+        constraints.push(Constraint::Length(3)); // Remote widgets
+    }
+    constraints.push(Constraint::Min(3)); // Quick Actions
 
     let chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -47,16 +50,21 @@ pub fn render_sidebar(frame: &mut Frame, area: Rect, app: &App) {
     // --- Project section ---
     render_project_section(frame, chunks[0], app, &t);
 
-    if has_scan {
+    let mut idx = if has_scan {
         render_scan_summary(frame, chunks[1], app, &t);
         render_context_zen_section(frame, chunks[2], app, &t);
         render_deadlines(frame, chunks[3], &t);
-        render_quick_actions(frame, chunks[4], &t);
+        4
     } else {
         render_context_zen_section(frame, chunks[1], app, &t);
         render_deadlines(frame, chunks[2], &t);
-        render_quick_actions(frame, chunks[3], &t);
+        3
+    };
+    if has_remote_widgets {
+        render_remote_widgets(frame, chunks[idx], &app.dashboard_widgets, &t);
+        idx += 1;
     }
+    render_quick_actions(frame, chunks[idx], &t);
 }
 
 fn render_project_section(frame: &mut Frame, area: Rect, app: &App, t: &theme::ThemeColors) {
@@ -214,6 +222,41 @@ fn render_deadlines(frame: &mut Frame, area: Rect, t: &theme::ThemeColors) {
     frame.render_widget(p, area);
 }
 
+/// Render engine-declared widgets (`GET /widgets`) generically by `kind`,
+/// one per line — this is the only place that needs to know about a new
+/// widget the engine starts sending; the CLI itself has no fixed idea of
+/// what these represent.
+fn render_remote_widgets(
+    frame: &mut Frame,
+    area: Rect,
+    widgets: &[RemoteWidget],
+    t: &theme::ThemeColors,
+) {
+    let lines: Vec<Line<'_>> = widgets
+        .iter()
+        .map(|w| {
+            let value = match &w.kind {
+                RemoteWidgetKind::KeyValue { value } => value.clone(),
+                RemoteWidgetKind::Gauge { value, max } => format!("{value:.0}/{max:.0}"),
+                RemoteWidgetKind::List { items } => items.join(", "),
+            };
+            Line::from(vec![
+                Span::styled(format!(" {}: ", w.title), Style::default().fg(t.muted)),
+                Span::styled(value, Style::default().fg(t.fg)),
+            ])
+        })
+        .collect();
+
+    let p = Paragraph::new(lines).block(
+        Block::default()
+            .title(" From Engine ")
+            .title_style(Style::default().fg(t.muted))
+            .borders(Borders::TOP)
+            .border_style(Style::default().fg(t.border)),
+    );
+    frame.render_widget(p, area);
+}
+
 fn render_quick_actions(frame: &mut Frame, area: Rect, t: &theme::ThemeColors) {
     let lines = vec![
         Line::from(vec![
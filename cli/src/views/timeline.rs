@@ -61,38 +61,17 @@ pub fn is_past(date: (u16, u8, u8)) -> bool {
 
 /// Days until a milestone (negative if past).
 pub fn days_until(date: (u16, u8, u8)) -> i64 {
-    let now_secs = std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_secs();
-    #[allow(clippy::cast_possible_wrap)]
-    let now_days = (now_secs / 86400) as i64;
     let target_days = days_from_date(date);
-    target_days - now_days
+    target_days - crate::date::today_epoch_days()
 }
 
-/// Approximate days since Unix epoch for a date.
+/// Days since the Unix epoch for a milestone date.
 fn days_from_date(date: (u16, u8, u8)) -> i64 {
-    let (y, m, d) = (i64::from(date.0), i64::from(date.1), i64::from(date.2));
-    let mut days = (y - 1970) * 365 + (y - 1969) / 4;
-    // Century/400-year leap year correction
-    days += (y - 1) / 400 - (y - 1) / 100 + 1970_i64 / 100 - 1970_i64 / 400;
-    const MONTH_DAYS: [i64; 13] = [0, 0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
-    days += MONTH_DAYS[m as usize] + d - 1;
-    // Leap year adjustment for months after February
-    if m > 2 && (y % 4 == 0 && (y % 100 != 0 || y % 400 == 0)) {
-        days += 1;
-    }
-    days
+    crate::date::ymd_epoch_days(date.0, date.1, date.2).unwrap_or(0)
 }
 
 fn format_date(date: (u16, u8, u8)) -> String {
-    const MONTHS: [&str; 13] = [
-        "", "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
-    ];
-    let m = date.1 as usize;
-    let month = if m < MONTHS.len() { MONTHS[m] } else { "???" };
-    format!("{month} {}, {}", date.2, date.0)
+    crate::locale::format_date(i64::from(date.0), date.1, date.2)
 }
 
 /// Render the Timeline View.
@@ -213,6 +192,29 @@ pub fn render_timeline_view(frame: &mut Frame, area: Rect, app: &App) {
         )));
     }
 
+    // Overdue findings (status/due-date tracked via `s`, `/status`, `/due`)
+    let now_days = crate::date::today_epoch_days();
+    let overdue: Vec<_> = app
+        .finding_states
+        .iter()
+        .filter(|s| crate::findings_state::is_overdue(s, now_days))
+        .collect();
+    if !overdue.is_empty() {
+        lines.push(Line::raw(""));
+        lines.push(Line::from(Span::styled(
+            "  OVERDUE FINDINGS",
+            Style::default().fg(t.zone_red).add_modifier(Modifier::BOLD),
+        )));
+        for state in &overdue {
+            let due = state.due_date.as_deref().unwrap_or("?");
+            lines.push(Line::from(vec![
+                Span::styled("  ! ", Style::default().fg(t.zone_red)),
+                Span::styled(state.check_id.clone(), Style::default().fg(t.zone_red)),
+                Span::styled(format!(" — due {due}"), Style::default().fg(t.muted)),
+            ]));
+        }
+    }
+
     // Scroll hints
     lines.push(Line::raw(""));
     lines.push(Line::from(vec![
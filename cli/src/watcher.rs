@@ -1,64 +1,334 @@
 // File-system watcher for Watch mode — auto-triggers scan on relevant file changes.
 
 use std::path::{Path, PathBuf};
-use std::time::Instant;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 use tokio::sync::mpsc;
 use tokio::task::JoinHandle;
 
-/// Spawn a blocking watcher that sends changed file paths through `tx`.
-/// Uses notify crate's recommended watcher with 500ms debounce.
-pub fn spawn_watcher(project_path: PathBuf, tx: mpsc::UnboundedSender<PathBuf>) -> JoinHandle<()> {
+/// How often the flush thread checks whether the debounce window has
+/// elapsed. Independent of `debounce_ms` — just a poll granularity.
+const FLUSH_POLL_MS: u64 = 50;
+
+/// Kind of filesystem change, as reported by `notify` — surfaced to the
+/// Changes feed panel (see `components::changes_feed`) alongside the path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    Created,
+    Modified,
+    /// `.git/HEAD` or `.git/index` changed — a branch switch or commit,
+    /// rather than a source-file edit.
+    GitRef,
+}
+
+impl ChangeKind {
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Created => "created",
+            Self::Modified => "modified",
+            Self::GitRef => "git",
+        }
+    }
+}
+
+/// Whether `path` is `.git/HEAD` or `.git/index` — the two files that change
+/// on a branch switch or commit. These are watched even though `is_relevant`
+/// would otherwise skip everything under a hidden `.git` directory.
+fn is_git_ref_path(path: &Path) -> bool {
+    matches!(
+        path.file_name().and_then(|n| n.to_str()),
+        Some("HEAD" | "index")
+    ) && path
+        .parent()
+        .and_then(|p| p.file_name())
+        .is_some_and(|n| n == ".git")
+}
+
+/// Symlink and network-filesystem knobs for [`spawn_watcher`], from
+/// `TuiConfig::watch_symlinks`/`watch_symlink_depth`/`watch_poll_interval_ms`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WatchOptions {
+    pub symlinks: SymlinkPolicy,
+    /// Force polling instead of OS-native file-change events, at this
+    /// interval. `None` uses the OS-native backend (inotify/`FSEvents`/etc.),
+    /// which is what most local filesystems want — NFS mounts and some
+    /// Docker bind mounts don't deliver those events reliably.
+    pub poll_interval_ms: Option<u64>,
+}
+
+/// How the watcher treats symlinked directories. `notify`'s recursive watch
+/// never follows symlinks — a backend limitation (inotify watches inodes,
+/// not paths), not a choice — so following them is done by walking the tree
+/// ourselves and adding resolved targets as extra watch roots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SymlinkPolicy {
+    /// Symlinked directories are left unwatched (matches `notify`'s native,
+    /// unassisted behavior).
+    #[default]
+    Ignore,
+    /// Symlinked directories are resolved and watched, following further
+    /// symlinks up to a generous bound that guards against a symlink cycle.
+    Follow,
+    /// Symlinked directories are resolved and watched, following the given
+    /// number of levels of further symlink indirection.
+    Limit(u32),
+}
+
+/// Bound against symlink cycles when `SymlinkPolicy::Follow` has no
+/// explicit depth to work from.
+const FOLLOW_MAX_DEPTH: u32 = 16;
+
+impl SymlinkPolicy {
+    /// From `watch_symlinks` (`"ignore"`, `"follow"`, `"limit"`) and
+    /// `watch_symlink_depth`. Unrecognized values fall back to `Ignore`,
+    /// matching `notify`'s own default behavior.
+    pub fn from_config(policy: &str, depth: u32) -> Self {
+        match policy {
+            "follow" => Self::Follow,
+            "limit" => Self::Limit(depth),
+            _ => Self::Ignore,
+        }
+    }
+
+    const fn max_depth(self) -> u32 {
+        match self {
+            Self::Ignore => 0,
+            Self::Follow => FOLLOW_MAX_DEPTH,
+            Self::Limit(depth) => depth,
+        }
+    }
+}
+
+/// Walk `root` looking for symlinked directories to add as extra watch
+/// targets, up to `policy`'s depth bound. Returns resolved, deduplicated
+/// paths; a symlink that fails to resolve (dangling, permission denied) is
+/// skipped rather than failing the whole scan.
+fn find_symlinked_dirs(root: &Path, policy: SymlinkPolicy) -> Vec<PathBuf> {
+    let max_depth = policy.max_depth();
+    if max_depth == 0 {
+        return Vec::new();
+    }
+
+    let mut found = Vec::new();
+    let mut seen = std::collections::HashSet::new();
+    walk_symlinks(root, max_depth, &mut found, &mut seen);
+    found
+}
+
+fn walk_symlinks(
+    dir: &Path,
+    depth_remaining: u32,
+    found: &mut Vec<PathBuf>,
+    seen: &mut std::collections::HashSet<PathBuf>,
+) {
+    let Ok(read_dir) = std::fs::read_dir(dir) else {
+        return;
+    };
+
+    for entry in read_dir.filter_map(std::result::Result::ok) {
+        let path = entry.path();
+        if !is_relevant(&path) {
+            continue;
+        }
+        let Ok(meta) = std::fs::symlink_metadata(&path) else {
+            continue;
+        };
+
+        if meta.file_type().is_symlink() {
+            if depth_remaining == 0 {
+                continue;
+            }
+            let Ok(target) = std::fs::canonicalize(&path) else {
+                continue; // dangling symlink
+            };
+            if !target.is_dir() || !seen.insert(target.clone()) {
+                continue;
+            }
+            found.push(target.clone());
+            walk_symlinks(&target, depth_remaining - 1, found, seen);
+        } else if meta.is_dir() {
+            walk_symlinks(&path, depth_remaining, found, seen);
+        }
+    }
+}
+
+/// Spawn a blocking watcher that batches changed file paths and sends them
+/// through `tx` once `debounce_ms` has passed with no further changes.
+///
+/// Batching (rather than dropping events during the debounce window, as a
+/// naive debounce would) means a rescan sees every file touched during a
+/// save-all or branch switch, not just whichever one arrived last.
+///
+/// `roots` are watched independently (one `notify` subscription per root),
+/// so a project split across sibling directories (`TuiConfig::watch_roots`)
+/// is watched as one logical project rather than requiring a common
+/// ancestor. `include`/`exclude` are glob patterns from
+/// `TuiConfig::watch_include` / `watch_exclude` (see `PatternSet`); an empty
+/// `include` list means "everything not excluded". `suppressor` lets other
+/// parts of the app (e.g. the fix pipeline) silence events for a
+/// self-inflicted batch of writes without tearing down and recreating the
+/// watcher. `options.symlinks` controls whether symlinked directories under
+/// each root get resolved and watched too; `options.poll_interval_ms` swaps
+/// the OS-native backend for polling, for network filesystems where inotify
+/// doesn't deliver events.
+pub fn spawn_watcher(
+    roots: Vec<PathBuf>,
+    tx: mpsc::UnboundedSender<Vec<(PathBuf, ChangeKind)>>,
+    debounce_ms: u64,
+    patterns: PatternSet,
+    suppressor: WatchSuppressor,
+    options: WatchOptions,
+) -> JoinHandle<()> {
     tokio::task::spawn_blocking(move || {
         use notify::{RecursiveMode, Watcher};
 
-        let tx_clone = tx;
-        let mut last_sent = Instant::now();
+        let pending: Arc<Mutex<Vec<(PathBuf, ChangeKind)>>> = Arc::new(Mutex::new(Vec::new()));
+        let last_event: Arc<Mutex<Instant>> = Arc::new(Mutex::new(Instant::now()));
 
-        let mut watcher =
-            match notify::recommended_watcher(move |res: Result<notify::Event, notify::Error>| {
-                if let Ok(event) = res {
-                    // Only care about Create and Modify events
-                    match event.kind {
-                        notify::EventKind::Create(_) | notify::EventKind::Modify(_) => {}
-                        _ => return,
-                    }
+        let pending_cb = pending.clone();
+        let last_event_cb = last_event.clone();
 
-                    for path in event.paths {
-                        if !is_relevant(&path) {
-                            continue;
-                        }
-                        // Debounce: skip if within 500ms of last send
-                        let now = Instant::now();
-                        if now.duration_since(last_sent).as_millis() < 500 {
-                            continue;
+        let handler = move |res: Result<notify::Event, notify::Error>| {
+            if let Ok(event) = res {
+                // Only care about Create and Modify events
+                let kind = match event.kind {
+                    notify::EventKind::Create(_) => ChangeKind::Created,
+                    notify::EventKind::Modify(_) => ChangeKind::Modified,
+                    _ => return,
+                };
+
+                if suppressor.is_suppressed() {
+                    return;
+                }
+
+                let relevant: Vec<(PathBuf, ChangeKind)> = event
+                    .paths
+                    .into_iter()
+                    .filter_map(|p| {
+                        if is_git_ref_path(&p) {
+                            Some((p, ChangeKind::GitRef))
+                        } else if is_relevant(&p) && patterns.matches(&p) {
+                            Some((p, kind))
+                        } else {
+                            None
                         }
-                        last_sent = now;
-                        let _ = tx_clone.send(path);
+                    })
+                    .collect();
+                if relevant.is_empty() {
+                    return;
+                }
+
+                if let Ok(mut buf) = pending_cb.lock() {
+                    buf.extend(relevant);
+                }
+                if let Ok(mut last) = last_event_cb.lock() {
+                    *last = Instant::now();
+                }
+            }
+        };
+
+        // Native OS events (inotify/FSEvents/etc.) by default; polling when
+        // `options.poll_interval_ms` is set, for filesystems (NFS, some
+        // Docker bind mounts) that don't deliver native events reliably.
+        let mut watcher: Box<dyn Watcher> = match options.poll_interval_ms {
+            Some(ms) => {
+                let poll_config =
+                    notify::Config::default().with_poll_interval(Duration::from_millis(ms));
+                match notify::PollWatcher::new(handler, poll_config) {
+                    Ok(w) => Box::new(w),
+                    Err(e) => {
+                        tracing::error!("Failed to create polling watcher: {e}");
+                        return;
                     }
                 }
-            }) {
-                Ok(w) => w,
+            }
+            None => match notify::recommended_watcher(handler) {
+                Ok(w) => Box::new(w),
                 Err(e) => {
                     tracing::error!("Failed to create watcher: {e}");
                     return;
                 }
-            };
+            },
+        };
 
-        if let Err(e) = watcher.watch(&project_path, RecursiveMode::Recursive) {
-            tracing::error!("Failed to watch {}: {e}", project_path.display());
+        // A misconfigured extra root shouldn't take down watching of the
+        // ones that are valid — log and keep going. Symlinked directories
+        // aren't followed by `notify` itself, so resolve and watch them
+        // separately per `options.symlinks`.
+        let mut watched = Vec::new();
+        for root in &roots {
+            match watcher.watch(root, RecursiveMode::Recursive) {
+                Ok(()) => watched.push(root.display().to_string()),
+                Err(e) => tracing::error!("Failed to watch {}: {e}", root.display()),
+            }
+            for symlinked in find_symlinked_dirs(root, options.symlinks) {
+                match watcher.watch(&symlinked, RecursiveMode::Recursive) {
+                    Ok(()) => watched.push(symlinked.display().to_string()),
+                    Err(e) => {
+                        tracing::warn!("Failed to watch symlinked {}: {e}", symlinked.display());
+                    }
+                }
+            }
+        }
+        if watched.is_empty() {
+            tracing::error!("No watch roots could be watched — watch mode is inactive");
             return;
         }
 
-        tracing::info!("Watching {} for changes", project_path.display());
+        tracing::info!(
+            "Watching {} for changes (debounce {debounce_ms}ms)",
+            watched.join(", ")
+        );
 
-        // Block forever — watcher lives until task is aborted
+        // Flush loop: once `debounce_ms` has elapsed since the last change,
+        // drain whatever accumulated and send it as one batch.
         loop {
-            std::thread::sleep(std::time::Duration::from_hours(1));
+            std::thread::sleep(Duration::from_millis(FLUSH_POLL_MS));
+
+            let quiet_for = last_event
+                .lock()
+                .map_or(Duration::ZERO, |last| last.elapsed());
+            if quiet_for < Duration::from_millis(debounce_ms) {
+                continue;
+            }
+
+            let Ok(mut buf) = pending.lock() else {
+                continue;
+            };
+            if buf.is_empty() {
+                continue;
+            }
+            let batch = std::mem::take(&mut *buf);
+            drop(buf);
+            let _ = tx.send(batch);
         }
     })
 }
 
+/// Current branch (or short commit hash if detached) and short commit hash,
+/// for the system message shown when `.git/HEAD`/`.git/index` change.
+/// Returns `None` if `git` isn't available or the directory isn't a repo.
+pub fn git_head_summary(project_path: &Path) -> Option<(String, String)> {
+    let branch = std::process::Command::new("git")
+        .args(["rev-parse", "--abbrev-ref", "HEAD"])
+        .current_dir(project_path)
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string())?;
+
+    let commit = std::process::Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .current_dir(project_path)
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string())?;
+
+    Some((branch, commit))
+}
+
 /// Filter: skip hidden files/dirs, `node_modules`, `target`, `.git`, etc.
 pub fn is_relevant(path: &Path) -> bool {
     // Check each path component
@@ -81,6 +351,102 @@ pub fn is_relevant(path: &Path) -> bool {
     true
 }
 
+/// Silences watcher events on request, without tearing down the watcher.
+///
+/// Shared (via `Clone`) between the watcher task and the fix pipeline: while
+/// fixes are being applied to disk, the app suppresses events so the writes
+/// don't queue a second, redundant `AutoScan` on top of the fix pipeline's
+/// own rescan.
+#[derive(Debug, Clone, Default)]
+pub struct WatchSuppressor {
+    suppressed: Arc<Mutex<bool>>,
+}
+
+impl WatchSuppressor {
+    pub fn suppress(&self) {
+        if let Ok(mut s) = self.suppressed.lock() {
+            *s = true;
+        }
+    }
+
+    pub fn resume(&self) {
+        if let Ok(mut s) = self.suppressed.lock() {
+            *s = false;
+        }
+    }
+
+    fn is_suppressed(&self) -> bool {
+        self.suppressed.lock().is_ok_and(|s| *s)
+    }
+}
+
+/// Compiled `watch_include`/`watch_exclude` glob patterns from `TuiConfig`.
+///
+/// An empty `include` list means "everything not excluded" — matching the
+/// existing `is_relevant` behavior of allowing by default and filtering out
+/// specific noise.
+#[derive(Debug, Clone, Default)]
+pub struct PatternSet {
+    include: Vec<regex::Regex>,
+    exclude: Vec<regex::Regex>,
+}
+
+impl PatternSet {
+    /// Compile glob patterns (`*`, `**`, `?`) into a matchable set. Patterns
+    /// that fail to compile are dropped with a warning rather than failing
+    /// watch-mode startup entirely.
+    pub fn new(include: &[String], exclude: &[String]) -> Self {
+        Self {
+            include: include.iter().filter_map(|p| compile_glob(p)).collect(),
+            exclude: exclude.iter().filter_map(|p| compile_glob(p)).collect(),
+        }
+    }
+
+    /// Whether `path` passes this pattern set: not excluded, and either no
+    /// include patterns are configured or it matches at least one.
+    pub fn matches(&self, path: &Path) -> bool {
+        let path_str = path.to_string_lossy();
+
+        if self.exclude.iter().any(|re| re.is_match(&path_str)) {
+            return false;
+        }
+
+        self.include.is_empty() || self.include.iter().any(|re| re.is_match(&path_str))
+    }
+}
+
+/// Translate a shell-style glob (`*`, `**`, `?`) into an anchored regex.
+/// `**` matches across path separators, `*` stops at `/`, `?` matches one
+/// non-separator character. Everything else is treated literally.
+fn compile_glob(pattern: &str) -> Option<regex::Regex> {
+    let mut regex_str = String::from("^");
+    let mut chars = pattern.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '*' => {
+                if chars.peek() == Some(&'*') {
+                    chars.next();
+                    regex_str.push_str(".*");
+                } else {
+                    regex_str.push_str("[^/]*");
+                }
+            }
+            '?' => regex_str.push_str("[^/]"),
+            _ => regex_str.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    regex_str.push('$');
+
+    match regex::Regex::new(&regex_str) {
+        Ok(re) => Some(re),
+        Err(e) => {
+            tracing::warn!("Invalid watch pattern {pattern:?}: {e}");
+            None
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -102,41 +468,139 @@ mod tests {
     }
 
     #[test]
-    fn test_debounce_skips_fast_events() {
-        // Debounce logic is internal to the watcher callback.
-        // We test the timing contract: two events within 500ms should produce at most one send.
-        use std::sync::{
-            Arc,
-            atomic::{AtomicUsize, Ordering},
-        };
+    fn test_watcher_batches_rapid_events_into_one_send() {
+        // Mirrors the flush loop's decision logic without spinning up notify.
+        let pending: Arc<Mutex<Vec<(PathBuf, ChangeKind)>>> = Arc::new(Mutex::new(Vec::new()));
+        let last_event = Arc::new(Mutex::new(Instant::now()));
+        let debounce_ms = 500;
 
-        let counter = Arc::new(AtomicUsize::new(0));
-        let counter_clone = counter.clone();
+        // Three rapid changes land in the buffer.
+        for name in ["a.rs", "b.rs", "c.rs"] {
+            pending
+                .lock()
+                .unwrap()
+                .push((PathBuf::from(name), ChangeKind::Modified));
+            *last_event.lock().unwrap() = Instant::now();
+        }
 
-        // Set last_sent far in the past so first event passes
-        let mut last_sent = Instant::now() - std::time::Duration::from_secs(10);
-        let debounce_ms: u128 = 500;
+        // Debounce window hasn't elapsed yet — nothing should flush.
+        let quiet_for = last_event.lock().unwrap().elapsed();
+        assert!(quiet_for < Duration::from_millis(debounce_ms));
 
-        // Simulate event processing
-        let process_event = |last: &mut Instant, counter: &AtomicUsize| {
-            let now = Instant::now();
-            if now.duration_since(*last).as_millis() >= debounce_ms {
-                *last = now;
-                counter.fetch_add(1, Ordering::SeqCst);
-            }
-        };
+        std::thread::sleep(Duration::from_millis(debounce_ms + 50));
+
+        let quiet_for = last_event.lock().unwrap().elapsed();
+        assert!(quiet_for >= Duration::from_millis(debounce_ms));
 
-        // First event: should pass (10s since last_sent)
-        process_event(&mut last_sent, &counter_clone);
-        assert_eq!(counter.load(Ordering::SeqCst), 1);
+        let batch = std::mem::take(&mut *pending.lock().unwrap());
+        assert_eq!(batch.len(), 3);
+    }
+
+    #[test]
+    fn test_watch_suppressor_toggles() {
+        let suppressor = WatchSuppressor::default();
+        assert!(!suppressor.is_suppressed());
+        suppressor.suppress();
+        assert!(suppressor.is_suppressed());
+        suppressor.resume();
+        assert!(!suppressor.is_suppressed());
+    }
+
+    #[test]
+    fn test_watch_suppressor_clone_shares_state() {
+        let suppressor = WatchSuppressor::default();
+        let clone = suppressor.clone();
+        clone.suppress();
+        assert!(suppressor.is_suppressed());
+    }
+
+    #[test]
+    fn test_pattern_set_empty_include_allows_everything_not_excluded() {
+        let patterns = PatternSet::new(&[], &["**/*_test.rs".to_string()]);
+        assert!(patterns.matches(Path::new("src/main.rs")));
+        assert!(!patterns.matches(Path::new("src/main_test.rs")));
+    }
+
+    #[test]
+    fn test_pattern_set_include_restricts_to_matches() {
+        let patterns = PatternSet::new(&["src/**/*.rs".to_string()], &[]);
+        assert!(patterns.matches(Path::new("src/watcher.rs")));
+        assert!(patterns.matches(Path::new("src/app/mod.rs")));
+        assert!(!patterns.matches(Path::new("README.md")));
+    }
+
+    #[test]
+    fn test_is_git_ref_path() {
+        assert!(is_git_ref_path(Path::new(".git/HEAD")));
+        assert!(is_git_ref_path(Path::new("/repo/.git/index")));
+        assert!(!is_git_ref_path(Path::new(".git/config")));
+        assert!(!is_git_ref_path(Path::new("src/HEAD")));
+        assert!(!is_git_ref_path(Path::new("HEAD")));
+    }
+
+    #[test]
+    fn test_pattern_set_exclude_wins_over_include() {
+        let patterns = PatternSet::new(
+            &["src/**/*.rs".to_string()],
+            &["src/**/*_test.rs".to_string()],
+        );
+        assert!(patterns.matches(Path::new("src/watcher.rs")));
+        assert!(!patterns.matches(Path::new("src/watcher_test.rs")));
+    }
+
+    #[test]
+    fn test_symlink_policy_from_config() {
+        assert_eq!(SymlinkPolicy::from_config("ignore", 3), SymlinkPolicy::Ignore);
+        assert_eq!(SymlinkPolicy::from_config("follow", 3), SymlinkPolicy::Follow);
+        assert_eq!(SymlinkPolicy::from_config("limit", 5), SymlinkPolicy::Limit(5));
+        // Unrecognized values fall back to Ignore, matching notify's own default.
+        assert_eq!(SymlinkPolicy::from_config("bogus", 3), SymlinkPolicy::Ignore);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_find_symlinked_dirs_ignore_policy_finds_nothing() {
+        let dir =
+            std::env::temp_dir().join(format!("complior-watcher-test-ignore-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("real")).unwrap();
+        std::os::unix::fs::symlink(dir.join("real"), dir.join("link")).unwrap();
+
+        let found = find_symlinked_dirs(&dir, SymlinkPolicy::Ignore);
+        assert!(found.is_empty());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_find_symlinked_dirs_follow_policy_resolves_symlink() {
+        let dir =
+            std::env::temp_dir().join(format!("complior-watcher-test-follow-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let real = dir.join("real");
+        std::fs::create_dir_all(&real).unwrap();
+        std::os::unix::fs::symlink(&real, dir.join("link")).unwrap();
+
+        let found = find_symlinked_dirs(&dir, SymlinkPolicy::Follow);
+        assert_eq!(found, vec![real.canonicalize().unwrap()]);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_find_symlinked_dirs_limit_zero_finds_nothing() {
+        let dir =
+            std::env::temp_dir().join(format!("complior-watcher-test-limit0-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let real = dir.join("real");
+        std::fs::create_dir_all(&real).unwrap();
+        std::os::unix::fs::symlink(&real, dir.join("link")).unwrap();
 
-        // Immediate second event: should be debounced
-        process_event(&mut last_sent, &counter_clone);
-        assert_eq!(counter.load(Ordering::SeqCst), 1);
+        let found = find_symlinked_dirs(&dir, SymlinkPolicy::Limit(0));
+        assert!(found.is_empty());
 
-        // After sleeping past debounce window
-        std::thread::sleep(std::time::Duration::from_millis(550));
-        process_event(&mut last_sent, &counter_clone);
-        assert_eq!(counter.load(Ordering::SeqCst), 2);
+        let _ = std::fs::remove_dir_all(&dir);
     }
 }